@@ -21,11 +21,15 @@ Register-ArgumentCompleter -Native -CommandName 'feroxbuster' -ScriptBlock {
 
     $completions = @(switch ($command) {
         'feroxbuster' {
-            [CompletionResult]::new('-u', 'u', [CompletionResultType]::ParameterName, 'The target URL (required, unless [--stdin || --resume-from] used)')
-            [CompletionResult]::new('--url', 'url', [CompletionResultType]::ParameterName, 'The target URL (required, unless [--stdin || --resume-from] used)')
+            [CompletionResult]::new('-u', 'u', [CompletionResultType]::ParameterName, 'The target URL (required, unless [--stdin || --resume-from || --test-server || --diff] used)')
+            [CompletionResult]::new('--url', 'url', [CompletionResultType]::ParameterName, 'The target URL (required, unless [--stdin || --resume-from || --test-server || --diff] used)')
             [CompletionResult]::new('--resume-from', 'resume-from', [CompletionResultType]::ParameterName, 'State file from which to resume a partially complete scan (ex. --resume-from ferox-1606586780.state)')
+            [CompletionResult]::new('--test-server', 'test-server', [CompletionResultType]::ParameterName, 'Run a local HTTP server for testing feroxbuster itself, instead of scanning')
+            [CompletionResult]::new('--test-server-rules', 'test-server-rules', [CompletionResultType]::ParameterName, 'TOML file of path_pattern -> {status, body} rules used by --test-server')
+            [CompletionResult]::new('--diff', 'diff', [CompletionResultType]::ParameterName, 'Compare two state files and print the responses and scans that differ, instead of scanning (ex: --diff first.state resumed.state)')
             [CompletionResult]::new('-p', 'p', [CompletionResultType]::ParameterName, 'Proxy to use for requests (ex: http(s)://host:port, socks5(h)://host:port)')
             [CompletionResult]::new('--proxy', 'proxy', [CompletionResultType]::ParameterName, 'Proxy to use for requests (ex: http(s)://host:port, socks5(h)://host:port)')
+            [CompletionResult]::new('--proxy-exclude-hosts', 'proxy-exclude-hosts', [CompletionResultType]::ParameterName, 'Hosts that bypass --proxy, in the same format as no_proxy (ex: --proxy-exclude-hosts localhost .corp.local 10.0.0.0/8)')
             [CompletionResult]::new('-P', 'P', [CompletionResultType]::ParameterName, 'Send only unfiltered requests through a Replay Proxy, instead of all requests')
             [CompletionResult]::new('--replay-proxy', 'replay-proxy', [CompletionResultType]::ParameterName, 'Send only unfiltered requests through a Replay Proxy, instead of all requests')
             [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'Status Codes to send through a Replay Proxy when found (default: --status-codes value)')
@@ -37,6 +41,8 @@ Register-ArgumentCompleter -Native -CommandName 'feroxbuster' -ScriptBlock {
             [CompletionResult]::new('-m', 'm', [CompletionResultType]::ParameterName, 'Which HTTP request method(s) should be sent (default: GET)')
             [CompletionResult]::new('--methods', 'methods', [CompletionResultType]::ParameterName, 'Which HTTP request method(s) should be sent (default: GET)')
             [CompletionResult]::new('--data', 'data', [CompletionResultType]::ParameterName, 'Request''s Body; can read data from a file if input starts with an @ (ex: @post.bin)')
+            [CompletionResult]::new('--multipart-wordlist', 'multipart-wordlist', [CompletionResultType]::ParameterName, 'POST a multipart/form-data file upload for each word in FILE, using the word as the uploaded filename')
+            [CompletionResult]::new('--multipart-field', 'multipart-field', [CompletionResultType]::ParameterName, 'Form field name used by --multipart-wordlist (default: file)')
             [CompletionResult]::new('-H', 'H', [CompletionResultType]::ParameterName, 'Specify HTTP headers to be used in each request (ex: -H Header:val -H ''stuff: things'')')
             [CompletionResult]::new('--headers', 'headers', [CompletionResultType]::ParameterName, 'Specify HTTP headers to be used in each request (ex: -H Header:val -H ''stuff: things'')')
             [CompletionResult]::new('-b', 'b', [CompletionResultType]::ParameterName, 'Specify HTTP cookies to be used in each request (ex: -b stuff=things)')
@@ -46,35 +52,75 @@ Register-ArgumentCompleter -Native -CommandName 'feroxbuster' -ScriptBlock {
             [CompletionResult]::new('--dont-scan', 'dont-scan', [CompletionResultType]::ParameterName, 'URL(s) or Regex Pattern(s) to exclude from recursion/scans')
             [CompletionResult]::new('-S', 'S', [CompletionResultType]::ParameterName, 'Filter out messages of a particular size (ex: -S 5120 -S 4927,1970)')
             [CompletionResult]::new('--filter-size', 'filter-size', [CompletionResultType]::ParameterName, 'Filter out messages of a particular size (ex: -S 5120 -S 4927,1970)')
+            [CompletionResult]::new('--filter-near-empty-body', 'filter-near-empty-body', [CompletionResultType]::ParameterName, 'Filter out responses whose body is at most MAX bytes')
+            [CompletionResult]::new('--filter-body-size-range', 'filter-body-size-range', [CompletionResultType]::ParameterName, 'Filter out messages whose size falls within a range; MAX may be ''unlimited'' (ex: --filter-body-size-range 100-200)')
+            [CompletionResult]::new('--keep-body-size-range', 'keep-body-size-range', [CompletionResultType]::ParameterName, 'Filter out messages whose size falls outside of every given range (ex: --keep-body-size-range 1000-unlimited)')
+            [CompletionResult]::new('--json-path-filter', 'json-path-filter', [CompletionResultType]::ParameterName, 'Filter out JSON responses matching a JSONPath expression (ex: --json-path-filter ''$.status == "error"'')')
+            [CompletionResult]::new('--json-path-keep-if', 'json-path-keep-if', [CompletionResultType]::ParameterName, 'Filter out JSON responses matching none of the given JSONPath expressions (ex: --json-path-keep-if ''$.data[0].id'')')
             [CompletionResult]::new('-X', 'X', [CompletionResultType]::ParameterName, 'Filter out messages via regular expression matching on the response''s body (ex: -X ''^ignore me$'')')
             [CompletionResult]::new('--filter-regex', 'filter-regex', [CompletionResultType]::ParameterName, 'Filter out messages via regular expression matching on the response''s body (ex: -X ''^ignore me$'')')
+            [CompletionResult]::new('--filter-extension', 'filter-extension', [CompletionResultType]::ParameterName, 'Filter out messages whose url ends with a particular extension (ex: --filter-extension png,jpg)')
             [CompletionResult]::new('-W', 'W', [CompletionResultType]::ParameterName, 'Filter out messages of a particular word count (ex: -W 312 -W 91,82)')
             [CompletionResult]::new('--filter-words', 'filter-words', [CompletionResultType]::ParameterName, 'Filter out messages of a particular word count (ex: -W 312 -W 91,82)')
+            [CompletionResult]::new('--filter-words-range', 'filter-words-range', [CompletionResultType]::ParameterName, 'Filter out messages whose word count falls within a range (ex: --filter-words-range 100-500)')
             [CompletionResult]::new('-N', 'N', [CompletionResultType]::ParameterName, 'Filter out messages of a particular line count (ex: -N 20 -N 31,30)')
             [CompletionResult]::new('--filter-lines', 'filter-lines', [CompletionResultType]::ParameterName, 'Filter out messages of a particular line count (ex: -N 20 -N 31,30)')
+            [CompletionResult]::new('--filter-lines-range', 'filter-lines-range', [CompletionResultType]::ParameterName, 'Filter out messages whose line count falls within a range (ex: --filter-lines-range 10-20)')
+            [CompletionResult]::new('--filter-response-time-above', 'filter-response-time-above', [CompletionResultType]::ParameterName, 'Filter out responses that took longer than the given number of milliseconds')
+            [CompletionResult]::new('--filter-response-time-below', 'filter-response-time-below', [CompletionResultType]::ParameterName, 'Filter out responses that took less than the given number of milliseconds')
+            [CompletionResult]::new('--filter-max-headers', 'filter-max-headers', [CompletionResultType]::ParameterName, 'Filter out responses with more than the given number of headers')
+            [CompletionResult]::new('--filter-min-headers', 'filter-min-headers', [CompletionResultType]::ParameterName, 'Filter out responses with fewer than the given number of headers')
             [CompletionResult]::new('-C', 'C', [CompletionResultType]::ParameterName, 'Filter out status codes (deny list) (ex: -C 200 -C 401)')
             [CompletionResult]::new('--filter-status', 'filter-status', [CompletionResultType]::ParameterName, 'Filter out status codes (deny list) (ex: -C 200 -C 401)')
+            [CompletionResult]::new('--output-filter-status', 'output-filter-status', [CompletionResultType]::ParameterName, 'Only write the given status codes to --output; the terminal is unaffected (ex: --output-filter-status 200)')
             [CompletionResult]::new('--filter-similar-to', 'filter-similar-to', [CompletionResultType]::ParameterName, 'Filter out pages that are similar to the given page (ex. --filter-similar-to http://site.xyz/soft404)')
+            [CompletionResult]::new('--filter-min-ratio', 'filter-min-ratio', [CompletionResultType]::ParameterName, 'Filter out responses whose size is within the given ratio (0.0-1.0) of the target''s wildcard response (ex: --filter-min-ratio 0.95)')
             [CompletionResult]::new('-s', 's', [CompletionResultType]::ParameterName, 'Status Codes to include (allow list) (default: 200 204 301 302 307 308 401 403 405)')
             [CompletionResult]::new('--status-codes', 'status-codes', [CompletionResultType]::ParameterName, 'Status Codes to include (allow list) (default: 200 204 301 302 307 308 401 403 405)')
             [CompletionResult]::new('-T', 'T', [CompletionResultType]::ParameterName, 'Number of seconds before a client''s request times out (default: 7)')
             [CompletionResult]::new('--timeout', 'timeout', [CompletionResultType]::ParameterName, 'Number of seconds before a client''s request times out (default: 7)')
+            [CompletionResult]::new('--dns-server', 'dns-server', [CompletionResultType]::ParameterName, 'DNS server used to resolve hostnames (ex: --dns-server 10.0.0.2:53)')
+            [CompletionResult]::new('--dns-over-https', 'dns-over-https', [CompletionResultType]::ParameterName, 'DNS over HTTPS server used to resolve hostnames (ex: --dns-over-https https://1.1.1.1/dns-query)')
             [CompletionResult]::new('-t', 't', [CompletionResultType]::ParameterName, 'Number of concurrent threads (default: 50)')
             [CompletionResult]::new('--threads', 'threads', [CompletionResultType]::ParameterName, 'Number of concurrent threads (default: 50)')
+            [CompletionResult]::new('--threads-burst', 'threads-burst', [CompletionResultType]::ParameterName, 'Number of concurrent requests allowed at the start of each scan, before settling back to --threads')
+            [CompletionResult]::new('--threads-burst-duration', 'threads-burst-duration', [CompletionResultType]::ParameterName, 'Milliseconds that --threads-burst lasts at the start of each scan (default: 1000)')
             [CompletionResult]::new('-d', 'd', [CompletionResultType]::ParameterName, 'Maximum recursion depth, a depth of 0 is infinite recursion (default: 4)')
             [CompletionResult]::new('--depth', 'depth', [CompletionResultType]::ParameterName, 'Maximum recursion depth, a depth of 0 is infinite recursion (default: 4)')
+            [CompletionResult]::new('--max-retries', 'max-retries', [CompletionResultType]::ParameterName, 'Number of times an unfinished scan may be resumed before it''s marked as failed (default: 3)')
+            [CompletionResult]::new('--recurse-on-status', 'recurse-on-status', [CompletionResultType]::ParameterName, 'Only recurse into directories found with the given status codes (default: 200 301 302)')
+            [CompletionResult]::new('--check-previous-state', 'check-previous-state', [CompletionResultType]::ParameterName, 'Skip urls already found (non-4xx/5xx) in the given state file (ex: --check-previous-state ferox-1606586780.state)')
+            [CompletionResult]::new('--inline-js-dir', 'inline-js-dir', [CompletionResultType]::ParameterName, 'Directory in which --collect-inline-js writes its scripts (default: ferox-inline-js)')
             [CompletionResult]::new('-L', 'L', [CompletionResultType]::ParameterName, 'Limit total number of concurrent scans (default: 0, i.e. no limit)')
             [CompletionResult]::new('--scan-limit', 'scan-limit', [CompletionResultType]::ParameterName, 'Limit total number of concurrent scans (default: 0, i.e. no limit)')
+            [CompletionResult]::new('--scan-limit-per-host', 'scan-limit-per-host', [CompletionResultType]::ParameterName, 'Limit number of concurrent scans against a single host (default: 0, i.e. no limit)')
             [CompletionResult]::new('--parallel', 'parallel', [CompletionResultType]::ParameterName, 'Run parallel feroxbuster instances (one child process per url passed via stdin)')
             [CompletionResult]::new('--rate-limit', 'rate-limit', [CompletionResultType]::ParameterName, 'Limit number of requests per second (per directory) (default: 0, i.e. no limit)')
+            [CompletionResult]::new('--rate-limit-recovery', 'rate-limit-recovery', [CompletionResultType]::ParameterName, 'Seconds without a 429 before --rate-limit-detect raises the rate again (default: 10)')
+            [CompletionResult]::new('--max-urls-per-scan', 'max-urls-per-scan', [CompletionResultType]::ParameterName, 'Stop requesting words for a scan once it has found this many urls (default: 0, i.e. no limit)')
+            [CompletionResult]::new('--max-response-size', 'max-response-size', [CompletionResultType]::ParameterName, 'Only read up to this many bytes of each response body (default: 0, i.e. no limit)')
             [CompletionResult]::new('--time-limit', 'time-limit', [CompletionResultType]::ParameterName, 'Limit total run time of all scans (ex: --time-limit 10m)')
+            [CompletionResult]::new('--checkpoint-interval', 'checkpoint-interval', [CompletionResultType]::ParameterName, 'Write a state file every TIME_SPEC, keeping only the latest (ex: --checkpoint-interval 5m)')
             [CompletionResult]::new('-w', 'w', [CompletionResultType]::ParameterName, 'Path to the wordlist')
             [CompletionResult]::new('--wordlist', 'wordlist', [CompletionResultType]::ParameterName, 'Path to the wordlist')
+            [CompletionResult]::new('--prefix-wordlist', 'prefix-wordlist', [CompletionResultType]::ParameterName, 'Insert each word in FILE in front of the target''s path instead of appending it (ex: /api/v1/ -> /WORD/api/v1/); implies --no-recursion')
+            [CompletionResult]::new('--wordlist-encoding', 'wordlist-encoding', [CompletionResultType]::ParameterName, 'Character encoding of the wordlist, e.g. latin1 or windows-1252 (default: utf-8)')
+            [CompletionResult]::new('--connection-errors-to-file', 'connection-errors-to-file', [CompletionResultType]::ParameterName, 'Write connection/timeout errors to the given file (one JSON object per line)')
             [CompletionResult]::new('-I', 'I', [CompletionResultType]::ParameterName, 'File extension(s) to Ignore while collecting extensions (only used with --collect-extensions)')
             [CompletionResult]::new('--dont-collect', 'dont-collect', [CompletionResultType]::ParameterName, 'File extension(s) to Ignore while collecting extensions (only used with --collect-extensions)')
+            [CompletionResult]::new('--html-gallery-dir', 'html-gallery-dir', [CompletionResultType]::ParameterName, 'Directory in which --store-responses-as-html writes its pages (default: ferox-gallery)')
             [CompletionResult]::new('-o', 'o', [CompletionResultType]::ParameterName, 'Output file to write results to (use w/ --json for JSON entries)')
             [CompletionResult]::new('--output', 'output', [CompletionResultType]::ParameterName, 'Output file to write results to (use w/ --json for JSON entries)')
+            [CompletionResult]::new('--tee', 'tee', [CompletionResultType]::ParameterName, 'Also write each result printed to the terminal to FILE, colors included (unlike -o)')
+            [CompletionResult]::new('--sort-by', 'sort-by', [CompletionResultType]::ParameterName, 'Sort key used by --sort-output (default: status, then url path)')
+            [CompletionResult]::new('--output-rotate-size', 'output-rotate-size', [CompletionResultType]::ParameterName, 'Start a new output file (FILE.1, FILE.2, ...) once the current one exceeds this many bytes')
+            [CompletionResult]::new('--output-rotate-max-files', 'output-rotate-max-files', [CompletionResultType]::ParameterName, 'Keep at most this many output files when rotating, deleting the oldest (default: 0, i.e. no limit)')
             [CompletionResult]::new('--debug-log', 'debug-log', [CompletionResultType]::ParameterName, 'Output file to write log entries (use w/ --json for JSON entries)')
+            [CompletionResult]::new('--export-metasploit', 'export-metasploit', [CompletionResultType]::ParameterName, 'Write discovered urls to a Metasploit resource script (ex: --export-metasploit ferox.rc)')
+            [CompletionResult]::new('--notify-url', 'notify-url', [CompletionResultType]::ParameterName, 'POST a JSON summary of the scan to the given URL once all scans complete')
+            [CompletionResult]::new('--exit-code-on-found', 'exit-code-on-found', [CompletionResultType]::ParameterName, 'Exit with the given code when at least one url is discovered (default: 0)')
+            [CompletionResult]::new('--exit-code-on-none', 'exit-code-on-none', [CompletionResultType]::ParameterName, 'Exit with the given code when no urls are discovered (default: 0)')
+            [CompletionResult]::new('--post-scan-script', 'post-scan-script', [CompletionResultType]::ParameterName, 'Run the given script (.sh, .py, or any executable) with a state file once all scans complete; a non-zero exit code becomes feroxbuster''s')
             [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
             [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
             [CompletionResult]::new('-V', 'V', [CompletionResultType]::ParameterName, 'Print version information')
@@ -88,6 +134,11 @@ Register-ArgumentCompleter -Native -CommandName 'feroxbuster' -ScriptBlock {
             [CompletionResult]::new('--random-agent', 'random-agent', [CompletionResultType]::ParameterName, 'Use a random User-Agent')
             [CompletionResult]::new('-f', 'f', [CompletionResultType]::ParameterName, 'Append / to each request''s URL')
             [CompletionResult]::new('--add-slash', 'add-slash', [CompletionResultType]::ParameterName, 'Append / to each request''s URL')
+            [CompletionResult]::new('--filter-empty-body', 'filter-empty-body', [CompletionResultType]::ParameterName, 'Filter out responses with an empty body (same as -S 0)')
+            [CompletionResult]::new('--include-all-status', 'include-all-status', [CompletionResultType]::ParameterName, 'Show responses of every status code; overrides --status-codes and --filter-status')
+            [CompletionResult]::new('--include-unconfirmed-redirects', 'include-unconfirmed-redirects', [CompletionResultType]::ParameterName, 'Show 3xx responses that redirect to an unscanned url, even when filtered by status code')
+            [CompletionResult]::new('--interesting-only', 'interesting-only', [CompletionResultType]::ParameterName, 'Only show responses that score as interesting (status, content type, size, depth, and keywords like admin/api/config/backup)')
+            [CompletionResult]::new('--filter-duplicate-content', 'filter-duplicate-content', [CompletionResultType]::ParameterName, 'Filter out responses whose body is identical to one already shown')
             [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Allow client to follow redirects')
             [CompletionResult]::new('--redirects', 'redirects', [CompletionResultType]::ParameterName, 'Allow client to follow redirects')
             [CompletionResult]::new('-k', 'k', [CompletionResultType]::ParameterName, 'Disables TLS certificate validation in the client')
@@ -95,8 +146,39 @@ Register-ArgumentCompleter -Native -CommandName 'feroxbuster' -ScriptBlock {
             [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Do not scan recursively')
             [CompletionResult]::new('--no-recursion', 'no-recursion', [CompletionResultType]::ParameterName, 'Do not scan recursively')
             [CompletionResult]::new('--force-recursion', 'force-recursion', [CompletionResultType]::ParameterName, 'Force recursion attempts on all ''found'' endpoints (still respects recursion depth)')
+            [CompletionResult]::new('--no-redundant-scans', 'no-redundant-scans', [CompletionResultType]::ParameterName, 'Skip targets and discovered urls (redirects, forms, etc) that fall beneath a directory that''s already being scanned')
             [CompletionResult]::new('-e', 'e', [CompletionResultType]::ParameterName, 'Extract links from response body (html, javascript, etc...); make new requests based on findings')
             [CompletionResult]::new('--extract-links', 'extract-links', [CompletionResultType]::ParameterName, 'Extract links from response body (html, javascript, etc...); make new requests based on findings')
+            [CompletionResult]::new('--no-extract-links', 'no-extract-links', [CompletionResultType]::ParameterName, 'Disable link extraction, even when enabled by a config file or --smart/--thorough')
+            [CompletionResult]::new('--redirect-as-discovery', 'redirect-as-discovery', [CompletionResultType]::ParameterName, 'Scan the destination of same-host 3xx redirects as newly discovered targets')
+            [CompletionResult]::new('--follow-meta-refresh', 'follow-meta-refresh', [CompletionResultType]::ParameterName, 'Scan the destination of same-host <meta http-equiv="refresh"> redirects as newly discovered targets')
+            [CompletionResult]::new('--collect-parameters', 'collect-parameters', [CompletionResultType]::ParameterName, 'Collect query parameter names from responses and save them to ferox-params-<host>-<timestamp>.txt')
+            [CompletionResult]::new('--collect-page-titles', 'collect-page-titles', [CompletionResultType]::ParameterName, 'Show the <title> of html pages next to their urls and save them to ferox-titles-<host>-<timestamp>.txt')
+            [CompletionResult]::new('--collect-meta-tags', 'collect-meta-tags', [CompletionResultType]::ParameterName, 'Collect <meta> tags (generator, author, etc) from html pages and save them to ferox-metatags-<host>-<timestamp>.json')
+            [CompletionResult]::new('--collect-inline-js', 'collect-inline-js', [CompletionResultType]::ParameterName, 'Write the inline <script> blocks of html pages to --inline-js-dir, one file per block')
+            [CompletionResult]::new('--collect-security-headers', 'collect-security-headers', [CompletionResultType]::ParameterName, 'Report which html pages are missing common security headers (CSP, HSTS, etc)')
+            [CompletionResult]::new('--collect-hsts', 'collect-hsts', [CompletionResultType]::ParameterName, 'Save https urls with a missing or weak (max-age under 120 days) Strict-Transport-Security header to a file')
+            [CompletionResult]::new('--collect-301-targets', 'collect-301-targets', [CompletionResultType]::ParameterName, 'Save the Location of each 3xx response to ferox-redirects-<host>-<timestamp>.txt')
+            [CompletionResult]::new('--collect-subpath-wordlist', 'collect-subpath-wordlist', [CompletionResultType]::ParameterName, 'Save the path components of each url that is found to ferox-discovered-words-<host>-<timestamp>.txt; with --adaptive-wordlist, they''re also added to the running scan')
+            [CompletionResult]::new('--collect-source-maps', 'collect-source-maps', [CompletionResultType]::ParameterName, 'Request the source maps referenced by javascript files, scan their original source paths, and save them to ferox-sourcemaps-<host>-<timestamp>.txt')
+            [CompletionResult]::new('--collect-api-versions', 'collect-api-versions', [CompletionResultType]::ParameterName, 'Probe the next 10 versions of any versioned api path found (ex: /api/v1/ -> /api/v2/) and print the versions discovered')
+            [CompletionResult]::new('--collect-certificates', 'collect-certificates', [CompletionResultType]::ParameterName, 'Fetch the tls certificate of each https host seen while scanning and save it to ferox-certs-<host>-<timestamp>.json; warns about certificates expiring within 30 days')
+            [CompletionResult]::new('--collect-technology-stack', 'collect-technology-stack', [CompletionResultType]::ParameterName, 'Fingerprint each host''s technologies (headers, cookies, body patterns) and print a summary')
+            [CompletionResult]::new('--collect-forms', 'collect-forms', [CompletionResultType]::ParameterName, 'Scan the same-origin action urls of html forms found in 200 responses')
+            [CompletionResult]::new('--collect-comments-only', 'collect-comments-only', [CompletionResultType]::ParameterName, 'Send HEAD requests, and only GET 200 html pages in order to print their html/javascript comments')
+            [CompletionResult]::new('--deduplicate-by-path', 'deduplicate-by-path', [CompletionResultType]::ParameterName, 'Only request the first extracted link seen for each path, ignoring query strings and fragments')
+            [CompletionResult]::new('--case-insensitive-dedup', 'case-insensitive-dedup', [CompletionResultType]::ParameterName, 'Treat urls that differ only in the case of their path as duplicates (ex: IIS)')
+            [CompletionResult]::new('--websocket-discovery', 'websocket-discovery', [CompletionResultType]::ParameterName, 'Probe each discovered url for websocket upgrade support (marked with [WS])')
+            [CompletionResult]::new('--cors-discovery', 'cors-discovery', [CompletionResultType]::ParameterName, 'Request each discovered url with a foreign Origin and warn about permissive CORS responses')
+            [CompletionResult]::new('--options-discovery', 'options-discovery', [CompletionResultType]::ParameterName, 'Send an OPTIONS request to each discovered directory and report its allowed methods')
+            [CompletionResult]::new('--rate-limit-detect', 'rate-limit-detect', [CompletionResultType]::ParameterName, 'Halve a scan''s requests per second each time a 429 is received, recovering once they stop')
+            [CompletionResult]::new('--pause-on-rate-limit', 'pause-on-rate-limit', [CompletionResultType]::ParameterName, 'Pause all scans when a 429 is received, for the number of seconds in its Retry-After header (default: 60)')
+            [CompletionResult]::new('--no-request-dedup', 'no-request-dedup', [CompletionResultType]::ParameterName, 'Send identical requests even when another scan already sent them (ex. when responses vary)')
+            [CompletionResult]::new('--adaptive-wordlist', 'adaptive-wordlist', [CompletionResultType]::ParameterName, 'Move words sharing a prefix with a found word (ex: config.php -> configuration) closer to the front of the wordlist')
+            [CompletionResult]::new('--watch-wordlist', 'watch-wordlist', [CompletionResultType]::ParameterName, 'Watch the wordlist for changes; words added to it are requested by scans that are still running')
+            [CompletionResult]::new('--no-wordlist-dedup', 'no-wordlist-dedup', [CompletionResultType]::ParameterName, 'Keep duplicate wordlist entries (duplicates cause the same url to be requested multiple times)')
+            [CompletionResult]::new('--simulate', 'simulate', [CompletionResultType]::ParameterName, 'Print an estimate of the number of requests the scan would send, then exit without sending any (alias: --dry-run)')
+            [CompletionResult]::new('--simulate-json', 'simulate-json', [CompletionResultType]::ParameterName, 'Same as --simulate, but print the estimate as JSON')
             [CompletionResult]::new('--auto-tune', 'auto-tune', [CompletionResultType]::ParameterName, 'Automatically lower scan rate when an excessive amount of errors are encountered')
             [CompletionResult]::new('--auto-bail', 'auto-bail', [CompletionResultType]::ParameterName, 'Automatically stop scanning when an excessive amount of errors are encountered')
             [CompletionResult]::new('-D', 'D', [CompletionResultType]::ParameterName, 'Don''t auto-filter wildcard responses')
@@ -107,13 +189,26 @@ Register-ArgumentCompleter -Native -CommandName 'feroxbuster' -ScriptBlock {
             [CompletionResult]::new('--collect-backups', 'collect-backups', [CompletionResultType]::ParameterName, 'Automatically request likely backup extensions for "found" urls')
             [CompletionResult]::new('-g', 'g', [CompletionResultType]::ParameterName, 'Automatically discover important words from within responses and add them to the wordlist')
             [CompletionResult]::new('--collect-words', 'collect-words', [CompletionResultType]::ParameterName, 'Automatically discover important words from within responses and add them to the wordlist')
+            [CompletionResult]::new('--collect-interesting-paths', 'collect-interesting-paths', [CompletionResultType]::ParameterName, 'Request a built-in list of high-value paths (.env, web.config, etc) in addition to the wordlist')
+            [CompletionResult]::new('--collect-passive', 'collect-passive', [CompletionResultType]::ParameterName, 'Add paths archived by the Wayback Machine for the --url''s host to the wordlist (no requests are sent to the target)')
+            [CompletionResult]::new('--collect-crtsh', 'collect-crtsh', [CompletionResultType]::ParameterName, 'Add subdomains found in certificate transparency logs (crt.sh) as additional targets')
             [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Increase verbosity level (use -vv or more for greater effect. [CAUTION] 4 -v''s is probably too much)')
             [CompletionResult]::new('--verbosity', 'verbosity', [CompletionResultType]::ParameterName, 'Increase verbosity level (use -vv or more for greater effect. [CAUTION] 4 -v''s is probably too much)')
             [CompletionResult]::new('--silent', 'silent', [CompletionResultType]::ParameterName, 'Only print URLs + turn off logging (good for piping a list of urls to other commands)')
             [CompletionResult]::new('-q', 'q', [CompletionResultType]::ParameterName, 'Hide progress bars and banner (good for tmux windows w/ notifications)')
             [CompletionResult]::new('--quiet', 'quiet', [CompletionResultType]::ParameterName, 'Hide progress bars and banner (good for tmux windows w/ notifications)')
+            [CompletionResult]::new('--live-output', 'live-output', [CompletionResultType]::ParameterName, 'Never draw progress bars and print each result straight to stdout as it''s found (good for piping to other tools)')
+            [CompletionResult]::new('--store-responses-as-html', 'store-responses-as-html', [CompletionResultType]::ParameterName, 'Write a static html page for each 200 html response, plus an index linking them, to --html-gallery-dir')
+            [CompletionResult]::new('--verbose-scan-start', 'verbose-scan-start', [CompletionResultType]::ParameterName, 'Print a line with the url, depth, and wordlist of each scan as it starts')
             [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Emit JSON logs to --output and --debug-log instead of normal text')
+            [CompletionResult]::new('--report-summary-only', 'report-summary-only', [CompletionResultType]::ParameterName, 'Don''t print results to the terminal; print a JSON summary once scanning completes instead (--output is unaffected, pair with --silent for JSON only)')
+            [CompletionResult]::new('--json-status', 'json-status', [CompletionResultType]::ParameterName, 'Print a JSON line to stderr with the number of known/complete scans and responses found whenever they change')
+            [CompletionResult]::new('--sort-output', 'sort-output', [CompletionResultType]::ParameterName, 'Hold results until the scan ends, then write them to --output sorted by --sort-by')
+            [CompletionResult]::new('--output-deduplicate', 'output-deduplicate', [CompletionResultType]::ParameterName, 'Write each url to the output file only once, even if it''s reported again (ex: with another method)')
             [CompletionResult]::new('--no-state', 'no-state', [CompletionResultType]::ParameterName, 'Disable state output file (*.state)')
+            [CompletionResult]::new('--split-state', 'split-state', [CompletionResultType]::ParameterName, 'Write the scans and responses of state files to their own files (ferox-scans-*.state and ferox-responses-*.state)')
+            [CompletionResult]::new('--save-state-on-empty', 'save-state-on-empty', [CompletionResultType]::ParameterName, 'Write a state file when a scan completes without finding anything (the same file ctrl+c would write)')
+            [CompletionResult]::new('--desktop-notify', 'desktop-notify', [CompletionResultType]::ParameterName, 'Send a desktop notification (via notify-send/osascript) once all scans complete')
             break
         }
     })
@@ -3,7 +3,8 @@ use crate::{
     config::Configuration,
     event_handlers::Handles,
     utils::{logged_request, status_colorizer},
-    DEFAULT_IGNORED_EXTENSIONS, DEFAULT_METHOD, VERSION,
+    DEFAULT_IGNORED_EXTENSIONS, DEFAULT_MAX_RETRIES, DEFAULT_METHOD, DEFAULT_RECURSE_ON_STATUS,
+    VERSION,
 };
 use anyhow::{bail, Result};
 use console::{style, Emoji};
@@ -41,6 +42,9 @@ pub struct Banner {
     /// represents Configuration.threads
     threads: BannerEntry,
 
+    /// represents Configuration.threads_burst
+    threads_burst: BannerEntry,
+
     /// represents Configuration.wordlist
     wordlist: BannerEntry,
 
@@ -83,6 +87,9 @@ pub struct Banner {
     /// represents Configuration.filter_regex
     filter_regex: Vec<BannerEntry>,
 
+    /// represents Configuration.filter_extension
+    filter_extension: Vec<BannerEntry>,
+
     /// represents Configuration.extract_links
     extract_links: BannerEntry,
 
@@ -131,6 +138,9 @@ pub struct Banner {
     /// represents Configuration.time_limit
     time_limit: BannerEntry,
 
+    /// represents Configuration.checkpoint_interval
+    checkpoint_interval: BannerEntry,
+
     /// represents Configuration.rate_limit
     rate_limit: BannerEntry,
 
@@ -166,6 +176,246 @@ pub struct Banner {
 
     /// represents Configuration.collect_words
     force_recursion: BannerEntry,
+
+    /// represents Configuration.no_redundant_scans
+    no_redundant_scans: BannerEntry,
+
+    /// represents Configuration.include_unconfirmed_redirects
+    include_unconfirmed_redirects: BannerEntry,
+
+    /// represents Configuration.notify_url
+    notify_url: BannerEntry,
+
+    /// represents Configuration.desktop_notify
+    desktop_notify: BannerEntry,
+
+    /// represents Configuration.redirect_as_discovery
+    redirect_as_discovery: BannerEntry,
+
+    /// represents Configuration.collect_forms
+    collect_forms: BannerEntry,
+
+    /// represents Configuration.collect_parameters
+    collect_parameters: BannerEntry,
+
+    /// represents Configuration.check_previous_state
+    check_previous_state: BannerEntry,
+
+    /// represents Configuration.connection_errors_to_file
+    connection_errors_to_file: BannerEntry,
+
+    /// represents Configuration.export_metasploit
+    export_metasploit: BannerEntry,
+
+    /// represents Configuration.max_retries
+    max_retries: BannerEntry,
+
+    /// represents Configuration.dns_server
+    dns_server: BannerEntry,
+
+    /// represents Configuration.dns_over_https
+    dns_over_https: BannerEntry,
+
+    /// represents Configuration.collect_technology_stack
+    collect_technology_stack: BannerEntry,
+
+    /// represents Configuration.no_wordlist_dedup
+    no_wordlist_dedup: BannerEntry,
+
+    /// represents Configuration.include_all_status
+    include_all_status: BannerEntry,
+
+    /// represents Configuration.max_response_size
+    max_response_size: BannerEntry,
+
+    /// represents Configuration.max_urls_per_scan
+    max_urls_per_scan: BannerEntry,
+
+    /// represents Configuration.no_request_dedup
+    no_request_dedup: BannerEntry,
+
+    /// represents Configuration.output_filter_status
+    output_filter_status: BannerEntry,
+
+    /// represents Configuration.adaptive_wordlist
+    adaptive_wordlist: BannerEntry,
+
+    /// represents Configuration.watch_wordlist
+    watch_wordlist: BannerEntry,
+
+    /// represents Configuration.filter_body_size_range
+    filter_body_size_range: BannerEntry,
+
+    /// represents Configuration.keep_body_size_range
+    keep_body_size_range: BannerEntry,
+
+    /// represents Configuration.collect_source_maps
+    collect_source_maps: BannerEntry,
+
+    /// represents Configuration.rate_limit_detect
+    rate_limit_detect: BannerEntry,
+
+    /// represents Configuration.live_output
+    live_output: BannerEntry,
+
+    /// represents Configuration.filter_words_range
+    filter_words_range: BannerEntry,
+
+    /// represents Configuration.filter_lines_range
+    filter_lines_range: BannerEntry,
+
+    /// represents Configuration.collect_certificates
+    collect_certificates: BannerEntry,
+
+    /// represents Configuration.store_responses_as_html
+    store_responses_as_html: BannerEntry,
+
+    /// represents Configuration.wordlist_encoding
+    wordlist_encoding: BannerEntry,
+
+    /// represents Configuration.collect_comments_only
+    collect_comments_only: BannerEntry,
+
+    /// represents Configuration.post_scan_script
+    post_scan_script: BannerEntry,
+
+    /// represents Configuration.exit_code_on_found
+    exit_code_on_found: BannerEntry,
+
+    /// represents Configuration.exit_code_on_none
+    exit_code_on_none: BannerEntry,
+
+    /// represents Configuration.interesting_only
+    interesting_only: BannerEntry,
+
+    /// represents Configuration.filter_duplicate_content
+    filter_duplicate_content: BannerEntry,
+
+    /// represents Configuration.websocket_discovery
+    websocket_discovery: BannerEntry,
+
+    /// represents Configuration.cors_discovery
+    cors_discovery: BannerEntry,
+
+    /// represents Configuration.options_discovery
+    options_discovery: BannerEntry,
+
+    /// represents Configuration.save_state_on_empty
+    save_state_on_empty: BannerEntry,
+
+    /// represents Configuration.recurse_on_status
+    recurse_on_status: BannerEntry,
+
+    /// represents Configuration.deduplicate_by_path
+    deduplicate_by_path: BannerEntry,
+
+    /// represents Configuration.output_rotate_size
+    output_rotate_size: BannerEntry,
+
+    /// represents Configuration.output_rotate_max_files
+    output_rotate_max_files: BannerEntry,
+
+    /// represents Configuration.filter_response_time_above
+    filter_response_time_above: BannerEntry,
+
+    /// represents Configuration.filter_response_time_below
+    filter_response_time_below: BannerEntry,
+
+    /// represents Configuration.case_insensitive_dedup
+    case_insensitive_dedup: BannerEntry,
+
+    /// represents Configuration.collect_api_versions
+    collect_api_versions: BannerEntry,
+
+    /// represents Configuration.filter_min_ratio
+    filter_min_ratio: BannerEntry,
+
+    /// represents Configuration.proxy_exclude_patterns
+    proxy_exclude_patterns: BannerEntry,
+
+    /// represents Configuration.collect_page_titles
+    collect_page_titles: BannerEntry,
+
+    /// represents Configuration.multipart_wordlist
+    multipart_wordlist: BannerEntry,
+
+    /// represents Configuration.json_path_filter
+    json_path_filter: BannerEntry,
+
+    /// represents Configuration.json_path_keep_if
+    json_path_keep_if: BannerEntry,
+
+    /// represents Configuration.verbose_scan_start
+    verbose_scan_start: BannerEntry,
+
+    /// represents Configuration.collect_301_targets
+    collect_301_targets: BannerEntry,
+
+    /// represents Configuration.collect_security_headers
+    collect_security_headers: BannerEntry,
+
+    /// represents Configuration.collect_hsts
+    collect_hsts: BannerEntry,
+
+    /// represents Configuration.sort_output
+    sort_output: BannerEntry,
+
+    /// represents Configuration.prefix_wordlist
+    prefix_wordlist: BannerEntry,
+
+    /// represents Configuration.collect_meta_tags
+    collect_meta_tags: BannerEntry,
+
+    /// represents Configuration.filter_max_headers
+    filter_max_headers: BannerEntry,
+
+    /// represents Configuration.filter_min_headers
+    filter_min_headers: BannerEntry,
+
+    /// represents Configuration.collect_inline_js
+    collect_inline_js: BannerEntry,
+
+    /// represents Configuration.tee
+    tee: BannerEntry,
+
+    /// represents Configuration.pause_on_rate_limit
+    pause_on_rate_limit: BannerEntry,
+
+    /// represents Configuration.split_state
+    split_state: BannerEntry,
+
+    /// represents Configuration.filter_empty_body
+    filter_empty_body: BannerEntry,
+
+    /// represents Configuration.filter_near_empty_body
+    filter_near_empty_body: BannerEntry,
+
+    /// represents Configuration.scan_limit_per_host
+    scan_limit_per_host: BannerEntry,
+
+    /// represents Configuration.collect_subpath_wordlist
+    collect_subpath_wordlist: BannerEntry,
+
+    /// represents Configuration.report_summary_only
+    report_summary_only: BannerEntry,
+
+    /// represents Configuration.json_status
+    json_status: BannerEntry,
+
+    /// represents Configuration.follow_meta_refresh
+    follow_meta_refresh: BannerEntry,
+
+    /// represents Configuration.output_deduplicate
+    output_deduplicate: BannerEntry,
+
+    /// represents Configuration.collect_interesting_paths
+    collect_interesting_paths: BannerEntry,
+
+    /// represents Configuration.collect_passive
+    collect_passive: BannerEntry,
+
+    /// represents Configuration.collect_crtsh
+    collect_crtsh: BannerEntry,
 }
 
 /// implementation of Banner
@@ -182,6 +432,7 @@ impl Banner {
         let mut filter_word_count = Vec::new();
         let mut filter_line_count = Vec::new();
         let mut filter_regex = Vec::new();
+        let mut filter_extension = Vec::new();
         let mut queries = Vec::new();
 
         for target in tgts {
@@ -265,6 +516,10 @@ impl Banner {
             filter_regex.push(BannerEntry::new("💢", "Regex Filter", filter));
         }
 
+        for filter in &config.filter_extension {
+            filter_extension.push(BannerEntry::new("💢", "Extension Filter", filter));
+        }
+
         for query in &config.queries {
             queries.push(BannerEntry::new(
                 "🤔",
@@ -305,12 +560,25 @@ impl Banner {
 
         let force_recursion =
             BannerEntry::new("🤘", "Force Recursion", &config.force_recursion.to_string());
+        let no_redundant_scans = BannerEntry::new(
+            "♻️",
+            "No Redundant Scans",
+            &config.no_redundant_scans.to_string(),
+        );
         let replay_proxy = BannerEntry::new("🎥", "Replay Proxy", &config.replay_proxy);
         let auto_tune = BannerEntry::new("🎶", "Auto Tune", &config.auto_tune.to_string());
         let auto_bail = BannerEntry::new("🪣", "Auto Bail", &config.auto_bail.to_string());
         let cfg = BannerEntry::new("💉", "Config File", &config.config);
         let proxy = BannerEntry::new("💎", "Proxy", &config.proxy);
         let threads = BannerEntry::new("🚀", "Threads", &config.threads.to_string());
+        let threads_burst = BannerEntry::new(
+            "🌋",
+            "Threads Burst",
+            &format!(
+                "{} (for {}ms)",
+                config.threads_burst, config.threads_burst_duration
+            ),
+        );
         let wordlist = BannerEntry::new("📖", "Wordlist", &config.wordlist);
         let timeout = BannerEntry::new("💥", "Timeout (secs)", &config.timeout.to_string());
         let user_agent = BannerEntry::new("🦡", "User-Agent", &config.user_agent);
@@ -363,6 +631,8 @@ impl Banner {
             BannerEntry::new("🤪", "Filter Wildcards", &(!config.dont_filter).to_string());
         let add_slash = BannerEntry::new("🪓", "Add Slash", &config.add_slash.to_string());
         let time_limit = BannerEntry::new("🕖", "Time Limit", &config.time_limit);
+        let checkpoint_interval =
+            BannerEntry::new("⏲", "Checkpoint Interval", &config.checkpoint_interval);
         let parallel = BannerEntry::new("🛤", "Parallel Scans", &config.parallel.to_string());
         let rate_limit =
             BannerEntry::new("🚧", "Requests per Second", &config.rate_limit.to_string());
@@ -377,10 +647,446 @@ impl Banner {
         let collect_words =
             BannerEntry::new("🤑", "Collect Words", &config.collect_words.to_string());
 
+        let include_unconfirmed_redirects = BannerEntry::new(
+            "🔀",
+            "Unconfirmed Redirects",
+            &config.include_unconfirmed_redirects.to_string(),
+        );
+
+        let notify_url = BannerEntry::new("📣", "Notify URL", &config.notify_url);
+
+        let desktop_notify = BannerEntry::new(
+            "🔔",
+            "Desktop Notification",
+            &config.desktop_notify.to_string(),
+        );
+
+        let redirect_as_discovery = BannerEntry::new(
+            "↪",
+            "Redirects as Discovery",
+            &config.redirect_as_discovery.to_string(),
+        );
+
+        let collect_forms =
+            BannerEntry::new("📝", "Collect Forms", &config.collect_forms.to_string());
+
+        let collect_parameters = BannerEntry::new(
+            "🔣",
+            "Collect Parameters",
+            &config.collect_parameters.to_string(),
+        );
+
+        let check_previous_state =
+            BannerEntry::new("⏭", "Skip Urls Found In", &config.check_previous_state);
+
+        let connection_errors_to_file = BannerEntry::new(
+            "🔌",
+            "Connection Error Log",
+            &config.connection_errors_to_file,
+        );
+
+        let export_metasploit =
+            BannerEntry::new("📜", "Metasploit Script", &config.export_metasploit);
+
+        let max_retries = BannerEntry::new("🔁", "Max Retries", &config.max_retries.to_string());
+
+        let dns_server = BannerEntry::new(
+            "🧭",
+            "DNS Server",
+            config.dns_server.as_deref().unwrap_or_default(),
+        );
+
+        let dns_over_https = BannerEntry::new(
+            "🧭",
+            "DNS over HTTPS",
+            config.dns_over_https.as_deref().unwrap_or_default(),
+        );
+
+        let collect_technology_stack = BannerEntry::new(
+            "🧬",
+            "Collect Technology Stack",
+            &config.collect_technology_stack.to_string(),
+        );
+
+        let no_wordlist_dedup = BannerEntry::new(
+            "👯",
+            "Keep Duplicate Words",
+            &config.no_wordlist_dedup.to_string(),
+        );
+
+        let include_all_status = BannerEntry::new(
+            "🌈",
+            "Include All Status Codes",
+            &config.include_all_status.to_string(),
+        );
+
+        let max_response_size = BannerEntry::new(
+            "📏",
+            "Max Response Size",
+            &config.max_response_size.to_string(),
+        );
+
+        let max_urls_per_scan = BannerEntry::new(
+            "🛑",
+            "Max Urls per Scan",
+            &config.max_urls_per_scan.to_string(),
+        );
+
+        let no_request_dedup = BannerEntry::new(
+            "🔂",
+            "Repeat Duplicate Requests",
+            &config.no_request_dedup.to_string(),
+        );
+
+        let output_filter_status = BannerEntry::new(
+            "🗃",
+            "Output Status Codes",
+            &format!(
+                "[{}]",
+                config
+                    .output_filter_status
+                    .iter()
+                    .map(|code| status_colorizer(&code.to_string()))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        );
+
+        let adaptive_wordlist = BannerEntry::new(
+            "🧬",
+            "Adaptive Wordlist",
+            &config.adaptive_wordlist.to_string(),
+        );
+
+        let watch_wordlist =
+            BannerEntry::new("👀", "Watch Wordlist", &config.watch_wordlist.to_string());
+
+        let filter_body_size_range = BannerEntry::new(
+            "💢",
+            "Size Range Filter",
+            &format!("[{}]", config.filter_body_size_range.join(", ")),
+        );
+
+        let keep_body_size_range = BannerEntry::new(
+            "📐",
+            "Keep Size Range",
+            &format!("[{}]", config.keep_body_size_range.join(", ")),
+        );
+
+        let collect_source_maps = BannerEntry::new(
+            "🗺",
+            "Collect Source Maps",
+            &config.collect_source_maps.to_string(),
+        );
+
+        let rate_limit_detect = BannerEntry::new(
+            "🚥",
+            "Rate Limit Detection",
+            &format!("true (recover after {}s)", config.rate_limit_recovery),
+        );
+
+        let live_output = BannerEntry::new("📺", "Live Output", &config.live_output.to_string());
+
+        let filter_words_range = BannerEntry::new(
+            "💢",
+            "Word Count Range Filter",
+            &format!("[{}]", config.filter_words_range.join(", ")),
+        );
+
+        let filter_lines_range = BannerEntry::new(
+            "💢",
+            "Line Count Range Filter",
+            &format!("[{}]", config.filter_lines_range.join(", ")),
+        );
+
+        let collect_certificates = BannerEntry::new(
+            "🔏",
+            "Collect Certificates",
+            &config.collect_certificates.to_string(),
+        );
+
+        let store_responses_as_html = BannerEntry::new(
+            "🖼",
+            "HTML Gallery",
+            &format!("true (written to {})", config.html_gallery_dir),
+        );
+
+        let wordlist_encoding =
+            BannerEntry::new("🔤", "Wordlist Encoding", &config.wordlist_encoding);
+
+        let collect_comments_only = BannerEntry::new(
+            "💬",
+            "Collect Comments Only",
+            &config.collect_comments_only.to_string(),
+        );
+
+        let post_scan_script = BannerEntry::new("🏁", "Post-scan Script", &config.post_scan_script);
+
+        let exit_code_on_found = BannerEntry::new(
+            "🚪",
+            "Exit Code On Found",
+            &config.exit_code_on_found.to_string(),
+        );
+
+        let exit_code_on_none = BannerEntry::new(
+            "🚪",
+            "Exit Code On None",
+            &config.exit_code_on_none.to_string(),
+        );
+
+        let interesting_only = BannerEntry::new(
+            "🧐",
+            "Interesting Only",
+            &config.interesting_only.to_string(),
+        );
+
+        let filter_duplicate_content = BannerEntry::new(
+            "♊",
+            "Filter Duplicate Content",
+            &config.filter_duplicate_content.to_string(),
+        );
+
+        let websocket_discovery = BannerEntry::new(
+            "🔌",
+            "WebSocket Discovery",
+            &config.websocket_discovery.to_string(),
+        );
+
+        let cors_discovery =
+            BannerEntry::new("🌐", "CORS Discovery", &config.cors_discovery.to_string());
+
+        let options_discovery = BannerEntry::new(
+            "📬",
+            "OPTIONS Discovery",
+            &config.options_discovery.to_string(),
+        );
+
+        let save_state_on_empty = BannerEntry::new(
+            "📭",
+            "Save State On Empty",
+            &config.save_state_on_empty.to_string(),
+        );
+
+        let recurse_on_status = BannerEntry::new(
+            "🪜",
+            "Recurse On Status",
+            &format!(
+                "[{}]",
+                config
+                    .recurse_on_status
+                    .iter()
+                    .map(|code| status_colorizer(&code.to_string()))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        );
+
+        let deduplicate_by_path = BannerEntry::new(
+            "🧹",
+            "Deduplicate By Path",
+            &config.deduplicate_by_path.to_string(),
+        );
+
+        let output_rotate_size = BannerEntry::new(
+            "🗂",
+            "Output Rotate Size",
+            &format!("{} bytes", config.output_rotate_size),
+        );
+
+        let output_rotate_max_files = BannerEntry::new(
+            "🗂",
+            "Output Rotate Max Files",
+            &config.output_rotate_max_files.to_string(),
+        );
+
+        let filter_response_time_above = BannerEntry::new(
+            "🐢",
+            "Response Time Filter",
+            &format!("above {}ms", config.filter_response_time_above),
+        );
+
+        let filter_response_time_below = BannerEntry::new(
+            "🐇",
+            "Response Time Filter",
+            &format!("below {}ms", config.filter_response_time_below),
+        );
+
+        let case_insensitive_dedup = BannerEntry::new(
+            "🔡",
+            "Case Insensitive Dedup",
+            &config.case_insensitive_dedup.to_string(),
+        );
+
+        let collect_api_versions = BannerEntry::new(
+            "🔢",
+            "Collect API Versions",
+            &config.collect_api_versions.to_string(),
+        );
+
+        let filter_min_ratio = BannerEntry::new(
+            "⚖",
+            "Size Ratio Filter",
+            &config.filter_min_ratio.to_string(),
+        );
+
+        let proxy_exclude_patterns = BannerEntry::new(
+            "🚷",
+            "Proxy Excluded Hosts",
+            &format!("[{}]", config.proxy_exclude_patterns.join(", ")),
+        );
+
+        let collect_page_titles = BannerEntry::new(
+            "🏷",
+            "Collect Page Titles",
+            &config.collect_page_titles.to_string(),
+        );
+
+        let multipart_wordlist = BannerEntry::new(
+            "📎",
+            "Multipart Wordlist",
+            &format!(
+                "{} (field: {})",
+                config.multipart_wordlist, config.multipart_field
+            ),
+        );
+
+        let json_path_filter = BannerEntry::new(
+            "🧾",
+            "JSON Path Filter",
+            &format!("[{}]", config.json_path_filter.join(", ")),
+        );
+
+        let json_path_keep_if = BannerEntry::new(
+            "📋",
+            "JSON Path Keep If",
+            &format!("[{}]", config.json_path_keep_if.join(", ")),
+        );
+
+        let verbose_scan_start = BannerEntry::new(
+            "🛫",
+            "Verbose Scan Start",
+            &config.verbose_scan_start.to_string(),
+        );
+
+        let collect_301_targets = BannerEntry::new(
+            "🚏",
+            "Collect 301 Targets",
+            &config.collect_301_targets.to_string(),
+        );
+
+        let collect_security_headers = BannerEntry::new(
+            "🦺",
+            "Collect Security Headers",
+            &config.collect_security_headers.to_string(),
+        );
+
+        let collect_hsts = BannerEntry::new("🚦", "Collect HSTS", &config.collect_hsts.to_string());
+
+        let sort_output = BannerEntry::new("📊", "Sort Output By", &config.sort_by);
+
+        let prefix_wordlist = BannerEntry::new("🔝", "Prefix Wordlist", &config.prefix_wordlist);
+
+        let collect_meta_tags = BannerEntry::new(
+            "🔖",
+            "Collect Meta Tags",
+            &config.collect_meta_tags.to_string(),
+        );
+
+        let filter_max_headers = BannerEntry::new(
+            "📨",
+            "Header Count Filter",
+            &format!("above {} headers", config.filter_max_headers),
+        );
+
+        let filter_min_headers = BannerEntry::new(
+            "🧮",
+            "Header Count Filter",
+            &format!("below {} headers", config.filter_min_headers),
+        );
+
+        let collect_inline_js = BannerEntry::new(
+            "🧩",
+            "Collect Inline JS",
+            &format!("true (written to {})", config.inline_js_dir),
+        );
+
+        let tee = BannerEntry::new("📠", "Tee", &config.tee);
+
+        let pause_on_rate_limit = BannerEntry::new(
+            "⏸",
+            "Pause On Rate Limit",
+            &config.pause_on_rate_limit.to_string(),
+        );
+
+        let split_state = BannerEntry::new("✂", "Split State", &config.split_state.to_string());
+
+        let filter_empty_body = BannerEntry::new(
+            "🕳",
+            "Empty Body Filter",
+            &config.filter_empty_body.to_string(),
+        );
+
+        let filter_near_empty_body = BannerEntry::new(
+            "🪶",
+            "Near Empty Body Filter",
+            &format!("0-{} bytes", config.filter_near_empty_body),
+        );
+
+        let scan_limit_per_host = BannerEntry::new(
+            "🏘",
+            "Concurrent Scan Limit Per Host",
+            &config.scan_limit_per_host.to_string(),
+        );
+
+        let collect_subpath_wordlist = BannerEntry::new(
+            "🏷",
+            "Collect Subpath Wordlist",
+            &config.collect_subpath_wordlist.to_string(),
+        );
+
+        let report_summary_only = BannerEntry::new(
+            "📑",
+            "Report Summary Only",
+            &config.report_summary_only.to_string(),
+        );
+
+        let json_status = BannerEntry::new("📈", "JSON Status", &config.json_status.to_string());
+
+        let follow_meta_refresh = BannerEntry::new(
+            "🔄",
+            "Follow Meta Refresh",
+            &config.follow_meta_refresh.to_string(),
+        );
+
+        let output_deduplicate = BannerEntry::new(
+            "🪞",
+            "Output Deduplicate",
+            &config.output_deduplicate.to_string(),
+        );
+
+        let collect_interesting_paths = BannerEntry::new(
+            "🔑",
+            "Collect Interesting Paths",
+            &config.collect_interesting_paths.to_string(),
+        );
+
+        let collect_passive = BannerEntry::new(
+            "🏛",
+            "Collect Passive (Wayback)",
+            &config.collect_passive.to_string(),
+        );
+
+        let collect_crtsh = BannerEntry::new(
+            "📇",
+            "Collect Subdomains (crt.sh)",
+            &config.collect_crtsh.to_string(),
+        );
+
         Self {
             targets,
             status_codes,
             threads,
+            threads_burst,
             wordlist,
             filter_status,
             timeout,
@@ -397,6 +1103,7 @@ impl Banner {
             filter_word_count,
             filter_line_count,
             filter_regex,
+            filter_extension,
             extract_links,
             parallel,
             json,
@@ -415,12 +1122,93 @@ impl Banner {
             rate_limit,
             scan_limit,
             force_recursion,
+            no_redundant_scans,
             time_limit,
+            checkpoint_interval,
             url_denylist,
             collect_extensions,
             collect_backups,
             collect_words,
             dont_collect,
+            include_unconfirmed_redirects,
+            notify_url,
+            desktop_notify,
+            redirect_as_discovery,
+            collect_forms,
+            collect_parameters,
+            check_previous_state,
+            connection_errors_to_file,
+            export_metasploit,
+            max_retries,
+            dns_server,
+            dns_over_https,
+            collect_technology_stack,
+            no_wordlist_dedup,
+            include_all_status,
+            max_response_size,
+            max_urls_per_scan,
+            no_request_dedup,
+            output_filter_status,
+            adaptive_wordlist,
+            watch_wordlist,
+            filter_body_size_range,
+            keep_body_size_range,
+            collect_source_maps,
+            rate_limit_detect,
+            live_output,
+            filter_words_range,
+            filter_lines_range,
+            collect_certificates,
+            store_responses_as_html,
+            wordlist_encoding,
+            collect_comments_only,
+            post_scan_script,
+            exit_code_on_found,
+            exit_code_on_none,
+            interesting_only,
+            filter_duplicate_content,
+            websocket_discovery,
+            cors_discovery,
+            options_discovery,
+            save_state_on_empty,
+            recurse_on_status,
+            deduplicate_by_path,
+            output_rotate_size,
+            output_rotate_max_files,
+            filter_response_time_above,
+            filter_response_time_below,
+            case_insensitive_dedup,
+            collect_api_versions,
+            filter_min_ratio,
+            proxy_exclude_patterns,
+            collect_page_titles,
+            multipart_wordlist,
+            json_path_filter,
+            json_path_keep_if,
+            verbose_scan_start,
+            collect_301_targets,
+            collect_security_headers,
+            collect_hsts,
+            sort_output,
+            prefix_wordlist,
+            collect_meta_tags,
+            filter_max_headers,
+            filter_min_headers,
+            collect_inline_js,
+            tee,
+            pause_on_rate_limit,
+            split_state,
+            filter_empty_body,
+            filter_near_empty_body,
+            scan_limit_per_host,
+            collect_subpath_wordlist,
+            report_summary_only,
+            json_status,
+            follow_meta_refresh,
+            output_deduplicate,
+            collect_interesting_paths,
+            collect_passive,
+            collect_crtsh,
             config: cfg,
             version: VERSION.to_string(),
             update_status: UpdateStatus::Unknown,
@@ -516,8 +1304,17 @@ by Ben "epi" Risher {}                 ver: {}"#,
         }
 
         writeln!(&mut writer, "{}", self.threads)?;
+
+        if config.threads_burst > 0 {
+            writeln!(&mut writer, "{}", self.threads_burst)?;
+        }
+
         writeln!(&mut writer, "{}", self.wordlist)?;
 
+        if !config.prefix_wordlist.is_empty() {
+            writeln!(&mut writer, "{}", self.prefix_wordlist)?;
+        }
+
         if config.filter_status.is_empty() {
             // -C and -s are mutually exclusive, and -s meaning changes when -C is used
             // so only print one or the other
@@ -543,6 +1340,10 @@ by Ben "epi" Risher {}                 ver: {}"#,
             writeln!(&mut writer, "{}", self.proxy)?;
         }
 
+        if !config.proxy_exclude_patterns.is_empty() {
+            writeln!(&mut writer, "{}", self.proxy_exclude_patterns)?;
+        }
+
         if !config.replay_proxy.is_empty() {
             // i include replay codes logic here because in config.rs, replay codes are set to the
             // value in status codes, meaning it's never empty
@@ -558,6 +1359,14 @@ by Ben "epi" Risher {}                 ver: {}"#,
             writeln!(&mut writer, "{}", filter)?;
         }
 
+        if config.filter_empty_body {
+            writeln!(&mut writer, "{}", self.filter_empty_body)?;
+        }
+
+        if config.filter_near_empty_body > 0 {
+            writeln!(&mut writer, "{}", self.filter_near_empty_body)?;
+        }
+
         for filter in &self.filter_similar {
             writeln!(&mut writer, "{}", filter)?;
         }
@@ -574,6 +1383,10 @@ by Ben "epi" Risher {}                 ver: {}"#,
             writeln!(&mut writer, "{}", filter)?;
         }
 
+        for filter in &self.filter_extension {
+            writeln!(&mut writer, "{}", filter)?;
+        }
+
         if config.extract_links {
             writeln!(&mut writer, "{}", self.extract_links)?;
         }
@@ -590,6 +1403,18 @@ by Ben "epi" Risher {}                 ver: {}"#,
             writeln!(&mut writer, "{}", self.output)?;
         }
 
+        if !config.tee.is_empty() {
+            writeln!(&mut writer, "{}", self.tee)?;
+        }
+
+        if config.report_summary_only {
+            writeln!(&mut writer, "{}", self.report_summary_only)?;
+        }
+
+        if config.json_status {
+            writeln!(&mut writer, "{}", self.json_status)?;
+        }
+
         if !config.debug_log.is_empty() {
             writeln!(&mut writer, "{}", self.debug_log)?;
         }
@@ -612,6 +1437,18 @@ by Ben "epi" Risher {}                 ver: {}"#,
             writeln!(&mut writer, "{}", self.collect_words)?;
         }
 
+        if config.collect_interesting_paths {
+            writeln!(&mut writer, "{}", self.collect_interesting_paths)?;
+        }
+
+        if config.collect_passive {
+            writeln!(&mut writer, "{}", self.collect_passive)?;
+        }
+
+        if config.collect_crtsh {
+            writeln!(&mut writer, "{}", self.collect_crtsh)?;
+        }
+
         if !config.methods.is_empty() {
             writeln!(&mut writer, "{}", self.methods)?;
         }
@@ -653,10 +1490,18 @@ by Ben "epi" Risher {}                 ver: {}"#,
             writeln!(&mut writer, "{}", self.force_recursion)?;
         }
 
+        if config.no_redundant_scans {
+            writeln!(&mut writer, "{}", self.no_redundant_scans)?;
+        }
+
         if config.scan_limit > 0 {
             writeln!(&mut writer, "{}", self.scan_limit)?;
         }
 
+        if config.scan_limit_per_host > 0 {
+            writeln!(&mut writer, "{}", self.scan_limit_per_host)?;
+        }
+
         if config.parallel > 0 {
             writeln!(&mut writer, "{}", self.parallel)?;
         }
@@ -669,6 +1514,287 @@ by Ben "epi" Risher {}                 ver: {}"#,
             writeln!(&mut writer, "{}", self.time_limit)?;
         }
 
+        if !config.checkpoint_interval.is_empty() {
+            writeln!(&mut writer, "{}", self.checkpoint_interval)?;
+        }
+
+        if config.include_unconfirmed_redirects {
+            writeln!(&mut writer, "{}", self.include_unconfirmed_redirects)?;
+        }
+
+        if !config.notify_url.is_empty() {
+            writeln!(&mut writer, "{}", self.notify_url)?;
+        }
+
+        if config.desktop_notify {
+            writeln!(&mut writer, "{}", self.desktop_notify)?;
+        }
+
+        if config.redirect_as_discovery {
+            writeln!(&mut writer, "{}", self.redirect_as_discovery)?;
+        }
+
+        if config.follow_meta_refresh {
+            writeln!(&mut writer, "{}", self.follow_meta_refresh)?;
+        }
+
+        if config.collect_forms {
+            writeln!(&mut writer, "{}", self.collect_forms)?;
+        }
+
+        if config.collect_parameters {
+            writeln!(&mut writer, "{}", self.collect_parameters)?;
+        }
+
+        if !config.check_previous_state.is_empty() {
+            writeln!(&mut writer, "{}", self.check_previous_state)?;
+        }
+
+        if !config.connection_errors_to_file.is_empty() {
+            writeln!(&mut writer, "{}", self.connection_errors_to_file)?;
+        }
+
+        if !config.export_metasploit.is_empty() {
+            writeln!(&mut writer, "{}", self.export_metasploit)?;
+        }
+
+        if config.max_retries != DEFAULT_MAX_RETRIES {
+            writeln!(&mut writer, "{}", self.max_retries)?;
+        }
+
+        if config.dns_server.is_some() {
+            writeln!(&mut writer, "{}", self.dns_server)?;
+        }
+
+        if config.dns_over_https.is_some() {
+            writeln!(&mut writer, "{}", self.dns_over_https)?;
+        }
+
+        if config.collect_technology_stack {
+            writeln!(&mut writer, "{}", self.collect_technology_stack)?;
+        }
+
+        if config.no_wordlist_dedup {
+            writeln!(&mut writer, "{}", self.no_wordlist_dedup)?;
+        }
+
+        if config.include_all_status {
+            writeln!(&mut writer, "{}", self.include_all_status)?;
+        }
+
+        if config.max_response_size > 0 {
+            writeln!(&mut writer, "{}", self.max_response_size)?;
+        }
+
+        if config.max_urls_per_scan > 0 {
+            writeln!(&mut writer, "{}", self.max_urls_per_scan)?;
+        }
+
+        if config.no_request_dedup {
+            writeln!(&mut writer, "{}", self.no_request_dedup)?;
+        }
+
+        if !config.output_filter_status.is_empty() {
+            writeln!(&mut writer, "{}", self.output_filter_status)?;
+        }
+
+        if config.adaptive_wordlist {
+            writeln!(&mut writer, "{}", self.adaptive_wordlist)?;
+        }
+
+        if config.watch_wordlist {
+            writeln!(&mut writer, "{}", self.watch_wordlist)?;
+        }
+
+        if !config.filter_body_size_range.is_empty() {
+            writeln!(&mut writer, "{}", self.filter_body_size_range)?;
+        }
+
+        if !config.keep_body_size_range.is_empty() {
+            writeln!(&mut writer, "{}", self.keep_body_size_range)?;
+        }
+
+        if !config.json_path_filter.is_empty() {
+            writeln!(&mut writer, "{}", self.json_path_filter)?;
+        }
+
+        if !config.json_path_keep_if.is_empty() {
+            writeln!(&mut writer, "{}", self.json_path_keep_if)?;
+        }
+
+        if config.collect_source_maps {
+            writeln!(&mut writer, "{}", self.collect_source_maps)?;
+        }
+
+        if config.rate_limit_detect {
+            writeln!(&mut writer, "{}", self.rate_limit_detect)?;
+        }
+
+        if config.pause_on_rate_limit {
+            writeln!(&mut writer, "{}", self.pause_on_rate_limit)?;
+        }
+
+        if config.live_output {
+            writeln!(&mut writer, "{}", self.live_output)?;
+        }
+
+        if !config.filter_words_range.is_empty() {
+            writeln!(&mut writer, "{}", self.filter_words_range)?;
+        }
+
+        if !config.filter_lines_range.is_empty() {
+            writeln!(&mut writer, "{}", self.filter_lines_range)?;
+        }
+
+        if config.collect_certificates {
+            writeln!(&mut writer, "{}", self.collect_certificates)?;
+        }
+
+        if config.store_responses_as_html {
+            writeln!(&mut writer, "{}", self.store_responses_as_html)?;
+        }
+
+        if config.wordlist_encoding != "utf-8" {
+            writeln!(&mut writer, "{}", self.wordlist_encoding)?;
+        }
+
+        if config.collect_comments_only {
+            writeln!(&mut writer, "{}", self.collect_comments_only)?;
+        }
+
+        if !config.post_scan_script.is_empty() {
+            writeln!(&mut writer, "{}", self.post_scan_script)?;
+        }
+
+        if config.exit_code_on_found != 0 {
+            writeln!(&mut writer, "{}", self.exit_code_on_found)?;
+        }
+
+        if config.exit_code_on_none != 0 {
+            writeln!(&mut writer, "{}", self.exit_code_on_none)?;
+        }
+
+        if config.interesting_only {
+            writeln!(&mut writer, "{}", self.interesting_only)?;
+        }
+
+        if config.filter_duplicate_content {
+            writeln!(&mut writer, "{}", self.filter_duplicate_content)?;
+        }
+
+        if config.websocket_discovery {
+            writeln!(&mut writer, "{}", self.websocket_discovery)?;
+        }
+
+        if config.cors_discovery {
+            writeln!(&mut writer, "{}", self.cors_discovery)?;
+        }
+
+        if config.options_discovery {
+            writeln!(&mut writer, "{}", self.options_discovery)?;
+        }
+
+        if config.save_state_on_empty {
+            writeln!(&mut writer, "{}", self.save_state_on_empty)?;
+        }
+
+        if config.split_state {
+            writeln!(&mut writer, "{}", self.split_state)?;
+        }
+
+        if config.recurse_on_status
+            != DEFAULT_RECURSE_ON_STATUS
+                .iter()
+                .map(|code| code.as_u16())
+                .collect::<Vec<_>>()
+        {
+            writeln!(&mut writer, "{}", self.recurse_on_status)?;
+        }
+
+        if config.deduplicate_by_path {
+            writeln!(&mut writer, "{}", self.deduplicate_by_path)?;
+        }
+
+        if config.output_rotate_size > 0 {
+            writeln!(&mut writer, "{}", self.output_rotate_size)?;
+        }
+
+        if config.output_rotate_max_files > 0 {
+            writeln!(&mut writer, "{}", self.output_rotate_max_files)?;
+        }
+
+        if config.output_deduplicate {
+            writeln!(&mut writer, "{}", self.output_deduplicate)?;
+        }
+
+        if config.filter_response_time_above > 0 {
+            writeln!(&mut writer, "{}", self.filter_response_time_above)?;
+        }
+
+        if config.filter_response_time_below > 0 {
+            writeln!(&mut writer, "{}", self.filter_response_time_below)?;
+        }
+
+        if config.filter_max_headers > 0 {
+            writeln!(&mut writer, "{}", self.filter_max_headers)?;
+        }
+
+        if config.filter_min_headers > 0 {
+            writeln!(&mut writer, "{}", self.filter_min_headers)?;
+        }
+
+        if config.case_insensitive_dedup {
+            writeln!(&mut writer, "{}", self.case_insensitive_dedup)?;
+        }
+
+        if config.collect_api_versions {
+            writeln!(&mut writer, "{}", self.collect_api_versions)?;
+        }
+
+        if config.filter_min_ratio > 0.0 {
+            writeln!(&mut writer, "{}", self.filter_min_ratio)?;
+        }
+
+        if config.collect_page_titles {
+            writeln!(&mut writer, "{}", self.collect_page_titles)?;
+        }
+
+        if config.collect_meta_tags {
+            writeln!(&mut writer, "{}", self.collect_meta_tags)?;
+        }
+
+        if config.collect_inline_js {
+            writeln!(&mut writer, "{}", self.collect_inline_js)?;
+        }
+
+        if !config.multipart_wordlist.is_empty() {
+            writeln!(&mut writer, "{}", self.multipart_wordlist)?;
+        }
+
+        if config.verbose_scan_start {
+            writeln!(&mut writer, "{}", self.verbose_scan_start)?;
+        }
+
+        if config.collect_301_targets {
+            writeln!(&mut writer, "{}", self.collect_301_targets)?;
+        }
+
+        if config.collect_subpath_wordlist {
+            writeln!(&mut writer, "{}", self.collect_subpath_wordlist)?;
+        }
+
+        if config.collect_security_headers {
+            writeln!(&mut writer, "{}", self.collect_security_headers)?;
+        }
+
+        if config.collect_hsts {
+            writeln!(&mut writer, "{}", self.collect_hsts)?;
+        }
+
+        if config.sort_output {
+            writeln!(&mut writer, "{}", self.sort_output)?;
+        }
+
         if matches!(self.update_status, UpdateStatus::OutOfDate) {
             let update = BannerEntry::new(
                 "🎉",
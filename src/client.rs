@@ -1,11 +1,14 @@
+use crate::dns::FeroxResolver;
 use anyhow::Result;
 use reqwest::header::HeaderMap;
-use reqwest::{redirect::Policy, Client, Proxy};
+use reqwest::{redirect::Policy, Client, NoProxy, Proxy};
 use std::collections::HashMap;
 use std::convert::TryInto;
+use std::sync::Arc;
 use std::time::Duration;
 
 /// Create and return an instance of [reqwest::Client](https://docs.rs/reqwest/latest/reqwest/struct.Client.html)
+#[allow(clippy::too_many_arguments)]
 pub fn initialize(
     timeout: u64,
     user_agent: &str,
@@ -13,6 +16,8 @@ pub fn initialize(
     insecure: bool,
     headers: &HashMap<String, String>,
     proxy: Option<&str>,
+    proxy_exclude: &[String],
+    resolver: Option<Arc<FeroxResolver>>,
 ) -> Result<Client> {
     let policy = if redirects {
         Policy::limited(10)
@@ -22,7 +27,7 @@ pub fn initialize(
 
     let header_map: HeaderMap = headers.try_into()?;
 
-    let client = Client::builder()
+    let mut client = Client::builder()
         .timeout(Duration::new(timeout, 0))
         .user_agent(user_agent)
         .danger_accept_invalid_certs(insecure)
@@ -30,10 +35,21 @@ pub fn initialize(
         .redirect(policy)
         .http1_title_case_headers();
 
+    if let Some(resolver) = resolver {
+        // --dns-server|--dns-over-https used; all hostname lookups go through the given resolver
+        client = client.dns_resolver(resolver);
+    }
+
     if let Some(some_proxy) = proxy {
         if !some_proxy.is_empty() {
             // it's not an empty string; set the proxy
-            let proxy_obj = Proxy::all(some_proxy)?;
+            let mut proxy_obj = Proxy::all(some_proxy)?;
+
+            if !proxy_exclude.is_empty() {
+                // --proxy-exclude-hosts; hosts matching these patterns are requested directly
+                proxy_obj = proxy_obj.no_proxy(NoProxy::from_string(&proxy_exclude.join(",")));
+            }
+
             return Ok(client.proxy(proxy_obj).build()?);
         }
     }
@@ -50,7 +66,17 @@ mod tests {
     /// create client with a bad proxy, expect panic
     fn client_with_bad_proxy() {
         let headers = HashMap::new();
-        initialize(0, "stuff", true, false, &headers, Some("not a valid proxy")).unwrap();
+        initialize(
+            0,
+            "stuff",
+            true,
+            false,
+            &headers,
+            Some("not a valid proxy"),
+            &[],
+            None,
+        )
+        .unwrap();
     }
 
     #[test]
@@ -58,6 +84,33 @@ mod tests {
     fn client_with_good_proxy() {
         let headers = HashMap::new();
         let proxy = "http://127.0.0.1:8080";
-        initialize(0, "stuff", true, true, &headers, Some(proxy)).unwrap();
+        initialize(0, "stuff", true, true, &headers, Some(proxy), &[], None).unwrap();
+    }
+
+    #[test]
+    /// create client with a proxy and hosts that bypass it, expect no error
+    fn client_with_proxy_exclusions() {
+        let headers = HashMap::new();
+        let proxy = "http://127.0.0.1:8080";
+        let excluded = vec!["localhost".to_string(), ".internal.example.com".to_string()];
+        initialize(
+            0,
+            "stuff",
+            true,
+            true,
+            &headers,
+            Some(proxy),
+            &excluded,
+            None,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    /// create client with a custom dns resolver, expect no error
+    fn client_with_custom_resolver() {
+        let headers = HashMap::new();
+        let resolver = crate::dns::resolver(Some("127.0.0.1:5353"), None).unwrap();
+        initialize(0, "stuff", true, true, &headers, None, &[], resolver).unwrap();
     }
 }
@@ -1,11 +1,18 @@
 use super::utils::{
-    depth, ignored_extensions, methods, report_and_exit, save_state, serialized_type, status_codes,
-    threads, timeout, user_agent, wordlist, OutputLevel, RequesterPolicy,
+    depth, html_gallery_dir, ignored_extensions, inline_js_dir, max_retries, methods,
+    multipart_field, rate_limit_recovery, recurse_on_status, report_and_exit, save_state,
+    serialized_type, sort_by, status_codes, threads, threads_burst_duration, timeout, user_agent,
+    wordlist, wordlist_encoding, OutputLevel, RequesterPolicy,
 };
 use crate::config::determine_output_level;
 use crate::config::utils::determine_requester_policy;
 use crate::{
-    client, parser, scan_manager::resume_scan, traits::FeroxSerialize, utils::fmt_err,
+    client, dns,
+    filters::{parse_count_range, JsonPathExpression, SizeRange},
+    parser,
+    scan_manager::resume_scan,
+    traits::FeroxSerialize,
+    utils::fmt_err,
     DEFAULT_CONFIG_NAME,
 };
 use anyhow::{anyhow, Context, Result};
@@ -233,6 +240,10 @@ pub struct Configuration {
     #[serde(default)]
     pub filter_regex: Vec<String>,
 
+    /// Filter out messages whose url ends with one of the given extensions
+    #[serde(default)]
+    pub filter_extension: Vec<String>,
+
     /// Don't auto-filter wildcard responses
     #[serde(default)]
     pub dont_filter: bool,
@@ -254,6 +265,11 @@ pub struct Configuration {
     #[serde(default)]
     pub time_limit: String,
 
+    /// How often the scan's state is written to disk while it runs, expressed the same way as
+    /// time_limit; an empty string means no checkpoints are written
+    #[serde(default)]
+    pub checkpoint_interval: String,
+
     /// Filter out response bodies that meet a certain threshold of similarity
     #[serde(default)]
     pub filter_similar: Vec<String>,
@@ -285,6 +301,374 @@ pub struct Configuration {
     /// override recursion logic to always attempt recursion, still respects --depth
     #[serde(default)]
     pub force_recursion: bool,
+
+    /// Report 3xx responses that redirect to a url that hasn't been scanned, even when filtered
+    #[serde(default)]
+    pub include_unconfirmed_redirects: bool,
+
+    /// URL to which a JSON summary is POSTed once all scans complete
+    #[serde(default)]
+    pub notify_url: String,
+
+    /// Send a desktop notification once all scans complete
+    #[serde(default)]
+    pub desktop_notify: bool,
+
+    /// Scan the destinations of same-host redirects as newly discovered targets
+    #[serde(default)]
+    pub redirect_as_discovery: bool,
+
+    /// Scan the action urls of html forms found during the scan
+    #[serde(default)]
+    pub collect_forms: bool,
+
+    /// Collect query parameter names found in responses, saved to ferox-params-<host>.txt
+    #[serde(default)]
+    pub collect_parameters: bool,
+
+    /// State file whose successfully found urls shouldn't be requested again
+    #[serde(default)]
+    pub check_previous_state: String,
+
+    /// File to which connection/timeout errors are written as NDJSON
+    #[serde(default)]
+    pub connection_errors_to_file: String,
+
+    /// File to which a Metasploit resource script (.rc) of discovered urls is written
+    #[serde(default)]
+    pub export_metasploit: String,
+
+    /// Number of times an unfinished scan may be resumed before it's marked as failed
+    #[serde(default = "max_retries")]
+    pub max_retries: u32,
+
+    /// DNS server (`ip[:port]`) used to resolve hostnames instead of the OS resolver
+    #[serde(default)]
+    pub dns_server: Option<String>,
+
+    /// DNS over HTTPS url used to resolve hostnames instead of the OS resolver
+    #[serde(default)]
+    pub dns_over_https: Option<String>,
+
+    /// Fingerprint each host's technologies and print a summary once scanning completes
+    #[serde(default)]
+    pub collect_technology_stack: bool,
+
+    /// Keep duplicate wordlist entries instead of dropping them
+    #[serde(default)]
+    pub no_wordlist_dedup: bool,
+
+    /// Show responses of every status code, ignoring --status-codes and --filter-status
+    #[serde(default)]
+    pub include_all_status: bool,
+
+    /// Maximum number of response body bytes to read; larger bodies are truncated (0 means no limit)
+    #[serde(default)]
+    pub max_response_size: u64,
+
+    /// Port on which to run a local test server instead of scanning (0 means no test server)
+    #[serde(default)]
+    pub test_server: u16,
+
+    /// TOML file of rules that decide how the test server responds to each path
+    #[serde(default)]
+    pub test_server_rules: String,
+
+    /// Two state files to compare instead of scanning (empty means no comparison)
+    #[serde(default)]
+    pub diff: Vec<String>,
+
+    /// Number of urls a single scan may find before it stops making requests (0 means no limit)
+    #[serde(default)]
+    pub max_urls_per_scan: u32,
+
+    /// Send identical requests even when they were already sent by another scan
+    #[serde(default)]
+    pub no_request_dedup: bool,
+
+    /// Status codes that are written to `--output`; all others are only shown on the terminal
+    #[serde(default)]
+    pub output_filter_status: Vec<u16>,
+
+    /// Reorder each scan's wordlist so words sharing a prefix with a found word are requested sooner
+    #[serde(default)]
+    pub adaptive_wordlist: bool,
+
+    /// Ranges of body sizes (`MIN-MAX`) whose responses are filtered out
+    #[serde(default)]
+    pub filter_body_size_range: Vec<String>,
+
+    /// Ranges of body sizes (`MIN-MAX`) outside of which responses are filtered out
+    #[serde(default)]
+    pub keep_body_size_range: Vec<String>,
+
+    /// Request the source maps referenced by javascript files, saved to ferox-sourcemaps-<host>.txt
+    #[serde(default)]
+    pub collect_source_maps: bool,
+
+    /// Halve a scan's requests per second each time a 429 is received
+    #[serde(default)]
+    pub rate_limit_detect: bool,
+
+    /// Seconds without a 429 before --rate-limit-detect raises the requests per second again
+    #[serde(default = "rate_limit_recovery")]
+    pub rate_limit_recovery: u64,
+
+    /// Print results directly to stdout as they arrive and disable progress bars
+    #[serde(default)]
+    pub live_output: bool,
+
+    /// Ranges of word counts (`MIN-MAX`) whose responses are filtered out
+    #[serde(default)]
+    pub filter_words_range: Vec<String>,
+
+    /// Ranges of line counts (`MIN-MAX`) whose responses are filtered out
+    #[serde(default)]
+    pub filter_lines_range: Vec<String>,
+
+    /// Fetch the tls certificate of each https host seen while scanning
+    #[serde(default)]
+    pub collect_certificates: bool,
+
+    /// Write a static html page for each 200 html response to `html_gallery_dir`
+    #[serde(default)]
+    pub store_responses_as_html: bool,
+
+    /// Directory in which the html gallery is written
+    #[serde(default = "html_gallery_dir")]
+    pub html_gallery_dir: String,
+
+    /// Character encoding used to decode the wordlist
+    #[serde(default = "wordlist_encoding")]
+    pub wordlist_encoding: String,
+
+    /// Send HEAD requests and only GET 200 html pages, reporting their html/js comments
+    #[serde(default)]
+    pub collect_comments_only: bool,
+
+    /// Script to run, with a state file, once all scans complete
+    #[serde(default)]
+    pub post_scan_script: String,
+
+    /// Code to exit with when at least one url is discovered
+    #[serde(default)]
+    pub exit_code_on_found: i32,
+
+    /// Code to exit with when no urls are discovered
+    #[serde(default)]
+    pub exit_code_on_none: i32,
+
+    /// Only report responses that FeroxResponse::is_interesting considers interesting
+    #[serde(default)]
+    pub interesting_only: bool,
+
+    /// Filter out responses whose body matches the body of a response that was already reported
+    #[serde(default)]
+    pub filter_duplicate_content: bool,
+
+    /// Probe each discovered url for websocket upgrade support
+    #[serde(default)]
+    pub websocket_discovery: bool,
+
+    /// Check each discovered url for a permissive Access-Control-Allow-Origin
+    #[serde(default)]
+    pub cors_discovery: bool,
+
+    /// Send an OPTIONS request to each discovered directory and report the methods it allows
+    #[serde(default)]
+    pub options_discovery: bool,
+
+    /// Write a state file when a scan completes without finding anything
+    #[serde(default)]
+    pub save_state_on_empty: bool,
+
+    /// Status codes a directory must be found with in order to be recursed into
+    #[serde(default = "recurse_on_status")]
+    pub recurse_on_status: Vec<u16>,
+
+    /// Only request the first extracted link seen for each path, ignoring query strings and fragments
+    #[serde(default)]
+    pub deduplicate_by_path: bool,
+
+    /// Size, in bytes, past which output is written to a new file; 0 disables rotation
+    #[serde(default)]
+    pub output_rotate_size: u64,
+
+    /// Maximum number of output files kept by --output-rotate-size; 0 means no limit
+    #[serde(default)]
+    pub output_rotate_max_files: usize,
+
+    /// Filter out responses that took longer than this many milliseconds; 0 disables the filter
+    #[serde(default)]
+    pub filter_response_time_above: u64,
+
+    /// Filter out responses that took less than this many milliseconds; 0 disables the filter
+    #[serde(default)]
+    pub filter_response_time_below: u64,
+
+    /// Treat urls that differ only in the case of their path as duplicates
+    #[serde(default)]
+    pub case_insensitive_dedup: bool,
+
+    /// Probe the versions following any versioned api path that is found
+    #[serde(default)]
+    pub collect_api_versions: bool,
+
+    /// Filter out responses whose size is within this ratio of the wildcard response's size; 0.0 disables the filter
+    #[serde(default)]
+    pub filter_min_ratio: f64,
+
+    /// Hosts that bypass --proxy; same format as the no_proxy environment variable
+    #[serde(default)]
+    pub proxy_exclude_patterns: Vec<String>,
+
+    /// Collect the title of each html page that is found
+    #[serde(default)]
+    pub collect_page_titles: bool,
+
+    /// Wordlist of filenames to upload as multipart/form-data POST requests
+    #[serde(default)]
+    pub multipart_wordlist: String,
+
+    /// Form field name used for --multipart-wordlist uploads
+    #[serde(default = "multipart_field")]
+    pub multipart_field: String,
+
+    /// Filter out JSON responses matching any of these JSONPath expressions
+    #[serde(default)]
+    pub json_path_filter: Vec<String>,
+
+    /// Filter out JSON responses matching none of these JSONPath expressions
+    #[serde(default)]
+    pub json_path_keep_if: Vec<String>,
+
+    /// Print a line describing each scan as it starts
+    #[serde(default)]
+    pub verbose_scan_start: bool,
+
+    /// Save the source and destination of each 3xx response to ferox-redirects-<host>.txt
+    #[serde(default)]
+    pub collect_301_targets: bool,
+
+    /// Report html pages that are missing common security headers
+    #[serde(default)]
+    pub collect_security_headers: bool,
+
+    /// Flag https urls with a missing or weak Strict-Transport-Security header
+    #[serde(default)]
+    pub collect_hsts: bool,
+
+    /// Write results to --output sorted by --sort-by once the scan ends
+    #[serde(default)]
+    pub sort_output: bool,
+
+    /// Sort key used by --sort-output; one of status, url, size, or time
+    #[serde(default = "sort_by")]
+    pub sort_by: String,
+
+    /// Wordlist of path segments to insert in front of the target url's path instead of appending
+    #[serde(default)]
+    pub prefix_wordlist: String,
+
+    /// Collect the meta tags of each html page that is found
+    #[serde(default)]
+    pub collect_meta_tags: bool,
+
+    /// Filter out responses with more than this many headers; 0 disables the filter
+    #[serde(default)]
+    pub filter_max_headers: usize,
+
+    /// Filter out responses with fewer than this many headers; 0 disables the filter
+    #[serde(default)]
+    pub filter_min_headers: usize,
+
+    /// Write the inline <script> blocks of each html page that is found to `inline_js_dir`
+    #[serde(default)]
+    pub collect_inline_js: bool,
+
+    /// Directory in which --collect-inline-js writes the inline scripts it finds
+    #[serde(default = "inline_js_dir")]
+    pub inline_js_dir: String,
+
+    /// File that every line printed to the terminal is also written to, colors included
+    #[serde(default)]
+    pub tee: String,
+
+    /// Pause every scan when a 429 is received, for as long as its Retry-After header asks
+    #[serde(default)]
+    pub pause_on_rate_limit: bool,
+
+    /// Write the scans and responses of state files to their own files
+    #[serde(default)]
+    pub split_state: bool,
+
+    /// Filter out responses with an empty body
+    #[serde(default)]
+    pub filter_empty_body: bool,
+
+    /// Filter out responses whose body is at most this many bytes; 0 disables the filter
+    #[serde(default)]
+    pub filter_near_empty_body: u64,
+
+    /// Limit the number of concurrent scans against a single host; 0 means no limit
+    #[serde(default)]
+    pub scan_limit_per_host: usize,
+
+    /// Build a wordlist from the path components of each url that is found
+    #[serde(default)]
+    pub collect_subpath_wordlist: bool,
+
+    /// Print a JSON summary once scanning completes instead of each result
+    #[serde(default)]
+    pub report_summary_only: bool,
+
+    /// Print the overall scan progress to stderr as JSON whenever it changes
+    #[serde(default)]
+    pub json_status: bool,
+
+    /// Scan the destination of same-host html meta refresh redirects as newly discovered targets
+    #[serde(default)]
+    pub follow_meta_refresh: bool,
+
+    /// Write each url to --output only once, even if it is reported again
+    #[serde(default)]
+    pub output_deduplicate: bool,
+
+    /// Request a built-in list of high-value paths in addition to the wordlist
+    #[serde(default)]
+    pub collect_interesting_paths: bool,
+
+    /// Add paths archived by the Wayback Machine for the target's host to the wordlist
+    #[serde(default)]
+    pub collect_passive: bool,
+
+    /// Add subdomains found in certificate transparency logs as additional targets
+    #[serde(default)]
+    pub collect_crtsh: bool,
+
+    /// Number of concurrent requests allowed at the start of each scan (0 means no burst)
+    #[serde(default)]
+    pub threads_burst: usize,
+
+    /// Milliseconds that --threads-burst lasts before dropping back to --threads
+    #[serde(default = "threads_burst_duration")]
+    pub threads_burst_duration: u64,
+
+    /// Watch the wordlist for changes and request any words added to it while scanning
+    #[serde(default)]
+    pub watch_wordlist: bool,
+
+    /// Print an estimate of the number of requests the scan would send, then exit
+    #[serde(default)]
+    pub simulate: bool,
+
+    /// Same as simulate, but the estimate is printed as JSON
+    #[serde(default)]
+    pub simulate_json: bool,
+
+    /// Don't start scans of urls beneath a directory that's already being scanned
+    #[serde(default)]
+    pub no_redundant_scans: bool,
 }
 
 impl Default for Configuration {
@@ -292,8 +676,17 @@ impl Default for Configuration {
     fn default() -> Self {
         let timeout = timeout();
         let user_agent = user_agent();
-        let client = client::initialize(timeout, &user_agent, false, false, &HashMap::new(), None)
-            .expect("Could not build client");
+        let client = client::initialize(
+            timeout,
+            &user_agent,
+            false,
+            false,
+            &HashMap::new(),
+            None,
+            &[],
+            None,
+        )
+        .expect("Could not build client");
         let replay_client = None;
         let status_codes = status_codes();
         let replay_codes = status_codes.clone();
@@ -340,6 +733,7 @@ impl Default for Configuration {
             debug_log: String::new(),
             target_url: String::new(),
             time_limit: String::new(),
+            checkpoint_interval: String::new(),
             resume_from: String::new(),
             replay_proxy: String::new(),
             queries: Vec::new(),
@@ -348,6 +742,7 @@ impl Default for Configuration {
             data: Vec::new(),
             filter_size: Vec::new(),
             filter_regex: Vec::new(),
+            filter_extension: Vec::new(),
             url_denylist: Vec::new(),
             regex_denylist: Vec::new(),
             filter_line_count: Vec::new(),
@@ -359,6 +754,98 @@ impl Default for Configuration {
             threads: threads(),
             wordlist: wordlist(),
             dont_collect: ignored_extensions(),
+            include_unconfirmed_redirects: false,
+            notify_url: String::new(),
+            desktop_notify: false,
+            redirect_as_discovery: false,
+            collect_forms: false,
+            collect_parameters: false,
+            check_previous_state: String::new(),
+            connection_errors_to_file: String::new(),
+            export_metasploit: String::new(),
+            max_retries: max_retries(),
+            dns_server: None,
+            dns_over_https: None,
+            collect_technology_stack: false,
+            no_wordlist_dedup: false,
+            include_all_status: false,
+            max_response_size: 0,
+            test_server: 0,
+            test_server_rules: String::new(),
+            diff: Vec::new(),
+            max_urls_per_scan: 0,
+            no_request_dedup: false,
+            output_filter_status: Vec::new(),
+            adaptive_wordlist: false,
+            filter_body_size_range: Vec::new(),
+            keep_body_size_range: Vec::new(),
+            collect_source_maps: false,
+            rate_limit_detect: false,
+            rate_limit_recovery: rate_limit_recovery(),
+            live_output: false,
+            filter_words_range: Vec::new(),
+            filter_lines_range: Vec::new(),
+            collect_certificates: false,
+            store_responses_as_html: false,
+            html_gallery_dir: html_gallery_dir(),
+            wordlist_encoding: wordlist_encoding(),
+            collect_comments_only: false,
+            post_scan_script: String::new(),
+            exit_code_on_found: 0,
+            exit_code_on_none: 0,
+            interesting_only: false,
+            filter_duplicate_content: false,
+            websocket_discovery: false,
+            cors_discovery: false,
+            options_discovery: false,
+            save_state_on_empty: false,
+            recurse_on_status: recurse_on_status(),
+            deduplicate_by_path: false,
+            output_rotate_size: 0,
+            output_rotate_max_files: 0,
+            filter_response_time_above: 0,
+            filter_response_time_below: 0,
+            case_insensitive_dedup: false,
+            collect_api_versions: false,
+            filter_min_ratio: 0.0,
+            proxy_exclude_patterns: Vec::new(),
+            collect_page_titles: false,
+            multipart_wordlist: String::new(),
+            multipart_field: multipart_field(),
+            json_path_filter: Vec::new(),
+            json_path_keep_if: Vec::new(),
+            verbose_scan_start: false,
+            collect_301_targets: false,
+            collect_security_headers: false,
+            collect_hsts: false,
+            sort_output: false,
+            sort_by: sort_by(),
+            prefix_wordlist: String::new(),
+            collect_meta_tags: false,
+            filter_max_headers: 0,
+            filter_min_headers: 0,
+            collect_inline_js: false,
+            inline_js_dir: inline_js_dir(),
+            tee: String::new(),
+            pause_on_rate_limit: false,
+            split_state: false,
+            filter_empty_body: false,
+            filter_near_empty_body: 0,
+            scan_limit_per_host: 0,
+            collect_subpath_wordlist: false,
+            report_summary_only: false,
+            json_status: false,
+            follow_meta_refresh: false,
+            output_deduplicate: false,
+            collect_interesting_paths: false,
+            collect_passive: false,
+            collect_crtsh: false,
+            threads_burst: 0,
+            threads_burst_duration: threads_burst_duration(),
+            watch_wordlist: false,
+            simulate: false,
+            simulate_json: false,
+            no_redundant_scans: false,
         }
     }
 }
@@ -400,6 +887,7 @@ impl Configuration {
     /// - **filter_size**: `None`
     /// - **filter_similar**: `None`
     /// - **filter_regex**: `None`
+    /// - **filter_extension**: `None`
     /// - **filter_word_count**: `None`
     /// - **filter_line_count**: `None`
     /// - **headers**: `None`
@@ -415,8 +903,101 @@ impl Configuration {
     /// - **parallel**: `0` (no limit on parallel scans imposed)
     /// - **rate_limit**: `0` (no limit on requests per second imposed)
     /// - **time_limit**: `None` (no limit on length of scan imposed)
+    /// - **checkpoint_interval**: `None` (no checkpoints written)
     /// - **replay_proxy**: `None` (no limit on concurrent scans imposed)
     /// - **replay_codes**: [`DEFAULT_RESPONSE_CODES`](constant.DEFAULT_RESPONSE_CODES.html)
+    /// - **include_unconfirmed_redirects**: `false`
+    /// - **notify_url**: `None`
+    /// - **desktop_notify**: `false`
+    /// - **redirect_as_discovery**: `false`
+    /// - **collect_forms**: `false`
+    /// - **collect_parameters**: `false`
+    /// - **check_previous_state**: `None`
+    /// - **connection_errors_to_file**: `None`
+    /// - **export_metasploit**: `None`
+    /// - **max_retries**: `3`
+    /// - **dns_server**: `None`
+    /// - **dns_over_https**: `None`
+    /// - **collect_technology_stack**: `false`
+    /// - **no_wordlist_dedup**: `false`
+    /// - **include_all_status**: `false`
+    /// - **max_response_size**: `0` (response bodies are always read in full)
+    /// - **test_server**: `0` (no test server)
+    /// - **test_server_rules**: `String::new()`
+    /// - **diff**: `None`
+    /// - **max_urls_per_scan**: `0` (no limit on urls found per scan)
+    /// - **no_request_dedup**: `false`
+    /// - **output_filter_status**: `None`
+    /// - **adaptive_wordlist**: `false`
+    /// - **filter_body_size_range**: `None`
+    /// - **keep_body_size_range**: `None`
+    /// - **collect_source_maps**: `false`
+    /// - **rate_limit_detect**: `false`
+    /// - **rate_limit_recovery**: `10` seconds
+    /// - **live_output**: `false`
+    /// - **filter_words_range**: `None`
+    /// - **filter_lines_range**: `None`
+    /// - **collect_certificates**: `false`
+    /// - **store_responses_as_html**: `false`
+    /// - **html_gallery_dir**: `ferox-gallery`
+    /// - **wordlist_encoding**: `utf-8`
+    /// - **collect_comments_only**: `false`
+    /// - **post_scan_script**: `None`
+    /// - **exit_code_on_found**: `0`
+    /// - **exit_code_on_none**: `0`
+    /// - **interesting_only**: `false`
+    /// - **filter_duplicate_content**: `false`
+    /// - **websocket_discovery**: `false`
+    /// - **cors_discovery**: `false`
+    /// - **options_discovery**: `false`
+    /// - **save_state_on_empty**: `false`
+    /// - **recurse_on_status**: [`DEFAULT_RECURSE_ON_STATUS`](constant.DEFAULT_RECURSE_ON_STATUS.html)
+    /// - **deduplicate_by_path**: `false`
+    /// - **output_rotate_size**: `0` (output is never rotated)
+    /// - **output_rotate_max_files**: `0` (rotated output files are never deleted)
+    /// - **filter_response_time_above**: `0` (no response time filter)
+    /// - **filter_response_time_below**: `0` (no response time filter)
+    /// - **case_insensitive_dedup**: `false`
+    /// - **collect_api_versions**: `false`
+    /// - **filter_min_ratio**: `0.0` (no size ratio filter)
+    /// - **proxy_exclude_patterns**: `None`
+    /// - **collect_page_titles**: `false`
+    /// - **multipart_wordlist**: `None`
+    /// - **multipart_field**: `file`
+    /// - **json_path_filter**: `None`
+    /// - **json_path_keep_if**: `None`
+    /// - **verbose_scan_start**: `false`
+    /// - **collect_301_targets**: `false`
+    /// - **collect_security_headers**: `false`
+    /// - **collect_hsts**: `false`
+    /// - **sort_output**: `false`
+    /// - **sort_by**: `status`
+    /// - **prefix_wordlist**: `None`
+    /// - **collect_meta_tags**: `false`
+    /// - **filter_max_headers**: `0` (no header count filter)
+    /// - **filter_min_headers**: `0` (no header count filter)
+    /// - **collect_inline_js**: `false`
+    /// - **inline_js_dir**: `ferox-inline-js`
+    /// - **tee**: `None`
+    /// - **pause_on_rate_limit**: `false`
+    /// - **split_state**: `false`
+    /// - **filter_empty_body**: `false`
+    /// - **filter_near_empty_body**: `0` (no near empty body filter)
+    /// - **scan_limit_per_host**: `0` (no limit)
+    /// - **collect_subpath_wordlist**: `false`
+    /// - **report_summary_only**: `false`
+    /// - **json_status**: `false`
+    /// - **follow_meta_refresh**: `false`
+    /// - **output_deduplicate**: `false`
+    /// - **collect_interesting_paths**: `false`
+    /// - **collect_passive**: `false`
+    /// - **collect_crtsh**: `false`
+    /// - **threads_burst**: `0` (no burst)
+    /// - **threads_burst_duration**: `1000` milliseconds
+    /// - **watch_wordlist**: `false`
+    /// - **simulate**: `false`
+    /// - **simulate_json**: `false`
+    /// - **no_redundant_scans**: `false`
     ///
     /// After which, any values defined in a
     /// [ferox-config.toml](constant.DEFAULT_CONFIG_NAME.html) config file will override the
@@ -493,6 +1074,10 @@ impl Configuration {
         // rebuild clients is the last step in either code branch
         Self::try_rebuild_clients(&mut config);
 
+        // --prefix-wordlist results would be scanned again with a second prefix in front of the
+        // first, so recursion is left off
+        config.no_recursion |= !config.prefix_wordlist.is_empty();
+
         Ok(config)
     }
 
@@ -511,9 +1096,7 @@ impl Configuration {
         //   - current directory
 
         // merge a config found at /etc/feroxbuster/ferox-config.toml
-        let config_file = PathBuf::new()
-            .join("/etc/feroxbuster")
-            .join(DEFAULT_CONFIG_NAME);
+        let config_file = PathBuf::from("/etc/feroxbuster").join(DEFAULT_CONFIG_NAME);
         Self::parse_and_merge_config(config_file, config)?;
 
         // merge a config found at ~/.config/feroxbuster/ferox-config.toml
@@ -547,6 +1130,12 @@ impl Configuration {
         let mut config = Configuration::default();
 
         update_config_if_present!(&mut config.threads, args, "threads");
+        update_config_if_present!(&mut config.threads_burst, args, "threads_burst");
+        update_config_if_present!(
+            &mut config.threads_burst_duration,
+            args,
+            "threads_burst_duration"
+        );
         update_config_if_present!(&mut config.depth, args, "depth");
         update_config_if_present!(&mut config.scan_limit, args, "scan_limit");
         update_config_if_present!(&mut config.parallel, args, "parallel");
@@ -555,6 +1144,7 @@ impl Configuration {
         update_config_if_present!(&mut config.output, args, "output");
         update_config_if_present!(&mut config.debug_log, args, "debug_log");
         update_config_if_present!(&mut config.time_limit, args, "time_limit");
+        update_config_if_present!(&mut config.checkpoint_interval, args, "checkpoint_interval");
         update_config_if_present!(&mut config.resume_from, args, "resume_from");
 
         if let Some(arg) = args.values_of("status_codes") {
@@ -676,6 +1266,13 @@ impl Configuration {
             config.filter_regex = arg.map(|val| val.to_string()).collect();
         }
 
+        if let Some(arg) = args.values_of("filter_extension") {
+            config.filter_extension = arg
+                .map(|val| val.trim_start_matches('.').to_string())
+                .filter(|val| !val.is_empty())
+                .collect();
+        }
+
         if let Some(arg) = args.values_of("filter_similar") {
             config.filter_similar = arg.map(|val| val.to_string()).collect();
         }
@@ -778,6 +1375,11 @@ impl Configuration {
             config.extract_links = true;
         }
 
+        if args.is_present("no_extract_links") {
+            // explicit opt-out; overrides both ferox-config.toml and --smart/--thorough
+            config.extract_links = false;
+        }
+
         if args.is_present("json") {
             config.json = true;
         }
@@ -785,7 +1387,298 @@ impl Configuration {
         if args.is_present("force_recursion") {
             config.force_recursion = true;
         }
+        if args.is_present("no_redundant_scans") {
+            config.no_redundant_scans = true;
+        }
 
+        if args.is_present("include_unconfirmed_redirects") {
+            config.include_unconfirmed_redirects = true;
+        }
+
+        update_config_if_present!(&mut config.notify_url, args, "notify_url");
+        if args.is_present("desktop_notify") {
+            config.desktop_notify = true;
+        }
+
+        if args.is_present("redirect_as_discovery") {
+            config.redirect_as_discovery = true;
+        }
+
+        if args.is_present("collect_forms") {
+            config.collect_forms = true;
+        }
+
+        if args.is_present("collect_parameters") {
+            config.collect_parameters = true;
+        }
+
+        update_config_if_present!(
+            &mut config.check_previous_state,
+            args,
+            "check_previous_state"
+        );
+        update_config_if_present!(
+            &mut config.connection_errors_to_file,
+            args,
+            "connection_errors_to_file"
+        );
+        update_config_if_present!(&mut config.export_metasploit, args, "export_metasploit");
+        update_config_if_present!(&mut config.max_retries, args, "max_retries");
+        if let Some(server) = args.value_of("dns_server") {
+            config.dns_server = Some(server.to_string());
+        }
+        if let Some(url) = args.value_of("dns_over_https") {
+            config.dns_over_https = Some(url.to_string());
+        }
+        if args.is_present("collect_technology_stack") {
+            config.collect_technology_stack = true;
+        }
+        if args.is_present("no_wordlist_dedup") {
+            config.no_wordlist_dedup = true;
+        }
+        if args.is_present("include_all_status") {
+            config.include_all_status = true;
+        }
+        update_config_if_present!(&mut config.max_response_size, args, "max_response_size");
+        update_config_if_present!(&mut config.test_server, args, "test_server");
+        update_config_if_present!(&mut config.test_server_rules, args, "test_server_rules");
+        if let Some(arg) = args.values_of("diff") {
+            config.diff = arg.map(|val| val.to_string()).collect();
+        }
+        update_config_if_present!(&mut config.max_urls_per_scan, args, "max_urls_per_scan");
+        if args.is_present("no_request_dedup") {
+            config.no_request_dedup = true;
+        }
+        if let Some(arg) = args.values_of("output_filter_status") {
+            config.output_filter_status = arg
+                .map(|code| {
+                    StatusCode::from_bytes(code.as_bytes())
+                        .unwrap_or_else(|e| report_and_exit(&e.to_string()))
+                        .as_u16()
+                })
+                .collect();
+        }
+        if args.is_present("adaptive_wordlist") {
+            config.adaptive_wordlist = true;
+        }
+        if args.is_present("watch_wordlist") {
+            config.watch_wordlist = true;
+        }
+        if args.is_present("simulate") {
+            config.simulate = true;
+        }
+        if args.is_present("simulate_json") {
+            // --simulate-json implies --simulate
+            config.simulate = true;
+            config.simulate_json = true;
+        }
+        if let Some(arg) = args.values_of("filter_body_size_range") {
+            config.filter_body_size_range = arg
+                .map(|range| {
+                    range
+                        .parse::<SizeRange>()
+                        .unwrap_or_else(|e| report_and_exit(&e.to_string()));
+                    range.to_string()
+                })
+                .collect();
+        }
+        if let Some(arg) = args.values_of("keep_body_size_range") {
+            config.keep_body_size_range = arg
+                .map(|range| {
+                    range
+                        .parse::<SizeRange>()
+                        .unwrap_or_else(|e| report_and_exit(&e.to_string()));
+                    range.to_string()
+                })
+                .collect();
+        }
+        if args.is_present("collect_source_maps") {
+            config.collect_source_maps = true;
+        }
+        if args.is_present("rate_limit_detect") {
+            config.rate_limit_detect = true;
+        }
+        update_config_if_present!(&mut config.rate_limit_recovery, args, "rate_limit_recovery");
+        if args.is_present("live_output") {
+            config.live_output = true;
+        }
+        if let Some(arg) = args.values_of("filter_words_range") {
+            config.filter_words_range = arg
+                .map(|range| {
+                    parse_count_range(range).unwrap_or_else(|e| report_and_exit(&e.to_string()));
+                    range.to_string()
+                })
+                .collect();
+        }
+        if let Some(arg) = args.values_of("filter_lines_range") {
+            config.filter_lines_range = arg
+                .map(|range| {
+                    parse_count_range(range).unwrap_or_else(|e| report_and_exit(&e.to_string()));
+                    range.to_string()
+                })
+                .collect();
+        }
+        if args.is_present("collect_certificates") {
+            config.collect_certificates = true;
+        }
+        if args.is_present("store_responses_as_html") {
+            config.store_responses_as_html = true;
+        }
+        update_config_if_present!(&mut config.html_gallery_dir, args, "html_gallery_dir");
+        update_config_if_present!(&mut config.wordlist_encoding, args, "wordlist_encoding");
+        if args.is_present("collect_comments_only") {
+            config.collect_comments_only = true;
+        }
+        update_config_if_present!(&mut config.post_scan_script, args, "post_scan_script");
+        update_config_if_present!(&mut config.exit_code_on_found, args, "exit_code_on_found");
+        update_config_if_present!(&mut config.exit_code_on_none, args, "exit_code_on_none");
+        if args.is_present("interesting_only") {
+            config.interesting_only = true;
+        }
+        if args.is_present("filter_duplicate_content") {
+            config.filter_duplicate_content = true;
+        }
+        if args.is_present("websocket_discovery") {
+            config.websocket_discovery = true;
+        }
+        if args.is_present("cors_discovery") {
+            config.cors_discovery = true;
+        }
+        if args.is_present("options_discovery") {
+            config.options_discovery = true;
+        }
+        if args.is_present("save_state_on_empty") {
+            config.save_state_on_empty = true;
+        }
+        if let Some(arg) = args.values_of("recurse_on_status") {
+            config.recurse_on_status = arg
+                .map(|code| {
+                    StatusCode::from_bytes(code.as_bytes())
+                        .unwrap_or_else(|e| report_and_exit(&e.to_string()))
+                        .as_u16()
+                })
+                .collect();
+        }
+        if args.is_present("deduplicate_by_path") {
+            config.deduplicate_by_path = true;
+        }
+        update_config_if_present!(&mut config.output_rotate_size, args, "output_rotate_size");
+        update_config_if_present!(
+            &mut config.output_rotate_max_files,
+            args,
+            "output_rotate_max_files"
+        );
+        update_config_if_present!(
+            &mut config.filter_response_time_above,
+            args,
+            "filter_response_time_above"
+        );
+        update_config_if_present!(
+            &mut config.filter_response_time_below,
+            args,
+            "filter_response_time_below"
+        );
+        if args.is_present("case_insensitive_dedup") {
+            config.case_insensitive_dedup = true;
+        }
+        if args.is_present("collect_api_versions") {
+            config.collect_api_versions = true;
+        }
+        update_config_if_present!(&mut config.filter_min_ratio, args, "filter_min_ratio");
+        if let Some(arg) = args.values_of("proxy_exclude_patterns") {
+            config.proxy_exclude_patterns = arg.map(|val| val.to_string()).collect();
+        }
+        if args.is_present("collect_page_titles") {
+            config.collect_page_titles = true;
+        }
+        update_config_if_present!(&mut config.multipart_wordlist, args, "multipart_wordlist");
+        update_config_if_present!(&mut config.multipart_field, args, "multipart_field");
+        if let Some(arg) = args.values_of("json_path_filter") {
+            config.json_path_filter = arg
+                .map(|expression| {
+                    expression
+                        .parse::<JsonPathExpression>()
+                        .unwrap_or_else(|e| report_and_exit(&e.to_string()));
+                    expression.to_string()
+                })
+                .collect();
+        }
+        if let Some(arg) = args.values_of("json_path_keep_if") {
+            config.json_path_keep_if = arg
+                .map(|expression| {
+                    expression
+                        .parse::<JsonPathExpression>()
+                        .unwrap_or_else(|e| report_and_exit(&e.to_string()));
+                    expression.to_string()
+                })
+                .collect();
+        }
+        if args.is_present("verbose_scan_start") {
+            config.verbose_scan_start = true;
+        }
+        if args.is_present("collect_301_targets") {
+            config.collect_301_targets = true;
+        }
+        if args.is_present("collect_security_headers") {
+            config.collect_security_headers = true;
+        }
+        if args.is_present("collect_hsts") {
+            config.collect_hsts = true;
+        }
+        if args.is_present("sort_output") {
+            config.sort_output = true;
+        }
+        update_config_if_present!(&mut config.sort_by, args, "sort_by");
+        update_config_if_present!(&mut config.prefix_wordlist, args, "prefix_wordlist");
+        if args.is_present("collect_meta_tags") {
+            config.collect_meta_tags = true;
+        }
+        update_config_if_present!(&mut config.filter_max_headers, args, "filter_max_headers");
+        update_config_if_present!(&mut config.filter_min_headers, args, "filter_min_headers");
+        if args.is_present("collect_inline_js") {
+            config.collect_inline_js = true;
+        }
+        update_config_if_present!(&mut config.inline_js_dir, args, "inline_js_dir");
+        update_config_if_present!(&mut config.tee, args, "tee");
+        if args.is_present("pause_on_rate_limit") {
+            config.pause_on_rate_limit = true;
+        }
+        if args.is_present("split_state") {
+            config.split_state = true;
+        }
+        if args.is_present("filter_empty_body") {
+            config.filter_empty_body = true;
+        }
+        update_config_if_present!(
+            &mut config.filter_near_empty_body,
+            args,
+            "filter_near_empty_body"
+        );
+        update_config_if_present!(&mut config.scan_limit_per_host, args, "scan_limit_per_host");
+        if args.is_present("collect_subpath_wordlist") {
+            config.collect_subpath_wordlist = true;
+        }
+        if args.is_present("report_summary_only") {
+            config.report_summary_only = true;
+        }
+        if args.is_present("json_status") {
+            config.json_status = true;
+        }
+        if args.is_present("follow_meta_refresh") {
+            config.follow_meta_refresh = true;
+        }
+        if args.is_present("output_deduplicate") {
+            config.output_deduplicate = true;
+        }
+        if args.is_present("collect_interesting_paths") {
+            config.collect_interesting_paths = true;
+        }
+        if args.is_present("collect_passive") {
+            config.collect_passive = true;
+        }
+        if args.is_present("collect_crtsh") {
+            config.collect_crtsh = true;
+        }
         ////
         // organizational breakpoint; all options below alter the Client configuration
         ////
@@ -865,6 +1758,12 @@ impl Configuration {
     /// either the config file or command line arguments; if we have, we need to rebuild
     /// the client and store it in the config struct
     fn try_rebuild_clients(configuration: &mut Configuration) {
+        let resolver = dns::resolver(
+            configuration.dns_server.as_deref(),
+            configuration.dns_over_https.as_deref(),
+        )
+        .unwrap_or_else(|e| report_and_exit(&e.to_string()));
+
         if !configuration.proxy.is_empty()
             || configuration.timeout != timeout()
             || configuration.user_agent != user_agent()
@@ -872,6 +1771,7 @@ impl Configuration {
             || configuration.insecure
             || !configuration.headers.is_empty()
             || configuration.resumed
+            || resolver.is_some()
         {
             if configuration.proxy.is_empty() {
                 configuration.client = client::initialize(
@@ -881,6 +1781,8 @@ impl Configuration {
                     configuration.insecure,
                     &configuration.headers,
                     None,
+                    &[],
+                    resolver.clone(),
                 )
                 .expect("Could not rebuild client")
             } else {
@@ -891,6 +1793,8 @@ impl Configuration {
                     configuration.insecure,
                     &configuration.headers,
                     Some(&configuration.proxy),
+                    &configuration.proxy_exclude_patterns,
+                    resolver.clone(),
                 )
                 .expect("Could not rebuild client")
             }
@@ -906,6 +1810,8 @@ impl Configuration {
                     configuration.insecure,
                     &configuration.headers,
                     Some(&configuration.replay_proxy),
+                    &[],
+                    resolver,
                 )
                 .expect("Could not rebuild client"),
             );
@@ -914,7 +1820,7 @@ impl Configuration {
 
     /// Given a configuration file's location and an instance of `Configuration`, read in
     /// the config file if found and update the current settings with the settings found therein
-    fn parse_and_merge_config(config_file: PathBuf, mut config: &mut Self) -> Result<()> {
+    fn parse_and_merge_config(config_file: PathBuf, config: &mut Self) -> Result<()> {
         if config_file.exists() {
             // save off a string version of the path before it goes out of scope
             let conf_str = config_file.to_str().unwrap_or("").to_string();
@@ -939,6 +1845,7 @@ impl Configuration {
         //  - config
         update_if_not_default!(&mut conf.target_url, new.target_url, "");
         update_if_not_default!(&mut conf.time_limit, new.time_limit, "");
+        update_if_not_default!(&mut conf.checkpoint_interval, new.checkpoint_interval, "");
         update_if_not_default!(&mut conf.proxy, new.proxy, "");
         update_if_not_default!(&mut conf.verbosity, new.verbosity, 0);
         update_if_not_default!(&mut conf.silent, new.silent, false);
@@ -955,6 +1862,7 @@ impl Configuration {
         update_if_not_default!(&mut conf.redirects, new.redirects, false);
         update_if_not_default!(&mut conf.insecure, new.insecure, false);
         update_if_not_default!(&mut conf.force_recursion, new.force_recursion, false);
+        update_if_not_default!(&mut conf.no_redundant_scans, new.no_redundant_scans, false);
         update_if_not_default!(&mut conf.extract_links, new.extract_links, false);
         update_if_not_default!(&mut conf.extensions, new.extensions, Vec::<String>::new());
         update_if_not_default!(&mut conf.methods, new.methods, Vec::<String>::new());
@@ -989,6 +1897,11 @@ impl Configuration {
             new.filter_word_count,
             Vec::<usize>::new()
         );
+        update_if_not_default!(
+            &mut conf.filter_extension,
+            new.filter_extension,
+            Vec::<String>::new()
+        );
         update_if_not_default!(
             &mut conf.filter_line_count,
             new.filter_line_count,
@@ -1007,11 +1920,270 @@ impl Configuration {
         update_if_not_default!(&mut conf.debug_log, new.debug_log, "");
         update_if_not_default!(&mut conf.resume_from, new.resume_from, "");
         update_if_not_default!(&mut conf.json, new.json, false);
+        update_if_not_default!(
+            &mut conf.include_unconfirmed_redirects,
+            new.include_unconfirmed_redirects,
+            false
+        );
+        update_if_not_default!(&mut conf.notify_url, new.notify_url, "");
+        update_if_not_default!(&mut conf.desktop_notify, new.desktop_notify, false);
+        update_if_not_default!(
+            &mut conf.redirect_as_discovery,
+            new.redirect_as_discovery,
+            false
+        );
+        update_if_not_default!(&mut conf.collect_forms, new.collect_forms, false);
+        update_if_not_default!(&mut conf.collect_parameters, new.collect_parameters, false);
+        update_if_not_default!(&mut conf.check_previous_state, new.check_previous_state, "");
+        update_if_not_default!(
+            &mut conf.connection_errors_to_file,
+            new.connection_errors_to_file,
+            ""
+        );
+        update_if_not_default!(&mut conf.export_metasploit, new.export_metasploit, "");
+        update_if_not_default!(&mut conf.max_retries, new.max_retries, max_retries());
+        update_if_not_default!(&mut conf.dns_server, new.dns_server, None);
+        update_if_not_default!(&mut conf.dns_over_https, new.dns_over_https, None);
+        update_if_not_default!(
+            &mut conf.collect_technology_stack,
+            new.collect_technology_stack,
+            false
+        );
+        update_if_not_default!(&mut conf.no_wordlist_dedup, new.no_wordlist_dedup, false);
+        update_if_not_default!(&mut conf.include_all_status, new.include_all_status, false);
+        update_if_not_default!(&mut conf.max_response_size, new.max_response_size, 0);
+        update_if_not_default!(&mut conf.test_server, new.test_server, 0);
+        update_if_not_default!(&mut conf.test_server_rules, new.test_server_rules, "");
+        update_if_not_default!(&mut conf.diff, new.diff, Vec::<String>::new());
+        update_if_not_default!(&mut conf.max_urls_per_scan, new.max_urls_per_scan, 0);
+        update_if_not_default!(&mut conf.no_request_dedup, new.no_request_dedup, false);
+        update_if_not_default!(
+            &mut conf.output_filter_status,
+            new.output_filter_status,
+            Vec::<u16>::new()
+        );
+        update_if_not_default!(&mut conf.adaptive_wordlist, new.adaptive_wordlist, false);
+        update_if_not_default!(&mut conf.watch_wordlist, new.watch_wordlist, false);
+        update_if_not_default!(&mut conf.simulate, new.simulate, false);
+        update_if_not_default!(&mut conf.simulate_json, new.simulate_json, false);
+        update_if_not_default!(
+            &mut conf.filter_body_size_range,
+            new.filter_body_size_range,
+            Vec::<String>::new()
+        );
+        update_if_not_default!(
+            &mut conf.keep_body_size_range,
+            new.keep_body_size_range,
+            Vec::<String>::new()
+        );
+        update_if_not_default!(
+            &mut conf.collect_source_maps,
+            new.collect_source_maps,
+            false
+        );
+        update_if_not_default!(&mut conf.rate_limit_detect, new.rate_limit_detect, false);
+        update_if_not_default!(
+            &mut conf.rate_limit_recovery,
+            new.rate_limit_recovery,
+            rate_limit_recovery()
+        );
+        update_if_not_default!(&mut conf.live_output, new.live_output, false);
+        update_if_not_default!(
+            &mut conf.filter_words_range,
+            new.filter_words_range,
+            Vec::<String>::new()
+        );
+        update_if_not_default!(
+            &mut conf.filter_lines_range,
+            new.filter_lines_range,
+            Vec::<String>::new()
+        );
+        update_if_not_default!(
+            &mut conf.collect_certificates,
+            new.collect_certificates,
+            false
+        );
+        update_if_not_default!(
+            &mut conf.store_responses_as_html,
+            new.store_responses_as_html,
+            false
+        );
+        update_if_not_default!(
+            &mut conf.html_gallery_dir,
+            new.html_gallery_dir,
+            html_gallery_dir()
+        );
+        update_if_not_default!(
+            &mut conf.wordlist_encoding,
+            new.wordlist_encoding,
+            wordlist_encoding()
+        );
+        update_if_not_default!(
+            &mut conf.collect_comments_only,
+            new.collect_comments_only,
+            false
+        );
+        update_if_not_default!(&mut conf.post_scan_script, new.post_scan_script, "");
+        update_if_not_default!(&mut conf.exit_code_on_found, new.exit_code_on_found, 0);
+        update_if_not_default!(&mut conf.exit_code_on_none, new.exit_code_on_none, 0);
+        update_if_not_default!(&mut conf.interesting_only, new.interesting_only, false);
+        update_if_not_default!(
+            &mut conf.filter_duplicate_content,
+            new.filter_duplicate_content,
+            false
+        );
+        update_if_not_default!(
+            &mut conf.websocket_discovery,
+            new.websocket_discovery,
+            false
+        );
+        update_if_not_default!(&mut conf.cors_discovery, new.cors_discovery, false);
+        update_if_not_default!(&mut conf.options_discovery, new.options_discovery, false);
+        update_if_not_default!(
+            &mut conf.save_state_on_empty,
+            new.save_state_on_empty,
+            false
+        );
+        update_if_not_default!(
+            &mut conf.recurse_on_status,
+            new.recurse_on_status,
+            recurse_on_status()
+        );
+        update_if_not_default!(
+            &mut conf.deduplicate_by_path,
+            new.deduplicate_by_path,
+            false
+        );
+        update_if_not_default!(&mut conf.output_rotate_size, new.output_rotate_size, 0);
+        update_if_not_default!(
+            &mut conf.output_rotate_max_files,
+            new.output_rotate_max_files,
+            0
+        );
+        update_if_not_default!(
+            &mut conf.filter_response_time_above,
+            new.filter_response_time_above,
+            0
+        );
+        update_if_not_default!(
+            &mut conf.filter_response_time_below,
+            new.filter_response_time_below,
+            0
+        );
+        update_if_not_default!(
+            &mut conf.case_insensitive_dedup,
+            new.case_insensitive_dedup,
+            false
+        );
+        update_if_not_default!(
+            &mut conf.collect_api_versions,
+            new.collect_api_versions,
+            false
+        );
+        update_if_not_default!(&mut conf.filter_min_ratio, new.filter_min_ratio, 0.0);
+        update_if_not_default!(
+            &mut conf.proxy_exclude_patterns,
+            new.proxy_exclude_patterns,
+            Vec::<String>::new()
+        );
+        update_if_not_default!(
+            &mut conf.collect_page_titles,
+            new.collect_page_titles,
+            false
+        );
+        update_if_not_default!(
+            &mut conf.multipart_wordlist,
+            new.multipart_wordlist,
+            String::new()
+        );
+        update_if_not_default!(
+            &mut conf.multipart_field,
+            new.multipart_field,
+            multipart_field()
+        );
+        update_if_not_default!(
+            &mut conf.json_path_filter,
+            new.json_path_filter,
+            Vec::<String>::new()
+        );
+        update_if_not_default!(
+            &mut conf.json_path_keep_if,
+            new.json_path_keep_if,
+            Vec::<String>::new()
+        );
+        update_if_not_default!(&mut conf.verbose_scan_start, new.verbose_scan_start, false);
+        update_if_not_default!(
+            &mut conf.collect_301_targets,
+            new.collect_301_targets,
+            false
+        );
+        update_if_not_default!(
+            &mut conf.collect_security_headers,
+            new.collect_security_headers,
+            false
+        );
+        update_if_not_default!(&mut conf.collect_hsts, new.collect_hsts, false);
+        update_if_not_default!(&mut conf.sort_output, new.sort_output, false);
+        update_if_not_default!(&mut conf.sort_by, new.sort_by, sort_by());
+        update_if_not_default!(
+            &mut conf.prefix_wordlist,
+            new.prefix_wordlist,
+            String::new()
+        );
+        update_if_not_default!(&mut conf.collect_meta_tags, new.collect_meta_tags, false);
+        update_if_not_default!(&mut conf.filter_max_headers, new.filter_max_headers, 0);
+        update_if_not_default!(&mut conf.filter_min_headers, new.filter_min_headers, 0);
+        update_if_not_default!(&mut conf.collect_inline_js, new.collect_inline_js, false);
+        update_if_not_default!(&mut conf.inline_js_dir, new.inline_js_dir, inline_js_dir());
+        update_if_not_default!(&mut conf.tee, new.tee, String::new());
+        update_if_not_default!(
+            &mut conf.pause_on_rate_limit,
+            new.pause_on_rate_limit,
+            false
+        );
+        update_if_not_default!(&mut conf.split_state, new.split_state, false);
+        update_if_not_default!(&mut conf.filter_empty_body, new.filter_empty_body, false);
+        update_if_not_default!(
+            &mut conf.filter_near_empty_body,
+            new.filter_near_empty_body,
+            0
+        );
+        update_if_not_default!(&mut conf.scan_limit_per_host, new.scan_limit_per_host, 0);
+        update_if_not_default!(
+            &mut conf.collect_subpath_wordlist,
+            new.collect_subpath_wordlist,
+            false
+        );
+        update_if_not_default!(
+            &mut conf.report_summary_only,
+            new.report_summary_only,
+            false
+        );
+        update_if_not_default!(&mut conf.json_status, new.json_status, false);
+        update_if_not_default!(
+            &mut conf.follow_meta_refresh,
+            new.follow_meta_refresh,
+            false
+        );
+        update_if_not_default!(&mut conf.output_deduplicate, new.output_deduplicate, false);
+        update_if_not_default!(
+            &mut conf.collect_interesting_paths,
+            new.collect_interesting_paths,
+            false
+        );
+        update_if_not_default!(&mut conf.collect_passive, new.collect_passive, false);
+        update_if_not_default!(&mut conf.collect_crtsh, new.collect_crtsh, false);
 
         update_if_not_default!(&mut conf.timeout, new.timeout, timeout());
         update_if_not_default!(&mut conf.user_agent, new.user_agent, user_agent());
         update_if_not_default!(&mut conf.random_agent, new.random_agent, false);
         update_if_not_default!(&mut conf.threads, new.threads, threads());
+        update_if_not_default!(&mut conf.threads_burst, new.threads_burst, 0);
+        update_if_not_default!(
+            &mut conf.threads_burst_duration,
+            new.threads_burst_duration,
+            threads_burst_duration()
+        );
         update_if_not_default!(&mut conf.depth, new.depth, depth());
         update_if_not_default!(&mut conf.wordlist, new.wordlist, wordlist());
         update_if_not_default!(&mut conf.status_codes, new.status_codes, status_codes());
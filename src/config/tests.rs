@@ -25,6 +25,7 @@ fn setup_config_test() -> Configuration {
             parallel = 14
             rate_limit = 250
             time_limit = "10m"
+            checkpoint_interval = "5m"
             output = "/some/otherpath"
             debug_log = "/yet/anotherpath"
             resume_from = "/some/state/file"
@@ -52,10 +53,100 @@ fn setup_config_test() -> Configuration {
             force_recursion = true
             filter_size = [4120]
             filter_regex = ["^ignore me$"]
+            filter_extension = ["png", "jpg"]
             filter_similar = ["https://somesite.com/soft404"]
             filter_word_count = [994, 992]
             filter_line_count = [34]
             filter_status = [201]
+            include_unconfirmed_redirects = true
+            notify_url = "http://localhost/hook"
+            desktop_notify = true
+            redirect_as_discovery = true
+            collect_forms = true
+            collect_parameters = true
+            check_previous_state = "ferox-1606586780.state"
+            connection_errors_to_file = "/some/errors.ndjson"
+            export_metasploit = "/some/ferox.rc"
+            max_retries = 5
+            dns_server = "10.0.0.2:53"
+            dns_over_https = "https://1.1.1.1/dns-query"
+            collect_technology_stack = true
+            no_wordlist_dedup = true
+            include_all_status = true
+            max_response_size = 4096
+            max_urls_per_scan = 5
+            no_request_dedup = true
+            output_filter_status = [200, 301]
+            adaptive_wordlist = true
+            filter_body_size_range = ["100-200", "300-unlimited"]
+            keep_body_size_range = ["1000-unlimited"]
+            collect_source_maps = true
+            rate_limit_detect = true
+            rate_limit_recovery = 30
+            live_output = true
+            filter_words_range = ["100-500", "10-20"]
+            filter_lines_range = ["10-20", "30-40"]
+            collect_certificates = true
+            store_responses_as_html = true
+            html_gallery_dir = "/tmp/ferox-gallery"
+            wordlist_encoding = "windows-1252"
+            collect_comments_only = true
+            post_scan_script = "/tmp/hook.sh"
+            exit_code_on_found = 1
+            exit_code_on_none = 2
+            interesting_only = true
+            filter_duplicate_content = true
+            websocket_discovery = true
+            cors_discovery = true
+            options_discovery = true
+            save_state_on_empty = true
+            recurse_on_status = [200]
+            deduplicate_by_path = true
+            output_rotate_size = 1000
+            output_rotate_max_files = 3
+            filter_response_time_above = 1000
+            filter_response_time_below = 50
+            case_insensitive_dedup = true
+            collect_api_versions = true
+            filter_min_ratio = 0.95
+            proxy_exclude_patterns = ["localhost"]
+            collect_page_titles = true
+            multipart_wordlist = "/tmp/uploads.txt"
+            multipart_field = "upload"
+            json_path_filter = ['$.status == "error"']
+            json_path_keep_if = ['$.status == "ok"']
+            verbose_scan_start = true
+            collect_301_targets = true
+            collect_security_headers = true
+            collect_hsts = true
+            sort_output = true
+            sort_by = "size"
+            prefix_wordlist = "/tmp/prefixes.txt"
+            collect_meta_tags = true
+            filter_max_headers = 20
+            filter_min_headers = 3
+            collect_inline_js = true
+            inline_js_dir = "/tmp/inline-js"
+            tee = "/tmp/ferox-tee.txt"
+            pause_on_rate_limit = true
+            split_state = true
+            filter_empty_body = true
+            filter_near_empty_body = 32
+            scan_limit_per_host = 2
+            collect_subpath_wordlist = true
+            report_summary_only = true
+            json_status = true
+            follow_meta_refresh = true
+            output_deduplicate = true
+            collect_interesting_paths = true
+            collect_passive = true
+            collect_crtsh = true
+            threads_burst = 200
+            threads_burst_duration = 2500
+            watch_wordlist = true
+            simulate = true
+            simulate_json = true
+            no_redundant_scans = true
         "#;
     let tmp_dir = TempDir::new().unwrap();
     let file = tmp_dir.path().join(DEFAULT_CONFIG_NAME);
@@ -71,6 +162,7 @@ fn default_configuration() {
     assert_eq!(config.proxy, String::new());
     assert_eq!(config.target_url, String::new());
     assert_eq!(config.time_limit, String::new());
+    assert_eq!(config.checkpoint_interval, String::new());
     assert_eq!(config.resume_from, String::new());
     assert_eq!(config.debug_log, String::new());
     assert_eq!(config.config, String::new());
@@ -112,10 +204,103 @@ fn default_configuration() {
     assert_eq!(config.url_denylist, Vec::<Url>::new());
     assert_eq!(config.dont_collect, ignored_extensions());
     assert_eq!(config.filter_regex, Vec::<String>::new());
+    assert_eq!(config.filter_extension, Vec::<String>::new());
     assert_eq!(config.filter_similar, Vec::<String>::new());
     assert_eq!(config.filter_word_count, Vec::<usize>::new());
     assert_eq!(config.filter_line_count, Vec::<usize>::new());
     assert_eq!(config.filter_status, Vec::<u16>::new());
+    assert!(!config.include_unconfirmed_redirects);
+    assert_eq!(config.notify_url, String::new());
+    assert!(!config.desktop_notify);
+    assert!(!config.redirect_as_discovery);
+    assert!(!config.collect_forms);
+    assert!(!config.collect_parameters);
+    assert_eq!(config.check_previous_state, String::new());
+    assert_eq!(config.connection_errors_to_file, String::new());
+    assert_eq!(config.export_metasploit, String::new());
+    assert_eq!(config.max_retries, 3);
+    assert_eq!(config.dns_server, None);
+    assert_eq!(config.dns_over_https, None);
+    assert!(!config.collect_technology_stack);
+    assert!(!config.no_wordlist_dedup);
+    assert!(!config.include_all_status);
+    assert_eq!(config.max_response_size, 0);
+    assert_eq!(config.test_server, 0);
+    assert_eq!(config.test_server_rules, "");
+    assert!(config.diff.is_empty());
+    assert_eq!(config.max_urls_per_scan, 0);
+    assert!(!config.no_request_dedup);
+    assert_eq!(config.output_filter_status, Vec::<u16>::new());
+    assert!(!config.adaptive_wordlist);
+    assert_eq!(config.filter_body_size_range, Vec::<String>::new());
+    assert_eq!(config.keep_body_size_range, Vec::<String>::new());
+    assert!(!config.collect_source_maps);
+    assert!(!config.rate_limit_detect);
+    assert_eq!(config.rate_limit_recovery, 10);
+    assert!(!config.live_output);
+    assert_eq!(config.filter_words_range, Vec::<String>::new());
+    assert_eq!(config.filter_lines_range, Vec::<String>::new());
+    assert!(!config.collect_certificates);
+    assert!(!config.store_responses_as_html);
+    assert_eq!(config.html_gallery_dir, "ferox-gallery");
+    assert_eq!(config.wordlist_encoding, "utf-8");
+    assert!(!config.collect_comments_only);
+    assert_eq!(config.post_scan_script, "");
+    assert_eq!(config.exit_code_on_found, 0);
+    assert_eq!(config.exit_code_on_none, 0);
+    assert!(!config.interesting_only);
+    assert!(!config.filter_duplicate_content);
+    assert!(!config.websocket_discovery);
+    assert!(!config.cors_discovery);
+    assert!(!config.options_discovery);
+    assert!(!config.save_state_on_empty);
+    assert_eq!(config.recurse_on_status, vec![200, 301, 302]);
+    assert!(!config.deduplicate_by_path);
+    assert_eq!(config.output_rotate_size, 0);
+    assert_eq!(config.output_rotate_max_files, 0);
+    assert_eq!(config.filter_response_time_above, 0);
+    assert_eq!(config.filter_response_time_below, 0);
+    assert!(!config.case_insensitive_dedup);
+    assert!(!config.collect_api_versions);
+    assert_eq!(config.filter_min_ratio, 0.0);
+    assert!(config.proxy_exclude_patterns.is_empty());
+    assert!(!config.collect_page_titles);
+    assert_eq!(config.multipart_wordlist, String::new());
+    assert_eq!(config.multipart_field, "file");
+    assert!(config.json_path_filter.is_empty());
+    assert!(config.json_path_keep_if.is_empty());
+    assert!(!config.verbose_scan_start);
+    assert!(!config.collect_301_targets);
+    assert!(!config.collect_security_headers);
+    assert!(!config.collect_hsts);
+    assert!(!config.sort_output);
+    assert_eq!(config.sort_by, "status");
+    assert_eq!(config.prefix_wordlist, String::new());
+    assert!(!config.collect_meta_tags);
+    assert_eq!(config.filter_max_headers, 0);
+    assert_eq!(config.filter_min_headers, 0);
+    assert!(!config.collect_inline_js);
+    assert_eq!(config.inline_js_dir, "ferox-inline-js");
+    assert_eq!(config.tee, String::new());
+    assert!(!config.pause_on_rate_limit);
+    assert!(!config.split_state);
+    assert!(!config.filter_empty_body);
+    assert_eq!(config.filter_near_empty_body, 0);
+    assert_eq!(config.scan_limit_per_host, 0);
+    assert!(!config.collect_subpath_wordlist);
+    assert!(!config.report_summary_only);
+    assert!(!config.json_status);
+    assert!(!config.follow_meta_refresh);
+    assert!(!config.output_deduplicate);
+    assert!(!config.collect_interesting_paths);
+    assert!(!config.collect_passive);
+    assert!(!config.collect_crtsh);
+    assert_eq!(config.threads_burst, 0);
+    assert_eq!(config.threads_burst_duration, 1000);
+    assert!(!config.watch_wordlist);
+    assert!(!config.simulate);
+    assert!(!config.simulate_json);
+    assert!(!config.no_redundant_scans);
     assert_eq!(config.headers, HashMap::new());
 }
 
@@ -387,6 +572,13 @@ fn config_reads_filter_regex() {
     assert_eq!(config.filter_regex, vec!["^ignore me$"]);
 }
 
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_filter_extension() {
+    let config = setup_config_test();
+    assert_eq!(config.filter_extension, vec!["png", "jpg"]);
+}
+
 #[test]
 /// parse the test config and see that the value parsed is correct
 fn config_reads_filter_similar() {
@@ -436,6 +628,13 @@ fn config_reads_time_limit() {
     assert_eq!(config.time_limit, "10m");
 }
 
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_checkpoint_interval() {
+    let config = setup_config_test();
+    assert_eq!(config.checkpoint_interval, "5m");
+}
+
 #[test]
 /// parse the test config and see that the value parsed is correct
 fn config_reads_resume_from() {
@@ -464,6 +663,635 @@ fn config_reads_queries() {
     assert_eq!(config.queries, queries);
 }
 
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_include_unconfirmed_redirects() {
+    let config = setup_config_test();
+    assert!(config.include_unconfirmed_redirects);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_notify_url() {
+    let config = setup_config_test();
+    assert_eq!(config.notify_url, "http://localhost/hook");
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_desktop_notify() {
+    let config = setup_config_test();
+    assert!(config.desktop_notify);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_redirect_as_discovery() {
+    let config = setup_config_test();
+    assert!(config.redirect_as_discovery);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_collect_forms() {
+    let config = setup_config_test();
+    assert!(config.collect_forms);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_collect_parameters() {
+    let config = setup_config_test();
+    assert!(config.collect_parameters);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_check_previous_state() {
+    let config = setup_config_test();
+    assert_eq!(config.check_previous_state, "ferox-1606586780.state");
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_connection_errors_to_file() {
+    let config = setup_config_test();
+    assert_eq!(config.connection_errors_to_file, "/some/errors.ndjson");
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_export_metasploit() {
+    let config = setup_config_test();
+    assert_eq!(config.export_metasploit, "/some/ferox.rc");
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_max_retries() {
+    let config = setup_config_test();
+    assert_eq!(config.max_retries, 5);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_dns_server() {
+    let config = setup_config_test();
+    assert_eq!(config.dns_server, Some(String::from("10.0.0.2:53")));
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_dns_over_https() {
+    let config = setup_config_test();
+    assert_eq!(
+        config.dns_over_https,
+        Some(String::from("https://1.1.1.1/dns-query"))
+    );
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_collect_technology_stack() {
+    let config = setup_config_test();
+    assert!(config.collect_technology_stack);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_no_wordlist_dedup() {
+    let config = setup_config_test();
+    assert!(config.no_wordlist_dedup);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_include_all_status() {
+    let config = setup_config_test();
+    assert!(config.include_all_status);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_max_response_size() {
+    let config = setup_config_test();
+    assert_eq!(config.max_response_size, 4096);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_max_urls_per_scan() {
+    let config = setup_config_test();
+    assert_eq!(config.max_urls_per_scan, 5);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_no_request_dedup() {
+    let config = setup_config_test();
+    assert!(config.no_request_dedup);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_output_filter_status() {
+    let config = setup_config_test();
+    assert_eq!(config.output_filter_status, vec![200, 301]);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_adaptive_wordlist() {
+    let config = setup_config_test();
+    assert!(config.adaptive_wordlist);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_filter_body_size_range() {
+    let config = setup_config_test();
+    assert_eq!(
+        config.filter_body_size_range,
+        vec!["100-200", "300-unlimited"]
+    );
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_keep_body_size_range() {
+    let config = setup_config_test();
+    assert_eq!(config.keep_body_size_range, vec!["1000-unlimited"]);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_collect_source_maps() {
+    let config = setup_config_test();
+    assert!(config.collect_source_maps);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_rate_limit_detect() {
+    let config = setup_config_test();
+    assert!(config.rate_limit_detect);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_rate_limit_recovery() {
+    let config = setup_config_test();
+    assert_eq!(config.rate_limit_recovery, 30);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_live_output() {
+    let config = setup_config_test();
+    assert!(config.live_output);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_filter_words_range() {
+    let config = setup_config_test();
+    assert_eq!(config.filter_words_range, vec!["100-500", "10-20"]);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_filter_lines_range() {
+    let config = setup_config_test();
+    assert_eq!(config.filter_lines_range, vec!["10-20", "30-40"]);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_collect_certificates() {
+    let config = setup_config_test();
+    assert!(config.collect_certificates);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_store_responses_as_html() {
+    let config = setup_config_test();
+    assert!(config.store_responses_as_html);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_html_gallery_dir() {
+    let config = setup_config_test();
+    assert_eq!(config.html_gallery_dir, "/tmp/ferox-gallery");
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_wordlist_encoding() {
+    let config = setup_config_test();
+    assert_eq!(config.wordlist_encoding, "windows-1252");
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_collect_comments_only() {
+    let config = setup_config_test();
+    assert!(config.collect_comments_only);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_post_scan_script() {
+    let config = setup_config_test();
+    assert_eq!(config.post_scan_script, "/tmp/hook.sh");
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_exit_code_on_found() {
+    let config = setup_config_test();
+    assert_eq!(config.exit_code_on_found, 1);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_exit_code_on_none() {
+    let config = setup_config_test();
+    assert_eq!(config.exit_code_on_none, 2);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_interesting_only() {
+    let config = setup_config_test();
+    assert!(config.interesting_only);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_filter_duplicate_content() {
+    let config = setup_config_test();
+    assert!(config.filter_duplicate_content);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_websocket_discovery() {
+    let config = setup_config_test();
+    assert!(config.websocket_discovery);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_cors_discovery() {
+    let config = setup_config_test();
+    assert!(config.cors_discovery);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_options_discovery() {
+    let config = setup_config_test();
+    assert!(config.options_discovery);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_save_state_on_empty() {
+    let config = setup_config_test();
+    assert!(config.save_state_on_empty);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_recurse_on_status() {
+    let config = setup_config_test();
+    assert_eq!(config.recurse_on_status, vec![200]);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_deduplicate_by_path() {
+    let config = setup_config_test();
+    assert!(config.deduplicate_by_path);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_output_rotate_size() {
+    let config = setup_config_test();
+    assert_eq!(config.output_rotate_size, 1000);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_output_rotate_max_files() {
+    let config = setup_config_test();
+    assert_eq!(config.output_rotate_max_files, 3);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_filter_response_time_above() {
+    let config = setup_config_test();
+    assert_eq!(config.filter_response_time_above, 1000);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_filter_response_time_below() {
+    let config = setup_config_test();
+    assert_eq!(config.filter_response_time_below, 50);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_case_insensitive_dedup() {
+    let config = setup_config_test();
+    assert!(config.case_insensitive_dedup);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_collect_api_versions() {
+    let config = setup_config_test();
+    assert!(config.collect_api_versions);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_filter_min_ratio() {
+    let config = setup_config_test();
+    assert_eq!(config.filter_min_ratio, 0.95);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_proxy_exclude_patterns() {
+    let config = setup_config_test();
+    assert_eq!(config.proxy_exclude_patterns, vec!["localhost"]);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_collect_page_titles() {
+    let config = setup_config_test();
+    assert!(config.collect_page_titles);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_multipart_wordlist() {
+    let config = setup_config_test();
+    assert_eq!(config.multipart_wordlist, "/tmp/uploads.txt");
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_multipart_field() {
+    let config = setup_config_test();
+    assert_eq!(config.multipart_field, "upload");
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_json_path_filter() {
+    let config = setup_config_test();
+    assert_eq!(config.json_path_filter, vec![r#"$.status == "error""#]);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_json_path_keep_if() {
+    let config = setup_config_test();
+    assert_eq!(config.json_path_keep_if, vec![r#"$.status == "ok""#]);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_verbose_scan_start() {
+    let config = setup_config_test();
+    assert!(config.verbose_scan_start);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_collect_301_targets() {
+    let config = setup_config_test();
+    assert!(config.collect_301_targets);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_collect_security_headers() {
+    let config = setup_config_test();
+    assert!(config.collect_security_headers);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_collect_hsts() {
+    let config = setup_config_test();
+    assert!(config.collect_hsts);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_sort_output() {
+    let config = setup_config_test();
+    assert!(config.sort_output);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_sort_by() {
+    let config = setup_config_test();
+    assert_eq!(config.sort_by, "size");
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_prefix_wordlist() {
+    let config = setup_config_test();
+    assert_eq!(config.prefix_wordlist, "/tmp/prefixes.txt");
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_collect_meta_tags() {
+    let config = setup_config_test();
+    assert!(config.collect_meta_tags);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_filter_max_headers() {
+    let config = setup_config_test();
+    assert_eq!(config.filter_max_headers, 20);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_filter_min_headers() {
+    let config = setup_config_test();
+    assert_eq!(config.filter_min_headers, 3);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_collect_inline_js() {
+    let config = setup_config_test();
+    assert!(config.collect_inline_js);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_inline_js_dir() {
+    let config = setup_config_test();
+    assert_eq!(config.inline_js_dir, "/tmp/inline-js");
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_tee() {
+    let config = setup_config_test();
+    assert_eq!(config.tee, "/tmp/ferox-tee.txt");
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_pause_on_rate_limit() {
+    let config = setup_config_test();
+    assert!(config.pause_on_rate_limit);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_split_state() {
+    let config = setup_config_test();
+    assert!(config.split_state);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_filter_empty_body() {
+    let config = setup_config_test();
+    assert!(config.filter_empty_body);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_filter_near_empty_body() {
+    let config = setup_config_test();
+    assert_eq!(config.filter_near_empty_body, 32);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_scan_limit_per_host() {
+    let config = setup_config_test();
+    assert_eq!(config.scan_limit_per_host, 2);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_collect_subpath_wordlist() {
+    let config = setup_config_test();
+    assert!(config.collect_subpath_wordlist);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_report_summary_only() {
+    let config = setup_config_test();
+    assert!(config.report_summary_only);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_json_status() {
+    let config = setup_config_test();
+    assert!(config.json_status);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_follow_meta_refresh() {
+    let config = setup_config_test();
+    assert!(config.follow_meta_refresh);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_output_deduplicate() {
+    let config = setup_config_test();
+    assert!(config.output_deduplicate);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_collect_interesting_paths() {
+    let config = setup_config_test();
+    assert!(config.collect_interesting_paths);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_collect_passive() {
+    let config = setup_config_test();
+    assert!(config.collect_passive);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_collect_crtsh() {
+    let config = setup_config_test();
+    assert!(config.collect_crtsh);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_threads_burst() {
+    let config = setup_config_test();
+    assert_eq!(config.threads_burst, 200);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_threads_burst_duration() {
+    let config = setup_config_test();
+    assert_eq!(config.threads_burst_duration, 2500);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_watch_wordlist() {
+    let config = setup_config_test();
+    assert!(config.watch_wordlist);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_simulate() {
+    let config = setup_config_test();
+    assert!(config.simulate);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_simulate_json() {
+    let config = setup_config_test();
+    assert!(config.simulate_json);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_no_redundant_scans() {
+    let config = setup_config_test();
+    assert!(config.no_redundant_scans);
+}
+
 #[test]
 fn config_default_not_random_agent() {
     let config = setup_config_test();
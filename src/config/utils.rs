@@ -1,6 +1,7 @@
 use crate::{
     utils::{module_colorizer, status_colorizer},
-    DEFAULT_IGNORED_EXTENSIONS, DEFAULT_METHOD, DEFAULT_STATUS_CODES, DEFAULT_WORDLIST, VERSION,
+    DEFAULT_IGNORED_EXTENSIONS, DEFAULT_MAX_RETRIES, DEFAULT_METHOD, DEFAULT_RECURSE_ON_STATUS,
+    DEFAULT_STATUS_CODES, DEFAULT_WORDLIST, VERSION,
 };
 #[cfg(not(test))]
 use std::process::exit;
@@ -34,16 +35,56 @@ pub(super) fn timeout() -> u64 {
     7
 }
 
+/// default rate_limit_recovery value
+pub(super) fn rate_limit_recovery() -> u64 {
+    10
+}
+
+/// default html_gallery_dir value
+pub(super) fn html_gallery_dir() -> String {
+    String::from("ferox-gallery")
+}
+
+/// default inline_js_dir value
+pub(super) fn inline_js_dir() -> String {
+    String::from("ferox-inline-js")
+}
+
+/// default multipart_field value
+pub(super) fn multipart_field() -> String {
+    String::from("file")
+}
+
+/// default sort_by value
+pub(super) fn sort_by() -> String {
+    String::from("status")
+}
+
+/// default wordlist_encoding value
+pub(super) fn wordlist_encoding() -> String {
+    String::from("utf-8")
+}
+
 /// default save_state value
 pub(super) fn save_state() -> bool {
     true
 }
 
+/// default max_retries value
+pub(super) fn max_retries() -> u32 {
+    DEFAULT_MAX_RETRIES
+}
+
 /// default threads value
 pub(super) fn threads() -> usize {
     50
 }
 
+/// default threads_burst_duration value
+pub(super) fn threads_burst_duration() -> u64 {
+    1000
+}
+
 /// default status codes
 pub(super) fn status_codes() -> Vec<u16> {
     DEFAULT_STATUS_CODES
@@ -52,6 +93,14 @@ pub(super) fn status_codes() -> Vec<u16> {
         .collect()
 }
 
+/// default status codes that allow recursion
+pub(super) fn recurse_on_status() -> Vec<u16> {
+    DEFAULT_RECURSE_ON_STATUS
+        .iter()
+        .map(|code| code.as_u16())
+        .collect()
+}
+
 /// default HTTP Method
 pub(super) fn methods() -> Vec<String> {
     vec![DEFAULT_METHOD.to_owned()]
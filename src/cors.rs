@@ -0,0 +1,117 @@
+use std::fmt;
+
+use reqwest::{
+    header::{ACCESS_CONTROL_ALLOW_ORIGIN, ORIGIN},
+    Client, Response,
+};
+use serde::{Deserialize, Serialize};
+
+/// origin sent by --cors-discovery; a server that trusts it will trust any origin
+pub const CORS_PROBE_ORIGIN: &str = "https://evil.com";
+
+/// kind of CORS misconfiguration found by --cors-discovery
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CorsVulnerability {
+    /// `Access-Control-Allow-Origin` echoed back the probe's `Origin`
+    ReflectedOrigin,
+
+    /// `Access-Control-Allow-Origin` was `*`
+    Wildcard,
+}
+
+/// implement Display for CorsVulnerability
+impl fmt::Display for CorsVulnerability {
+    /// human readable description of the finding
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CorsVulnerability::ReflectedOrigin => write!(f, "reflects arbitrary origins"),
+            CorsVulnerability::Wildcard => write!(f, "allows any origin (*)"),
+        }
+    }
+}
+
+/// examine the `Access-Control-Allow-Origin` header of a response to a request sent with
+/// `Origin: CORS_PROBE_ORIGIN`
+pub fn check_cors(response: &Response) -> Option<CorsVulnerability> {
+    let allowed = response
+        .headers()
+        .get(ACCESS_CONTROL_ALLOW_ORIGIN)?
+        .to_str()
+        .ok()?
+        .trim();
+
+    if allowed == "*" {
+        Some(CorsVulnerability::Wildcard)
+    } else if allowed.eq_ignore_ascii_case(CORS_PROBE_ORIGIN) {
+        Some(CorsVulnerability::ReflectedOrigin)
+    } else {
+        None
+    }
+}
+
+/// request `url` with `Origin: CORS_PROBE_ORIGIN` and check the response for a CORS
+/// misconfiguration; any error is treated as no finding
+pub async fn probe_cors(client: &Client, url: &str) -> Option<CorsVulnerability> {
+    log::trace!("enter: probe_cors({})", url);
+
+    let vulnerability = match client
+        .get(url)
+        .header(ORIGIN, CORS_PROBE_ORIGIN)
+        .send()
+        .await
+    {
+        Ok(response) => check_cors(&response),
+        Err(e) => {
+            log::debug!("cors probe of {} failed: {}", url, e);
+            None
+        }
+    };
+
+    log::trace!("exit: probe_cors -> {:?}", vulnerability);
+    vulnerability
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// helper to build a response with the given Access-Control-Allow-Origin, if any
+    fn response_with_acao(allowed: Option<&str>) -> Response {
+        let mut builder = hyper::Response::builder();
+
+        if let Some(allowed) = allowed {
+            builder = builder.header(ACCESS_CONTROL_ALLOW_ORIGIN, allowed);
+        }
+
+        Response::from(builder.body("").unwrap())
+    }
+
+    #[test]
+    /// a wildcard or reflected origin should be flagged, anything else shouldn't
+    fn check_cors_flags_wildcard_and_reflected_origins() {
+        assert_eq!(
+            check_cors(&response_with_acao(Some("*"))),
+            Some(CorsVulnerability::Wildcard)
+        );
+        assert_eq!(
+            check_cors(&response_with_acao(Some("https://EVIL.com"))),
+            Some(CorsVulnerability::ReflectedOrigin)
+        );
+        assert_eq!(
+            check_cors(&response_with_acao(Some("https://example.com"))),
+            None
+        );
+        assert_eq!(check_cors(&response_with_acao(None)), None);
+    }
+
+    #[test]
+    /// findings should serialize to snake_case names and back
+    fn cors_vulnerability_serde_round_trips() {
+        let json = serde_json::to_string(&CorsVulnerability::ReflectedOrigin).unwrap();
+        assert_eq!(json, r#""reflected_origin""#);
+
+        let vulnerability: CorsVulnerability = serde_json::from_str(r#""wildcard""#).unwrap();
+        assert_eq!(vulnerability, CorsVulnerability::Wildcard);
+    }
+}
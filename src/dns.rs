@@ -0,0 +1,167 @@
+use std::{
+    net::{IpAddr, SocketAddr, ToSocketAddrs},
+    sync::Arc,
+};
+
+use anyhow::{bail, Context, Result};
+use hickory_resolver::{
+    config::{NameServerConfigGroup, ResolverConfig, ResolverOpts},
+    TokioAsyncResolver,
+};
+use hyper::client::connect::dns::Name;
+use reqwest::{
+    dns::{Addrs, Resolve, Resolving},
+    Url,
+};
+
+/// default port used when --dns-server is given without one
+const DEFAULT_DNS_PORT: u16 = 53;
+
+/// default port used when a --dns-over-https url doesn't specify one
+const DEFAULT_DOH_PORT: u16 = 443;
+
+/// hostname resolver backed by `hickory-resolver`, used in place of the OS resolver when
+/// --dns-server or --dns-over-https is given
+#[derive(Clone)]
+pub(crate) struct FeroxResolver {
+    /// underlying async resolver; cheap to clone, all clones share the same cache
+    resolver: TokioAsyncResolver,
+}
+
+/// Debug implementation for FeroxResolver
+impl std::fmt::Debug for FeroxResolver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FeroxResolver").finish()
+    }
+}
+
+impl FeroxResolver {
+    /// create a resolver that sends plain DNS queries to the given `ip[:port]`
+    fn with_dns_server(server: &str) -> Result<Self> {
+        let (ip, port) = if let Ok(addr) = server.parse::<SocketAddr>() {
+            (addr.ip(), addr.port())
+        } else if let Ok(ip) = server.parse::<IpAddr>() {
+            (ip, DEFAULT_DNS_PORT)
+        } else {
+            bail!("{} is not a valid ip or ip:port", server);
+        };
+
+        let name_servers = NameServerConfigGroup::from_ips_clear(&[ip], port, true);
+
+        Ok(Self::from_name_servers(name_servers))
+    }
+
+    /// create a resolver that sends DNS queries to the given DNS over HTTPS url
+    ///
+    /// if the url's host is a domain, it's resolved once (using the OS resolver) to find the
+    /// DoH server's address
+    fn with_dns_over_https(url: &str) -> Result<Self> {
+        let parsed = Url::parse(url).with_context(|| format!("{} is not a valid url", url))?;
+
+        if parsed.scheme() != "https" {
+            bail!("DNS over HTTPS url must use https: {}", url);
+        }
+
+        let host = match parsed.host_str() {
+            Some(host) => host.trim_start_matches('[').trim_end_matches(']'),
+            None => bail!("DNS over HTTPS url has no host: {}", url),
+        };
+
+        let port = parsed.port().unwrap_or(DEFAULT_DOH_PORT);
+
+        let ips: Vec<IpAddr> = if let Ok(ip) = host.parse::<IpAddr>() {
+            vec![ip]
+        } else {
+            (host, port)
+                .to_socket_addrs()
+                .with_context(|| format!("Could not resolve DNS over HTTPS server {}", host))?
+                .map(|addr| addr.ip())
+                .collect()
+        };
+
+        let name_servers =
+            NameServerConfigGroup::from_ips_https(&ips, port, host.to_string(), true);
+
+        Ok(Self::from_name_servers(name_servers))
+    }
+
+    /// wrap the given name servers in a new resolver
+    fn from_name_servers(name_servers: NameServerConfigGroup) -> Self {
+        let config = ResolverConfig::from_parts(None, vec![], name_servers);
+
+        Self {
+            resolver: TokioAsyncResolver::tokio(config, ResolverOpts::default()),
+        }
+    }
+}
+
+/// Resolve implementation for FeroxResolver; hooks the resolver into reqwest's clients
+impl Resolve for FeroxResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let resolver = self.resolver.clone();
+
+        Box::pin(async move {
+            let lookup = resolver.lookup_ip(name.as_str()).await?;
+
+            // reqwest replaces the port with the one from the url being requested
+            let addrs: Addrs = Box::new(lookup.into_iter().map(|ip| SocketAddr::new(ip, 0)));
+
+            Ok(addrs)
+        })
+    }
+}
+
+/// given the values of --dns-server and --dns-over-https, create the resolver that clients
+/// should use; `None` means the OS resolver should be used
+pub(crate) fn resolver(
+    dns_server: Option<&str>,
+    dns_over_https: Option<&str>,
+) -> Result<Option<Arc<FeroxResolver>>> {
+    match (dns_server, dns_over_https) {
+        (Some(_), Some(_)) => bail!("--dns-server and --dns-over-https are mutually exclusive"),
+        (Some(server), None) => Ok(Some(Arc::new(FeroxResolver::with_dns_server(server)?))),
+        (None, Some(url)) => Ok(Some(Arc::new(FeroxResolver::with_dns_over_https(url)?))),
+        (None, None) => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// no dns settings means no custom resolver
+    fn resolver_returns_none_without_settings() {
+        assert!(resolver(None, None).unwrap().is_none());
+    }
+
+    #[test]
+    /// dns server with and without a port should be accepted
+    fn resolver_accepts_dns_server_with_and_without_port() {
+        assert!(resolver(Some("10.0.0.1:5353"), None).unwrap().is_some());
+        assert!(resolver(Some("10.0.0.1"), None).unwrap().is_some());
+        assert!(resolver(Some("[::1]:53"), None).unwrap().is_some());
+    }
+
+    #[test]
+    /// garbage dns server should be an error
+    fn resolver_rejects_invalid_dns_server() {
+        assert!(resolver(Some("not-an-ip"), None).is_err());
+    }
+
+    #[test]
+    /// doh urls must be https and ip hosts shouldn't need to be resolved
+    fn resolver_validates_dns_over_https_url() {
+        assert!(resolver(None, Some("https://1.1.1.1/dns-query"))
+            .unwrap()
+            .is_some());
+        assert!(resolver(None, Some("http://1.1.1.1/dns-query")).is_err());
+        assert!(resolver(None, Some("not a url")).is_err());
+    }
+
+    #[test]
+    /// both settings at once is an error
+    fn resolver_rejects_both_settings() {
+        assert!(resolver(Some("10.0.0.1"), Some("https://1.1.1.1/dns-query")).is_err());
+    }
+}
@@ -7,6 +7,7 @@ use crate::response::FeroxResponse;
 use crate::{
     event_handlers::Handles,
     message::FeroxMessage,
+    scan_manager::ScanStats,
     statistics::{StatError, StatField},
     traits::FeroxFilter,
 };
@@ -26,6 +27,9 @@ pub enum Command {
     /// Create the progress bar (`BarType::Total`) that is updated from the stats thread
     CreateBar,
 
+    /// Show the given snapshot of the overall scan's progress on the `BarType::Total` bar
+    UpdateScanStats(ScanStats),
+
     /// Add to a `Stats` field that corresponds to the given `StatField` by the given `usize` value
     AddToUsizeField(StatField, usize),
 
@@ -59,6 +63,12 @@ pub enum Command {
     /// Determine whether or not recursion is appropriate, given a FeroxResponse, if so start a scan
     TryRecursion(Box<FeroxResponse>),
 
+    /// Determine whether or not a 3xx response's destination should be scanned, if so start a scan
+    TryRedirectDiscovery(Box<FeroxResponse>),
+
+    /// Determine whether or not a form action (url) should be scanned, if so start a scan
+    TryFormDiscovery(String),
+
     /// Send a pointer to the wordlist to the recursion handler
     UpdateWordlist(Arc<Vec<String>>),
 
@@ -84,11 +84,11 @@ impl FiltersHandler {
 
         while let Some(command) = self.receiver.recv().await {
             match command {
-                Command::AddFilter(filter) => {
-                    if filter.as_any().downcast_ref::<EmptyFilter>().is_none() {
-                        // don't add an empty filter
-                        self.data.push(filter)?;
-                    }
+                Command::AddFilter(filter)
+                    if filter.as_any().downcast_ref::<EmptyFilter>().is_none() =>
+                {
+                    // don't add an empty filter
+                    self.data.push(filter)?;
                 }
                 Command::RemoveFilters(mut indices) => self.data.remove(&mut indices),
                 Command::Sync(sender) => {
@@ -1,11 +1,10 @@
+use super::outputs::write_sorted_output;
 use super::*;
 use crate::{
     progress::PROGRESS_PRINTER,
-    scan_manager::{FeroxState, PAUSE_SCAN},
-    scanner::RESPONSES,
+    scan_manager::{save_state, state_filename, FeroxState, PAUSE_SCAN},
+    scanner::{flush_error_log, RESPONSES},
     statistics::StatError,
-    utils::slugify_filename,
-    utils::{open_file, write_to},
     SLEEP_DURATION,
 };
 use anyhow::Result;
@@ -75,38 +74,46 @@ impl TermInputHandler {
 
     /// Writes the current state of the program to disk (if save_state is true) and then exits
     pub fn sigint_handler(handles: Arc<Handles>) -> Result<()> {
-        log::trace!("enter: sigint_handler({:?})", handles);
+        Self::save_state_and_exit(handles, "ctrl+c")
+    }
+
+    /// Writes the current state of the program to disk and then exits; `reason` is shown to the
+    /// user as what stopped the scan (i.e. ctrl+c)
+    pub fn save_state_and_exit(handles: Arc<Handles>, reason: &str) -> Result<()> {
+        log::trace!("enter: save_state_and_exit({:?}, {})", handles, reason);
 
-        let filename = if !handles.config.target_url.is_empty() {
-            // target url populated
-            slugify_filename(&handles.config.target_url, "ferox", "state")
-        } else {
-            // stdin used
-            slugify_filename("stdin", "ferox", "state")
-        };
+        // process::exit skips the flush in main's clean_up, so buffered entries would be lost
+        flush_error_log()
+            .unwrap_or_else(|e| log::warn!("Could not flush connection error log: {}", e));
+
+        let filename = state_filename(&handles.config);
 
         let warning = format!(
             "🚨 Caught {} 🚨 saving scan state to {} ...",
-            style("ctrl+c").yellow(),
+            style(reason).yellow(),
             filename
         );
 
         PROGRESS_PRINTER.println(warning);
 
+        if handles.config.sort_output {
+            // results held back by --sort-output would otherwise be lost
+            write_sorted_output(&handles.config)?;
+        }
+
+        let scans = handles.ferox_scans()?;
+
         let state = FeroxState::new(
-            handles.ferox_scans()?,
-            handles.config.clone(),
+            &scans,
+            &handles.config,
             &RESPONSES,
-            handles.stats.data.clone(),
-            handles.filters.data.clone(),
+            &handles.stats.data,
+            &handles.filters.data,
         );
 
-        let state_file = open_file(&filename);
-
-        let mut buffered_file = state_file?;
-        write_to(&state, &mut buffered_file, true)?;
+        save_state(&state, &filename)?;
 
-        log::trace!("exit: sigint_handler (end of program)");
+        log::trace!("exit: save_state_and_exit (end of program)");
         std::process::exit(1);
     }
 
@@ -2,23 +2,105 @@ use super::Command::AddToUsizeField;
 use super::*;
 
 use anyhow::{Context, Result};
+use console::style;
 use futures::future::{BoxFuture, FutureExt};
 use tokio::sync::{mpsc, oneshot};
 
 use crate::{
     config::Configuration,
+    gallery::Gallery,
+    inline_js::InlineJsCollector,
     progress::PROGRESS_PRINTER,
-    response::FeroxResponse,
+    response::{DiscoveryMethod, FeroxResponse},
+    scan_manager::{add_api_version, host_key, VersionProber},
     scanner::RESPONSES,
     send_command, skip_fail,
-    statistics::StatField::{ResourcesDiscovered, TotalExpected},
+    statistics::StatField::{DuplicatesFiltered, ResourcesDiscovered, TotalExpected},
     traits::FeroxSerialize,
-    utils::{ferox_print, fmt_err, make_request, open_file, write_to},
+    utils::{ferox_print, fmt_err, make_replay_request, make_request, open_file, write_to},
     CommandReceiver, CommandSender, Joiner,
 };
-use std::sync::Arc;
+use std::{
+    collections::HashSet,
+    fs::{remove_file, File},
+    io::{BufWriter, Write},
+    sync::{Arc, Mutex},
+};
+use tokio::task::JoinSet;
 use url::Url;
 
+/// responses held back by --sort-output until the scan completes or is cancelled
+static SORTED_OUTPUT: Mutex<Vec<FeroxResponse>> = Mutex::new(Vec::new());
+
+/// sort responses by the given --sort-by key; ties are broken by url
+pub(crate) fn sort_responses(responses: &mut [FeroxResponse], sort_by: &str) {
+    match sort_by {
+        "url" => responses.sort_by(|a, b| a.url().as_str().cmp(b.url().as_str())),
+        "size" => responses.sort_by_key(|r| (r.content_length(), r.url().to_string())),
+        "time" => responses.sort_by_key(|r| (r.response_time_ms(), r.url().to_string())),
+        _ => responses.sort_by_key(|r| (r.status().as_u16(), r.url().path().to_string())),
+    }
+}
+
+/// prints each line to the terminal via `PROGRESS_PRINTER` and writes it, colors included, to the
+/// file given to --tee
+#[derive(Debug)]
+pub(crate) struct TeeWriter {
+    /// file that every printed line is also written to
+    file: Mutex<BufWriter<File>>,
+}
+
+/// implementation of TeeWriter
+impl TeeWriter {
+    /// open (or create) the given file for appending
+    pub(crate) fn new(filename: &str) -> Result<Self> {
+        Ok(Self {
+            file: Mutex::new(open_file(filename)?),
+        })
+    }
+
+    /// print `msg` to the terminal and write it to the tee file; the file is flushed after each
+    /// line so that it can be followed while the scan runs
+    pub(crate) fn println(&self, msg: &str) {
+        ferox_print(msg, &PROGRESS_PRINTER);
+
+        if let Ok(mut file) = self.file.lock() {
+            // responses already end in a newline, everything else doesn't
+            let line = msg.trim_end_matches('\n');
+
+            if let Err(e) = writeln!(file, "{}", line).and_then(|_| file.flush()) {
+                log::warn!("Could not write to tee file: {}", e);
+            }
+        }
+    }
+}
+
+/// --sort-output; write every held back response to --output, sorted by --sort-by
+pub(crate) fn write_sorted_output(config: &Configuration) -> Result<()> {
+    log::trace!("enter: write_sorted_output");
+
+    let mut responses = match SORTED_OUTPUT.lock() {
+        Ok(mut guard) => std::mem::take(&mut *guard),
+        Err(_) => Vec::new(),
+    };
+
+    if responses.is_empty() {
+        log::trace!("exit: write_sorted_output (nothing to write)");
+        return Ok(());
+    }
+
+    sort_responses(&mut responses, &config.sort_by);
+
+    let mut file = open_file(&config.output)?;
+
+    for response in &responses {
+        skip_fail!(write_to(response, &mut file, config.json));
+    }
+
+    log::trace!("exit: write_sorted_output");
+    Ok(())
+}
+
 #[derive(Debug, Copy, Clone)]
 /// Simple enum for semantic clarity around calling expectations for `process_response`
 enum ProcessResponseCall {
@@ -76,6 +158,13 @@ pub struct FileOutHandler {
 
     /// pointer to "global" configuration struct
     config: Arc<Configuration>,
+
+    /// number of times the output file has been rotated by --output-rotate-size
+    rotations: usize,
+
+    /// urls already written to the output file, used by --output-deduplicate; kept separate from
+    /// RESPONSES so that output can be deduplicated independently of what's shown on the terminal
+    written_urls: HashSet<String>,
 }
 
 impl FileOutHandler {
@@ -85,9 +174,66 @@ impl FileOutHandler {
         Self {
             receiver: rx,
             config,
+            rotations: 0,
+            written_urls: HashSet::new(),
         }
     }
 
+    /// whether a response for the same url was already written to the output file; when
+    /// --output-deduplicate isn't used, nothing counts as already written
+    fn already_written(&mut self, response: &FeroxResponse) -> bool {
+        self.config.output_deduplicate && !self.written_urls.insert(response.url().to_string())
+    }
+
+    /// name of the output file for the given rotation; the first file is --output itself, the
+    /// rest get a numeric suffix (i.e. results.txt, results.txt.1, results.txt.2, ...)
+    fn rotated_filename(&self, rotation: usize) -> String {
+        if rotation == 0 {
+            self.config.output.clone()
+        } else {
+            format!("{}.{}", self.config.output, rotation)
+        }
+    }
+
+    /// rotate the output file if needed; a failed rotation is logged and writing continues to
+    /// the current file rather than dropping the entry
+    fn rotate_or_warn(&mut self, file: &mut BufWriter<File>) {
+        if let Err(e) = self.rotate_if_needed(file) {
+            log::warn!("Could not rotate output file: {}", e);
+        }
+    }
+
+    /// --output-rotate-size; swap `file` for the next output file once it's grown past the
+    /// threshold, deleting the oldest file when --output-rotate-max-files would be exceeded
+    fn rotate_if_needed(&mut self, file: &mut BufWriter<File>) -> Result<()> {
+        if self.config.output_rotate_size == 0 {
+            return Ok(());
+        }
+
+        if file.get_ref().metadata()?.len() <= self.config.output_rotate_size {
+            return Ok(());
+        }
+
+        self.rotations += 1;
+
+        let filename = self.rotated_filename(self.rotations);
+        *file = open_file(&filename)?;
+
+        log::info!("Output file rotated, writing scan results to {}", filename);
+
+        let max_files = self.config.output_rotate_max_files;
+
+        if max_files > 0 && self.rotations >= max_files {
+            let oldest = self.rotated_filename(self.rotations - max_files);
+
+            if let Err(e) = remove_file(&oldest) {
+                log::warn!("Could not remove rotated output file {}: {}", oldest, e);
+            }
+        }
+
+        Ok(())
+    }
+
     /// Spawn a single consumer task (sc side of mpsc)
     ///
     /// The consumer simply receives responses from the terminal handler and writes them to disk
@@ -100,13 +246,24 @@ impl FileOutHandler {
 
         while let Some(command) = self.receiver.recv().await {
             match command {
+                Command::Report(response) if self.already_written(&response) => {
+                    log::debug!("skipped already written url: {}", response.url());
+                }
+                Command::Report(response) if self.config.sort_output => {
+                    // written in sorted order once the scan is over
+                    if let Ok(mut sorted) = SORTED_OUTPUT.lock() {
+                        sorted.push(*response);
+                    }
+                }
                 Command::Report(response) => {
+                    self.rotate_or_warn(&mut file);
                     skip_fail!(write_to(&*response, &mut file, self.config.json));
                 }
                 Command::WriteToDisk(message) => {
                     // todo consider making report accept dyn FeroxSerialize; would mean adding
                     //  as_any/box_eq/PartialEq to the trait and then adding them to the
                     //  implementing structs
+                    self.rotate_or_warn(&mut file);
                     skip_fail!(write_to(&*message, &mut file, self.config.json));
                 }
                 Command::Exit => {
@@ -122,6 +279,12 @@ impl FileOutHandler {
         // close the file before we tell statistics to save current data to the same file
         drop(file);
 
+        if self.config.sort_output {
+            if let Err(e) = write_sorted_output(&self.config) {
+                log::warn!("Could not write sorted output: {}", e);
+            }
+        }
+
         send_command!(tx_stats, Command::Save);
 
         log::trace!("exit: start_file_handler");
@@ -146,6 +309,19 @@ pub struct TermOutHandler {
 
     /// handles instance
     handles: Option<Arc<Handles>>,
+
+    /// in-flight requests being replayed through --replay-proxy; finished requests are reaped
+    /// as new ones are added, the rest are awaited before exiting
+    replay_tasks: Mutex<JoinSet<()>>,
+
+    /// html gallery written to when --store-responses-as-html is used
+    gallery: Option<Gallery>,
+
+    /// inline scripts are written through this when --collect-inline-js is used
+    inline_js: Option<InlineJsCollector>,
+
+    /// results are printed through this when --tee is used
+    tee: Option<TeeWriter>,
 }
 
 /// implementation of TermOutHandler
@@ -158,12 +334,58 @@ impl TermOutHandler {
         file_task: Option<Joiner>,
         config: Arc<Configuration>,
     ) -> Self {
+        let gallery = if config.store_responses_as_html {
+            match Gallery::new(&config.html_gallery_dir) {
+                Ok(gallery) => {
+                    log::info!("Writing html gallery to {}", config.html_gallery_dir);
+                    Some(gallery)
+                }
+                Err(e) => {
+                    log::warn!("{}; html gallery disabled", e);
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+        let inline_js = if config.collect_inline_js {
+            match InlineJsCollector::new(&config.inline_js_dir) {
+                Ok(inline_js) => {
+                    log::info!("Writing inline scripts to {}", config.inline_js_dir);
+                    Some(inline_js)
+                }
+                Err(e) => {
+                    log::warn!("{}; inline script collection disabled", e);
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+        let tee = if config.tee.is_empty() {
+            None
+        } else {
+            match TeeWriter::new(&config.tee) {
+                Ok(tee) => Some(tee),
+                Err(e) => {
+                    log::warn!("{}; --tee disabled", e);
+                    None
+                }
+            }
+        };
+
         Self {
             receiver,
             tx_file,
             file_task,
             config,
             handles: None,
+            replay_tasks: Mutex::new(JoinSet::new()),
+            gallery,
+            inline_js,
+            tee,
         }
     }
 
@@ -190,6 +412,8 @@ impl TermOutHandler {
             None
         };
 
+        RESPONSES.set_filter_duplicate_content(config.filter_duplicate_content);
+
         let mut term_handler = Self::new(rx_term, tx_file.clone(), file_task, config);
         let term_task = tokio::spawn(async move { term_handler.start(tx_stats).await });
 
@@ -219,8 +443,12 @@ impl TermOutHandler {
                     self.handles = Some(handles);
                 }
                 Command::Exit => {
-                    if self.file_task.is_some() && self.tx_file.send(Command::Exit).is_ok() {
-                        self.file_task.as_mut().unwrap().await??; // wait for death
+                    self.join_replay_tasks().await;
+
+                    if let Some(file_task) = self.file_task.as_mut() {
+                        if self.tx_file.send(Command::Exit).is_ok() {
+                            file_task.await??; // wait for death
+                        }
                     }
                     break;
                 }
@@ -231,6 +459,16 @@ impl TermOutHandler {
         Ok(())
     }
 
+    /// wait for any outstanding --replay-proxy requests to complete
+    async fn join_replay_tasks(&self) {
+        let mut tasks = match self.replay_tasks.lock() {
+            Ok(mut guard) => std::mem::take(&mut *guard),
+            Err(_) => return,
+        };
+
+        while tasks.join_next().await.is_some() {}
+    }
+
     /// upon receiving a `FeroxResponse` from the mpsc, handle printing, sending to the replay
     /// proxy, checking for backups of the `FeroxResponse`'s url, and tracking the response.
     fn process_response(
@@ -250,7 +488,10 @@ impl TermOutHandler {
                 .data
                 .should_filter_response(&resp, self.handles.as_ref().unwrap().stats.tx.clone());
 
-            let contains_sentry = if !self.config.filter_status.is_empty() {
+            let contains_sentry = if self.config.include_all_status {
+                // --include-all-status overrides both -s and -C
+                true
+            } else if !self.config.filter_status.is_empty() {
                 // -C was used, meaning -s was not and we should ignore the defaults
                 // https://github.com/epi052/feroxbuster/issues/535
                 // -C indicates that we should filter that status code, but allow all others
@@ -260,19 +501,81 @@ impl TermOutHandler {
                 self.config.status_codes.contains(&resp.status().as_u16())
             };
 
-            let unknown_sentry = !RESPONSES.contains(&resp); // !contains == unknown
-            let should_process_response = contains_sentry && unknown_sentry && !should_filter;
+            // --include-unconfirmed-redirects allows redirects that would otherwise be hidden by
+            // -s/-C through, as long as they point somewhere we haven't scanned yet
+            let unconfirmed_redirect = !contains_sentry
+                && self.config.include_unconfirmed_redirects
+                && self.is_unconfirmed_redirect(&resp);
+
+            let unknown_sentry = !RESPONSES.contains(&resp, self.config.case_insensitive_dedup); // !contains == unknown
+
+            // --interesting-only hides anything that doesn't score high enough for triage
+            let is_interesting = !self.config.interesting_only || resp.is_interesting();
+
+            // --filter-duplicate-content hides responses whose body was already seen elsewhere
+            let duplicate_content = self.config.filter_duplicate_content
+                && RESPONSES.contains_content(resp.content_hash());
+
+            let should_process_response = (contains_sentry || unconfirmed_redirect)
+                && unknown_sentry
+                && !should_filter
+                && is_interesting;
+
+            let should_process_response = if should_process_response && duplicate_content {
+                log::debug!("{} has the same content as a known response", resp.url());
+                send_command!(tx_stats, AddToUsizeField(DuplicatesFiltered, 1));
+                false
+            } else {
+                should_process_response
+            };
 
             if should_process_response {
-                // print to stdout
-                ferox_print(&resp.as_str(), &PROGRESS_PRINTER);
+                log::info!("{} has request id {}", resp.url(), resp.request_id());
+
+                // print to stdout; unconfirmed redirects and paths from the
+                // --collect-interesting-paths list get a distinct marker
+                let line = if unconfirmed_redirect {
+                    format!("{} {}", style("→").yellow(), resp.as_str())
+                } else if resp.discovered_via() == DiscoveryMethod::BuiltinList {
+                    format!("{} {}", style("[!]").red().bold(), resp.as_str())
+                } else {
+                    resp.as_str()
+                };
+
+                match self.tee.as_ref() {
+                    // --report-summary-only used, results are only counted in the final summary
+                    _ if self.config.report_summary_only => {}
+                    // --tee used, the line goes to the tee file as well
+                    Some(tee) => tee.println(&line),
+                    None => ferox_print(&line, &PROGRESS_PRINTER),
+                }
 
                 send_command!(tx_stats, AddToUsizeField(ResourcesDiscovered, 1));
 
-                if self.file_task.is_some() {
-                    // -o used, need to send the report to be written out to disk
+                if let Some(gallery) = self.gallery.as_ref() {
+                    // --store-responses-as-html used; only 200s with an html body are written
+                    if let Err(e) = gallery.add(&resp) {
+                        log::warn!("Could not add {} to the html gallery: {}", resp.url(), e);
+                    }
+                }
+
+                if let Some(inline_js) = self.inline_js.as_ref() {
+                    // --collect-inline-js used; only 200s with an html body have their scripts
+                    // written
+                    if let Err(e) = inline_js.add(&resp) {
+                        log::warn!("Could not write inline scripts of {}: {}", resp.url(), e);
+                    }
+                }
+
+                if self.file_task.is_some() && self.should_write_to_file(&resp) {
+                    // -o used, need to send the report to be written out to disk; the body
+                    // isn't part of what's written, and --sort-output holds onto every report
+                    // until the scan is over, so the copy goes without it
+                    let mut report = resp.clone();
+                    report.drop_text();
+
                     self.tx_file
-                        .send(Command::Report(resp.clone()))
+                        .send(Command::Report(report))
                         .with_context(|| {
                             fmt_err(&format!("Could not send {} to file handler", resp))
                         })?;
@@ -280,30 +583,56 @@ impl TermOutHandler {
             }
             log::trace!("report complete: {}", resp.url());
 
-            if self.config.replay_client.is_some() && should_process_response {
+            let should_replay = should_process_response
+                && self.config.replay_codes.contains(&resp.status().as_u16());
+
+            if let (Some(replay_client), true) = (self.config.replay_client.as_ref(), should_replay)
+            {
                 // replay proxy specified/client created and this response's status code is one that
                 // should be replayed; not using logged_request due to replay proxy client
-                let data = if self.config.data.is_empty() {
-                    None
-                } else {
-                    Some(self.config.data.as_slice())
+                //
+                // the replay is fire-and-forget, since the result is only of interest to whoever's
+                // watching the proxy; the task is tracked so it can be awaited before exiting
+                let client = replay_client.clone();
+                let config = self.config.clone();
+                let url = resp.url().clone();
+                let method = resp.method().to_string();
+                let request_id = resp.request_id().to_string();
+                let tx_stats = tx_stats.clone();
+
+                let task = async move {
+                    let data = if config.data.is_empty() {
+                        None
+                    } else {
+                        Some(config.data.as_slice())
+                    };
+
+                    if let Err(e) = make_replay_request(
+                        &client,
+                        &url,
+                        &method,
+                        data,
+                        &request_id,
+                        &config,
+                        tx_stats,
+                    )
+                    .await
+                    {
+                        log::warn!("Could not replay {} through replay proxy: {}", url, e);
+                    }
                 };
 
-                make_request(
-                    self.config.replay_client.as_ref().unwrap(),
-                    resp.url(),
-                    resp.method().as_str(),
-                    data,
-                    self.config.output_level,
-                    &self.config,
-                    tx_stats.clone(),
-                )
-                .await
-                .with_context(|| "Could not replay request through replay proxy")?;
+                if let Ok(mut tasks) = self.replay_tasks.lock() {
+                    // drop the replays that have already finished, so only in-flight ones are kept
+                    while tasks.try_join_next().is_some() {}
+
+                    tasks.spawn(task);
+                }
             }
 
             if self.config.collect_backups
                 && should_process_response
+                && !unconfirmed_redirect
                 && matches!(call_type, ProcessResponseCall::Recursive)
             {
                 // --collect-backups was used; the response is one we care about, and the function
@@ -333,6 +662,7 @@ impl TermOutHandler {
                         resp.url().as_str(),
                         resp.method().as_str(),
                         resp.output_level,
+                        self.config.max_response_size,
                     )
                     .await;
 
@@ -345,6 +675,10 @@ impl TermOutHandler {
                 }
             }
 
+            if should_process_response && self.config.collect_api_versions {
+                self.record_api_version(&resp);
+            }
+
             if should_process_response {
                 // add response to RESPONSES for serialization in case of ctrl+c
                 // placed all by its lonesome like this so that RESPONSES can take ownership
@@ -362,6 +696,47 @@ impl TermOutHandler {
         .boxed()
     }
 
+    /// whether a response that was shown on the terminal should also be written to --output;
+    /// --output-filter-status restricts the file to its status codes, independent of -s/-C
+    fn should_write_to_file(&self, resp: &FeroxResponse) -> bool {
+        self.config.output_filter_status.is_empty()
+            || self
+                .config
+                .output_filter_status
+                .contains(&resp.status().as_u16())
+    }
+
+    /// determine whether the given response is a redirect whose `Location` points to a url that
+    /// isn't already known to `FeroxScans`
+    fn is_unconfirmed_redirect(&self, resp: &FeroxResponse) -> bool {
+        let target = match resp.redirect_location() {
+            Some(target) => target,
+            None => return false,
+        };
+
+        match self.handles.as_ref().map(|handles| handles.ferox_scans()) {
+            Some(Ok(scans)) => !scans.contains(target.as_str()),
+            _ => false,
+        }
+    }
+
+    /// --collect-api-versions; note the api version found in the given response's url, if any
+    fn record_api_version(&self, resp: &FeroxResponse) {
+        let prober = match VersionProber::from_url(resp.url()) {
+            Some(prober) => prober,
+            None => return,
+        };
+
+        let host = match host_key(resp.url()) {
+            Some(host) => host,
+            None => return,
+        };
+
+        if let Ok(version_url) = Url::parse(&prober.version_url()) {
+            add_api_version(&host, version_url.path());
+        }
+    }
+
     /// internal helper to stay DRY
     fn add_new_url_to_vec(&self, url: &Url, new_name: &str, urls: &mut Vec<Url>) {
         if let Ok(joined) = url.join(new_name) {
@@ -389,7 +764,7 @@ impl TermOutHandler {
         let url = response.url();
 
         // confirmed safe: see src/response.rs for comments
-        let filename = url.path_segments().unwrap().last().unwrap();
+        let filename = url.path_segments().unwrap().next_back().unwrap();
 
         if !filename.is_empty() {
             // append rules
@@ -422,6 +797,7 @@ impl TermOutHandler {
 mod tests {
     use super::*;
     use crate::event_handlers::Command;
+    use std::io::Write;
 
     #[test]
     /// try to hit struct field coverage of FileOutHandler
@@ -431,10 +807,138 @@ mod tests {
         let foh = FileOutHandler {
             config,
             receiver: rx,
+            rotations: 0,
+            written_urls: HashSet::new(),
         };
         println!("{:?}", foh);
     }
 
+    #[test]
+    /// with --output-deduplicate, only the first response for a url should be written
+    fn already_written_only_skips_repeated_urls_when_enabled() {
+        let response = |url: &str| {
+            let mut response = FeroxResponse::default();
+            response.set_url(url);
+            response
+        };
+
+        let (_, rx) = mpsc::unbounded_channel::<Command>();
+        let mut foh = FileOutHandler::new(rx, Arc::new(Configuration::default()));
+
+        assert!(!foh.already_written(&response("http://localhost/a")));
+        assert!(!foh.already_written(&response("http://localhost/a")));
+
+        let (_, rx) = mpsc::unbounded_channel::<Command>();
+        let config = Configuration {
+            output_deduplicate: true,
+            ..Default::default()
+        };
+        let mut foh = FileOutHandler::new(rx, Arc::new(config));
+
+        assert!(!foh.already_written(&response("http://localhost/a")));
+        assert!(!foh.already_written(&response("http://localhost/b")));
+        assert!(foh.already_written(&response("http://localhost/a")));
+    }
+
+    #[test]
+    /// lines passed to TeeWriter::println should be appended to the tee file as-is, ansi color
+    /// codes included
+    fn tee_writer_keeps_colors() {
+        let tmp_dir = tempfile::TempDir::new().unwrap();
+        let filename = tmp_dir.path().join("tee.txt");
+        let filename = filename.to_str().unwrap();
+
+        std::fs::write(filename, "existing\n").unwrap();
+
+        let tee = TeeWriter::new(filename).unwrap();
+        tee.println("\x1b[32m200\x1b[0m GET http://localhost/admin");
+        tee.println("second\n");
+
+        assert_eq!(
+            std::fs::read_to_string(filename).unwrap(),
+            "existing\n\x1b[32m200\x1b[0m GET http://localhost/admin\nsecond\n"
+        );
+    }
+
+    #[test]
+    /// sort_responses should order by the given --sort-by key, using the url to break ties
+    fn sort_responses_orders_by_key() {
+        let mut responses: Vec<FeroxResponse> =
+            [("/c", "a", 30), ("/a", "aaa", 10), ("/b", "a", 20)]
+                .iter()
+                .map(|(path, text, time)| {
+                    let mut response = FeroxResponse::default();
+                    response.set_url(&format!("http://localhost{}", path));
+                    response.set_text(text);
+                    response.set_response_time_ms(*time);
+                    response
+                })
+                .collect();
+
+        let paths = |responses: &[FeroxResponse]| {
+            responses
+                .iter()
+                .map(|r| r.url().path().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        sort_responses(&mut responses, "url");
+        assert_eq!(paths(&responses), ["/a", "/b", "/c"]);
+
+        sort_responses(&mut responses, "size");
+        assert_eq!(paths(&responses), ["/b", "/c", "/a"]);
+
+        sort_responses(&mut responses, "time");
+        assert_eq!(paths(&responses), ["/a", "/b", "/c"]);
+
+        // all statuses are equal, so the default falls back to the path
+        sort_responses(&mut responses, "status");
+        assert_eq!(paths(&responses), ["/a", "/b", "/c"]);
+    }
+
+    #[test]
+    /// output should move on to numbered files once the current one is past the threshold, and
+    /// only the newest --output-rotate-max-files files should be kept
+    fn rotate_if_needed_moves_to_numbered_files() {
+        let tmp_dir = tempfile::TempDir::new().unwrap();
+        let output = tmp_dir.path().join("results.txt");
+        let output = output.to_str().unwrap();
+
+        let (_, rx) = mpsc::unbounded_channel::<Command>();
+        let config = Configuration {
+            output: output.to_string(),
+            output_rotate_size: 10,
+            output_rotate_max_files: 2,
+            ..Default::default()
+        };
+        let mut foh = FileOutHandler::new(rx, Arc::new(config));
+
+        let mut file = open_file(output).unwrap();
+
+        // under the threshold, nothing happens
+        file.write_all(b"0123456789").unwrap();
+        file.flush().unwrap();
+        foh.rotate_if_needed(&mut file).unwrap();
+        assert_eq!(foh.rotations, 0);
+
+        // past the threshold, results.txt.1 is started and results.txt is kept
+        file.write_all(b"a").unwrap();
+        file.flush().unwrap();
+        foh.rotate_if_needed(&mut file).unwrap();
+        assert_eq!(foh.rotations, 1);
+        assert!(std::path::Path::new(&format!("{}.1", output)).exists());
+        assert!(std::path::Path::new(output).exists());
+
+        // past the threshold again, results.txt.2 is started and results.txt is removed
+        file.write_all(b"0123456789a").unwrap();
+        file.flush().unwrap();
+        foh.rotate_if_needed(&mut file).unwrap();
+        assert_eq!(foh.rotations, 2);
+        assert!(std::path::Path::new(&format!("{}.2", output)).exists());
+        assert!(std::path::Path::new(&format!("{}.1", output)).exists());
+        assert!(!std::path::Path::new(output).exists());
+    }
+
     #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
     /// try to hit struct field coverage of TermOutHandler
     async fn struct_fields_of_term_out_handler() {
@@ -449,6 +953,10 @@ mod tests {
             receiver: rx,
             tx_file,
             handles: Some(handles),
+            replay_tasks: Mutex::new(JoinSet::new()),
+            gallery: None,
+            inline_js: None,
+            tee: None,
         };
 
         println!("{:?}", toh);
@@ -469,6 +977,10 @@ mod tests {
             receiver: rx,
             tx_file,
             handles: Some(handles),
+            replay_tasks: Mutex::new(JoinSet::new()),
+            gallery: None,
+            inline_js: None,
+            tee: None,
         };
 
         let expected: Vec<_> = vec![
@@ -488,7 +1000,7 @@ mod tests {
 
         let paths: Vec<_> = urls
             .iter()
-            .map(|url| url.path_segments().unwrap().last().unwrap())
+            .map(|url| url.path_segments().unwrap().next_back().unwrap())
             .collect();
 
         assert_eq!(urls.len(), 7);
@@ -514,6 +1026,10 @@ mod tests {
             receiver: rx,
             tx_file,
             handles: Some(handles),
+            replay_tasks: Mutex::new(JoinSet::new()),
+            gallery: None,
+            inline_js: None,
+            tee: None,
         };
 
         let expected: Vec<_> = vec![
@@ -532,7 +1048,7 @@ mod tests {
 
         let paths: Vec<_> = urls
             .iter()
-            .map(|url| url.path_segments().unwrap().last().unwrap())
+            .map(|url| url.path_segments().unwrap().next_back().unwrap())
             .collect();
 
         assert_eq!(urls.len(), 6);
@@ -559,6 +1075,10 @@ mod tests {
             receiver: rx,
             tx_file,
             handles: Some(handles),
+            replay_tasks: Mutex::new(JoinSet::new()),
+            gallery: None,
+            inline_js: None,
+            tee: None,
         };
 
         let expected: Vec<_> = vec![
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use anyhow::{bail, Result};
 use tokio::sync::{mpsc, Semaphore};
@@ -69,6 +69,9 @@ pub struct ScanHandler {
 
     /// Bounded semaphore used as a barrier to limit concurrent scans
     limiter: Arc<Semaphore>,
+
+    /// per-host semaphores used to limit concurrent scans against a single host
+    host_limiters: HashMap<String, Arc<Semaphore>>,
 }
 
 /// implementation of event handler for filters
@@ -102,15 +105,35 @@ impl ScanHandler {
             tasks: Vec::new(),
             depths: Vec::new(),
             limiter: Arc::new(limiter),
+            host_limiters: HashMap::new(),
             wordlist: std::sync::Mutex::new(None),
         }
     }
 
+    /// get the semaphore that limits concurrent scans against the target's host, creating it on
+    /// first use; returns None when --scan-limit-per-host isn't in use
+    fn host_limiter(&mut self, target: &str) -> Option<Arc<Semaphore>> {
+        let limit = self.handles.config.scan_limit_per_host;
+
+        if limit == 0 {
+            return None;
+        }
+
+        let host = Url::parse(target).ok()?.host_str()?.to_lowercase();
+
+        let limiter = self
+            .host_limiters
+            .entry(host)
+            .or_insert_with(|| Arc::new(Semaphore::new(limit)));
+
+        Some(limiter.clone())
+    }
+
     /// Set the wordlist
     fn wordlist(&self, wordlist: Arc<Vec<String>>) {
         if let Ok(mut guard) = self.wordlist.lock() {
             if guard.is_none() {
-                let _ = std::mem::replace(&mut *guard, Some(wordlist));
+                let _ = guard.replace(wordlist);
             }
         }
     }
@@ -120,7 +143,17 @@ impl ScanHandler {
     pub fn initialize(handles: Arc<Handles>) -> (Joiner, ScanHandle) {
         log::trace!("enter: initialize");
 
-        let data = Arc::new(FeroxScans::new(handles.config.output_level));
+        let rate_limit = match handles.config.rate_limit {
+            0 => None,
+            limit => Some(limit.try_into().unwrap_or(u32::MAX)),
+        };
+
+        let scans = FeroxScans::new(
+            handles.config.output_level,
+            rate_limit,
+            handles.config.case_insensitive_dedup,
+        );
+        let data = Arc::new(scans);
         let (tx, rx): FeroxChannel<Command> = mpsc::unbounded_channel();
 
         let max_depth = handles.config.depth;
@@ -174,6 +207,12 @@ impl ScanHandler {
                 Command::TryRecursion(response) => {
                     self.try_recursion(response).await?;
                 }
+                Command::TryRedirectDiscovery(response) => {
+                    self.try_redirect_discovery(response).await?;
+                }
+                Command::TryFormDiscovery(url) => {
+                    self.try_form_discovery(&url).await?;
+                }
                 Command::Sync(sender) => {
                     sender.send(true).unwrap_or_default();
                 }
@@ -182,11 +221,11 @@ impl ScanHandler {
                     // the --dont-collect list AND it's also not in the --extensions list, AND
                     // we actually added a new extension (i.e. wasn't previously known), add
                     // it to FeroxScans.collected_extensions
-                    if self.handles.config.collect_extensions
+                    let should_collect = self.handles.config.collect_extensions
                         && !self.handles.config.dont_collect.contains(&new_extension)
-                        && !self.handles.config.extensions.contains(&new_extension)
-                        && self.data.add_discovered_extension(new_extension)
-                    {
+                        && !self.handles.config.extensions.contains(&new_extension);
+
+                    if should_collect && self.data.add_discovered_extension(new_extension) {
                         self.update_all_bar_lengths()?;
                         self.handles
                             .stats
@@ -206,12 +245,12 @@ impl ScanHandler {
     ///
     /// updating all bar lengths correctly requires a few different actions on our part.
     /// - get the current number of requests expected per scan (dynamic when --collect-extensions
-    ///     is used)
+    ///   is used)
     /// - update the overall progress bar via the statistics handler (total expected)
     /// - update the expected per scan value tracked in the statistics handler
     /// - update progress bars on each FeroxScan (type::directory) that are running/not-started
     /// - update progress bar length on FeroxScans (this is used when creating new a FeroxScan and
-    ///     determines the new scan's progress bar length)
+    ///   determines the new scan's progress bar length)
     fn update_all_bar_lengths(&self) -> Result<()> {
         log::trace!("enter: update_all_bar_lengths");
 
@@ -307,6 +346,15 @@ impl ScanHandler {
             || !self.handles.config.regex_denylist.is_empty();
 
         for target in targets {
+            if matches!(order, ScanOrder::Initial) && self.is_redundant(&target) {
+                // --no-redundant-scans; an earlier target already covers this one
+                log::info!(
+                    "skipping {}, a directory above it is already being scanned",
+                    target
+                );
+                continue;
+            }
+
             if self.data.contains(&target) && matches!(order, ScanOrder::Latest) {
                 // FeroxScans knows about this url and scan isn't an Initial scan
                 // initial scans are skipped because when resuming from a .state file, the scans
@@ -345,6 +393,7 @@ impl ScanHandler {
                 order,
                 list,
                 self.limiter.clone(),
+                self.host_limiter(&target),
                 self.handles.clone(),
             );
 
@@ -368,11 +417,131 @@ impl ScanHandler {
     async fn try_recursion(&mut self, response: Box<FeroxResponse>) -> Result<()> {
         log::trace!("enter: try_recursion({:?})", response,);
 
+        // a scan of an Unknown type is classified by the first response for its url
+        self.data.resolve_unknown_scan_type(&response);
+
         if !self.handles.config.force_recursion && !response.is_directory() {
             // not a directory and --force-recursion wasn't used, quick exit
             return Ok(());
         }
 
+        if self.reached_max_depth(&response) {
+            // at or past recursion depth
+            return Ok(());
+        }
+
+        if !self
+            .handles
+            .config
+            .recurse_on_status
+            .contains(&response.status().as_u16())
+        {
+            // --recurse-on-status; only affects recursion, the response is still reported
+            log::debug!(
+                "not recursing into {}; {} isn't in --recurse-on-status",
+                response.url(),
+                response.status()
+            );
+            return Ok(());
+        }
+
+        let targets = vec![response.url().to_string()];
+        self.ordered_scan_url(targets, ScanOrder::Latest).await?;
+
+        log::info!("Added new directory to recursive scan: {}", response.url());
+
+        log::trace!("exit: try_recursion");
+        Ok(())
+    }
+
+    /// given a 3xx response, or an html page with a meta refresh, start a scan against its
+    /// (same-host) destination, if not already known
+    async fn try_redirect_discovery(&mut self, response: Box<FeroxResponse>) -> Result<()> {
+        log::trace!("enter: try_redirect_discovery({:?})", response);
+
+        let location = response
+            .redirect_location()
+            .or_else(|| response.meta_refresh_location());
+
+        let destination = match location {
+            Some(destination) => destination,
+            None => return Ok(()),
+        };
+
+        if destination.origin() != response.url().origin() {
+            // only follow redirects that stay on the same scheme/host/port
+            return Ok(());
+        }
+
+        self.scan_discovered_url(destination).await?;
+
+        log::trace!("exit: try_redirect_discovery");
+        Ok(())
+    }
+
+    /// given a form action's url, start a scan against it, if not already known
+    async fn try_form_discovery(&mut self, url: &str) -> Result<()> {
+        log::trace!("enter: try_form_discovery({})", url);
+
+        self.scan_discovered_url(Url::parse(url)?).await?;
+
+        log::trace!("exit: try_form_discovery");
+        Ok(())
+    }
+
+    /// shared logic for urls discovered outside of the wordlist (redirects, forms, etc); starts
+    /// a new scan against the url unless it's already known or past the max recursion depth
+    async fn scan_discovered_url(&mut self, mut url: Url) -> Result<()> {
+        log::trace!("enter: scan_discovered_url({})", url);
+
+        url.set_query(None);
+        url.set_fragment(None);
+
+        let target = url.to_string();
+
+        // FeroxScans knows about every scan ever started, so checking it here prevents loops
+        // (i.e. redirects from a -> b -> a) and urls pointing back at a scan's own url from
+        // spawning new scans
+        if self.data.contains(&target) || self.data.contains(target.trim_end_matches('/')) {
+            return Ok(());
+        }
+
+        if self.is_redundant(&target) {
+            // --no-redundant-scans; the scan already covering this url will get to it
+            log::info!(
+                "skipping {}, a directory above it is already being scanned",
+                target
+            );
+            return Ok(());
+        }
+
+        let mut discovered = FeroxResponse::default();
+        discovered.set_url(&target);
+
+        if self.reached_max_depth(&discovered) {
+            return Ok(());
+        }
+
+        self.ordered_scan_url(vec![target], ScanOrder::Latest)
+            .await?;
+
+        log::info!("Added discovered url to scan: {}", url);
+
+        log::trace!("exit: scan_discovered_url");
+        Ok(())
+    }
+
+    /// --no-redundant-scans; whether the given (not yet known) url falls beneath a directory
+    /// that's already being scanned
+    fn is_redundant(&self, url: &str) -> bool {
+        self.handles.config.no_redundant_scans
+            && !self.data.contains(url)
+            && self.data.contains_prefix(url)
+    }
+
+    /// determine whether the given response is at or past the maximum recursion depth, relative
+    /// to the initial target it was found under
+    fn reached_max_depth(&self, response: &FeroxResponse) -> bool {
         let mut base_depth = 1_usize;
 
         for (base_url, base_url_depth) in &self.depths {
@@ -382,16 +551,21 @@ impl ScanHandler {
         }
 
         if response.reached_max_depth(base_depth, self.max_depth, self.handles.clone()) {
-            // at or past recursion depth
-            return Ok(());
+            return true;
         }
 
-        let targets = vec![response.url().to_string()];
-        self.ordered_scan_url(targets, ScanOrder::Latest).await?;
+        if self.max_depth == 0 {
+            // 0 means recurse forever
+            return false;
+        }
 
-        log::info!("Added new directory to recursive scan: {}", response.url());
+        // a new scan is always one level beneath the scan whose url it falls under (the same
+        // lookup FeroxScans uses to assign its parent); scans reached through a redirect or an
+        // extracted link can be deeper in the chain of scans than their path suggests
+        let url = response.url().as_str().trim_end_matches('/');
 
-        log::trace!("exit: try_recursion");
-        Ok(())
+        self.data
+            .get_base_scan_by_url(url)
+            .is_some_and(|parent| self.data.depth_of(parent.id()) + 1 >= self.max_depth)
     }
 }
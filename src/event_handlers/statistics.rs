@@ -2,6 +2,7 @@ use super::*;
 use crate::{
     config::Configuration,
     progress::{add_bar, BarType},
+    scan_manager::ScanStats,
     statistics::{StatField, Stats},
     CommandSender, FeroxChannel, Joiner,
 };
@@ -57,6 +58,9 @@ pub struct StatsHandler {
 
     /// data class that stores all statistics updates
     stats: Arc<Stats>,
+
+    /// latest snapshot of how many scans are known/complete, shown on the overall bar
+    scan_stats: ScanStats,
 }
 
 /// implementation of event handler for statistics
@@ -70,6 +74,7 @@ impl StatsHandler {
             bar,
             stats,
             receiver: rx_stats,
+            scan_stats: ScanStats::default(),
         }
     }
 
@@ -118,6 +123,10 @@ impl StatsHandler {
                 Command::CreateBar => {
                     self.bar = add_bar("", self.stats.total_expected() as u64, BarType::Total);
                 }
+                Command::UpdateScanStats(scan_stats) => {
+                    self.scan_stats = scan_stats;
+                    self.bar.set_message(&self.bar_message());
+                }
                 Command::LoadStats(filename) => {
                     self.stats.merge_from(&filename)?;
                 }
@@ -136,17 +145,23 @@ impl StatsHandler {
         Ok(())
     }
 
-    /// Wrapper around incrementing the overall scan's progress bar
-    fn increment_bar(&self) {
-        let msg = format!(
-            "{}:{:<7} {}:{:<7}",
+    /// message shown on the overall scan's progress bar
+    fn bar_message(&self) -> String {
+        format!(
+            "{}:{:<7} {}:{:<7} {}:{}/{}",
             style("found").green(),
             self.stats.resources_discovered(),
             style("errors").red(),
             self.stats.errors(),
-        );
+            style("scans").cyan(),
+            self.scan_stats.complete,
+            self.scan_stats.total,
+        )
+    }
 
-        self.bar.set_message(&msg);
+    /// Wrapper around incrementing the overall scan's progress bar
+    fn increment_bar(&self) {
+        self.bar.set_message(&self.bar_message());
         self.bar.inc(1);
     }
 
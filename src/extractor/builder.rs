@@ -24,6 +24,15 @@ pub enum ExtractionTarget {
 
     /// Extract all <a> tags from a page
     DirectoryListing,
+
+    /// Extract the action attribute of all <form> tags from a page
+    Forms,
+
+    /// Request the source map referenced by a javascript file and extract its original sources
+    SourceMap,
+
+    /// Generate the urls of the api versions following the one in a response's url
+    ApiVersions,
 }
 
 /// responsible for building an `Extractor`
@@ -90,11 +99,7 @@ impl<'a> ExtractorBuilder<'a> {
         Ok(Extractor {
             links_regex: Regex::new(LINKFINDER_REGEX).unwrap(),
             robots_regex: Regex::new(ROBOTS_TXT_REGEX).unwrap(),
-            response: if self.response.is_some() {
-                Some(self.response.unwrap())
-            } else {
-                None
-            },
+            response: self.response,
             url: self.url.to_owned(),
             handles: self.handles.as_ref().unwrap().clone(),
             target: self.target,
@@ -1,11 +1,13 @@
 use super::*;
 use crate::{
-    client,
+    client, dns,
     event_handlers::{
         Command::{AddError, AddToUsizeField},
         Handles,
     },
-    scan_manager::ScanOrder,
+    scan_manager::{
+        detect_scan_type, host_key, ScanOrder, ScanType, VersionProber, SOURCE_MAP_PATHS,
+    },
     statistics::{
         StatError::Other,
         StatField::{LinksExtracted, TotalExpected},
@@ -17,6 +19,7 @@ use crate::{
 use anyhow::{bail, Context, Result};
 use reqwest::{Client, StatusCode, Url};
 use scraper::{Html, Selector};
+use serde::Deserialize;
 use std::collections::HashSet;
 
 /// Whether an active scan is recursive or not
@@ -29,6 +32,63 @@ enum RecursionStatus {
     NotRecursive,
 }
 
+/// the parts of a javascript source map used by --collect-source-maps
+#[derive(Debug, Deserialize)]
+struct SourceMap {
+    /// prefix prepended to each entry in `sources`
+    #[serde(default, rename = "sourceRoot")]
+    source_root: Option<String>,
+
+    /// paths of the original source files; entries may be null
+    #[serde(default)]
+    sources: Vec<Option<String>>,
+}
+
+/// given a javascript response body, return the value of the `//# sourceMappingURL=` comment
+/// found on its last line, if any; inline `data:` maps are ignored, since there's nothing to
+/// request
+fn source_map_reference(body: &str) -> Option<&str> {
+    let last_line = body
+        .lines()
+        .rev()
+        .find(|line| !line.trim().is_empty())?
+        .trim();
+
+    let reference = last_line
+        .strip_prefix("//# sourceMappingURL=")
+        .or_else(|| last_line.strip_prefix("//@ sourceMappingURL="))?
+        .trim();
+
+    (!reference.is_empty() && !reference.starts_with("data:")).then_some(reference)
+}
+
+/// given a source map's `sourceRoot` and a single entry from its `sources`, return the original
+/// file's path, without bundler schemes (`webpack:///`), leading `./`, or query strings
+///
+/// example: `webpack:///./src/app.js?5d1f` -> `src/app.js`
+fn normalize_source_path(source_root: &str, source: &str) -> String {
+    let source = if source_root.is_empty() {
+        source.to_string()
+    } else {
+        format!("{}/{}", source_root.trim_end_matches('/'), source)
+    };
+
+    // bundler schemes are followed by an optional namespace, e.g. webpack://my-app/./src/app.js
+    let path = match source.split_once("://") {
+        Some((_, rest)) => rest.split_once('/').map_or("", |(_, path)| path),
+        None => source.as_str(),
+    };
+
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+
+    let mut path = path.trim();
+    while let Some(stripped) = path.strip_prefix("./") {
+        path = stripped;
+    }
+
+    path.to_string()
+}
+
 /// Handles all logic related to extracting links from requested source code
 #[derive(Debug)]
 pub struct Extractor<'a> {
@@ -63,6 +123,9 @@ impl<'a> Extractor<'a> {
             ExtractionTarget::ResponseBody => Ok(self.extract_from_body().await?),
             ExtractionTarget::RobotsTxt => Ok(self.extract_from_robots().await?),
             ExtractionTarget::DirectoryListing => Ok(self.extract_from_dir_listing().await?),
+            ExtractionTarget::Forms => Ok(self.extract_from_forms().await?),
+            ExtractionTarget::SourceMap => Ok(self.extract_from_source_map().await?),
+            ExtractionTarget::ApiVersions => Ok(self.extract_from_api_versions()?),
         }
     }
 
@@ -154,7 +217,14 @@ impl<'a> Extractor<'a> {
             if resp.is_file() || !resp.is_directory() {
                 log::debug!("Extracted File: {}", resp);
 
-                scanned_urls.add_file_scan(resp.url().as_str(), ScanOrder::Latest);
+                // links that don't look like a file or a directory are recorded as such, and
+                // get classified once another response for the url arrives
+                match detect_scan_type(resp.url().as_str(), &resp) {
+                    ScanType::Unknown => {
+                        scanned_urls.add_unknown_scan(resp.url().as_str(), ScanOrder::Latest)
+                    }
+                    _ => scanned_urls.add_file_scan(resp.url().as_str(), ScanOrder::Latest),
+                };
 
                 if self.handles.config.collect_extensions {
                     resp.parse_extension(self.handles.clone())?;
@@ -184,7 +254,9 @@ impl<'a> Extractor<'a> {
                     resp.set_url(&format!("{}/", resp.url()));
                 }
 
-                if self.handles.config.filter_status.is_empty() {
+                if self.handles.config.filter_status.is_empty()
+                    && !self.handles.config.include_all_status
+                {
                     // -C wasn't used, so -s is the only 'filter' left to account for
                     if self
                         .handles
@@ -195,8 +267,8 @@ impl<'a> Extractor<'a> {
                         send_try_recursion_command(self.handles.clone(), resp).await?;
                     }
                 } else {
-                    // -C was used, that means the filters above would have removed
-                    // those responses, and anything else should be let through
+                    // -C or --include-all-status was used, that means the filters above would
+                    // have removed those responses, and anything else should be let through
                     send_try_recursion_command(self.handles.clone(), resp).await?;
                 }
             }
@@ -377,9 +449,11 @@ impl<'a> Extractor<'a> {
         log::trace!("enter: add_link_to_set_of_links({}, {:?})", link, links);
 
         let old_url = match self.target {
-            ExtractionTarget::ResponseBody | ExtractionTarget::DirectoryListing => {
-                self.response.unwrap().url().clone()
-            }
+            ExtractionTarget::ResponseBody
+            | ExtractionTarget::DirectoryListing
+            | ExtractionTarget::Forms
+            | ExtractionTarget::SourceMap
+            | ExtractionTarget::ApiVersions => self.response.unwrap().url().clone(),
             ExtractionTarget::RobotsTxt => match Url::parse(&self.url) {
                 Ok(u) => u,
                 Err(e) => {
@@ -413,12 +487,19 @@ impl<'a> Extractor<'a> {
 
         let scanned_urls = self.handles.ferox_scans()?;
 
-        if scanned_urls.get_scan_by_url(&new_url.to_string()).is_some() {
+        if scanned_urls.get_scan_by_url(new_url.as_ref()).is_some() {
             //we've seen the url before and don't need to scan again
             log::trace!("exit: request_link -> None");
             bail!("previously seen url");
         }
 
+        if self.handles.config.deduplicate_by_path && !scanned_urls.add_seen_path(new_url.as_str())
+        {
+            // --deduplicate-by-path; only the first query string seen for a path is requested
+            log::trace!("exit: request_link -> None");
+            bail!("previously seen path");
+        }
+
         if (!self.handles.config.url_denylist.is_empty()
             || !self.handles.config.regex_denylist.is_empty())
             && should_deny_url(&new_url, self.handles.clone())?
@@ -441,6 +522,7 @@ impl<'a> Extractor<'a> {
             url,
             DEFAULT_METHOD,
             self.handles.config.output_level,
+            self.handles.config.max_response_size,
         )
         .await;
 
@@ -525,6 +607,161 @@ impl<'a> Extractor<'a> {
         Ok(result)
     }
 
+    /// parses html response bodies in search of <form action="..."> attributes.
+    ///
+    /// unlike the other extraction targets, only same-origin urls are returned, and they're
+    /// returned as absolute urls w/o a query string or fragment, suitable for use as scan targets
+    pub(super) async fn extract_from_forms(&self) -> Result<ExtractionResult> {
+        log::trace!("enter: extract_from_forms");
+
+        let mut result = ExtractionResult::new();
+
+        let response = self.response.unwrap();
+        let resp_url = response.url();
+        let html = Html::parse_document(response.text());
+
+        let selector = Selector::parse("form[action]").unwrap();
+
+        for form in html.select(&selector) {
+            let action = match form.value().attr("action").map(str::trim) {
+                // an empty action submits back to the current page, nothing new there
+                Some(action) if !action.is_empty() => action,
+                _ => continue,
+            };
+
+            let mut absolute = match resp_url.join(action) {
+                Ok(absolute) => absolute,
+                Err(e) => {
+                    log::debug!("Could not join {} with {}: {}", action, resp_url, e);
+                    continue;
+                }
+            };
+
+            if absolute.origin() != resp_url.origin() {
+                log::debug!("form action didn't belong to the target origin: {}", action);
+                continue;
+            }
+
+            absolute.set_query(None);
+            absolute.set_fragment(None);
+
+            log::debug!("Parsed form action \"{}\" from {}", absolute, resp_url);
+            result.insert(absolute.to_string());
+        }
+
+        log::trace!("exit: extract_from_forms -> {:?}", result);
+        Ok(result)
+    }
+
+    /// reads the `//# sourceMappingURL=` comment from the last line of a javascript response,
+    /// requests the source map it references, and parses the map's `sources` array
+    ///
+    /// the original source paths are recorded for --collect-source-maps' output file, and are
+    /// returned as absolute, same-origin urls (relative to the source map) to be requested
+    pub(super) async fn extract_from_source_map(&self) -> Result<ExtractionResult> {
+        log::trace!("enter: extract_from_source_map");
+
+        let mut result = ExtractionResult::new();
+
+        let response = self.response.unwrap();
+        let resp_url = response.url();
+
+        let reference = match source_map_reference(response.text()) {
+            Some(reference) => reference,
+            None => {
+                log::trace!("exit: extract_from_source_map -> {:?}", result);
+                return Ok(result);
+            }
+        };
+
+        let map_url = resp_url
+            .join(reference)
+            .with_context(|| format!("Could not join {} with {}", resp_url, reference))?;
+
+        if map_url.origin() != resp_url.origin() {
+            log::debug!("source map didn't belong to the target origin: {}", map_url);
+            log::trace!("exit: extract_from_source_map -> {:?}", result);
+            return Ok(result);
+        }
+
+        let scans = self.handles.ferox_scans()?;
+
+        if !scans.add_source_map(map_url.as_str()) {
+            // already requested via another javascript file
+            log::trace!("exit: extract_from_source_map -> {:?}", result);
+            return Ok(result);
+        }
+
+        let map_response =
+            logged_request(&map_url, DEFAULT_METHOD, None, self.handles.clone()).await?;
+
+        if !map_response.status().is_success() {
+            bail!("source map {} returned {}", map_url, map_response.status());
+        }
+
+        let source_map: SourceMap = serde_json::from_str(&map_response.text().await?)
+            .with_context(|| format!("Could not parse source map {}", map_url))?;
+
+        let source_root = source_map.source_root.unwrap_or_default();
+        let mut paths = HashSet::new();
+
+        for source in source_map.sources.iter().flatten() {
+            let path = normalize_source_path(&source_root, source);
+
+            if path.is_empty() {
+                continue;
+            }
+
+            if let Ok(mut absolute) = map_url.join(&path) {
+                if absolute.origin() == map_url.origin() {
+                    absolute.set_query(None);
+                    absolute.set_fragment(None);
+                    result.insert(absolute.to_string());
+                }
+            }
+
+            paths.insert(path);
+        }
+
+        if let Some(host) = host_key(&map_url) {
+            SOURCE_MAP_PATHS.extend(&host, paths);
+        }
+
+        log::trace!("exit: extract_from_source_map -> {:?}", result);
+        Ok(result)
+    }
+
+    /// given a response whose url contains an api version (i.e. /api/v1/users), return the
+    /// urls of the versions that follow it (/api/v2/, /api/v3/, ...) that haven't already been
+    /// probed
+    pub(super) fn extract_from_api_versions(&self) -> Result<ExtractionResult> {
+        log::trace!("enter: extract_from_api_versions");
+
+        let mut result = ExtractionResult::new();
+
+        let prober = match VersionProber::from_url(self.response.unwrap().url()) {
+            Some(prober) => prober,
+            None => {
+                log::trace!("exit: extract_from_api_versions -> {:?}", result);
+                return Ok(result);
+            }
+        };
+
+        let scans = self.handles.ferox_scans()?;
+
+        // the detected version itself was already requested, or is the parent of what was
+        scans.add_api_version_probe(&prober.version_url());
+
+        for url in prober.next_versions() {
+            if scans.add_api_version_probe(&url) {
+                result.insert(url);
+            }
+        }
+
+        log::trace!("exit: extract_from_api_versions -> {:?}", result);
+        Ok(result)
+    }
+
     /// simple helper to get html links by tag/attribute and add it to the `links` HashSet
     fn extract_links_by_attr(
         &self,
@@ -582,6 +819,11 @@ impl<'a> Extractor<'a> {
                 Some(self.handles.config.proxy.as_str())
             };
 
+            let resolver = dns::resolver(
+                self.handles.config.dns_server.as_deref(),
+                self.handles.config.dns_over_https.as_deref(),
+            )?;
+
             client = client::initialize(
                 self.handles.config.timeout,
                 &self.handles.config.user_agent,
@@ -589,6 +831,8 @@ impl<'a> Extractor<'a> {
                 self.handles.config.insecure,
                 &self.handles.config.headers,
                 proxy,
+                &self.handles.config.proxy_exclude_patterns,
+                resolver,
             )?;
         }
 
@@ -618,6 +862,7 @@ impl<'a> Extractor<'a> {
             &self.url,
             DEFAULT_METHOD,
             self.handles.config.output_level,
+            self.handles.config.max_response_size,
         )
         .await;
         // note: don't call parse_extension here. If we call it here, it gets called on robots.txt
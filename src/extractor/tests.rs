@@ -1,7 +1,7 @@
 use super::builder::{LINKFINDER_REGEX, ROBOTS_TXT_REGEX};
 use super::*;
 use crate::config::{Configuration, OutputLevel};
-use crate::scan_manager::ScanOrder;
+use crate::scan_manager::{ScanOrder, SOURCE_MAP_PATHS};
 use crate::{
     event_handlers::Handles, scan_manager::FeroxScans, utils::make_request, Command, FeroxChannel,
     DEFAULT_METHOD,
@@ -48,6 +48,13 @@ fn setup_extractor(target: ExtractionTarget, scanned_urls: Arc<FeroxScans>) -> E
         ExtractionTarget::DirectoryListing => builder
             .url("http://localhost")
             .target(ExtractionTarget::DirectoryListing),
+        ExtractionTarget::Forms => builder.target(ExtractionTarget::Forms).response(&RESPONSE),
+        ExtractionTarget::SourceMap => builder
+            .target(ExtractionTarget::SourceMap)
+            .response(&RESPONSE),
+        ExtractionTarget::ApiVersions => builder
+            .target(ExtractionTarget::ApiVersions)
+            .response(&RESPONSE),
     };
 
     let config = Arc::new(Configuration::new().unwrap());
@@ -267,8 +274,14 @@ async fn extractor_get_links_with_absolute_url_that_differs_from_target_domain()
     let (handles, _rx) = Handles::for_testing(None, None);
 
     let handles = Arc::new(handles);
-    let ferox_response =
-        FeroxResponse::from(response, &srv.url(""), DEFAULT_METHOD, OutputLevel::Default).await;
+    let ferox_response = FeroxResponse::from(
+        response,
+        &srv.url(""),
+        DEFAULT_METHOD,
+        OutputLevel::Default,
+        0,
+    )
+    .await;
 
     let extractor = Extractor {
         links_regex: Regex::new(LINKFINDER_REGEX).unwrap(),
@@ -396,3 +409,109 @@ async fn request_link_bails_on_seen_url() -> Result<()> {
     assert_eq!(mock.hits(), 0); // function exits before requests can happen
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+/// form actions should be resolved to absolute, same-origin urls w/o query strings; offsite and
+/// empty actions should be ignored
+async fn extractor_extract_from_forms_keeps_only_same_origin_actions() -> Result<()> {
+    let handles = Arc::new(Handles::for_testing(None, None).0);
+
+    let mut response = FeroxResponse::default();
+    response.set_url("http://localhost/account/");
+    response.set_text(
+        r#"<html><body>
+        <form action="login.php?next=/"><input name="user"></form>
+        <form action="/api/search#results"></form>
+        <form action="https://offsite.example.com/collect"></form>
+        <form action=""></form>
+        <form></form>
+        </body></html>"#,
+    );
+
+    let extractor = ExtractorBuilder::default()
+        .target(ExtractionTarget::Forms)
+        .response(&response)
+        .handles(handles)
+        .build()?;
+
+    let links = extractor.extract().await?;
+
+    let expected: HashSet<String> = [
+        "http://localhost/account/login.php",
+        "http://localhost/api/search",
+    ]
+    .iter()
+    .map(|link| link.to_string())
+    .collect();
+
+    assert_eq!(links, expected);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+/// the source map referenced on a javascript file's last line should be requested once, and its
+/// sources returned as same-origin urls relative to the map, with bundler prefixes removed
+async fn extractor_extract_from_source_map_returns_original_sources() -> Result<()> {
+    let srv = MockServer::start();
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET).path("/static/js/app.js.map");
+        then.status(200).body(
+            r#"{"version":3,"sources":["webpack:///./src/index.js?5d1f","../lib/util.js",null],"mappings":""}"#,
+        );
+    });
+
+    let scans = Arc::new(FeroxScans::default());
+    let handles = Arc::new(Handles::for_testing(Some(scans.clone()), None).0);
+
+    let mut response = FeroxResponse::default();
+    response.set_url(&srv.url("/static/js/app.js"));
+    response.set_text("var a = 1;\n//# sourceMappingURL=app.js.map\n");
+
+    let extractor = ExtractorBuilder::default()
+        .target(ExtractionTarget::SourceMap)
+        .response(&response)
+        .handles(handles)
+        .build()?;
+
+    let links = extractor.extract().await?;
+
+    let expected: HashSet<String> = [
+        srv.url("/static/js/src/index.js"),
+        srv.url("/static/lib/util.js"),
+    ]
+    .into_iter()
+    .collect();
+
+    assert_eq!(links, expected);
+    assert_eq!(
+        SOURCE_MAP_PATHS
+            .by_host()
+            .into_iter()
+            .find(|(host, _)| *host == srv.address().to_string())
+            .map(|(_, paths)| paths.into_iter().collect::<Vec<_>>()),
+        Some(vec![
+            "../lib/util.js".to_string(),
+            "src/index.js".to_string()
+        ])
+    );
+
+    // the same map referenced again shouldn't be requested a second time
+    assert!(extractor.extract().await?.is_empty());
+    assert_eq!(mock.hits(), 1);
+
+    // a reference that isn't on the last line is ignored
+    let mut response = FeroxResponse::default();
+    response.set_url(&srv.url("/static/js/other.js"));
+    response.set_text("//# sourceMappingURL=other.js.map\nvar a = 1;");
+
+    let extractor = ExtractorBuilder::default()
+        .target(ExtractionTarget::SourceMap)
+        .response(&response)
+        .handles(Arc::new(Handles::for_testing(Some(scans), None).0))
+        .build()?;
+
+    assert!(extractor.extract().await?.is_empty());
+
+    Ok(())
+}
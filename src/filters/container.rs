@@ -9,8 +9,9 @@ use crate::{
 };
 
 use super::{
-    FeroxFilter, LinesFilter, RegexFilter, SimilarityFilter, SizeFilter, StatusCodeFilter,
-    WildcardFilter, WordsFilter,
+    ExtensionFilter, FeroxFilter, HeaderCountFilter, JsonPathFilter, LinesFilter, LinesRangeFilter,
+    RegexFilter, ResponseTimeFilter, SimilarityFilter, SizeFilter, SizeRangeFilter,
+    SizeRatioFilter, StatusCodeFilter, WildcardFilter, WordsFilter, WordsRangeFilter,
 };
 
 /// Container around a collection of `FeroxFilters`s
@@ -118,6 +119,42 @@ impl Serialize for FeroxFilters {
                     filter.as_any().downcast_ref::<WildcardFilter>()
                 {
                     seq.serialize_element(wildcard_filter).unwrap_or_default();
+                } else if let Some(extension_filter) =
+                    filter.as_any().downcast_ref::<ExtensionFilter>()
+                {
+                    seq.serialize_element(extension_filter).unwrap_or_default();
+                } else if let Some(size_range_filter) =
+                    filter.as_any().downcast_ref::<SizeRangeFilter>()
+                {
+                    seq.serialize_element(size_range_filter).unwrap_or_default();
+                } else if let Some(words_range_filter) =
+                    filter.as_any().downcast_ref::<WordsRangeFilter>()
+                {
+                    seq.serialize_element(words_range_filter)
+                        .unwrap_or_default();
+                } else if let Some(lines_range_filter) =
+                    filter.as_any().downcast_ref::<LinesRangeFilter>()
+                {
+                    seq.serialize_element(lines_range_filter)
+                        .unwrap_or_default();
+                } else if let Some(response_time_filter) =
+                    filter.as_any().downcast_ref::<ResponseTimeFilter>()
+                {
+                    seq.serialize_element(response_time_filter)
+                        .unwrap_or_default();
+                } else if let Some(header_count_filter) =
+                    filter.as_any().downcast_ref::<HeaderCountFilter>()
+                {
+                    seq.serialize_element(header_count_filter)
+                        .unwrap_or_default();
+                } else if let Some(size_ratio_filter) =
+                    filter.as_any().downcast_ref::<SizeRatioFilter>()
+                {
+                    seq.serialize_element(size_ratio_filter).unwrap_or_default();
+                } else if let Some(json_path_filter) =
+                    filter.as_any().downcast_ref::<JsonPathFilter>()
+                {
+                    seq.serialize_element(json_path_filter).unwrap_or_default();
                 }
             }
             seq.end()
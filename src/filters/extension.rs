@@ -0,0 +1,41 @@
+use super::*;
+
+/// Simple implementor of FeroxFilter; used to filter out responses based on the file extension
+/// of the response's (final) url; specified using --filter-extension
+#[derive(Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ExtensionFilter {
+    /// extension (without the leading period) that should be filtered
+    pub extension: String,
+}
+
+/// implementation of FeroxFilter for ExtensionFilter
+impl FeroxFilter for ExtensionFilter {
+    /// Check the extension of the response's url against what was passed in via
+    /// --filter-extension
+    fn should_filter_response(&self, response: &FeroxResponse) -> bool {
+        log::trace!("enter: should_filter_response({:?} {})", self, response);
+
+        // only the last path segment is considered, so /some.dir/file doesn't match `dir`
+        let result = response
+            .url()
+            .path()
+            .rsplit('/')
+            .next()
+            .and_then(|segment| segment.rsplit_once('.'))
+            .is_some_and(|(_, ext)| ext.eq_ignore_ascii_case(&self.extension));
+
+        log::trace!("exit: should_filter_response -> {}", result);
+
+        result
+    }
+
+    /// Compare one ExtensionFilter to another
+    fn box_eq(&self, other: &dyn Any) -> bool {
+        other.downcast_ref::<Self>().is_some_and(|a| self == a)
+    }
+
+    /// Return self as Any for dynamic dispatch purposes
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -0,0 +1,40 @@
+use super::*;
+
+/// Simple implementor of FeroxFilter; used to filter out responses based on how many headers
+/// they were sent with; specified using --filter-max-headers and --filter-min-headers
+#[derive(Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct HeaderCountFilter {
+    /// Number of headers that a Response's header count is compared against
+    pub threshold: usize,
+
+    /// whether responses with more (true) or fewer (false) headers than `threshold` are filtered
+    pub above: bool,
+}
+
+/// implementation of FeroxFilter for HeaderCountFilter
+impl FeroxFilter for HeaderCountFilter {
+    /// Check `header_count` against the threshold passed in via --filter-max-headers|min-headers
+    fn should_filter_response(&self, response: &FeroxResponse) -> bool {
+        log::trace!("enter: should_filter_response({:?} {})", self, response);
+
+        let result = if self.above {
+            response.header_count() > self.threshold
+        } else {
+            response.header_count() < self.threshold
+        };
+
+        log::trace!("exit: should_filter_response -> {}", result);
+
+        result
+    }
+
+    /// Compare one HeaderCountFilter to another
+    fn box_eq(&self, other: &dyn Any) -> bool {
+        other.downcast_ref::<Self>().is_some_and(|a| self == a)
+    }
+
+    /// Return self as Any for dynamic dispatch purposes
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -1,6 +1,8 @@
 use super::{
-    utils::create_similarity_filter, LinesFilter, RegexFilter, SizeFilter, StatusCodeFilter,
-    WordsFilter,
+    utils::{create_similarity_filter, parse_count_range},
+    ExtensionFilter, HeaderCountFilter, JsonPathExpression, JsonPathFilter, LinesFilter,
+    LinesRangeFilter, RegexFilter, ResponseTimeFilter, SizeFilter, SizeRange, SizeRangeFilter,
+    StatusCodeFilter, WordsFilter, WordsRangeFilter,
 };
 use crate::{event_handlers::Handles, skip_fail, utils::fmt_err, Command::AddFilter};
 use anyhow::Result;
@@ -9,8 +11,15 @@ use std::sync::Arc;
 
 /// add all user-supplied filters to the (already started) filters handler
 pub async fn initialize(handles: Arc<Handles>) -> Result<()> {
-    // add any status code filters to filters handler's FeroxFilters  (-C|--filter-status)
-    for code_filter in &handles.config.filter_status {
+    // add any status code filters to filters handler's FeroxFilters  (-C|--filter-status);
+    // --include-all-status means no status code is ever filtered
+    let filter_status: &[u16] = if handles.config.include_all_status {
+        &[]
+    } else {
+        &handles.config.filter_status
+    };
+
+    for code_filter in filter_status {
         let filter = StatusCodeFilter {
             filter_code: *code_filter,
         };
@@ -36,15 +45,118 @@ pub async fn initialize(handles: Arc<Handles>) -> Result<()> {
         skip_fail!(handles.filters.send(AddFilter(boxed_filter)));
     }
 
+    // add any word count range filters to filters handler's FeroxFilters  (--filter-words-range)
+    for words_range in &handles.config.filter_words_range {
+        let filter = WordsRangeFilter {
+            word_range: skip_fail!(parse_count_range(words_range)),
+        };
+        let boxed_filter = Box::new(filter);
+        skip_fail!(handles.filters.send(AddFilter(boxed_filter)));
+    }
+
+    // add any line count range filters to filters handler's FeroxFilters  (--filter-lines-range)
+    for lines_range in &handles.config.filter_lines_range {
+        let filter = LinesRangeFilter {
+            line_range: skip_fail!(parse_count_range(lines_range)),
+        };
+        let boxed_filter = Box::new(filter);
+        skip_fail!(handles.filters.send(AddFilter(boxed_filter)));
+    }
+
+    // --filter-empty-body is shorthand for -S 0
+    let empty_body =
+        (handles.config.filter_empty_body && !handles.config.filter_size.contains(&0)).then_some(0);
+
     // add any line count filters to filters handler's FeroxFilters  (-S|--filter-size)
-    for size_filter in &handles.config.filter_size {
+    for size_filter in handles.config.filter_size.iter().copied().chain(empty_body) {
         let filter = SizeFilter {
-            content_length: *size_filter,
+            content_length: size_filter,
         };
         let boxed_filter = Box::new(filter);
         skip_fail!(handles.filters.send(AddFilter(boxed_filter)));
     }
 
+    // --filter-near-empty-body MAX is shorthand for --filter-body-size-range 0-MAX
+    let near_empty_body = match handles.config.filter_near_empty_body {
+        0 => Vec::new(),
+        max => vec![format!("0-{}", max)],
+    };
+
+    // add size range filters to filters handler's FeroxFilters  (--filter-body-size-range and
+    // --keep-body-size-range); each flag gets a single filter covering all of its ranges, since
+    // a response only needs to be within one of the --keep-body-size-range ranges to be kept
+    for (ranges, keep) in [
+        (&handles.config.filter_body_size_range, false),
+        (&near_empty_body, false),
+        (&handles.config.keep_body_size_range, true),
+    ] {
+        if ranges.is_empty() {
+            continue;
+        }
+
+        let ranges = skip_fail!(ranges
+            .iter()
+            .map(|range| range.parse::<SizeRange>())
+            .collect::<Result<Vec<_>>>());
+
+        let filter = SizeRangeFilter { ranges, keep };
+        let boxed_filter = Box::new(filter);
+        skip_fail!(handles.filters.send(AddFilter(boxed_filter)));
+    }
+
+    // add json path filters to filters handler's FeroxFilters  (--json-path-filter and
+    // --json-path-keep-if); as with the size range filters, each flag gets a single filter
+    for (expressions, keep) in [
+        (&handles.config.json_path_filter, false),
+        (&handles.config.json_path_keep_if, true),
+    ] {
+        if expressions.is_empty() {
+            continue;
+        }
+
+        let expressions = skip_fail!(expressions
+            .iter()
+            .map(|expression| expression.parse::<JsonPathExpression>())
+            .collect::<Result<Vec<_>>>());
+
+        let filter = JsonPathFilter { expressions, keep };
+        let boxed_filter = Box::new(filter);
+        skip_fail!(handles.filters.send(AddFilter(boxed_filter)));
+    }
+
+    // add response time filters to filters handler's FeroxFilters  (--filter-response-time-above
+    // and --filter-response-time-below); a threshold of 0 means the filter isn't in use
+    for (threshold_ms, above) in [
+        (handles.config.filter_response_time_above, true),
+        (handles.config.filter_response_time_below, false),
+    ] {
+        if threshold_ms == 0 {
+            continue;
+        }
+
+        let filter = ResponseTimeFilter {
+            threshold_ms,
+            above,
+        };
+        let boxed_filter = Box::new(filter);
+        skip_fail!(handles.filters.send(AddFilter(boxed_filter)));
+    }
+
+    // add header count filters to filters handler's FeroxFilters  (--filter-max-headers and
+    // --filter-min-headers); a threshold of 0 means the filter isn't in use
+    for (threshold, above) in [
+        (handles.config.filter_max_headers, true),
+        (handles.config.filter_min_headers, false),
+    ] {
+        if threshold == 0 {
+            continue;
+        }
+
+        let filter = HeaderCountFilter { threshold, above };
+        let boxed_filter = Box::new(filter);
+        skip_fail!(handles.filters.send(AddFilter(boxed_filter)));
+    }
+
     // add any regex filters to filters handler's FeroxFilters  (-X|--filter-regex)
     for regex_filter in &handles.config.filter_regex {
         let raw = regex_filter;
@@ -58,6 +170,15 @@ pub async fn initialize(handles: Arc<Handles>) -> Result<()> {
         skip_fail!(handles.filters.send(AddFilter(boxed_filter)));
     }
 
+    // add any extension filters to filters handler's FeroxFilters  (--filter-extension)
+    for extension_filter in &handles.config.filter_extension {
+        let filter = ExtensionFilter {
+            extension: extension_filter.to_owned(),
+        };
+        let boxed_filter = Box::new(filter);
+        skip_fail!(handles.filters.send(AddFilter(boxed_filter)));
+    }
+
     // add any similarity filters to filters handler's FeroxFilters  (--filter-similar-to)
     for similarity_filter in &handles.config.filter_similar {
         let filter = skip_fail!(create_similarity_filter(similarity_filter, handles.clone()).await);
@@ -0,0 +1,276 @@
+use super::*;
+use anyhow::{bail, Result};
+use serde_json::Value;
+use std::{cmp::Ordering, fmt, str::FromStr};
+
+/// single step taken while walking down into a json document
+#[derive(Debug, Clone, PartialEq)]
+enum PathSegment {
+    /// object member, given as `.name` or `["name"]`
+    Key(String),
+
+    /// array element, given as `[N]`
+    Index(usize),
+}
+
+/// comparison between the value found at a path and a literal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operator {
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+/// operators in the order they're matched, so that `<=` isn't read as `<`
+const OPERATORS: [(&str, Operator); 6] = [
+    ("==", Operator::Equal),
+    ("!=", Operator::NotEqual),
+    ("<=", Operator::LessOrEqual),
+    (">=", Operator::GreaterOrEqual),
+    ("<", Operator::Less),
+    (">", Operator::Greater),
+];
+
+/// minimal JSONPath expression, i.e. `$.data[0].status == "ok"`
+///
+/// a path on its own (`$.data.admin`) matches when the value it points to exists and isn't
+/// `null` or `false`; a path followed by a comparison against a json literal matches when the
+/// comparison holds, with missing values treated as `null`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct JsonPathExpression {
+    /// expression as passed in on the command line
+    raw: String,
+
+    /// steps to take from the document's root
+    path: Vec<PathSegment>,
+
+    /// optional comparison against a literal value
+    comparison: Option<(Operator, Value)>,
+}
+
+/// implementation of JsonPathExpression
+impl JsonPathExpression {
+    /// follow the expression's path from `root`
+    fn resolve<'a>(&self, root: &'a Value) -> Option<&'a Value> {
+        self.path
+            .iter()
+            .try_fold(root, |value, segment| match segment {
+                PathSegment::Key(key) => value.get(key),
+                PathSegment::Index(index) => value.get(index),
+            })
+    }
+
+    /// whether the expression holds for the given json document
+    pub fn matches(&self, root: &Value) -> bool {
+        let found = self.resolve(root);
+
+        let (operator, literal) = match &self.comparison {
+            Some((operator, literal)) => (operator, literal),
+            None => return !matches!(found, None | Some(Value::Null) | Some(Value::Bool(false))),
+        };
+
+        let found = found.unwrap_or(&Value::Null);
+
+        match operator {
+            Operator::Equal => values_equal(found, literal),
+            Operator::NotEqual => !values_equal(found, literal),
+            _ => match compare_values(found, literal) {
+                Some(ordering) => match operator {
+                    Operator::Less => ordering == Ordering::Less,
+                    Operator::LessOrEqual => ordering != Ordering::Greater,
+                    Operator::Greater => ordering == Ordering::Greater,
+                    _ => ordering != Ordering::Less,
+                },
+                None => false,
+            },
+        }
+    }
+}
+
+/// json equality, except that numbers are compared by value (`1 == 1.0`)
+fn values_equal(left: &Value, right: &Value) -> bool {
+    match (left.as_f64(), right.as_f64()) {
+        (Some(left), Some(right)) => left == right,
+        _ => left == right,
+    }
+}
+
+/// ordering of two numbers or two strings; anything else can't be ordered
+fn compare_values(left: &Value, right: &Value) -> Option<Ordering> {
+    match (left, right) {
+        (Value::Number(left), Value::Number(right)) => left.as_f64()?.partial_cmp(&right.as_f64()?),
+        (Value::String(left), Value::String(right)) => Some(left.cmp(right)),
+        _ => None,
+    }
+}
+
+/// split the path at the start of `expression` into its segments, returning them along with
+/// whatever follows the path
+fn parse_path(expression: &str) -> Result<(Vec<PathSegment>, &str)> {
+    let mut rest = match expression.strip_prefix('$') {
+        Some(rest) => rest,
+        None => bail!("JSON path {} should start with $", expression),
+    };
+
+    let mut path = Vec::new();
+
+    loop {
+        if let Some(after_dot) = rest.strip_prefix('.') {
+            let end = after_dot
+                .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '-'))
+                .unwrap_or(after_dot.len());
+
+            if end == 0 {
+                bail!("JSON path {} has an empty key after a '.'", expression);
+            }
+
+            path.push(PathSegment::Key(after_dot[..end].to_string()));
+            rest = &after_dot[end..];
+        } else if let Some(after_bracket) = rest.strip_prefix('[') {
+            let end = match after_bracket.find(']') {
+                Some(end) => end,
+                None => bail!("JSON path {} has an unclosed '['", expression),
+            };
+
+            let inner = after_bracket[..end].trim();
+
+            let segment = if let Ok(index) = inner.parse::<usize>() {
+                PathSegment::Index(index)
+            } else if inner.len() >= 2
+                && ((inner.starts_with('"') && inner.ends_with('"'))
+                    || (inner.starts_with('\'') && inner.ends_with('\'')))
+            {
+                PathSegment::Key(inner[1..inner.len() - 1].to_string())
+            } else {
+                bail!(
+                    "JSON path {} should use a number or a quoted key inside of []",
+                    expression
+                );
+            };
+
+            path.push(segment);
+            rest = &after_bracket[end + 1..];
+        } else {
+            return Ok((path, rest));
+        }
+    }
+}
+
+/// parse an expression such as `$.status == "ok"` into a JsonPathExpression
+impl FromStr for JsonPathExpression {
+    type Err = anyhow::Error;
+
+    fn from_str(expression: &str) -> Result<Self> {
+        let trimmed = expression.trim();
+
+        let (path, rest) = parse_path(trimmed)?;
+        let rest = rest.trim();
+
+        let comparison = if rest.is_empty() {
+            None
+        } else {
+            let (operator, literal) = match OPERATORS
+                .iter()
+                .find_map(|(token, operator)| Some((*operator, rest.strip_prefix(token)?)))
+            {
+                Some((operator, literal)) => (operator, literal.trim()),
+                None => bail!(
+                    "JSON path {} should be followed by one of ==, !=, <, <=, >, >=",
+                    expression
+                ),
+            };
+
+            let literal = match serde_json::from_str::<Value>(literal) {
+                Ok(value) => value,
+                Err(_)
+                    if literal.len() >= 2
+                        && literal.starts_with('\'')
+                        && literal.ends_with('\'') =>
+                {
+                    // single quotes are easier to type inside of a shell's double quotes
+                    Value::String(literal[1..literal.len() - 1].to_string())
+                }
+                Err(_) => bail!(
+                    "JSON path {} should be compared against a json value, i.e. \"ok\" or 200",
+                    expression
+                ),
+            };
+
+            Some((operator, literal))
+        };
+
+        Ok(Self {
+            raw: trimmed.to_string(),
+            path,
+            comparison,
+        })
+    }
+}
+
+/// allows JsonPathExpression to be deserialized from the expression it was created from
+impl TryFrom<String> for JsonPathExpression {
+    type Error = anyhow::Error;
+
+    fn try_from(expression: String) -> Result<Self> {
+        expression.parse()
+    }
+}
+
+/// allows JsonPathExpression to be serialized as the expression it was created from
+impl From<JsonPathExpression> for String {
+    fn from(expression: JsonPathExpression) -> Self {
+        expression.raw
+    }
+}
+
+/// display a JsonPathExpression the same way it's given on the command line
+impl fmt::Display for JsonPathExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
+
+/// Simple implementor of FeroxFilter; used to filter out json responses based on whether or not
+/// their bodies match one of the given expressions; specified using --json-path-filter or
+/// --json-path-keep-if
+#[derive(Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct JsonPathFilter {
+    /// expressions to evaluate against each json body
+    pub expressions: Vec<JsonPathExpression>,
+
+    /// when true (--json-path-keep-if), json responses matching none of the expressions are
+    /// filtered, otherwise json responses matching any of the expressions are filtered
+    pub keep: bool,
+}
+
+/// implementation of FeroxFilter for JsonPathFilter
+impl FeroxFilter for JsonPathFilter {
+    /// Check the response body against the expressions passed in via --json-path-filter or
+    /// --json-path-keep-if; bodies that aren't json are never filtered
+    fn should_filter_response(&self, response: &FeroxResponse) -> bool {
+        log::trace!("enter: should_filter_response({:?} {})", self, response);
+
+        let result = match serde_json::from_str::<Value>(response.text()) {
+            Ok(body) => self.expressions.iter().any(|expr| expr.matches(&body)) != self.keep,
+            Err(_) => false,
+        };
+
+        log::trace!("exit: should_filter_response -> {}", result);
+
+        result
+    }
+
+    /// Compare one JsonPathFilter to another
+    fn box_eq(&self, other: &dyn Any) -> bool {
+        other.downcast_ref::<Self>().is_some_and(|a| self == a)
+    }
+
+    /// Return self as Any for dynamic dispatch purposes
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
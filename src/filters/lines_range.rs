@@ -0,0 +1,35 @@
+use super::*;
+
+/// Simple implementor of FeroxFilter; used to filter out responses based on whether the number
+/// of lines in a Response body falls within an inclusive range; specified using
+/// --filter-lines-range
+#[derive(Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LinesRangeFilter {
+    /// Smallest and largest number of lines in a Response's body that should be filtered
+    pub line_range: (usize, usize),
+}
+
+/// implementation of FeroxFilter for LinesRangeFilter
+impl FeroxFilter for LinesRangeFilter {
+    /// Check `line_count` against the range passed in via --filter-lines-range
+    fn should_filter_response(&self, response: &FeroxResponse) -> bool {
+        log::trace!("enter: should_filter_response({:?} {})", self, response);
+
+        let (min, max) = self.line_range;
+        let result = (min..=max).contains(&response.line_count());
+
+        log::trace!("exit: should_filter_response -> {}", result);
+
+        result
+    }
+
+    /// Compare one LinesRangeFilter to another
+    fn box_eq(&self, other: &dyn Any) -> bool {
+        other.downcast_ref::<Self>().is_some_and(|a| self == a)
+    }
+
+    /// Return self as Any for dynamic dispatch purposes
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
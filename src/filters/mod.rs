@@ -8,22 +8,37 @@ use crate::traits::{FeroxFilter, FeroxSerialize};
 
 pub use self::container::FeroxFilters;
 pub(crate) use self::empty::EmptyFilter;
+pub use self::extension::ExtensionFilter;
+pub use self::header_count::HeaderCountFilter;
 pub use self::init::initialize;
+pub use self::json_path::{JsonPathExpression, JsonPathFilter};
 pub use self::lines::LinesFilter;
+pub use self::lines_range::LinesRangeFilter;
 pub use self::regex::RegexFilter;
+pub use self::response_time::ResponseTimeFilter;
 pub use self::similarity::SimilarityFilter;
 pub use self::size::SizeFilter;
+pub use self::size_range::{SizeRange, SizeRangeFilter};
+pub use self::size_ratio::SizeRatioFilter;
 pub use self::status_code::StatusCodeFilter;
-pub(crate) use self::utils::{create_similarity_filter, filter_lookup};
+pub(crate) use self::utils::{create_similarity_filter, filter_lookup, parse_count_range};
 pub use self::wildcard::WildcardFilter;
 pub use self::words::WordsFilter;
+pub use self::words_range::WordsRangeFilter;
 
 mod wildcard;
 mod status_code;
 mod words;
+mod words_range;
 mod lines;
+mod json_path;
+mod lines_range;
 mod size;
+mod size_range;
+mod size_ratio;
 mod regex;
+mod response_time;
+mod header_count;
 mod similarity;
 mod container;
 #[cfg(test)]
@@ -31,3 +46,4 @@ mod tests;
 mod init;
 mod utils;
 mod empty;
+mod extension;
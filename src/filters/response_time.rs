@@ -0,0 +1,45 @@
+use super::*;
+
+/// Simple implementor of FeroxFilter; used to filter out responses based on how long they took
+/// to come back; specified using --filter-response-time-above and --filter-response-time-below
+#[derive(Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ResponseTimeFilter {
+    /// Number of milliseconds that a Response's time is compared against
+    pub threshold_ms: u64,
+
+    /// whether responses slower (true) or faster (false) than `threshold_ms` are filtered
+    pub above: bool,
+}
+
+/// implementation of FeroxFilter for ResponseTimeFilter
+impl FeroxFilter for ResponseTimeFilter {
+    /// Check `response_time_ms` against the threshold passed in via
+    /// --filter-response-time-above|below; responses without a recorded time are never filtered
+    fn should_filter_response(&self, response: &FeroxResponse) -> bool {
+        log::trace!("enter: should_filter_response({:?} {})", self, response);
+
+        let elapsed = response.response_time_ms();
+
+        let result = if elapsed == 0 {
+            false
+        } else if self.above {
+            elapsed > self.threshold_ms
+        } else {
+            elapsed < self.threshold_ms
+        };
+
+        log::trace!("exit: should_filter_response -> {}", result);
+
+        result
+    }
+
+    /// Compare one ResponseTimeFilter to another
+    fn box_eq(&self, other: &dyn Any) -> bool {
+        other.downcast_ref::<Self>().is_some_and(|a| self == a)
+    }
+
+    /// Return self as Any for dynamic dispatch purposes
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -20,9 +20,9 @@ impl FeroxFilter for SimilarityFilter {
     /// Check `FeroxResponse::text` against what was requested from the site passed in via
     /// --filter-similar-to
     fn should_filter_response(&self, response: &FeroxResponse) -> bool {
-        let other = FuzzyHash::new(&response.text());
+        let other = FuzzyHash::new(response.text());
 
-        if let Ok(result) = FuzzyHash::compare(&self.hash, &other.to_string()) {
+        if let Ok(result) = FuzzyHash::compare(&self.hash, other.to_string()) {
             return result >= self.threshold;
         }
 
@@ -33,7 +33,7 @@ impl FeroxFilter for SimilarityFilter {
 
     /// Compare one SimilarityFilter to another
     fn box_eq(&self, other: &dyn Any) -> bool {
-        other.downcast_ref::<Self>().map_or(false, |a| self == a)
+        other.downcast_ref::<Self>().is_some_and(|a| self == a)
     }
 
     /// Return self as Any for dynamic dispatch purposes
@@ -23,7 +23,7 @@ impl FeroxFilter for SizeFilter {
 
     /// Compare one SizeFilter to another
     fn box_eq(&self, other: &dyn Any) -> bool {
-        other.downcast_ref::<Self>().map_or(false, |a| self == a)
+        other.downcast_ref::<Self>().is_some_and(|a| self == a)
     }
 
     /// Return self as Any for dynamic dispatch purposes
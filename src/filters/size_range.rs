@@ -0,0 +1,102 @@
+use super::*;
+use anyhow::{bail, Result};
+use std::{fmt, str::FromStr};
+
+/// inclusive range of body sizes, given as `MIN-MAX` on the command line, where `MAX` may be
+/// `unlimited`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SizeRange {
+    /// smallest body size in the range
+    pub min: u64,
+
+    /// largest body size in the range; `u64::MAX` when given as `unlimited`
+    pub max: u64,
+}
+
+/// implementation of SizeRange
+impl SizeRange {
+    /// whether the given body size falls within the range
+    pub fn contains(&self, size: u64) -> bool {
+        self.min <= size && size <= self.max
+    }
+}
+
+/// parse a `MIN-MAX` string into a SizeRange
+impl FromStr for SizeRange {
+    type Err = anyhow::Error;
+
+    fn from_str(range: &str) -> Result<Self> {
+        let (min, max) = match range.split_once('-') {
+            Some((min, max)) => (min.trim(), max.trim()),
+            None => bail!("Size range {} should be formatted as MIN-MAX", range),
+        };
+
+        let min = min.parse::<u64>()?;
+
+        let max = if max.eq_ignore_ascii_case("unlimited") {
+            u64::MAX
+        } else {
+            max.parse::<u64>()?
+        };
+
+        if min > max {
+            bail!("Size range {} has a MIN that's larger than its MAX", range);
+        }
+
+        Ok(Self { min, max })
+    }
+}
+
+/// display a SizeRange the same way it's given on the command line
+impl fmt::Display for SizeRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.max == u64::MAX {
+            write!(f, "{}-unlimited", self.min)
+        } else {
+            write!(f, "{}-{}", self.min, self.max)
+        }
+    }
+}
+
+/// Simple implementor of FeroxFilter; used to filter out responses based on whether or not
+/// the length of their body falls within one of the given ranges; specified using
+/// --filter-body-size-range or --keep-body-size-range
+#[derive(Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SizeRangeFilter {
+    /// ranges of body sizes to check against
+    pub ranges: Vec<SizeRange>,
+
+    /// when true (--keep-body-size-range), responses *outside* of every range are filtered,
+    /// otherwise responses inside of any range are filtered
+    pub keep: bool,
+}
+
+/// implementation of FeroxFilter for SizeRangeFilter
+impl FeroxFilter for SizeRangeFilter {
+    /// Check `content_length` against the ranges passed in via --filter-body-size-range or
+    /// --keep-body-size-range
+    fn should_filter_response(&self, response: &FeroxResponse) -> bool {
+        log::trace!("enter: should_filter_response({:?} {})", self, response);
+
+        let in_range = self
+            .ranges
+            .iter()
+            .any(|range| range.contains(response.content_length()));
+
+        let result = in_range != self.keep;
+
+        log::trace!("exit: should_filter_response -> {}", result);
+
+        result
+    }
+
+    /// Compare one SizeRangeFilter to another
+    fn box_eq(&self, other: &dyn Any) -> bool {
+        other.downcast_ref::<Self>().is_some_and(|a| self == a)
+    }
+
+    /// Return self as Any for dynamic dispatch purposes
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -0,0 +1,53 @@
+use super::*;
+
+/// Simple implementor of FeroxFilter; used to filter out responses whose size is close to the
+/// size of the wildcard response seen while auto-calibrating a target; specified using
+/// --filter-min-ratio
+#[derive(Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SizeRatioFilter {
+    /// url of the scan whose wildcard response provided the baseline
+    pub url: String,
+
+    /// size of the wildcard response returned for `url`
+    pub baseline: u64,
+
+    /// smallest ratio (0.0 - 1.0) between a response's size and `baseline` that is filtered
+    pub ratio: f64,
+}
+
+/// implementation of FeroxFilter for SizeRatioFilter
+impl FeroxFilter for SizeRatioFilter {
+    /// Compare `content_length` to the baseline of the scan the response belongs to; responses
+    /// whose size is within --filter-min-ratio of the baseline are filtered
+    fn should_filter_response(&self, response: &FeroxResponse) -> bool {
+        log::trace!("enter: should_filter_response({:?} {})", self, response);
+
+        if self.baseline == 0 || !response.url().as_str().starts_with(&self.url) {
+            log::trace!("exit: should_filter_response -> false");
+            return false;
+        }
+
+        let size = response.content_length();
+        let (smaller, larger) = if size < self.baseline {
+            (size, self.baseline)
+        } else {
+            (self.baseline, size)
+        };
+
+        let result = smaller as f64 / larger as f64 >= self.ratio;
+
+        log::trace!("exit: should_filter_response -> {}", result);
+
+        result
+    }
+
+    /// Compare one SizeRatioFilter to another
+    fn box_eq(&self, other: &dyn Any) -> bool {
+        other.downcast_ref::<Self>().is_some_and(|a| self == a)
+    }
+
+    /// Return self as Any for dynamic dispatch purposes
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
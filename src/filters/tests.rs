@@ -178,6 +178,270 @@ fn regexfilter_should_filter_when_regex_matches_on_response_body() {
     assert!(filter.should_filter_response(&resp));
 }
 
+#[test]
+/// test should_filter on ExtensionFilter; only the last path segment's extension should match,
+/// regardless of case, and query strings shouldn't affect the result
+fn extensionfilter_should_filter_when_url_ends_with_extension() {
+    let filter = ExtensionFilter {
+        extension: "png".to_string(),
+    };
+
+    let mut resp = FeroxResponse::default();
+
+    resp.set_url("http://localhost/images/logo.png");
+    assert!(filter.should_filter_response(&resp));
+
+    resp.set_url("http://localhost/images/LOGO.PNG?size=large");
+    assert!(filter.should_filter_response(&resp));
+
+    resp.set_url("http://localhost/images.png/logo");
+    assert!(!filter.should_filter_response(&resp));
+
+    resp.set_url("http://localhost/images/logo.pngx");
+    assert!(!filter.should_filter_response(&resp));
+
+    resp.set_url("http://localhost/png");
+    assert!(!filter.should_filter_response(&resp));
+}
+
+#[test]
+/// test should_filter on SizeRangeFilter; --filter-body-size-range filters responses inside
+/// of any range, --keep-body-size-range filters responses outside of every range
+fn size_range_filter_should_filter_based_on_ranges() {
+    let ranges = vec![
+        "10-20".parse::<SizeRange>().unwrap(),
+        "100-unlimited".parse::<SizeRange>().unwrap(),
+    ];
+
+    let filter = SizeRangeFilter {
+        ranges: ranges.clone(),
+        keep: false,
+    };
+    let keep_filter = SizeRangeFilter { ranges, keep: true };
+
+    let mut resp = FeroxResponse::default();
+
+    for (size, in_range) in [
+        (9, false),
+        (10, true),
+        (20, true),
+        (21, false),
+        (5000, true),
+    ] {
+        resp.set_text(&"a".repeat(size));
+        assert_eq!(filter.should_filter_response(&resp), in_range);
+        assert_eq!(keep_filter.should_filter_response(&resp), !in_range);
+    }
+}
+
+#[test]
+/// test should_filter on WordsRangeFilter and LinesRangeFilter; both ends of the range are
+/// inclusive
+fn count_range_filters_should_filter_inclusive_ranges() {
+    let words_filter = WordsRangeFilter { word_range: (2, 3) };
+    let lines_filter = LinesRangeFilter { line_range: (2, 3) };
+
+    let mut resp = FeroxResponse::default();
+
+    for (count, in_range) in [(1, false), (2, true), (3, true), (4, false)] {
+        resp.set_text(&vec!["word"; count].join(" "));
+        assert_eq!(words_filter.should_filter_response(&resp), in_range);
+
+        resp.set_text(&vec!["line"; count].join("\n"));
+        assert_eq!(lines_filter.should_filter_response(&resp), in_range);
+    }
+}
+
+#[test]
+/// test should_filter on ResponseTimeFilter; responses past the threshold in the given
+/// direction are filtered, and responses without a recorded time never are
+fn response_time_filter_should_filter_based_on_threshold() {
+    let above = ResponseTimeFilter {
+        threshold_ms: 100,
+        above: true,
+    };
+    let below = ResponseTimeFilter {
+        threshold_ms: 100,
+        above: false,
+    };
+
+    let mut resp = FeroxResponse::default();
+
+    for (elapsed, filter_above, filter_below) in [
+        (0, false, false),
+        (50, false, true),
+        (100, false, false),
+        (150, true, false),
+    ] {
+        resp.set_response_time_ms(elapsed);
+        assert_eq!(above.should_filter_response(&resp), filter_above);
+        assert_eq!(below.should_filter_response(&resp), filter_below);
+    }
+}
+
+#[test]
+/// test should_filter on HeaderCountFilter; responses with more (or fewer) headers than the
+/// threshold are filtered, depending on the filter's direction
+fn header_count_filter_should_filter_based_on_threshold() {
+    let above = HeaderCountFilter {
+        threshold: 2,
+        above: true,
+    };
+    let below = HeaderCountFilter {
+        threshold: 2,
+        above: false,
+    };
+
+    for (num_headers, filter_above, filter_below) in [
+        (0, false, true),
+        (1, false, true),
+        (2, false, false),
+        (3, true, false),
+    ] {
+        let headers: serde_json::Map<_, _> = (0..num_headers)
+            .map(|i| (format!("x-header-{}", i), serde_json::Value::from("value")))
+            .collect();
+        let resp: FeroxResponse =
+            serde_json::from_value(serde_json::json!({ "headers": headers })).unwrap();
+
+        assert_eq!(resp.header_count(), num_headers);
+        assert_eq!(above.should_filter_response(&resp), filter_above);
+        assert_eq!(below.should_filter_response(&resp), filter_below);
+    }
+}
+
+#[test]
+/// test should_filter on SizeRatioFilter; only responses beneath the filter's url whose size is
+/// within the ratio of the baseline are filtered
+fn size_ratio_filter_should_filter_sizes_near_baseline() {
+    let filter = SizeRatioFilter {
+        url: "http://localhost/".to_string(),
+        baseline: 1000,
+        ratio: 0.95,
+    };
+
+    let mut resp = FeroxResponse::default();
+    resp.set_url("http://localhost/stuff");
+
+    for (size, filtered) in [
+        (949, false),
+        (950, true),
+        (1000, true),
+        (1050, true),
+        (1100, false),
+    ] {
+        resp.set_text(&"a".repeat(size));
+        assert_eq!(filter.should_filter_response(&resp), filtered);
+    }
+
+    resp.set_text(&"a".repeat(1000));
+    resp.set_url("http://otherhost/stuff");
+    assert!(!filter.should_filter_response(&resp));
+}
+
+#[test]
+/// SizeRange should parse MIN-MAX and MIN-unlimited, and reject anything else
+fn size_range_parses_min_and_max() {
+    let range = "10-20".parse::<SizeRange>().unwrap();
+    assert_eq!(range, SizeRange { min: 10, max: 20 });
+    assert_eq!(range.to_string(), "10-20");
+
+    let range = "10-Unlimited".parse::<SizeRange>().unwrap();
+    assert_eq!(range.max, u64::MAX);
+    assert_eq!(range.to_string(), "10-unlimited");
+
+    assert!("10".parse::<SizeRange>().is_err());
+    assert!("20-10".parse::<SizeRange>().is_err());
+    assert!("a-10".parse::<SizeRange>().is_err());
+    assert!("unlimited-10".parse::<SizeRange>().is_err());
+}
+
+#[test]
+/// JsonPathExpression should parse paths with optional comparisons, and reject anything else
+fn json_path_expression_parses_paths_and_comparisons() {
+    for expression in [
+        "$",
+        "$.status",
+        "$.data[0]['user-name']",
+        r#"$["status"] == "ok""#,
+        "$.count>=10",
+        "$.status == 'ok'",
+        "$.admin != null",
+    ] {
+        let parsed = expression.parse::<JsonPathExpression>().unwrap();
+        assert_eq!(parsed.to_string(), expression);
+    }
+
+    for expression in [
+        "status",
+        "$.",
+        "$.data[0",
+        "$.data[abc]",
+        "$.status = \"ok\"",
+        "$.status == ok",
+    ] {
+        assert!(expression.parse::<JsonPathExpression>().is_err());
+    }
+}
+
+#[test]
+/// JsonPathExpression should navigate objects and arrays, comparing the value found against
+/// the given literal
+fn json_path_expression_matches_json_values() {
+    let body: serde_json::Value = serde_json::from_str(
+        r#"{"status":"ok","count":10,"admin":false,"data":[{"id":1.0,"name":"epi"}]}"#,
+    )
+    .unwrap();
+
+    for (expression, expected) in [
+        ("$.status", true),
+        ("$.admin", false),
+        ("$.missing", false),
+        ("$.data[0].name", true),
+        ("$.data[1].name", false),
+        (r#"$.status == "ok""#, true),
+        ("$.status == 'error'", false),
+        ("$.status != 'error'", true),
+        ("$.data[0].id == 1", true),
+        ("$.count > 9", true),
+        ("$.count <= 9", false),
+        ("$.status > 10", false),
+        ("$.missing == null", true),
+    ] {
+        let parsed = expression.parse::<JsonPathExpression>().unwrap();
+        assert_eq!(parsed.matches(&body), expected, "{}", expression);
+    }
+}
+
+#[test]
+/// test should_filter on JsonPathFilter; bodies that aren't json are never filtered
+fn json_path_filter_should_filter_based_on_expressions() {
+    let expressions = vec![r#"$.status == "error""#.parse::<JsonPathExpression>().unwrap()];
+
+    let filter = JsonPathFilter {
+        expressions: expressions.clone(),
+        keep: false,
+    };
+    let keep_filter = JsonPathFilter {
+        expressions,
+        keep: true,
+    };
+
+    let mut resp = FeroxResponse::default();
+
+    resp.set_text(r#"{"status":"error"}"#);
+    assert!(filter.should_filter_response(&resp));
+    assert!(!keep_filter.should_filter_response(&resp));
+
+    resp.set_text(r#"{"status":"ok"}"#);
+    assert!(!filter.should_filter_response(&resp));
+    assert!(keep_filter.should_filter_response(&resp));
+
+    resp.set_text("<html>not json</html>");
+    assert!(!filter.should_filter_response(&resp));
+    assert!(!keep_filter.should_filter_response(&resp));
+}
+
 #[test]
 /// a few simple tests for similarity filter
 fn similarity_filter_is_accurate() {
@@ -254,7 +518,7 @@ fn remove_function_works_as_expected() {
 
     assert_eq!(data.filters.read().unwrap().len(), 5);
 
-    let expected = vec![
+    let expected = [
         WordsFilter { word_count: 1 },
         WordsFilter { word_count: 3 },
         WordsFilter { word_count: 5 },
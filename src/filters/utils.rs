@@ -4,7 +4,7 @@ use crate::event_handlers::Handles;
 use crate::response::FeroxResponse;
 use crate::utils::logged_request;
 use crate::{DEFAULT_METHOD, SIMILARITY_THRESHOLD};
-use anyhow::Result;
+use anyhow::{bail, Result};
 use fuzzyhash::FuzzyHash;
 use regex::Regex;
 use reqwest::Url;
@@ -33,6 +33,7 @@ pub(crate) async fn create_similarity_filter(
         similarity_filter,
         DEFAULT_METHOD,
         handles.config.output_level,
+        handles.config.max_response_size,
     )
     .await;
 
@@ -41,7 +42,7 @@ pub(crate) async fn create_similarity_filter(
     }
 
     // hash the response body and store the resulting hash in the filter object
-    let hash = FuzzyHash::new(&fr.text()).to_string();
+    let hash = FuzzyHash::new(fr.text()).to_string();
 
     Ok(SimilarityFilter {
         hash,
@@ -50,6 +51,20 @@ pub(crate) async fn create_similarity_filter(
     })
 }
 
+/// parse an inclusive `MIN-MAX` range of word/line counts, e.g. `100-500` becomes `(100, 500)`
+pub(crate) fn parse_count_range(range: &str) -> Result<(usize, usize)> {
+    let (min, max) = match range.split_once('-') {
+        Some((min, max)) => (min.trim().parse::<usize>()?, max.trim().parse::<usize>()?),
+        None => bail!("Range {} should be formatted as MIN-MAX", range),
+    };
+
+    if min > max {
+        bail!("Range {} has a MIN that's larger than its MAX", range);
+    }
+
+    Ok((min, max))
+}
+
 /// used in conjunction with the Scan Management Menu
 ///
 /// when a user uses the n[ew-filter] command in the menu, the two params are passed here for
@@ -80,11 +95,29 @@ pub(crate) fn filter_lookup(filter_type: &str, filter_value: &str) -> Option<Box
                 }));
             }
         }
+        "size-range" => {
+            if let Ok(parsed) = filter_value.parse() {
+                return Some(Box::new(super::SizeRangeFilter {
+                    ranges: vec![parsed],
+                    keep: false,
+                }));
+            }
+        }
         "words" => {
             if let Ok(parsed) = filter_value.parse() {
                 return Some(Box::new(super::WordsFilter { word_count: parsed }));
             }
         }
+        "words-range" => {
+            if let Ok(parsed) = parse_count_range(filter_value) {
+                return Some(Box::new(super::WordsRangeFilter { word_range: parsed }));
+            }
+        }
+        "lines-range" => {
+            if let Ok(parsed) = parse_count_range(filter_value) {
+                return Some(Box::new(super::LinesRangeFilter { line_range: parsed }));
+            }
+        }
         "regex" => {
             if let Ok(parsed) = Regex::new(filter_value) {
                 return Some(Box::new(super::RegexFilter {
@@ -93,6 +126,15 @@ pub(crate) fn filter_lookup(filter_type: &str, filter_value: &str) -> Option<Box
                 }));
             }
         }
+        "extension" => {
+            let extension = filter_value.trim_start_matches('.');
+
+            if !extension.is_empty() {
+                return Some(Box::new(super::ExtensionFilter {
+                    extension: extension.to_string(),
+                }));
+            }
+        }
         "similarity" => {
             return Some(Box::new(SimilarityFilter {
                 hash: String::new(),
@@ -110,7 +152,10 @@ pub(crate) fn filter_lookup(filter_type: &str, filter_value: &str) -> Option<Box
 mod tests {
     use super::*;
     use crate::config::Configuration;
-    use crate::filters::{LinesFilter, RegexFilter, SizeFilter, StatusCodeFilter, WordsFilter};
+    use crate::filters::{
+        ExtensionFilter, LinesFilter, LinesRangeFilter, RegexFilter, SizeFilter, SizeRange,
+        SizeRangeFilter, StatusCodeFilter, WordsFilter, WordsRangeFilter,
+    };
     use crate::scan_manager::FeroxScans;
     use httpmock::Method::GET;
     use httpmock::MockServer;
@@ -136,12 +181,40 @@ mod tests {
             &SizeFilter { content_length: 20 }
         );
 
+        let filter = filter_lookup("size-range", "20-unlimited").unwrap();
+        assert_eq!(
+            filter.as_any().downcast_ref::<SizeRangeFilter>().unwrap(),
+            &SizeRangeFilter {
+                ranges: vec![SizeRange {
+                    min: 20,
+                    max: u64::MAX
+                }],
+                keep: false
+            }
+        );
+
         let filter = filter_lookup("words", "30").unwrap();
         assert_eq!(
             filter.as_any().downcast_ref::<WordsFilter>().unwrap(),
             &WordsFilter { word_count: 30 }
         );
 
+        let filter = filter_lookup("words-range", "30-40").unwrap();
+        assert_eq!(
+            filter.as_any().downcast_ref::<WordsRangeFilter>().unwrap(),
+            &WordsRangeFilter {
+                word_range: (30, 40)
+            }
+        );
+
+        let filter = filter_lookup("lines-range", "10-10").unwrap();
+        assert_eq!(
+            filter.as_any().downcast_ref::<LinesRangeFilter>().unwrap(),
+            &LinesRangeFilter {
+                line_range: (10, 10)
+            }
+        );
+
         let filter = filter_lookup("regex", "stuff.*").unwrap();
         let compiled = Regex::new("stuff.*").unwrap();
         let raw_string = String::from("stuff.*");
@@ -153,6 +226,14 @@ mod tests {
             }
         );
 
+        let filter = filter_lookup("extension", ".png").unwrap();
+        assert_eq!(
+            filter.as_any().downcast_ref::<ExtensionFilter>().unwrap(),
+            &ExtensionFilter {
+                extension: "png".to_string()
+            }
+        );
+
         let filter = filter_lookup("similarity", "http://localhost").unwrap();
         assert_eq!(
             filter.as_any().downcast_ref::<SimilarityFilter>().unwrap(),
@@ -164,6 +245,19 @@ mod tests {
         );
 
         assert!(filter_lookup("non-existent", "").is_none());
+        assert!(filter_lookup("words-range", "40-30").is_none());
+    }
+
+    #[test]
+    /// parse_count_range should parse MIN-MAX into a tuple and reject anything else
+    fn parse_count_range_parses_min_and_max() {
+        assert_eq!(parse_count_range("100-500").unwrap(), (100, 500));
+        assert_eq!(parse_count_range(" 7 - 7 ").unwrap(), (7, 7));
+
+        assert!(parse_count_range("100").is_err());
+        assert!(parse_count_range("500-100").is_err());
+        assert!(parse_count_range("a-100").is_err());
+        assert!(parse_count_range("100-unlimited").is_err());
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
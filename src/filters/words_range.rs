@@ -0,0 +1,35 @@
+use super::*;
+
+/// Simple implementor of FeroxFilter; used to filter out responses based on whether the number
+/// of words in a Response body falls within an inclusive range; specified using
+/// --filter-words-range
+#[derive(Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WordsRangeFilter {
+    /// Smallest and largest number of words in a Response's body that should be filtered
+    pub word_range: (usize, usize),
+}
+
+/// implementation of FeroxFilter for WordsRangeFilter
+impl FeroxFilter for WordsRangeFilter {
+    /// Check `word_count` against the range passed in via --filter-words-range
+    fn should_filter_response(&self, response: &FeroxResponse) -> bool {
+        log::trace!("enter: should_filter_response({:?} {})", self, response);
+
+        let (min, max) = self.word_range;
+        let result = (min..=max).contains(&response.word_count());
+
+        log::trace!("exit: should_filter_response -> {}", result);
+
+        result
+    }
+
+    /// Compare one WordsRangeFilter to another
+    fn box_eq(&self, other: &dyn Any) -> bool {
+        other.downcast_ref::<Self>().is_some_and(|a| self == a)
+    }
+
+    /// Return self as Any for dynamic dispatch purposes
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
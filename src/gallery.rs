@@ -0,0 +1,253 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use anyhow::{Context, Result};
+
+use crate::response::FeroxResponse;
+
+/// name of the page that links to every other page in the gallery
+const INDEX_FILENAME: &str = "index.html";
+
+/// escape the characters that have special meaning in html text and attribute values
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// render a standalone html page showing the response's discovery metadata, a sandboxed
+/// rendering of its body, and the body's source
+///
+/// the body is only ever included escaped, and the iframe's empty `sandbox` attribute keeps
+/// scripts, forms, and plugins in the rendered copy from running
+pub fn render_response_html(response: &FeroxResponse) -> String {
+    let url = escape_html(response.url().as_str());
+    let body = escape_html(response.text());
+
+    format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>{url}</title>
+</head>
+<body>
+<p><a href="{index}">&larr; back to index</a></p>
+<h1><a href="{url}">{url}</a></h1>
+<table>
+<tr><th>Method</th><td>{method}</td></tr>
+<tr><th>Status</th><td>{status}</td></tr>
+<tr><th>Lines</th><td>{lines}</td></tr>
+<tr><th>Words</th><td>{words}</td></tr>
+<tr><th>Chars</th><td>{chars}</td></tr>
+</table>
+<iframe sandbox="" srcdoc="{body}" width="100%" height="600"></iframe>
+<details>
+<summary>Source</summary>
+<pre>{body}</pre>
+</details>
+</body>
+</html>
+"#,
+        url = url,
+        index = INDEX_FILENAME,
+        method = escape_html(response.method().as_str()),
+        status = response.status().as_u16(),
+        lines = response.line_count(),
+        words = response.word_count(),
+        chars = response.content_length(),
+        body = body,
+    )
+}
+
+/// render the gallery's index page, linking to each (page filename, url) pair in order
+fn render_index_html(pages: &[(String, String)]) -> String {
+    let links: String = pages
+        .iter()
+        .map(|(filename, url)| {
+            format!(
+                "<li><a href=\"{}\">{}</a></li>\n",
+                escape_html(filename),
+                escape_html(url)
+            )
+        })
+        .collect();
+
+    format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>feroxbuster gallery</title>
+</head>
+<body>
+<h1>feroxbuster gallery ({} pages)</h1>
+<ol>
+{}</ol>
+</body>
+</html>
+"#,
+        pages.len(),
+        links
+    )
+}
+
+/// gallery of discovered html pages written to --html-gallery-dir by --store-responses-as-html
+#[derive(Debug)]
+pub struct Gallery {
+    /// directory the gallery is written to
+    directory: PathBuf,
+
+    /// (page filename, url) of each page written so far
+    pages: Mutex<Vec<(String, String)>>,
+}
+
+/// implementation of Gallery
+impl Gallery {
+    /// create the gallery's directory, if necessary, and an empty gallery that writes to it
+    pub fn new(directory: &str) -> Result<Self> {
+        fs::create_dir_all(directory)
+            .with_context(|| format!("Could not create gallery directory {}", directory))?;
+
+        let gallery = Self {
+            directory: PathBuf::from(directory),
+            pages: Mutex::new(Vec::new()),
+        };
+
+        gallery.write_index(&[])?;
+
+        Ok(gallery)
+    }
+
+    /// getter for the gallery's directory
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// write a page for the response if it's a 200 with an html body and refresh the index;
+    /// returns whether a page was written
+    ///
+    /// the index is rewritten every time so that it's complete even if the scan is cut short
+    pub fn add(&self, response: &FeroxResponse) -> Result<bool> {
+        if !response.is_html_page() {
+            return Ok(false);
+        }
+
+        let mut pages = match self.pages.lock() {
+            Ok(pages) => pages,
+            Err(_) => return Ok(false),
+        };
+
+        let filename = format!("page-{:05}.html", pages.len() + 1);
+
+        fs::write(
+            self.directory.join(&filename),
+            render_response_html(response),
+        )?;
+
+        pages.push((filename, response.url().to_string()));
+
+        self.write_index(&pages)?;
+
+        Ok(true)
+    }
+
+    /// write the index page for the given pages
+    fn write_index(&self, pages: &[(String, String)]) -> Result<()> {
+        fs::write(
+            self.directory.join(INDEX_FILENAME),
+            render_index_html(pages),
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    /// special html characters should be escaped
+    fn escape_html_escapes_special_characters() {
+        assert_eq!(
+            escape_html(r#"<a href="x">Tom & Jerry's</a>"#),
+            "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&#39;s&lt;/a&gt;"
+        );
+    }
+
+    #[test]
+    /// the rendered page should include the metadata and only ever include the body escaped
+    fn render_response_html_escapes_body_and_includes_metadata() {
+        let mut response = FeroxResponse::default();
+        response.set_url("http://localhost/admin");
+        response.set_text("<script>alert(1)</script>\nsecond line");
+
+        let page = render_response_html(&response);
+
+        assert!(page.contains("<h1><a href=\"http://localhost/admin\">"));
+        assert!(page.contains("<tr><th>Status</th><td>200</td></tr>"));
+        assert!(page.contains("<tr><th>Lines</th><td>2</td></tr>"));
+        assert!(page.contains("&lt;script&gt;alert(1)&lt;/script&gt;"));
+        assert!(page.contains("<iframe sandbox=\"\""));
+        assert!(!page.contains("<script>"));
+    }
+
+    #[test]
+    /// responses without an html content-type shouldn't be added to the gallery
+    fn gallery_only_adds_html_pages() {
+        let tmp_dir = TempDir::new().unwrap();
+        let directory = tmp_dir.path().join("gallery");
+        let gallery = Gallery::new(directory.to_str().unwrap()).unwrap();
+
+        // no content-type header
+        let response = FeroxResponse::default();
+        assert!(!gallery.add(&response).unwrap());
+
+        let index = fs::read_to_string(directory.join(INDEX_FILENAME)).unwrap();
+        assert!(index.contains("(0 pages)"));
+        assert_eq!(fs::read_dir(&directory).unwrap().count(), 1);
+    }
+
+    #[test]
+    /// the index should link to each page in the order they were added
+    fn render_index_html_links_pages_in_order() {
+        let pages = vec![
+            (
+                "page-00001.html".to_string(),
+                "http://localhost/".to_string(),
+            ),
+            (
+                "page-00002.html".to_string(),
+                "http://localhost/?a=1&b=2".to_string(),
+            ),
+        ];
+
+        let index = render_index_html(&pages);
+
+        let first = index
+            .find("<li><a href=\"page-00001.html\">http://localhost/</a></li>")
+            .unwrap();
+        let second = index
+            .find("<li><a href=\"page-00002.html\">http://localhost/?a=1&amp;b=2</a></li>")
+            .unwrap();
+
+        assert!(first < second);
+        assert!(index.contains("(2 pages)"));
+    }
+}
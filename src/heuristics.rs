@@ -9,7 +9,7 @@ use crate::message::FeroxMessage;
 use crate::{
     config::OutputLevel,
     event_handlers::{Command, Handles},
-    filters::WildcardFilter,
+    filters::{SizeRatioFilter, WildcardFilter},
     progress::PROGRESS_PRINTER,
     response::FeroxResponse,
     skip_fail,
@@ -140,6 +140,19 @@ impl HeuristicTests {
 
             let wc_length = ferox_response.content_length();
 
+            if self.handles.config.filter_min_ratio > 0.0 && wc_length > 0 {
+                // --filter-min-ratio; the wildcard response is this target's baseline
+                let ratio_filter = SizeRatioFilter {
+                    url: target_url.to_string(),
+                    baseline: wc_length,
+                    ratio: self.handles.config.filter_min_ratio,
+                };
+
+                self.handles
+                    .filters
+                    .send(Command::AddFilter(Box::new(ratio_filter)))?;
+            }
+
             if wc_length == 0 {
                 log::trace!("exit: wildcard_test -> 1");
                 self.send_filter(wildcard)?;
@@ -213,7 +226,12 @@ impl HeuristicTests {
             None
         };
 
-        let nonexistent_url = target.format(&unique_str, slash)?;
+        let nonexistent_url = if self.handles.config.prefix_wordlist.is_empty() {
+            target.format(&unique_str, slash)?
+        } else {
+            // wildcards need to be checked the same way that --prefix-wordlist builds its urls
+            target.format_prefix(&unique_str)?
+        };
 
         let response = logged_request(
             &nonexistent_url.to_owned(),
@@ -236,6 +254,7 @@ impl HeuristicTests {
                 &target.target,
                 method,
                 self.handles.config.output_level,
+                self.handles.config.max_response_size,
             )
             .await;
             ferox_response.set_wildcard(true);
@@ -340,6 +359,7 @@ impl HeuristicTests {
             &url.target,
             DEFAULT_METHOD,
             self.handles.config.output_level,
+            self.handles.config.max_response_size,
         )
         .await;
 
@@ -348,14 +368,13 @@ impl HeuristicTests {
 
         let dirlist_type = self.detect_directory_listing(&html);
 
-        if dirlist_type.is_some() {
+        if let Some(listing_type) = &dirlist_type {
             // folks that run things and step away/rely on logs need to be notified of directory
             // listing, since they won't see the message on the bar; bastardizing FeroxMessage
             // for ease of implementation. This could use a bit of polish at some point.
             let msg = format!(
                 "detected directory listing: {} ({:?})",
-                target_url,
-                dirlist_type.unwrap()
+                target_url, listing_type
             );
             let ferox_msg = FeroxMessage {
                 kind: "log".to_string(),
@@ -0,0 +1,175 @@
+use std::{
+    fs,
+    path::PathBuf,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use anyhow::{Context, Result};
+use reqwest::Url;
+use scraper::{Html, Selector};
+
+use crate::response::FeroxResponse;
+
+/// longest url-derived portion of a script's filename
+const MAX_NAME_LENGTH: usize = 100;
+
+/// whether a <script> tag's `type` attribute marks its contents as javascript; a missing or empty
+/// type means javascript, while types like application/ld+json or text/template don't
+fn is_javascript_type(script_type: Option<&str>) -> bool {
+    match script_type.map(|value| value.trim().to_lowercase()) {
+        None => true,
+        Some(value) => {
+            value.is_empty()
+                || value == "module"
+                || value.contains("javascript")
+                || value.contains("ecmascript")
+        }
+    }
+}
+
+/// contents of each <script> tag in the given html that doesn't have a `src` attribute and holds
+/// javascript, in the order they appear; blocks containing only whitespace are skipped
+pub fn extract_inline_scripts(html: &str) -> Vec<String> {
+    let html = Html::parse_document(html);
+    let selector = Selector::parse("script").unwrap();
+
+    html.select(&selector)
+        .filter(|script| {
+            script.value().attr("src").is_none() && is_javascript_type(script.value().attr("type"))
+        })
+        .map(|script| script.text().collect::<String>())
+        .filter(|script| !script.trim().is_empty())
+        .collect()
+}
+
+/// filename-friendly version of the url's host, port, and path
+///
+/// example: http://localhost:8080/admin/login.php -> localhost_8080_admin_login.php
+fn url_to_filename(url: &Url) -> String {
+    let raw = match url.port() {
+        Some(port) => format!(
+            "{}_{}{}",
+            url.host_str().unwrap_or_default(),
+            port,
+            url.path()
+        ),
+        None => format!("{}{}", url.host_str().unwrap_or_default(), url.path()),
+    };
+
+    let mut name = String::with_capacity(raw.len());
+
+    for c in raw.chars() {
+        let c = if c.is_ascii_alphanumeric() || c == '.' || c == '-' {
+            c
+        } else {
+            '_'
+        };
+
+        if !(c == '_' && name.ends_with('_')) {
+            name.push(c);
+        }
+    }
+
+    let name = name.trim_matches('_');
+
+    // only ascii characters are left, so slicing on a byte index is safe
+    name[..name.len().min(MAX_NAME_LENGTH)].to_string()
+}
+
+/// writes the inline scripts of discovered html pages to --inline-js-dir for --collect-inline-js
+#[derive(Debug)]
+pub struct InlineJsCollector {
+    /// directory the scripts are written to
+    directory: PathBuf,
+
+    /// number of scripts written so far, used to number each script's file
+    written: AtomicUsize,
+}
+
+/// implementation of InlineJsCollector
+impl InlineJsCollector {
+    /// create the collector's directory, if necessary, and a collector that writes to it
+    pub fn new(directory: &str) -> Result<Self> {
+        fs::create_dir_all(directory)
+            .with_context(|| format!("Could not create inline js directory {}", directory))?;
+
+        Ok(Self {
+            directory: PathBuf::from(directory),
+            written: AtomicUsize::new(0),
+        })
+    }
+
+    /// write each inline script of a 200 html response to its own numbered file, named after
+    /// the response's url; returns the number of scripts written
+    pub fn add(&self, response: &FeroxResponse) -> Result<usize> {
+        if !response.is_html_page() {
+            return Ok(0);
+        }
+
+        let scripts = extract_inline_scripts(response.text());
+        let name = url_to_filename(response.url());
+
+        for script in &scripts {
+            let number = self.written.fetch_add(1, Ordering::Relaxed) + 1;
+            let filename = format!("{:05}-{}.js", number, name);
+
+            fs::write(self.directory.join(filename), script)?;
+        }
+
+        Ok(scripts.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    /// only non-empty javascript blocks without a src attribute should be extracted
+    fn extract_inline_scripts_skips_external_and_non_javascript_blocks() {
+        let html = r#"<html><head>
+            <script src="/static/app.js"></script>
+            <script>var api = "/api/v1/users";</script>
+            <script type="application/ld+json">{"@type": "Organization"}</script>
+            <script type="module">import x from "/static/x.js";</script>
+            <script>   </script>
+            </head></html>"#;
+
+        assert_eq!(
+            extract_inline_scripts(html),
+            [
+                r#"var api = "/api/v1/users";"#,
+                r#"import x from "/static/x.js";"#
+            ]
+        );
+    }
+
+    #[test]
+    /// host, port, and path should be kept, with everything else collapsed into underscores
+    fn url_to_filename_keeps_host_port_and_path() {
+        let url = Url::parse("http://localhost:8080/admin//login.php?next=/").unwrap();
+        assert_eq!(url_to_filename(&url), "localhost_8080_admin_login.php");
+
+        let url = Url::parse("https://example.com/").unwrap();
+        assert_eq!(url_to_filename(&url), "example.com");
+
+        let url = Url::parse(&format!("https://example.com/{}", "a".repeat(200))).unwrap();
+        assert_eq!(url_to_filename(&url).len(), MAX_NAME_LENGTH);
+    }
+
+    #[test]
+    /// responses that aren't 200 html pages shouldn't have their scripts written
+    fn collector_only_reads_html_pages() {
+        let tmp_dir = TempDir::new().unwrap();
+        let directory = tmp_dir.path().join("inline-js");
+        let collector = InlineJsCollector::new(directory.to_str().unwrap()).unwrap();
+
+        // no content-type header
+        let mut response = FeroxResponse::default();
+        response.set_text("<script>alert(1)</script>");
+
+        assert_eq!(collector.add(&response).unwrap(), 0);
+        assert_eq!(fs::read_dir(&directory).unwrap().count(), 0);
+    }
+}
@@ -13,15 +13,24 @@ use crate::event_handlers::Command;
 pub mod banner;
 pub mod config;
 mod client;
+pub mod cors;
+mod dns;
 pub mod event_handlers;
 pub mod filters;
+pub mod gallery;
 pub mod heuristics;
+pub mod inline_js;
 pub mod logger;
+pub mod notify;
+pub mod passive;
 mod parser;
+pub mod post_scan;
 pub mod progress;
 pub mod scan_manager;
 pub mod scanner;
+pub mod simulate;
 pub mod statistics;
+pub mod test_server;
 mod traits;
 pub mod utils;
 mod extractor;
@@ -79,6 +88,10 @@ pub const DEFAULT_WORDLIST: &str =
 pub const SECONDARY_WORDLIST: &str =
     "/usr/local/share/seclists/Discovery/Web-Content/raft-medium-directories.txt";
 
+/// High-value paths (`.env`, `web.config`, etc) requested in addition to the wordlist when
+/// --collect-interesting-paths is used; see `utils::interesting_paths`
+pub const INTERESTING_PATHS: &str = include_str!("../assets/interesting_paths.txt");
+
 /// Number of milliseconds to wait between polls of `PAUSE_SCAN` when user pauses a scan
 pub(crate) const SLEEP_DURATION: u64 = 500;
 
@@ -110,9 +123,22 @@ pub const DEFAULT_STATUS_CODES: [StatusCode; 10] = [
     StatusCode::INTERNAL_SERVER_ERROR,
 ];
 
+/// Default set of status codes that allow a directory to be recursed into
+pub const DEFAULT_RECURSE_ON_STATUS: [StatusCode; 3] = [
+    StatusCode::OK,
+    StatusCode::MOVED_PERMANENTLY,
+    StatusCode::FOUND,
+];
+
 /// Default method for requests
 pub(crate) const DEFAULT_METHOD: &str = "GET";
 
+/// Default number of times an unfinished scan may be resumed before it's marked as failed
+pub(crate) const DEFAULT_MAX_RETRIES: u32 = 3;
+
+/// Header carrying a response's `request_id` on requests sent through --replay-proxy
+pub(crate) const REQUEST_ID_HEADER: &str = "X-Ferox-Request-ID";
+
 /// Default filename for config file settings
 ///
 /// Expected location is in the same directory as the feroxbuster binary.
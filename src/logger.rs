@@ -5,12 +5,13 @@ use std::sync::{Arc, RwLock};
 use std::time::Instant;
 
 use anyhow::{Context, Result};
+use console::user_attended;
 use env_logger::Builder;
 
 use crate::{
     config::Configuration,
     message::FeroxMessage,
-    progress::PROGRESS_PRINTER,
+    progress::{live_output, PROGRESS_PRINTER},
     traits::FeroxSerialize,
     utils::{fmt_err, write_to},
 };
@@ -65,7 +66,12 @@ pub fn initialize(config: Arc<Configuration>) -> Result<()> {
                 kind: "log".to_string(),
             };
 
-            PROGRESS_PRINTER.println(&log_entry.as_str());
+            if live_output() && user_attended() {
+                // --live-output hides the progress bars, which PROGRESS_PRINTER relies on
+                println!("{}", log_entry.as_str());
+            } else {
+                PROGRESS_PRINTER.println(log_entry.as_str());
+            }
 
             if let Some(buffered_file) = file.clone() {
                 if let Ok(mut unlocked) = buffered_file.write() {
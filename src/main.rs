@@ -1,15 +1,18 @@
 use std::io::stdin;
 use std::{
+    collections::HashSet,
     env::args,
-    fs::{create_dir, remove_file, File},
-    io::{stderr, BufRead, BufReader},
+    fs::{self, create_dir, remove_file},
+    io::{stderr, BufRead},
     ops::Index,
     path::Path,
     process::Command,
     sync::{atomic::Ordering, Arc},
+    time::Instant,
 };
 
 use anyhow::{bail, Context, Result};
+use encoding_rs::Encoding;
 use futures::StreamExt;
 use tokio::{
     io,
@@ -27,11 +30,12 @@ use feroxbuster::{
         FiltersHandler, Handles, ScanHandler, StatsHandler, Tasks, TermInputHandler,
         TermOutHandler, SCAN_COMPLETE,
     },
-    filters, heuristics, logger,
-    progress::{PROGRESS_BAR, PROGRESS_PRINTER},
+    filters, heuristics, logger, notify, passive, post_scan,
+    progress::{enable_live_output, PROGRESS_BAR, PROGRESS_PRINTER},
     scan_manager::{self, ScanType},
-    scanner,
-    utils::{fmt_err, slugify_filename},
+    scanner, simulate,
+    test_server::{self, TestServerRules},
+    utils::{decode_wordlist, fmt_err, interesting_paths, slugify_filename},
     SECONDARY_WORDLIST,
 };
 #[cfg(not(target_os = "windows"))]
@@ -44,27 +48,41 @@ lazy_static! {
     static ref PARALLEL_LIMITER: Semaphore = Semaphore::new(0);
 }
 
-/// Create a HashSet of Strings from the given wordlist then stores it inside an Arc
-fn get_unique_words_from_wordlist(path: &str) -> Result<Arc<Vec<String>>> {
-    log::trace!("enter: get_unique_words_from_wordlist({})", path);
-
-    let file = File::open(&path).with_context(|| format!("Could not open {}", path))?;
+/// Create a Vec of Strings from the given wordlist then stores it inside an Arc
+///
+/// duplicate entries are dropped (keeping the first occurrence) unless `dedup` is `false`
+fn get_unique_words_from_wordlist(
+    path: &str,
+    dedup: bool,
+    encoding: &'static Encoding,
+) -> Result<Arc<Vec<String>>> {
+    log::trace!(
+        "enter: get_unique_words_from_wordlist({}, {}, {})",
+        path,
+        dedup,
+        encoding.name()
+    );
 
-    let reader = BufReader::new(file);
+    let file = fs::File::open(path).with_context(|| format!("Could not open {}", path))?;
 
     // this empty string ensures that we call Requester::request with the base url, i.e.
     // `http://localhost/` instead of going straight into `http://localhost/WORD.EXT`.
     // for vanilla scans, it doesn't matter all that much, but it can be a significant difference
     // when `-e` is used, depending on the content at the base url.
     let mut words = vec![String::from("")];
+    let mut seen = HashSet::new();
 
-    for line in reader.lines() {
-        line.map(|result| {
-            if !result.starts_with('#') && !result.is_empty() {
-                words.push(result);
-            }
-        })
-        .ok();
+    let lines =
+        decode_wordlist(file, encoding).with_context(|| format!("Could not read {}", path))?;
+
+    for line in lines {
+        if line.starts_with('#') || line.is_empty() {
+            continue;
+        }
+
+        if !dedup || seen.insert(line.clone()) {
+            words.push(line);
+        }
     }
 
     log::trace!(
@@ -75,6 +93,108 @@ fn get_unique_words_from_wordlist(path: &str) -> Result<Arc<Vec<String>>> {
     Ok(Arc::new(words))
 }
 
+/// Append the built-in --collect-interesting-paths list to the given words, skipping any paths
+/// the wordlist already contains
+fn add_interesting_paths(words: Arc<Vec<String>>) -> Arc<Vec<String>> {
+    log::trace!(
+        "enter: add_interesting_paths(wordlist[{} words...])",
+        words.len()
+    );
+
+    let mut words = Arc::try_unwrap(words).unwrap_or_else(|words| (*words).clone());
+    let known: HashSet<_> = words.iter().cloned().collect();
+
+    words.extend(
+        interesting_paths()
+            .filter(|path| !known.contains(*path))
+            .map(String::from),
+    );
+
+    log::trace!(
+        "exit: add_interesting_paths -> Arc<wordlist[{} words...]>",
+        words.len()
+    );
+
+    Arc::new(words)
+}
+
+/// --collect-passive; append the paths the Wayback Machine has archived for the --url's host to
+/// the given words, skipping any paths the wordlist already contains
+async fn add_passive_paths(target_url: &str, words: Arc<Vec<String>>) -> Arc<Vec<String>> {
+    log::trace!(
+        "enter: add_passive_paths({}, wordlist[{} words...])",
+        target_url,
+        words.len()
+    );
+
+    let host = match passive::target_host(target_url) {
+        Some(host) => host,
+        None => {
+            log::warn!("--collect-passive needs a target given with --url; skipping");
+            return words;
+        }
+    };
+
+    let paths = passive::paths_from_urls(&passive::fetch_wayback_urls(&host).await);
+
+    let mut words = Arc::try_unwrap(words).unwrap_or_else(|words| (*words).clone());
+    let known: HashSet<_> = words.iter().cloned().collect();
+    let num_words = words.len();
+
+    words.extend(paths.into_iter().filter(|path| !known.contains(path)));
+
+    log::info!(
+        "added {} archived paths for {} to the wordlist",
+        words.len() - num_words,
+        host
+    );
+
+    log::trace!(
+        "exit: add_passive_paths -> Arc<wordlist[{} words...]>",
+        words.len()
+    );
+
+    Arc::new(words)
+}
+
+/// --collect-crtsh; add the subdomains found in certificate transparency logs for each target's
+/// host as targets of their own, using the same scheme as the target they came from
+async fn add_crtsh_targets(mut targets: Vec<String>) -> Vec<String> {
+    log::trace!("enter: add_crtsh_targets({:?})", targets);
+
+    let mut known: HashSet<String> = targets
+        .iter()
+        .filter_map(|t| passive::target_host(t))
+        .collect();
+    let mut new_targets = Vec::new();
+
+    for target in &targets {
+        let (scheme, host) = match reqwest::Url::parse(target) {
+            Ok(url) => match url.host_str() {
+                Some(host) => (url.scheme().to_string(), host.to_string()),
+                None => continue,
+            },
+            Err(_) => continue,
+        };
+
+        for subdomain in passive::fetch_crtsh_subdomains(&host).await {
+            if known.insert(subdomain.clone()) {
+                new_targets.push(format!("{}://{}", scheme, subdomain));
+            }
+        }
+    }
+
+    log::info!(
+        "added {} subdomains from certificate transparency logs as targets",
+        new_targets.len()
+    );
+
+    targets.extend(new_targets);
+
+    log::trace!("exit: add_crtsh_targets -> {:?}", targets);
+    targets
+}
+
 /// Determine whether it's a single url scan or urls are coming from stdin, then scan as needed
 async fn scan(targets: Vec<String>, handles: Arc<Handles>) -> Result<()> {
     log::trace!("enter: scan({:?}, {:?})", targets, handles);
@@ -133,18 +253,16 @@ async fn get_targets(handles: Arc<Handles>) -> Result<Vec<String>> {
         // scan, making it mutually exclusive from either of the other two options
         let ferox_scans = handles.ferox_scans()?;
 
-        if let Ok(scans) = ferox_scans.scans.read() {
-            for scan in scans.iter() {
-                // ferox_scans gets deserialized scans added to it at program start if --resume-from
-                // is used, so scans that aren't marked complete still need to be scanned
-                if scan.is_complete() || matches!(scan.scan_type, ScanType::File) {
-                    // this one's already done, or it's not a directory, ignore it
-                    continue;
-                }
-
-                targets.push(scan.url().to_owned());
+        // ferox_scans gets deserialized scans added to it at program start if --resume-from
+        // is used, so scans that aren't marked complete still need to be scanned
+        for scan in ferox_scans.get_incomplete() {
+            if scan.is_failed() || !matches!(scan.scan_type(), ScanType::Directory) {
+                // this one's been retried too many times, or it's not a directory, ignore it
+                continue;
             }
-        };
+
+            targets.push(scan.url().to_owned());
+        }
     } else {
         targets.push(handles.config.target_url.clone());
     }
@@ -183,7 +301,8 @@ async fn get_targets(handles: Arc<Handles>) -> Result<Vec<String>> {
 
 /// async main called from real main, broken out in this way to allow for some synchronous code
 /// to be executed before bringing the tokio runtime online
-async fn wrapped_main(config: Arc<Configuration>) -> Result<()> {
+/// returns the code that feroxbuster should exit with
+async fn wrapped_main(config: Arc<Configuration>) -> Result<i32> {
     // join can only be called once, otherwise it causes the thread to panic
     tokio::task::spawn_blocking(move || {
         // ok, lazy_static! uses (unsurprisingly in retrospect) a lazy loading model where the
@@ -200,25 +319,66 @@ async fn wrapped_main(config: Arc<Configuration>) -> Result<()> {
     // cloning an Arc is cheap (it's basically a pointer into the heap)
     // so that will allow for cheap/safe sharing of a single wordlist across multi-target scans
     // as well as additional directories found as part of recursion
-    let words = match get_unique_words_from_wordlist(&config.wordlist) {
-        Ok(w) => w,
-        Err(err) => {
-            let secondary = Path::new(SECONDARY_WORDLIST);
-
-            if secondary.exists() {
-                eprintln!("Found wordlist in secondary location");
-                get_unique_words_from_wordlist(SECONDARY_WORDLIST)?
-            } else {
-                return Err(err);
-            }
-        }
+    let encoding = Encoding::for_label(config.wordlist_encoding.as_bytes()).with_context(|| {
+        fmt_err(&format!(
+            "Unknown wordlist encoding: {}",
+            config.wordlist_encoding
+        ))
+    })?;
+
+    // --multipart-wordlist supplies the filenames to upload and --prefix-wordlist the path
+    // prefixes to insert, each in place of the normal wordlist
+    let wordlist = if !config.multipart_wordlist.is_empty() {
+        &config.multipart_wordlist
+    } else if !config.prefix_wordlist.is_empty() {
+        &config.prefix_wordlist
+    } else {
+        &config.wordlist
     };
 
+    let (words, wordlist) =
+        match get_unique_words_from_wordlist(wordlist, !config.no_wordlist_dedup, encoding) {
+            Ok(w) => (w, wordlist.as_str()),
+            Err(err) if wordlist == &config.wordlist => {
+                let secondary = Path::new(SECONDARY_WORDLIST);
+
+                if secondary.exists() {
+                    eprintln!("Found wordlist in secondary location");
+                    let words = get_unique_words_from_wordlist(
+                        SECONDARY_WORDLIST,
+                        !config.no_wordlist_dedup,
+                        encoding,
+                    )?;
+                    (words, SECONDARY_WORDLIST)
+                } else {
+                    return Err(err);
+                }
+            }
+            Err(err) => return Err(err),
+        };
+
     if words.len() <= 1 {
         // the check is now <= 1 due to the initial empty string added in 2.6.0
         // 1 -> empty wordlist
         // 0 -> error
-        bail!("Did not find any words in {}", config.wordlist);
+        bail!("Did not find any words in {}", wordlist);
+    }
+
+    let words = if config.collect_interesting_paths {
+        add_interesting_paths(words)
+    } else {
+        words
+    };
+
+    let words = if config.collect_passive {
+        add_passive_paths(&config.target_url, words).await
+    } else {
+        words
+    };
+
+    if config.watch_wordlist {
+        // words added to the wordlist from here on out are requested by the running scans
+        scanner::watch_wordlist(wordlist, words.clone(), encoding);
     }
 
     // spawn all event handlers, expect back a JoinHandle and a *Handle to the specific event
@@ -253,6 +413,18 @@ async fn wrapped_main(config: Arc<Configuration>) -> Result<()> {
         tokio::spawn(async move { scan_manager::start_max_time_thread(time_handles).await });
     }
 
+    // keeps the overall progress bar's scan counts (and --json-status) up to date
+    let status_handles = handles.clone();
+    tokio::spawn(async move { scan_manager::start_status_thread(status_handles).await });
+
+    if !config.checkpoint_interval.is_empty() {
+        // --checkpoint-interval; periodically write the scan's state to disk
+        let checkpoint_handles = handles.clone();
+        tokio::spawn(
+            async move { scan_manager::start_checkpoint_thread(checkpoint_handles).await },
+        );
+    }
+
     // can't trace main until after logger is initialized and the above task is started
     log::trace!("enter: main");
 
@@ -273,6 +445,29 @@ async fn wrapped_main(config: Arc<Configuration>) -> Result<()> {
         handles.stats.send(LoadStats(from_here))?;
     }
 
+    if !config.connection_errors_to_file.is_empty() {
+        scanner::initialize_error_log(&config.connection_errors_to_file)?;
+    }
+
+    if !config.check_previous_state.is_empty() {
+        // populate FeroxScans with urls that were already found in a previous scan
+        let num_previous = handles
+            .ferox_scans()?
+            .add_previous_responses(&config.check_previous_state)
+            .with_context(|| {
+                fmt_err(&format!(
+                    "Could not load responses from {}",
+                    config.check_previous_state
+                ))
+            })?;
+
+        log::info!(
+            "loaded {} previously found urls from {}",
+            num_previous,
+            config.check_previous_state
+        );
+    }
+
     // get targets from command line or stdin
     let targets = match get_targets(handles.clone()).await {
         Ok(t) => t,
@@ -283,6 +478,31 @@ async fn wrapped_main(config: Arc<Configuration>) -> Result<()> {
         }
     };
 
+    let targets = if config.collect_crtsh {
+        add_crtsh_targets(targets).await
+    } else {
+        targets
+    };
+
+    if config.resumed && targets.is_empty() {
+        let scans = handles.ferox_scans()?;
+
+        if !scans.get_failed_scans().is_empty() {
+            // every unfinished scan in the state file has been retried too many times, so
+            // there's nothing left to do but let the user know
+            clean_up(handles, tasks).await?;
+            scan_manager::report_failed_scans(&scans, config.max_retries, config.output_level);
+            return Ok(0);
+        }
+    }
+
+    if config.simulate {
+        // --simulate; nothing is requested, the estimate is all the user gets
+        clean_up(handles.clone(), tasks).await?;
+        simulate::print_simulation(&config, handles.wordlist.len(), targets.len())?;
+        return Ok(0);
+    }
+
     // --parallel branch
     if config.parallel > 0 {
         log::trace!("enter: parallel branch");
@@ -339,6 +559,10 @@ async fn wrapped_main(config: Arc<Configuration>) -> Result<()> {
             String::new()
         };
 
+        // each child runs its own reports, --post-scan-script, --notify-url, etc...; only their
+        // exit codes need to make it back to this process
+        let mut children = Vec::new();
+
         // unvalidated targets fresh from stdin, just spawn children and let them do all checks
         for target in targets {
             // add the current target to the provided command
@@ -376,7 +600,7 @@ async fn wrapped_main(config: Arc<Configuration>) -> Result<()> {
 
             log::debug!("parallel exec: {} {}", bin, args.join(" "));
 
-            tokio::task::spawn_blocking(move || {
+            children.push(tokio::task::spawn_blocking(move || {
                 let result = Command::new(bin)
                     .args(&args)
                     .spawn()
@@ -386,7 +610,20 @@ async fn wrapped_main(config: Arc<Configuration>) -> Result<()> {
 
                 drop(permit);
                 result
-            });
+            }));
+        }
+
+        // --exit-code-on-found / --exit-code-on-none / --post-scan-script; the first child (in
+        // the order the targets were given) that exited with a non-zero code decides ours
+        let mut exit_code = 0;
+
+        for child in children {
+            // a child killed by a signal has no exit code
+            let child_code = child.await?.code().unwrap_or(1);
+
+            if exit_code == 0 {
+                exit_code = child_code;
+            }
         }
 
         // the output handler creates an empty file to which it will try to write, because
@@ -405,8 +642,8 @@ async fn wrapped_main(config: Arc<Configuration>) -> Result<()> {
             }
         }
 
-        log::trace!("exit: parallel branch && wrapped main");
-        return Ok(());
+        log::trace!("exit: parallel branch && wrapped main -> {}", exit_code);
+        return Ok(exit_code);
     }
 
     if matches!(config.output_level, OutputLevel::Default) {
@@ -441,12 +678,13 @@ async fn wrapped_main(config: Arc<Configuration>) -> Result<()> {
     // discard non-responsive targets
     let live_targets = {
         let test = heuristics::HeuristicTests::new(handles.clone());
-        let result = test.connectivity(&targets).await;
-        if result.is_err() {
-            clean_up(handles, tasks).await?;
-            bail!(fmt_err(&result.unwrap_err().to_string()));
+        match test.connectivity(&targets).await {
+            Ok(result) => result,
+            Err(e) => {
+                clean_up(handles, tasks).await?;
+                bail!(fmt_err(&e.to_string()));
+            }
         }
-        result?
     };
 
     if live_targets.is_empty() {
@@ -454,6 +692,8 @@ async fn wrapped_main(config: Arc<Configuration>) -> Result<()> {
         bail!(fmt_err("Could not find any live targets to scan"));
     }
 
+    let scan_start = Instant::now();
+
     // kick off a scan against any targets determined to be responsive
     match scan(live_targets, handles.clone()).await {
         Ok(_) => {}
@@ -463,10 +703,135 @@ async fn wrapped_main(config: Arc<Configuration>) -> Result<()> {
         }
     }
 
+    let stats = handles.stats.data.clone();
+    let filters = handles.filters.data.clone();
+    let scans = handles.ferox_scans()?;
+
     clean_up(handles, tasks).await?;
 
-    log::trace!("exit: wrapped_main");
-    Ok(())
+    if config.collect_parameters {
+        scan_manager::report_collected_parameters(&config)?;
+    }
+
+    if config.collect_technology_stack {
+        scan_manager::report_technology_stack(config.output_level)?;
+    }
+
+    if config.collect_api_versions {
+        scan_manager::report_api_versions(config.output_level)?;
+    }
+
+    if config.collect_security_headers {
+        scan_manager::report_security_headers(config.output_level)?;
+    }
+
+    if config.collect_hsts {
+        scan_manager::report_hsts(&config)?;
+    }
+
+    if config.collect_page_titles {
+        scan_manager::report_page_titles(&config)?;
+    }
+
+    if config.collect_meta_tags {
+        scan_manager::report_meta_tags(&config)?;
+    }
+
+    if config.collect_301_targets {
+        scan_manager::report_redirect_targets(&config)?;
+    }
+
+    if config.collect_subpath_wordlist {
+        scan_manager::report_subpath_words(&config)?;
+    }
+
+    if config.collect_source_maps {
+        scan_manager::report_source_map_paths(&config)?;
+    }
+
+    if config.collect_certificates {
+        scan_manager::report_certificates(&config)?;
+    }
+
+    if !config.export_metasploit.is_empty() {
+        scan_manager::export_metasploit(&config.export_metasploit).with_context(|| {
+            fmt_err(&format!(
+                "Could not write Metasploit script to {}",
+                config.export_metasploit
+            ))
+        })?;
+    }
+
+    scan_manager::report_scan_timings(&scans, config.output_level)?;
+
+    scan_manager::report_failed_scans(&scans, config.max_retries, config.output_level);
+
+    scan_manager::report_cancelled_scans(&scans, config.output_level);
+
+    if !config.check_previous_state.is_empty()
+        && !matches!(config.output_level, OutputLevel::Silent)
+    {
+        eprintln!(
+            "Skipped {} urls already found in {}",
+            scans.num_previous_skipped(),
+            config.check_previous_state
+        );
+    }
+
+    if stats.requests_deduplicated() > 0 && !matches!(config.output_level, OutputLevel::Silent) {
+        eprintln!(
+            "Skipped {} duplicate requests that were already sent",
+            stats.requests_deduplicated()
+        );
+    }
+
+    if stats.duplicates_filtered() > 0 && !matches!(config.output_level, OutputLevel::Silent) {
+        eprintln!(
+            "Filtered {} responses with duplicate content",
+            stats.duplicates_filtered()
+        );
+    }
+
+    if config.report_summary_only {
+        notify::print_report_summary(&stats, scan_start.elapsed().as_secs_f64())?;
+    }
+
+    // --notify-url / --desktop-notify; only sent once everything's been joined and flushed
+    notify::notify_completion(&config, &stats, scan_start.elapsed().as_secs_f64()).await;
+
+    if config.save_state_on_empty && stats.resources_discovered() == 0 {
+        // leave a record that the target was scanned, even though nothing was found
+        let filename = scan_manager::write_state_file(&config, &scans, &stats, &filters)?;
+
+        if !matches!(config.output_level, OutputLevel::Silent) {
+            eprintln!("No results found; saved scan state to {}", filename);
+        }
+    }
+
+    // --exit-code-on-found / --exit-code-on-none
+    let mut exit_code = if stats.resources_discovered() > 0 {
+        config.exit_code_on_found
+    } else {
+        config.exit_code_on_none
+    };
+
+    if !config.post_scan_script.is_empty() {
+        let script_exit_code = post_scan::run_post_scan_script(
+            &config,
+            &scans,
+            &stats,
+            &filters,
+            scan_start.elapsed().as_secs_f64(),
+        )?;
+
+        if script_exit_code != 0 {
+            // a failing script takes precedence over the found/none exit codes
+            exit_code = script_exit_code;
+        }
+    }
+
+    log::trace!("exit: wrapped_main -> {}", exit_code);
+    Ok(exit_code)
 }
 
 /// Single cleanup function that handles all the necessary drops/finishes etc required to gracefully
@@ -493,6 +858,8 @@ async fn clean_up(handles: Arc<Handles>, tasks: Tasks) -> Result<()> {
     tasks.stats.await??;
     log::trace!("stats handler closed");
 
+    scanner::flush_error_log()?;
+
     // mark all scans complete so the terminal input handler will exit cleanly
     SCAN_COMPLETE.store(true, Ordering::Relaxed);
 
@@ -504,9 +871,25 @@ async fn clean_up(handles: Arc<Handles>, tasks: Tasks) -> Result<()> {
     Ok(())
 }
 
+/// run --test-server until the process is killed; none of the scan setup in wrapped_main applies
+async fn run_test_server(config: Arc<Configuration>) -> Result<()> {
+    let rules = if config.test_server_rules.is_empty() {
+        TestServerRules::default()
+    } else {
+        TestServerRules::from_file(&config.test_server_rules)?
+    };
+
+    test_server::serve(config.test_server, rules).await
+}
+
 fn main() -> Result<()> {
     let config = Arc::new(Configuration::new().with_context(|| "Could not create Configuration")?);
 
+    if config.live_output {
+        // progress bars need to be hidden before anything is printed
+        enable_live_output();
+    }
+
     // setup logging based on the number of -v's used
     if matches!(
         config.output_level,
@@ -524,40 +907,67 @@ fn main() -> Result<()> {
         .enable_all()
         .build()
     {
+        if config.test_server > 0 {
+            if let Err(e) = runtime.block_on(run_test_server(config.clone())) {
+                eprintln!("{}", e);
+            }
+
+            log::trace!("exit: main");
+            return Ok(());
+        }
+
+        if !config.diff.is_empty() {
+            if let Err(e) = scan_manager::print_state_diff(&config.diff) {
+                eprintln!("{}", e);
+            }
+
+            log::trace!("exit: main");
+            return Ok(());
+        }
+
         let future = wrapped_main(config.clone());
-        if let Err(e) = runtime.block_on(future) {
-            eprintln!("{}", e);
-
-            // the code below is to facilitate testing tests/test_banner entries. Since it's an
-            // integration test, normal test detection (cfg!(test), etc...) won't work. So, in
-            // the tests themselves, we pass
-            // `--wordlist /definitely/doesnt/exist/0cd7fed0-47f4-4b18-a1b0-ac39708c1676`
-            // and look for that here to print the banner.
-            //
-            // this change became a necessity once we moved wordlist parsing out of `scan` and into
-            // `wrapped_main`.
-            if e.to_string()
-                .contains("/definitely/doesnt/exist/0cd7fed0-47f4-4b18-a1b0-ac39708c1676")
-            {
-                // support the handful of tests that use `--stdin`
-                let targets: Vec<_> = if config.stdin {
-                    stdin().lock().lines().map(|tgt| tgt.unwrap()).collect()
-                } else {
-                    vec!["http://localhost".to_string()]
-                };
-
-                // print the banner to stderr
-                let std_stderr = stderr(); // std::io::stderr
-                let banner = Banner::new(&targets, &config);
-                if !config.quiet && !config.silent {
-                    banner.print_to(std_stderr, config).unwrap();
-                }
+        match runtime.block_on(future) {
+            Ok(0) => {}
+            Ok(exit_code) => {
+                // the scan itself went fine, but --exit-code-on-found|none or --post-scan-script
+                // asked for a non-zero exit
+                log::trace!("exit: main -> {}", exit_code);
+                std::process::exit(exit_code);
             }
+            Err(e) => {
+                eprintln!("{}", e);
+
+                // the code below is to facilitate testing tests/test_banner entries. Since it's an
+                // integration test, normal test detection (cfg!(test), etc...) won't work. So, in
+                // the tests themselves, we pass
+                // `--wordlist /definitely/doesnt/exist/0cd7fed0-47f4-4b18-a1b0-ac39708c1676`
+                // and look for that here to print the banner.
+                //
+                // this change became a necessity once we moved wordlist parsing out of `scan` and into
+                // `wrapped_main`.
+                if e.to_string()
+                    .contains("/definitely/doesnt/exist/0cd7fed0-47f4-4b18-a1b0-ac39708c1676")
+                {
+                    // support the handful of tests that use `--stdin`
+                    let targets: Vec<_> = if config.stdin {
+                        stdin().lock().lines().map(|tgt| tgt.unwrap()).collect()
+                    } else {
+                        vec!["http://localhost".to_string()]
+                    };
+
+                    // print the banner to stderr
+                    let std_stderr = stderr(); // std::io::stderr
+                    let banner = Banner::new(&targets, &config);
+                    if !config.quiet && !config.silent {
+                        banner.print_to(std_stderr, config).unwrap();
+                    }
+                }
 
-            // if we've encountered an error before clean_up can be called (i.e. a wordlist error)
-            // we need to at least spin-down the progress bar
-            PROGRESS_PRINTER.finish();
-        };
+                // if we've encountered an error before clean_up can be called (i.e. a wordlist error)
+                // we need to at least spin-down the progress bar
+                PROGRESS_PRINTER.finish();
+            }
+        }
     }
 
     log::trace!("exit: main");
@@ -0,0 +1,276 @@
+use std::{collections::BTreeMap, process::Command, time::Duration};
+
+use anyhow::{bail, Result};
+use reqwest::header::CONTENT_TYPE;
+use serde::Serialize;
+
+use crate::{
+    config::Configuration, dns, response::FeroxResponse, scanner::RESPONSES, statistics::Stats,
+};
+
+/// Summary of a completed scan, sent as the JSON body of a `--notify-url` request
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ScanSummary {
+    /// number of resources discovered during the scan
+    pub total_urls: usize,
+
+    /// wall-clock runtime of the scan, in seconds
+    pub scan_time: f64,
+
+    /// hostname of the machine that ran the scan
+    pub hostname: String,
+}
+
+impl ScanSummary {
+    /// given the scan's `Stats` and runtime, create a new `ScanSummary`
+    pub fn new(stats: &Stats, scan_time: f64) -> Self {
+        Self {
+            total_urls: stats.resources_discovered(),
+            scan_time,
+            hostname: hostname(),
+        }
+    }
+
+    /// one-line, human readable version of the summary, used as the desktop notification body
+    fn message(&self) -> String {
+        format!(
+            "Found {} urls in {:.2}s on {}",
+            self.total_urls, self.scan_time, self.hostname
+        )
+    }
+}
+
+/// Summary of a completed scan, printed to stdout as JSON by `--report-summary-only`
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ReportSummary {
+    /// number of requests sent during the scan
+    pub total_scanned: usize,
+
+    /// number of urls found during the scan
+    pub total_found: usize,
+
+    /// number of urls found per status code
+    pub urls_by_status: BTreeMap<u16, usize>,
+
+    /// wall-clock runtime of the scan, in seconds
+    pub elapsed_s: f64,
+
+    /// hostname of the machine that ran the scan
+    pub hostname: String,
+}
+
+impl ReportSummary {
+    /// given the scan's `Stats`, the responses that were found, and its runtime, create a new
+    /// `ReportSummary`
+    pub fn new(stats: &Stats, responses: &[FeroxResponse], elapsed_s: f64) -> Self {
+        let mut urls_by_status = BTreeMap::new();
+
+        for response in responses {
+            *urls_by_status
+                .entry(response.status().as_u16())
+                .or_insert(0) += 1;
+        }
+
+        Self {
+            total_scanned: stats.requests(),
+            total_found: responses.len(),
+            urls_by_status,
+            elapsed_s,
+            hostname: hostname(),
+        }
+    }
+}
+
+/// print the summary of a completed scan to stdout as a single line of JSON for
+/// `--report-summary-only`
+pub fn print_report_summary(stats: &Stats, elapsed_s: f64) -> Result<()> {
+    log::trace!("enter: print_report_summary({}, {:?})", elapsed_s, stats);
+
+    let summary = match RESPONSES.responses.read() {
+        Ok(responses) => ReportSummary::new(stats, &responses, elapsed_s),
+        Err(_) => ReportSummary::new(stats, &[], elapsed_s),
+    };
+
+    println!("{}", serde_json::to_string(&summary)?);
+
+    log::trace!("exit: print_report_summary");
+    Ok(())
+}
+
+/// best-effort lookup of the current machine's hostname
+fn hostname() -> String {
+    if let Ok(name) = std::env::var("HOSTNAME").or_else(|_| std::env::var("COMPUTERNAME")) {
+        if !name.is_empty() {
+            return name;
+        }
+    }
+
+    Command::new("hostname")
+        .output()
+        .ok()
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| String::from("unknown"))
+}
+
+/// POST the given summary as JSON to `url`
+async fn send_webhook(config: &Configuration, summary: &ScanSummary) -> Result<()> {
+    let url = &config.notify_url;
+
+    log::trace!("enter: send_webhook({}, {:?})", url, summary);
+
+    // a dedicated client is used so that scan-specific settings (proxies, headers, etc) aren't
+    // applied to the notification; a custom dns resolver still applies, since the webhook may
+    // only be reachable through it
+    let mut builder = reqwest::Client::builder().timeout(Duration::from_secs(config.timeout));
+
+    if let Some(resolver) = dns::resolver(
+        config.dns_server.as_deref(),
+        config.dns_over_https.as_deref(),
+    )? {
+        builder = builder.dns_resolver(resolver);
+    }
+
+    let client = builder.build()?;
+
+    let response = client
+        .post(url)
+        .header(CONTENT_TYPE, "application/json")
+        .body(serde_json::to_string(summary)?)
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        bail!(
+            "notification to {} failed with status {}",
+            url,
+            response.status()
+        );
+    }
+
+    log::trace!("exit: send_webhook");
+    Ok(())
+}
+
+/// show a desktop notification containing the given summary
+fn send_desktop_notification(summary: &ScanSummary) -> Result<()> {
+    log::trace!("enter: send_desktop_notification({:?})", summary);
+
+    let title = "feroxbuster scan complete";
+    let message = summary.message();
+
+    let status = if cfg!(target_os = "macos") {
+        Command::new("osascript")
+            .arg("-e")
+            .arg(format!(
+                "display notification {:?} with title {:?}",
+                message, title
+            ))
+            .status()?
+    } else {
+        Command::new("notify-send")
+            .arg(title)
+            .arg(message)
+            .status()?
+    };
+
+    if !status.success() {
+        bail!("desktop notification command exited with {}", status);
+    }
+
+    log::trace!("exit: send_desktop_notification");
+    Ok(())
+}
+
+/// send any notifications requested via `--notify-url` and/or `--desktop-notify`
+///
+/// failures are logged, but never cause the scan itself to be considered a failure
+pub async fn notify_completion(config: &Configuration, stats: &Stats, scan_time: f64) {
+    log::trace!("enter: notify_completion({}, {:?})", scan_time, stats);
+
+    if config.notify_url.is_empty() && !config.desktop_notify {
+        log::trace!("exit: notify_completion (no notifications requested)");
+        return;
+    }
+
+    let summary = ScanSummary::new(stats, scan_time);
+
+    if !config.notify_url.is_empty() {
+        if let Err(e) = send_webhook(config, &summary).await {
+            log::warn!(
+                "Could not send notification to {}: {}",
+                config.notify_url,
+                e
+            );
+        }
+    }
+
+    if config.desktop_notify {
+        if let Err(e) = send_desktop_notification(&summary) {
+            log::warn!("Could not send desktop notification: {}", e);
+        }
+    }
+
+    log::trace!("exit: notify_completion");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// summary should pull total_urls from resources_discovered and always have a hostname
+    fn scan_summary_new_populates_fields() {
+        let stats = Stats::new(false);
+        let summary = ScanSummary::new(&stats, 1.5);
+
+        assert_eq!(summary.total_urls, 0);
+        assert_eq!(summary.scan_time, 1.5);
+        assert!(!summary.hostname.is_empty());
+    }
+
+    #[test]
+    /// summary should serialize to the documented json shape
+    fn scan_summary_serializes_expected_keys() {
+        let summary = ScanSummary {
+            total_urls: 3,
+            scan_time: 2.0,
+            hostname: String::from("stuff"),
+        };
+
+        let json = serde_json::to_string(&summary).unwrap();
+        assert_eq!(
+            json,
+            r#"{"total_urls":3,"scan_time":2.0,"hostname":"stuff"}"#
+        );
+    }
+
+    #[test]
+    /// report summary should count found urls per status code and serialize to the documented
+    /// json shape
+    fn report_summary_counts_urls_by_status() {
+        let response = |url: &str, status: u16| -> FeroxResponse {
+            serde_json::from_value(serde_json::json!({"url": url, "status": status})).unwrap()
+        };
+
+        let responses = [
+            response("http://localhost/a", 200),
+            response("http://localhost/b", 403),
+            response("http://localhost/c", 200),
+        ];
+
+        let mut summary = ReportSummary::new(&Stats::new(false), &responses, 1.5);
+
+        assert_eq!(summary.total_found, 3);
+        assert_eq!(summary.urls_by_status, BTreeMap::from([(200, 2), (403, 1)]));
+        assert!(!summary.hostname.is_empty());
+
+        summary.hostname = String::from("stuff");
+
+        assert_eq!(
+            serde_json::to_string(&summary).unwrap(),
+            r#"{"total_scanned":0,"total_found":3,"urls_by_status":{"200":2,"403":1},"elapsed_s":1.5,"hostname":"stuff"}"#
+        );
+    }
+}
@@ -39,12 +39,12 @@ pub fn initialize() -> Command<'static> {
             Arg::new("url")
                 .short('u')
                 .long("url")
-                .required_unless_present_any(&["stdin", "resume_from"])
+                .required_unless_present_any(["stdin", "resume_from", "test_server", "diff"])
                 .help_heading("Target selection")
                 .value_name("URL")
                 .use_value_delimiter(true)
                 .value_hint(ValueHint::Url)
-                .help("The target URL (required, unless [--stdin || --resume-from] used)"),
+                .help("The target URL (required, unless [--stdin || --resume-from || --test-server || --diff] used)"),
         )
         .arg(
             Arg::new("stdin")
@@ -63,6 +63,36 @@ pub fn initialize() -> Command<'static> {
                 .help("State file from which to resume a partially complete scan (ex. --resume-from ferox-1606586780.state)")
                 .conflicts_with("url")
                 .takes_value(true),
+        )
+        .arg(
+            Arg::new("test_server")
+                .long("test-server")
+                .value_name("PORT")
+                .help_heading("Target selection")
+                .help("Run a local HTTP server for testing feroxbuster itself, instead of scanning")
+                .conflicts_with_all(&["url", "stdin", "resume_from"])
+                .takes_value(true),
+        )
+        .arg(
+            Arg::new("test_server_rules")
+                .long("test-server-rules")
+                .value_hint(ValueHint::FilePath)
+                .value_name("FILE")
+                .help_heading("Target selection")
+                .help("TOML file of path_pattern -> {status, body} rules used by --test-server")
+                .requires("test_server")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::new("diff")
+                .long("diff")
+                .value_names(&["STATE_FILE_A", "STATE_FILE_B"])
+                .value_hint(ValueHint::FilePath)
+                .number_of_values(2)
+                .help_heading("Target selection")
+                .help("Compare two state files and print the responses and scans that differ, instead of scanning (ex: --diff first.state resumed.state)")
+                .conflicts_with_all(&["url", "stdin", "resume_from", "test_server"])
+                .takes_value(true),
         );
 
     /////////////////////////////////////////////////////////////////////
@@ -111,6 +141,20 @@ pub fn initialize() -> Command<'static> {
                     "Proxy to use for requests (ex: http(s)://host:port, socks5(h)://host:port)",
                 ),
         )
+        .arg(
+            Arg::new("proxy_exclude_patterns")
+                .long("proxy-exclude-hosts")
+                .value_name("PATTERN")
+                .takes_value(true)
+                .multiple_values(true)
+                .multiple_occurrences(true)
+                .use_value_delimiter(true)
+                .requires("proxy")
+                .help_heading("Proxy settings")
+                .help(
+                    "Hosts that bypass --proxy, in the same format as no_proxy (ex: --proxy-exclude-hosts localhost .corp.local 10.0.0.0/8)",
+                ),
+        )
         .arg(
             Arg::new("replay_proxy")
                 .short('P')
@@ -198,6 +242,26 @@ pub fn initialize() -> Command<'static> {
                     "Request's Body; can read data from a file if input starts with an @ (ex: @post.bin)",
                 ),
         )
+        .arg(
+            Arg::new("multipart_wordlist")
+                .long("multipart-wordlist")
+                .value_name("FILE")
+                .takes_value(true)
+                .conflicts_with_all(&["data", "methods", "extensions"])
+                .help_heading("Request settings")
+                .help(
+                    "POST a multipart/form-data file upload for each word in FILE, using the word as the uploaded filename",
+                ),
+        )
+        .arg(
+            Arg::new("multipart_field")
+                .long("multipart-field")
+                .value_name("NAME")
+                .takes_value(true)
+                .requires("multipart_wordlist")
+                .help_heading("Request settings")
+                .help("Form field name used by --multipart-wordlist (default: file)"),
+        )
         .arg(
             Arg::new("headers")
                 .short('H')
@@ -282,6 +346,69 @@ pub fn initialize() -> Command<'static> {
                     "Filter out messages of a particular size (ex: -S 5120 -S 4927,1970)",
                 ),
         )
+        .arg(
+            Arg::new("filter_empty_body")
+                .long("filter-empty-body")
+                .takes_value(false)
+                .help_heading("Response filters")
+                .help("Filter out responses with an empty body (same as -S 0)"),
+        )
+        .arg(
+            Arg::new("filter_near_empty_body")
+                .long("filter-near-empty-body")
+                .value_name("MAX")
+                .takes_value(true)
+                .help_heading("Response filters")
+                .help("Filter out responses whose body is at most MAX bytes"),
+        )
+        .arg(
+            Arg::new("filter_body_size_range")
+                .long("filter-body-size-range")
+                .value_name("MIN-MAX")
+                .takes_value(true)
+                .multiple_values(true)
+                .multiple_occurrences(true)
+                .use_value_delimiter(true)
+                .help_heading("Response filters")
+                .help(
+                    "Filter out messages whose size falls within a range; MAX may be 'unlimited' (ex: --filter-body-size-range 100-200)",
+                ),
+        )
+        .arg(
+            Arg::new("keep_body_size_range")
+                .long("keep-body-size-range")
+                .value_name("MIN-MAX")
+                .takes_value(true)
+                .multiple_values(true)
+                .multiple_occurrences(true)
+                .use_value_delimiter(true)
+                .help_heading("Response filters")
+                .help(
+                    "Filter out messages whose size falls outside of every given range (ex: --keep-body-size-range 1000-unlimited)",
+                ),
+        )
+        .arg(
+            Arg::new("json_path_filter")
+                .long("json-path-filter")
+                .value_name("EXPRESSION")
+                .takes_value(true)
+                .multiple_occurrences(true)
+                .help_heading("Response filters")
+                .help(
+                    "Filter out JSON responses matching a JSONPath expression (ex: --json-path-filter '$.status == \"error\"')",
+                ),
+        )
+        .arg(
+            Arg::new("json_path_keep_if")
+                .long("json-path-keep-if")
+                .value_name("EXPRESSION")
+                .takes_value(true)
+                .multiple_occurrences(true)
+                .help_heading("Response filters")
+                .help(
+                    "Filter out JSON responses matching none of the given JSONPath expressions (ex: --json-path-keep-if '$.data[0].id')",
+                ),
+        )
         .arg(
             Arg::new("filter_regex")
                 .short('X')
@@ -296,6 +423,19 @@ pub fn initialize() -> Command<'static> {
                     "Filter out messages via regular expression matching on the response's body (ex: -X '^ignore me$')",
                 ),
         )
+        .arg(
+            Arg::new("filter_extension")
+                .long("filter-extension")
+                .value_name("EXTENSION")
+                .takes_value(true)
+                .multiple_values(true)
+                .multiple_occurrences(true)
+                .use_value_delimiter(true)
+                .help_heading("Response filters")
+                .help(
+                    "Filter out messages whose url ends with a particular extension (ex: --filter-extension png,jpg)",
+                ),
+        )
         .arg(
             Arg::new("filter_words")
                 .short('W')
@@ -310,6 +450,19 @@ pub fn initialize() -> Command<'static> {
                     "Filter out messages of a particular word count (ex: -W 312 -W 91,82)",
                 ),
         )
+        .arg(
+            Arg::new("filter_words_range")
+                .long("filter-words-range")
+                .value_name("MIN-MAX")
+                .takes_value(true)
+                .multiple_values(true)
+                .multiple_occurrences(true)
+                .use_value_delimiter(true)
+                .help_heading("Response filters")
+                .help(
+                    "Filter out messages whose word count falls within a range (ex: --filter-words-range 100-500)",
+                ),
+        )
         .arg(
             Arg::new("filter_lines")
                 .short('N')
@@ -324,6 +477,55 @@ pub fn initialize() -> Command<'static> {
                     "Filter out messages of a particular line count (ex: -N 20 -N 31,30)",
                 ),
         )
+        .arg(
+            Arg::new("filter_lines_range")
+                .long("filter-lines-range")
+                .value_name("MIN-MAX")
+                .takes_value(true)
+                .multiple_values(true)
+                .multiple_occurrences(true)
+                .use_value_delimiter(true)
+                .help_heading("Response filters")
+                .help(
+                    "Filter out messages whose line count falls within a range (ex: --filter-lines-range 10-20)",
+                ),
+        )
+        .arg(
+            Arg::new("filter_response_time_above")
+                .long("filter-response-time-above")
+                .value_name("MS")
+                .takes_value(true)
+                .help_heading("Response filters")
+                .help(
+                    "Filter out responses that took longer than the given number of milliseconds",
+                ),
+        )
+        .arg(
+            Arg::new("filter_response_time_below")
+                .long("filter-response-time-below")
+                .value_name("MS")
+                .takes_value(true)
+                .help_heading("Response filters")
+                .help(
+                    "Filter out responses that took less than the given number of milliseconds",
+                ),
+        )
+        .arg(
+            Arg::new("filter_max_headers")
+                .long("filter-max-headers")
+                .value_name("NUM_HEADERS")
+                .takes_value(true)
+                .help_heading("Response filters")
+                .help("Filter out responses with more than the given number of headers"),
+        )
+        .arg(
+            Arg::new("filter_min_headers")
+                .long("filter-min-headers")
+                .value_name("NUM_HEADERS")
+                .takes_value(true)
+                .help_heading("Response filters")
+                .help("Filter out responses with fewer than the given number of headers"),
+        )
         .arg(
             Arg::new("filter_status")
                 .short('C')
@@ -339,6 +541,19 @@ pub fn initialize() -> Command<'static> {
                     "Filter out status codes (deny list) (ex: -C 200 -C 401)",
                 ),
         )
+        .arg(
+            Arg::new("output_filter_status")
+                .long("output-filter-status")
+                .value_name("STATUS_CODE")
+                .takes_value(true)
+                .multiple_values(true)
+                .multiple_occurrences(true)
+                .use_value_delimiter(true)
+                .help_heading("Response filters")
+                .help(
+                    "Only write the given status codes to --output; the terminal is unaffected (ex: --output-filter-status 200)",
+                ),
+        )
         .arg(
             Arg::new("filter_similar")
                 .long("filter-similar-to")
@@ -353,6 +568,18 @@ pub fn initialize() -> Command<'static> {
                     "Filter out pages that are similar to the given page (ex. --filter-similar-to http://site.xyz/soft404)",
                 ),
         )
+        .arg(
+            Arg::new("filter_min_ratio")
+                .long("filter-min-ratio")
+                .value_name("RATIO")
+                .takes_value(true)
+                .validator(valid_ratio)
+                .conflicts_with("dont_filter")
+                .help_heading("Response filters")
+                .help(
+                    "Filter out responses whose size is within the given ratio (0.0-1.0) of the target's wildcard response (ex: --filter-min-ratio 0.95)",
+                ),
+        )
         .arg(
             Arg::new("status_codes")
                 .short('s')
@@ -366,6 +593,40 @@ pub fn initialize() -> Command<'static> {
                 .help(
                     "Status Codes to include (allow list) (default: 200 204 301 302 307 308 401 403 405)",
                 ),
+        )
+        .arg(
+            Arg::new("include_all_status")
+                .long("include-all-status")
+                .takes_value(false)
+                .help_heading("Response filters")
+                .help(
+                    "Show responses of every status code; overrides --status-codes and --filter-status",
+                ),
+        )
+        .arg(
+            Arg::new("include_unconfirmed_redirects")
+                .long("include-unconfirmed-redirects")
+                .takes_value(false)
+                .help_heading("Response filters")
+                .help(
+                    "Show 3xx responses that redirect to an unscanned url, even when filtered by status code",
+                ),
+        )
+        .arg(
+            Arg::new("interesting_only")
+                .long("interesting-only")
+                .takes_value(false)
+                .help_heading("Response filters")
+                .help(
+                    "Only show responses that score as interesting (status, content type, size, depth, and keywords like admin/api/config/backup)",
+                ),
+        )
+        .arg(
+            Arg::new("filter_duplicate_content")
+                .long("filter-duplicate-content")
+                .takes_value(false)
+                .help_heading("Response filters")
+                .help("Filter out responses whose body is identical to one already shown"),
         );
 
     /////////////////////////////////////////////////////////////////////
@@ -396,6 +657,23 @@ pub fn initialize() -> Command<'static> {
                 .takes_value(false)
                 .help_heading("Client settings")
                 .help("Disables TLS certificate validation in the client"),
+        )
+        .arg(
+            Arg::new("dns_server")
+                .long("dns-server")
+                .value_name("IP:PORT")
+                .takes_value(true)
+                .conflicts_with("dns_over_https")
+                .help_heading("Client settings")
+                .help("DNS server used to resolve hostnames (ex: --dns-server 10.0.0.2:53)"),
+        )
+        .arg(
+            Arg::new("dns_over_https")
+                .long("dns-over-https")
+                .value_name("URL")
+                .takes_value(true)
+                .help_heading("Client settings")
+                .help("DNS over HTTPS server used to resolve hostnames (ex: --dns-over-https https://1.1.1.1/dns-query)"),
         );
 
     /////////////////////////////////////////////////////////////////////
@@ -411,6 +689,23 @@ pub fn initialize() -> Command<'static> {
                 .help_heading("Scan settings")
                 .help("Number of concurrent threads (default: 50)"),
         )
+        .arg(
+            Arg::new("threads_burst")
+                .long("threads-burst")
+                .value_name("THREADS")
+                .takes_value(true)
+                .help_heading("Scan settings")
+                .help("Number of concurrent requests allowed at the start of each scan, before settling back to --threads"),
+        )
+        .arg(
+            Arg::new("threads_burst_duration")
+                .long("threads-burst-duration")
+                .value_name("MILLISECONDS")
+                .takes_value(true)
+                .requires("threads_burst")
+                .help_heading("Scan settings")
+                .help("Milliseconds that --threads-burst lasts at the start of each scan (default: 1000)"),
+        )
         .arg(
             Arg::new("no_recursion")
                 .short('n')
@@ -427,12 +722,36 @@ pub fn initialize() -> Command<'static> {
                 .takes_value(true)
                 .help_heading("Scan settings")
                 .help("Maximum recursion depth, a depth of 0 is infinite recursion (default: 4)"),
+        ).arg(
+            Arg::new("max_retries")
+                .long("max-retries")
+                .value_name("MAX_RETRIES")
+                .takes_value(true)
+                .help_heading("Scan settings")
+                .help("Number of times an unfinished scan may be resumed before it's marked as failed (default: 3)"),
         ).arg(
             Arg::new("force_recursion")
                 .long("force-recursion")
                 .conflicts_with("no_recursion")
                 .help_heading("Scan settings")
                 .help("Force recursion attempts on all 'found' endpoints (still respects recursion depth)"),
+        ).arg(
+            Arg::new("no_redundant_scans")
+                .long("no-redundant-scans")
+                .takes_value(false)
+                .help_heading("Scan settings")
+                .help("Skip targets and discovered urls (redirects, forms, etc) that fall beneath a directory that's already being scanned"),
+        ).arg(
+            Arg::new("recurse_on_status")
+                .long("recurse-on-status")
+                .value_name("STATUS_CODE")
+                .takes_value(true)
+                .multiple_values(true)
+                .multiple_occurrences(true)
+                .use_value_delimiter(true)
+                .conflicts_with("no_recursion")
+                .help_heading("Scan settings")
+                .help("Only recurse into directories found with the given status codes (default: 200 301 302)"),
         ).arg(
             Arg::new("extract_links")
                 .short('e')
@@ -441,6 +760,179 @@ pub fn initialize() -> Command<'static> {
                 .help_heading("Scan settings")
                 .help("Extract links from response body (html, javascript, etc...); make new requests based on findings")
         )
+        .arg(
+            Arg::new("no_extract_links")
+                .long("no-extract-links")
+                .takes_value(false)
+                .conflicts_with("extract_links")
+                .help_heading("Scan settings")
+                .help("Disable link extraction, even when enabled by a config file or --smart/--thorough")
+        )
+        .arg(
+            Arg::new("redirect_as_discovery")
+                .long("redirect-as-discovery")
+                .takes_value(false)
+                .help_heading("Scan settings")
+                .help("Scan the destination of same-host 3xx redirects as newly discovered targets")
+        )
+        .arg(
+            Arg::new("follow_meta_refresh")
+                .long("follow-meta-refresh")
+                .takes_value(false)
+                .help_heading("Scan settings")
+                .help("Scan the destination of same-host <meta http-equiv=\"refresh\"> redirects as newly discovered targets")
+        )
+        .arg(
+            Arg::new("check_previous_state")
+                .long("check-previous-state")
+                .value_name("STATE_FILE")
+                .value_hint(ValueHint::FilePath)
+                .takes_value(true)
+                .help_heading("Scan settings")
+                .help("Skip urls already found (non-4xx/5xx) in the given state file (ex: --check-previous-state ferox-1606586780.state)")
+        )
+        .arg(
+            Arg::new("collect_parameters")
+                .long("collect-parameters")
+                .takes_value(false)
+                .help_heading("Scan settings")
+                .help("Collect query parameter names from responses and save them to ferox-params-<host>-<timestamp>.txt")
+        )
+        .arg(
+            Arg::new("collect_page_titles")
+                .long("collect-page-titles")
+                .takes_value(false)
+                .help_heading("Scan settings")
+                .help("Show the <title> of html pages next to their urls and save them to ferox-titles-<host>-<timestamp>.txt")
+        )
+        .arg(
+            Arg::new("collect_meta_tags")
+                .long("collect-meta-tags")
+                .takes_value(false)
+                .help_heading("Scan settings")
+                .help("Collect <meta> tags (generator, author, etc) from html pages and save them to ferox-metatags-<host>-<timestamp>.json")
+        )
+        .arg(
+            Arg::new("collect_inline_js")
+                .long("collect-inline-js")
+                .takes_value(false)
+                .help_heading("Scan settings")
+                .help("Write the inline <script> blocks of html pages to --inline-js-dir, one file per block")
+        )
+        .arg(
+            Arg::new("inline_js_dir")
+                .long("inline-js-dir")
+                .value_name("DIR")
+                .takes_value(true)
+                .requires("collect_inline_js")
+                .help_heading("Scan settings")
+                .help("Directory in which --collect-inline-js writes its scripts (default: ferox-inline-js)")
+        )
+        .arg(
+            Arg::new("collect_security_headers")
+                .long("collect-security-headers")
+                .takes_value(false)
+                .help_heading("Scan settings")
+                .help("Report which html pages are missing common security headers (CSP, HSTS, etc)")
+        )
+        .arg(
+            Arg::new("collect_hsts")
+                .long("collect-hsts")
+                .takes_value(false)
+                .help_heading("Scan settings")
+                .help("Save https urls with a missing or weak (max-age under 120 days) Strict-Transport-Security header to a file")
+        )
+        .arg(
+            Arg::new("collect_301_targets")
+                .long("collect-301-targets")
+                .takes_value(false)
+                .help_heading("Scan settings")
+                .help("Save the Location of each 3xx response to ferox-redirects-<host>-<timestamp>.txt")
+        )
+        .arg(
+            Arg::new("collect_subpath_wordlist")
+                .long("collect-subpath-wordlist")
+                .takes_value(false)
+                .help_heading("Scan settings")
+                .help("Save the path components of each url that is found to ferox-discovered-words-<host>-<timestamp>.txt; with --adaptive-wordlist, they're also added to the running scan")
+        )
+        .arg(
+            Arg::new("collect_source_maps")
+                .long("collect-source-maps")
+                .takes_value(false)
+                .help_heading("Scan settings")
+                .help("Request the source maps referenced by javascript files, scan their original source paths, and save them to ferox-sourcemaps-<host>-<timestamp>.txt")
+        )
+        .arg(
+            Arg::new("collect_api_versions")
+                .long("collect-api-versions")
+                .takes_value(false)
+                .help_heading("Scan settings")
+                .help("Probe the next 10 versions of any versioned api path found (ex: /api/v1/ -> /api/v2/) and print the versions discovered")
+        )
+        .arg(
+            Arg::new("collect_certificates")
+                .long("collect-certificates")
+                .takes_value(false)
+                .help_heading("Scan settings")
+                .help("Fetch the tls certificate of each https host seen while scanning and save it to ferox-certs-<host>-<timestamp>.json; warns about certificates expiring within 30 days")
+        )
+        .arg(
+            Arg::new("collect_technology_stack")
+                .long("collect-technology-stack")
+                .takes_value(false)
+                .help_heading("Scan settings")
+                .help("Fingerprint each host's technologies (headers, cookies, body patterns) and print a summary")
+        )
+        .arg(
+            Arg::new("collect_forms")
+                .long("collect-forms")
+                .takes_value(false)
+                .help_heading("Scan settings")
+                .help("Scan the same-origin action urls of html forms found in 200 responses")
+        )
+        .arg(
+            Arg::new("collect_comments_only")
+                .long("collect-comments-only")
+                .takes_value(false)
+                .help_heading("Scan settings")
+                .help("Send HEAD requests, and only GET 200 html pages in order to print their html/javascript comments")
+        )
+        .arg(
+            Arg::new("deduplicate_by_path")
+                .long("deduplicate-by-path")
+                .takes_value(false)
+                .help_heading("Scan settings")
+                .help("Only request the first extracted link seen for each path, ignoring query strings and fragments")
+        )
+        .arg(
+            Arg::new("case_insensitive_dedup")
+                .long("case-insensitive-dedup")
+                .takes_value(false)
+                .help_heading("Scan settings")
+                .help("Treat urls that differ only in the case of their path as duplicates (ex: IIS)")
+        )
+        .arg(
+            Arg::new("websocket_discovery")
+                .long("websocket-discovery")
+                .takes_value(false)
+                .help_heading("Scan settings")
+                .help("Probe each discovered url for websocket upgrade support (marked with [WS])")
+        )
+        .arg(
+            Arg::new("cors_discovery")
+                .long("cors-discovery")
+                .takes_value(false)
+                .help_heading("Scan settings")
+                .help("Request each discovered url with a foreign Origin and warn about permissive CORS responses")
+        )
+        .arg(
+            Arg::new("options_discovery")
+                .long("options-discovery")
+                .takes_value(false)
+                .help_heading("Scan settings")
+                .help("Send an OPTIONS request to each discovered directory and report its allowed methods")
+        )
         .arg(
             Arg::new("scan_limit")
                 .short('L')
@@ -450,6 +942,14 @@ pub fn initialize() -> Command<'static> {
                 .help_heading("Scan settings")
                 .help("Limit total number of concurrent scans (default: 0, i.e. no limit)")
         )
+        .arg(
+            Arg::new("scan_limit_per_host")
+                .long("scan-limit-per-host")
+                .value_name("SCAN_LIMIT")
+                .takes_value(true)
+                .help_heading("Scan settings")
+                .help("Limit number of concurrent scans against a single host (default: 0, i.e. no limit)")
+        )
         .arg(
             Arg::new("parallel")
                 .long("parallel")
@@ -468,6 +968,67 @@ pub fn initialize() -> Command<'static> {
                 .help_heading("Scan settings")
                 .help("Limit number of requests per second (per directory) (default: 0, i.e. no limit)")
         )
+        .arg(
+            Arg::new("rate_limit_detect")
+                .long("rate-limit-detect")
+                .takes_value(false)
+                .conflicts_with("auto_tune")
+                .help_heading("Scan settings")
+                .help("Halve a scan's requests per second each time a 429 is received, recovering once they stop")
+        )
+        .arg(
+            Arg::new("rate_limit_recovery")
+                .long("rate-limit-recovery")
+                .value_name("SECONDS")
+                .takes_value(true)
+                .requires("rate_limit_detect")
+                .help_heading("Scan settings")
+                .help("Seconds without a 429 before --rate-limit-detect raises the rate again (default: 10)")
+        )
+        .arg(
+            Arg::new("pause_on_rate_limit")
+                .long("pause-on-rate-limit")
+                .takes_value(false)
+                .help_heading("Scan settings")
+                .help("Pause all scans when a 429 is received, for the number of seconds in its Retry-After header (default: 60)")
+        )
+        .arg(
+            Arg::new("no_request_dedup")
+                .long("no-request-dedup")
+                .takes_value(false)
+                .help_heading("Scan settings")
+                .help("Send identical requests even when another scan already sent them (ex. when responses vary)")
+        )
+        .arg(
+            Arg::new("adaptive_wordlist")
+                .long("adaptive-wordlist")
+                .takes_value(false)
+                .help_heading("Scan settings")
+                .help("Move words sharing a prefix with a found word (ex: config.php -> configuration) closer to the front of the wordlist")
+        )
+        .arg(
+            Arg::new("watch_wordlist")
+                .long("watch-wordlist")
+                .takes_value(false)
+                .help_heading("Scan settings")
+                .help("Watch the wordlist for changes; words added to it are requested by scans that are still running")
+        )
+        .arg(
+            Arg::new("max_urls_per_scan")
+                .long("max-urls-per-scan")
+                .value_name("NUM_URLS")
+                .takes_value(true)
+                .help_heading("Scan settings")
+                .help("Stop requesting words for a scan once it has found this many urls (default: 0, i.e. no limit)")
+        )
+        .arg(
+            Arg::new("max_response_size")
+                .long("max-response-size")
+                .value_name("BYTES")
+                .takes_value(true)
+                .help_heading("Scan settings")
+                .help("Only read up to this many bytes of each response body (default: 0, i.e. no limit)")
+        )
         .arg(
             Arg::new("time_limit")
                 .long("time-limit")
@@ -477,6 +1038,15 @@ pub fn initialize() -> Command<'static> {
                 .help_heading("Scan settings")
                 .help("Limit total run time of all scans (ex: --time-limit 10m)")
         )
+        .arg(
+            Arg::new("checkpoint_interval")
+                .long("checkpoint-interval")
+                .value_name("TIME_SPEC")
+                .takes_value(true)
+                .validator(valid_time_spec)
+                .help_heading("Scan settings")
+                .help("Write a state file every TIME_SPEC, keeping only the latest (ex: --checkpoint-interval 5m)")
+        )
         .arg(
             Arg::new("wordlist")
                 .short('w')
@@ -486,6 +1056,43 @@ pub fn initialize() -> Command<'static> {
                 .help("Path to the wordlist")
                 .help_heading("Scan settings")
                 .takes_value(true),
+        ).arg(
+            Arg::new("prefix_wordlist")
+                .long("prefix-wordlist")
+                .value_hint(ValueHint::FilePath)
+                .value_name("FILE")
+                .takes_value(true)
+                .conflicts_with_all(&["multipart_wordlist", "extensions"])
+                .help_heading("Scan settings")
+                .help("Insert each word in FILE in front of the target's path instead of appending it (ex: /api/v1/ -> /WORD/api/v1/); implies --no-recursion"),
+        ).arg(
+            Arg::new("no_wordlist_dedup")
+                .long("no-wordlist-dedup")
+                .takes_value(false)
+                .help_heading("Scan settings")
+                .help("Keep duplicate wordlist entries (duplicates cause the same url to be requested multiple times)"),
+        )
+        .arg(
+            Arg::new("simulate")
+                .long("simulate")
+                .alias("dry-run")
+                .takes_value(false)
+                .help_heading("Scan settings")
+                .help("Print an estimate of the number of requests the scan would send, then exit without sending any (alias: --dry-run)"),
+        )
+        .arg(
+            Arg::new("simulate_json")
+                .long("simulate-json")
+                .takes_value(false)
+                .help_heading("Scan settings")
+                .help("Same as --simulate, but print the estimate as JSON"),
+        ).arg(
+            Arg::new("wordlist_encoding")
+                .long("wordlist-encoding")
+                .value_name("ENCODING")
+                .takes_value(true)
+                .help_heading("Scan settings")
+                .help("Character encoding of the wordlist, e.g. latin1 or windows-1252 (default: utf-8)"),
         ).arg(
             Arg::new("auto_tune")
                 .long("auto-tune")
@@ -500,6 +1107,15 @@ pub fn initialize() -> Command<'static> {
                 .takes_value(false)
                 .help_heading("Scan settings")
                 .help("Automatically stop scanning when an excessive amount of errors are encountered")
+        )
+        .arg(
+            Arg::new("connection_errors_to_file")
+                .long("connection-errors-to-file")
+                .value_name("FILE")
+                .value_hint(ValueHint::FilePath)
+                .takes_value(true)
+                .help_heading("Scan settings")
+                .help("Write connection/timeout errors to the given file (one JSON object per line)")
         ).arg(
             Arg::new("dont_filter")
                 .short('D')
@@ -528,6 +1144,26 @@ pub fn initialize() -> Command<'static> {
                 .takes_value(false)
                 .help_heading("Dynamic collection settings")
                 .help("Automatically discover important words from within responses and add them to the wordlist")
+        ).arg(
+            Arg::new("collect_interesting_paths")
+                .long("collect-interesting-paths")
+                .takes_value(false)
+                .conflicts_with_all(&["multipart_wordlist", "prefix_wordlist"])
+                .help_heading("Dynamic collection settings")
+                .help("Request a built-in list of high-value paths (.env, web.config, etc) in addition to the wordlist")
+        ).arg(
+            Arg::new("collect_passive")
+                .long("collect-passive")
+                .takes_value(false)
+                .conflicts_with_all(&["multipart_wordlist", "prefix_wordlist"])
+                .help_heading("Dynamic collection settings")
+                .help("Add paths archived by the Wayback Machine for the --url's host to the wordlist (no requests are sent to the target)")
+        ).arg(
+            Arg::new("collect_crtsh")
+                .long("collect-crtsh")
+                .takes_value(false)
+                .help_heading("Dynamic collection settings")
+                .help("Add subdomains found in certificate transparency logs (crt.sh) as additional targets")
         ).arg(
             Arg::new("dont_collect")
                 .short('I')
@@ -572,7 +1208,37 @@ pub fn initialize() -> Command<'static> {
                 .help_heading("Output settings")
                 .help("Hide progress bars and banner (good for tmux windows w/ notifications)")
         )
+        .arg(
+            Arg::new("live_output")
+                .long("live-output")
+                .takes_value(false)
+                .help_heading("Output settings")
+                .help("Never draw progress bars and print each result straight to stdout as it's found (good for piping to other tools)")
+        )
+        .arg(
+            Arg::new("store_responses_as_html")
+                .long("store-responses-as-html")
+                .takes_value(false)
+                .help_heading("Output settings")
+                .help("Write a static html page for each 200 html response, plus an index linking them, to --html-gallery-dir")
+        )
+        .arg(
+            Arg::new("html_gallery_dir")
+                .long("html-gallery-dir")
+                .value_name("DIR")
+                .takes_value(true)
+                .requires("store_responses_as_html")
+                .help_heading("Output settings")
+                .help("Directory in which --store-responses-as-html writes its pages (default: ferox-gallery)")
+        )
 
+        .arg(
+            Arg::new("verbose_scan_start")
+                .long("verbose-scan-start")
+                .takes_value(false)
+                .help_heading("Output settings")
+                .help("Print a line with the url, depth, and wordlist of each scan as it starts")
+        )
         .arg(
             Arg::new("json")
                 .long("json")
@@ -590,6 +1256,74 @@ pub fn initialize() -> Command<'static> {
                 .help("Output file to write results to (use w/ --json for JSON entries)")
                 .takes_value(true),
         )
+        .arg(
+            Arg::new("tee")
+                .long("tee")
+                .value_hint(ValueHint::FilePath)
+                .value_name("FILE")
+                .takes_value(true)
+                .help_heading("Output settings")
+                .help("Also write each result printed to the terminal to FILE, colors included (unlike -o)"),
+        )
+        .arg(
+            Arg::new("report_summary_only")
+                .long("report-summary-only")
+                .takes_value(false)
+                .help_heading("Output settings")
+                .help("Don't print results to the terminal; print a JSON summary once scanning completes instead (--output is unaffected, pair with --silent for JSON only)"),
+        )
+        .arg(
+            Arg::new("json_status")
+                .long("json-status")
+                .takes_value(false)
+                .help_heading("Output settings")
+                .help("Print a JSON line to stderr with the number of known/complete scans and responses found whenever they change"),
+        )
+        .arg(
+            Arg::new("sort_output")
+                .long("sort-output")
+                .takes_value(false)
+                .requires("output")
+                .conflicts_with("output_rotate_size")
+                .help_heading("Output settings")
+                .help("Hold results until the scan ends, then write them to --output sorted by --sort-by")
+        )
+        .arg(
+            Arg::new("sort_by")
+                .long("sort-by")
+                .value_name("KEY")
+                .takes_value(true)
+                .possible_values(["status", "url", "size", "time"])
+                .requires("sort_output")
+                .help_heading("Output settings")
+                .help("Sort key used by --sort-output (default: status, then url path)")
+        )
+        .arg(
+            Arg::new("output_rotate_size")
+                .long("output-rotate-size")
+                .value_name("BYTES")
+                .takes_value(true)
+                .requires("output")
+                .help_heading("Output settings")
+                .help("Start a new output file (FILE.1, FILE.2, ...) once the current one exceeds this many bytes")
+        )
+        .arg(
+            Arg::new("output_rotate_max_files")
+                .long("output-rotate-max-files")
+                .value_name("NUM")
+                .takes_value(true)
+                .requires("output_rotate_size")
+                .help_heading("Output settings")
+                .help("Keep at most this many output files when rotating, deleting the oldest (default: 0, i.e. no limit)")
+        )
+        .arg(
+            Arg::new("output_deduplicate")
+                .long("output-deduplicate")
+                .takes_value(false)
+                .requires("output")
+                .help_heading("Output settings")
+                .help("Write each url to the output file only once, even if it's reported again (ex: with another method)")
+        )
         .arg(
             Arg::new("debug_log")
                 .long("debug-log")
@@ -599,12 +1333,77 @@ pub fn initialize() -> Command<'static> {
                 .help("Output file to write log entries (use w/ --json for JSON entries)")
                 .takes_value(true),
         )
+        .arg(
+            Arg::new("export_metasploit")
+                .long("export-metasploit")
+                .value_name("FILE")
+                .value_hint(ValueHint::FilePath)
+                .takes_value(true)
+                .help_heading("Output settings")
+                .help("Write discovered urls to a Metasploit resource script (ex: --export-metasploit ferox.rc)")
+        )
         .arg(
             Arg::new("no_state")
                 .long("no-state")
                 .takes_value(false)
                 .help_heading("Output settings")
                 .help("Disable state output file (*.state)")
+        )
+        .arg(
+            Arg::new("split_state")
+                .long("split-state")
+                .takes_value(false)
+                .help_heading("Output settings")
+                .conflicts_with("no_state")
+                .help("Write the scans and responses of state files to their own files (ferox-scans-*.state and ferox-responses-*.state)")
+        )
+        .arg(
+            Arg::new("notify_url")
+                .long("notify-url")
+                .value_name("URL")
+                .value_hint(ValueHint::Url)
+                .takes_value(true)
+                .help_heading("Output settings")
+                .help("POST a JSON summary of the scan to the given URL once all scans complete")
+        )
+        .arg(
+            Arg::new("exit_code_on_found")
+                .long("exit-code-on-found")
+                .value_name("CODE")
+                .takes_value(true)
+                .help_heading("Output settings")
+                .help("Exit with the given code when at least one url is discovered (default: 0)")
+        )
+        .arg(
+            Arg::new("exit_code_on_none")
+                .long("exit-code-on-none")
+                .value_name("CODE")
+                .takes_value(true)
+                .help_heading("Output settings")
+                .help("Exit with the given code when no urls are discovered (default: 0)")
+        )
+        .arg(
+            Arg::new("post_scan_script")
+                .long("post-scan-script")
+                .value_name("FILE")
+                .value_hint(ValueHint::FilePath)
+                .takes_value(true)
+                .help_heading("Output settings")
+                .help("Run the given script (.sh, .py, or any executable) with a state file once all scans complete; a non-zero exit code becomes feroxbuster's")
+        )
+        .arg(
+            Arg::new("save_state_on_empty")
+                .long("save-state-on-empty")
+                .takes_value(false)
+                .help_heading("Output settings")
+                .help("Write a state file when a scan completes without finding anything (the same file ctrl+c would write)")
+        )
+        .arg(
+            Arg::new("desktop_notify")
+                .long("desktop-notify")
+                .takes_value(false)
+                .help_heading("Output settings")
+                .help("Send a desktop notification (via notify-send/osascript) once all scans complete")
         );
 
     /////////////////////////////////////////////////////////////////////
@@ -654,6 +1453,17 @@ fn valid_time_spec(time_spec: &str) -> Result<(), String> {
     }
 }
 
+/// Validate that a string is a number between 0.0 and 1.0 (inclusive)
+fn valid_ratio(ratio: &str) -> Result<(), String> {
+    match ratio.parse::<f64>() {
+        Ok(value) if (0.0..=1.0).contains(&value) => Ok(()),
+        _ => Err(format!(
+            "Expected a number between 0.0 and 1.0; received {}",
+            ratio
+        )),
+    }
+}
+
 const EPILOGUE: &str = r#"NOTE:
     Options that take multiple values are very flexible.  Consider the following ways of specifying
     extensions:
@@ -0,0 +1,250 @@
+use std::{collections::HashSet, time::Duration};
+
+use anyhow::{bail, Result};
+use reqwest::{Client, Url};
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Wayback Machine CDX API, queried by --collect-passive
+const WAYBACK_CDX_URL: &str = "http://web.archive.org/cdx/search/cdx";
+
+/// crt.sh certificate transparency log search, queried by --collect-crtsh
+const CRTSH_URL: &str = "https://crt.sh/";
+
+/// maximum number of archived urls requested from the Wayback Machine
+const WAYBACK_LIMIT: &str = "10000";
+
+/// both services can be slow to answer large queries, so they get a more generous timeout than
+/// the scan itself
+const PASSIVE_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// a single certificate as returned by crt.sh; only the names it's valid for are of interest
+#[derive(Debug, Deserialize)]
+struct CrtShEntry {
+    /// newline separated list of names (common name and SANs) found in the certificate
+    #[serde(default)]
+    name_value: String,
+}
+
+/// the host of the given target; targets without a scheme (i.e. --url example.com) are treated
+/// as https, the same way they're scanned
+pub fn target_host(target: &str) -> Option<String> {
+    let target = if target.starts_with("http") {
+        target.to_string()
+    } else {
+        format!("https://{}", target)
+    };
+
+    Url::parse(&target)
+        .ok()
+        .and_then(|url| url.host_str().map(String::from))
+}
+
+/// a dedicated client is used so that scan-specific settings (proxies, headers, etc) aren't
+/// applied to third party services
+fn passive_client() -> Result<Client> {
+    Ok(Client::builder().timeout(PASSIVE_TIMEOUT).build()?)
+}
+
+/// query the CDX API at `base_url` for every url archived beneath `host`
+async fn fetch_wayback_urls_from(base_url: &str, host: &str) -> Result<Vec<String>> {
+    let response = passive_client()?
+        .get(base_url)
+        .query(&[
+            ("url", format!("{}/*", host).as_str()),
+            ("output", "json"),
+            ("limit", WAYBACK_LIMIT),
+        ])
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        bail!("Wayback Machine responded with {}", response.status());
+    }
+
+    // output=json is a list of rows, the first of which names the columns
+    let rows: Vec<Vec<Value>> = serde_json::from_str(&response.text().await?)?;
+
+    let original = match rows.first() {
+        Some(header) => header
+            .iter()
+            .position(|column| column == "original")
+            .unwrap_or(0),
+        None => return Ok(Vec::new()),
+    };
+
+    Ok(rows
+        .iter()
+        .skip(1)
+        .filter_map(|row| row.get(original).and_then(Value::as_str))
+        .map(String::from)
+        .collect())
+}
+
+/// --collect-passive; every url the Wayback Machine has archived beneath `host`
+///
+/// no requests are sent to `host` itself; failures are logged and result in an empty list
+pub async fn fetch_wayback_urls(host: &str) -> Vec<String> {
+    log::trace!("enter: fetch_wayback_urls({})", host);
+
+    let urls = fetch_wayback_urls_from(WAYBACK_CDX_URL, host)
+        .await
+        .unwrap_or_else(|e| {
+            log::warn!("Could not fetch archived urls for {}: {}", host, e);
+            Vec::new()
+        });
+
+    log::trace!("exit: fetch_wayback_urls -> [{} urls...]", urls.len());
+    urls
+}
+
+/// reduce the given urls to unique paths that can be used as words, i.e.
+/// http://example.com:80/admin/login.php?next=/ -> admin/login.php
+pub fn paths_from_urls(urls: &[String]) -> Vec<String> {
+    let mut seen = HashSet::new();
+
+    urls.iter()
+        .filter_map(|url| Url::parse(url).ok())
+        .map(|url| url.path().trim_matches('/').to_string())
+        .filter(|path| !path.is_empty() && seen.insert(path.clone()))
+        .collect()
+}
+
+/// query crt.sh at `base_url` for certificates issued to `domain` or any of its subdomains
+async fn fetch_crtsh_subdomains_from(base_url: &str, domain: &str) -> Result<Vec<String>> {
+    let response = passive_client()?
+        .get(base_url)
+        .query(&[("q", format!("%.{}", domain).as_str()), ("output", "json")])
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        bail!("crt.sh responded with {}", response.status());
+    }
+
+    let entries: Vec<CrtShEntry> = serde_json::from_str(&response.text().await?)?;
+
+    let suffix = format!(".{}", domain);
+    let mut seen = HashSet::new();
+
+    Ok(entries
+        .iter()
+        .flat_map(|entry| entry.name_value.lines())
+        .map(|name| name.trim().trim_start_matches("*.").to_lowercase())
+        .filter(|name| name.ends_with(&suffix) && seen.insert(name.clone()))
+        .collect())
+}
+
+/// --collect-crtsh; subdomains of `domain` found in certificate transparency logs
+///
+/// `domain` itself isn't included; failures are logged and result in an empty list
+pub async fn fetch_crtsh_subdomains(domain: &str) -> Vec<String> {
+    log::trace!("enter: fetch_crtsh_subdomains({})", domain);
+
+    let subdomains = fetch_crtsh_subdomains_from(CRTSH_URL, domain)
+        .await
+        .unwrap_or_else(|e| {
+            log::warn!("Could not fetch subdomains of {}: {}", domain, e);
+            Vec::new()
+        });
+
+    log::trace!(
+        "exit: fetch_crtsh_subdomains -> [{} subdomains...]",
+        subdomains.len()
+    );
+    subdomains
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use httpmock::{Method::GET, MockServer};
+
+    #[test]
+    /// paths should lose their leading/trailing slashes and query strings, and be deduplicated
+    fn paths_from_urls_keeps_unique_paths() {
+        let urls = [
+            "http://example.com:80/admin/login.php?next=/",
+            "https://example.com/admin/login.php",
+            "http://example.com/",
+            "http://example.com/js/",
+            "not a url",
+        ]
+        .map(String::from);
+
+        assert_eq!(paths_from_urls(&urls), ["admin/login.php", "js"]);
+    }
+
+    #[test]
+    /// hosts should be found with or without a scheme
+    fn target_host_handles_missing_scheme() {
+        assert_eq!(
+            target_host("http://example.com:8080/api"),
+            Some("example.com".to_string())
+        );
+        assert_eq!(target_host("example.com"), Some("example.com".to_string()));
+        assert_eq!(target_host(""), None);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    /// the original url column should be pulled from every row after the header
+    async fn fetch_wayback_urls_from_reads_original_column() {
+        let srv = MockServer::start();
+
+        let mock = srv.mock(|when, then| {
+            when.method(GET)
+                .path("/cdx")
+                .query_param("url", "example.com/*")
+                .query_param("output", "json")
+                .query_param("limit", "10000");
+            then.status(200).body(
+                r#"[["urlkey","timestamp","original"],["com,example)/a","2020","http://example.com/a"],["com,example)/b","2021","http://example.com/b?x=1"]]"#,
+            );
+        });
+
+        let urls = fetch_wayback_urls_from(&srv.url("/cdx"), "example.com")
+            .await
+            .unwrap();
+
+        mock.assert();
+        assert_eq!(urls, ["http://example.com/a", "http://example.com/b?x=1"]);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    /// names should be split, stripped of wildcards, deduplicated, and limited to subdomains
+    async fn fetch_crtsh_subdomains_from_collects_subdomains() {
+        let srv = MockServer::start();
+
+        let mock = srv.mock(|when, then| {
+            when.method(GET)
+                .path("/")
+                .query_param("q", "%.example.com")
+                .query_param("output", "json");
+            then.status(200).body(
+                r#"[{"name_value":"example.com\n*.example.com\nwww.example.com"},{"name_value":"API.example.com\nwww.example.com"},{"name_value":"example.org"}]"#,
+            );
+        });
+
+        let subdomains = fetch_crtsh_subdomains_from(&srv.url("/"), "example.com")
+            .await
+            .unwrap();
+
+        mock.assert();
+        assert_eq!(subdomains, ["www.example.com", "api.example.com"]);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    /// an unsuccessful status should be reported as an error
+    async fn fetch_crtsh_subdomains_from_errors_on_bad_status() {
+        let srv = MockServer::start();
+
+        srv.mock(|when, then| {
+            when.method(GET).path("/");
+            then.status(503);
+        });
+
+        assert!(fetch_crtsh_subdomains_from(&srv.url("/"), "example.com")
+            .await
+            .is_err());
+    }
+}
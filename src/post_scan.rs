@@ -0,0 +1,117 @@
+use std::{path::Path, process::Command};
+
+use anyhow::{Context, Result};
+use reqwest::Url;
+
+use crate::{
+    config::Configuration,
+    filters::FeroxFilters,
+    scan_manager::{write_state_file, FeroxScans},
+    statistics::Stats,
+    utils::fmt_err,
+};
+
+/// build the command used to run `script`; python and shell scripts are run through their
+/// interpreter so they don't need to be executable, anything else is executed directly
+fn script_command(script: &str) -> Command {
+    match Path::new(script).extension().and_then(|ext| ext.to_str()) {
+        Some("py") => {
+            let mut command = Command::new("python3");
+            command.arg(script);
+            command
+        }
+        Some("sh") => {
+            let mut command = Command::new("sh");
+            command.arg(script);
+            command
+        }
+        _ => Command::new(script),
+    }
+}
+
+/// host of the scan's target, or an empty string when targets came from stdin
+fn target_host(config: &Configuration) -> String {
+    Url::parse(&config.target_url)
+        .ok()
+        .and_then(|url| url.host_str().map(String::from))
+        .unwrap_or_default()
+}
+
+/// --post-scan-script; write the scan's state to a state file, then run the script with the
+/// state file's path as its only argument
+///
+/// the following environment variables are exported to the script:
+/// - `FEROX_STATE_FILE`: path to the state file
+/// - `FEROX_HOST`: host of the scan's target (empty when --stdin was used)
+/// - `FEROX_TOTAL_FOUND`: number of resources discovered
+/// - `FEROX_ELAPSED_SECS`: wall-clock runtime of the scan, in seconds
+///
+/// returns the script's exit code; a script killed by a signal is treated as exiting with 1
+pub fn run_post_scan_script(
+    config: &Configuration,
+    scans: &FeroxScans,
+    stats: &Stats,
+    filters: &FeroxFilters,
+    scan_time: f64,
+) -> Result<i32> {
+    log::trace!(
+        "enter: run_post_scan_script({}, {})",
+        config.post_scan_script,
+        scan_time
+    );
+
+    // the writer is dropped, and so flushed, before the script gets a chance to read the file
+    let state_file = write_state_file(config, scans, stats, filters)?;
+
+    let status = script_command(&config.post_scan_script)
+        .arg(&state_file)
+        .env("FEROX_STATE_FILE", &state_file)
+        .env("FEROX_HOST", target_host(config))
+        .env(
+            "FEROX_TOTAL_FOUND",
+            stats.resources_discovered().to_string(),
+        )
+        .env("FEROX_ELAPSED_SECS", format!("{:.2}", scan_time))
+        .status()
+        .with_context(|| {
+            fmt_err(&format!(
+                "Could not run post-scan script {}",
+                config.post_scan_script
+            ))
+        })?;
+
+    let exit_code = status.code().unwrap_or(1);
+
+    log::trace!("exit: run_post_scan_script -> {}", exit_code);
+    Ok(exit_code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// python and shell scripts should be run through their interpreter, everything else as is
+    fn script_command_picks_interpreter_by_extension() {
+        let command = script_command("/tmp/hook.py");
+        assert_eq!(command.get_program(), "python3");
+        assert_eq!(command.get_args().collect::<Vec<_>>(), ["/tmp/hook.py"]);
+
+        let command = script_command("/tmp/hook.sh");
+        assert_eq!(command.get_program(), "sh");
+
+        let command = script_command("/tmp/hook");
+        assert_eq!(command.get_program(), "/tmp/hook");
+        assert_eq!(command.get_args().count(), 0);
+    }
+
+    #[test]
+    /// the host should come from the target url, and be empty without one
+    fn target_host_uses_target_url() {
+        let mut config = Configuration::new().unwrap();
+        assert_eq!(target_host(&config), "");
+
+        config.target_url = String::from("https://localhost:8443/api/");
+        assert_eq!(target_host(&config), "localhost");
+    }
+}
@@ -1,5 +1,6 @@
 use indicatif::{MultiProgress, ProgressBar, ProgressDrawTarget, ProgressStyle};
 use lazy_static::lazy_static;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 lazy_static! {
     /// Global progress bar that houses other progress bars
@@ -9,6 +10,20 @@ lazy_static! {
     pub static ref PROGRESS_PRINTER: ProgressBar = add_bar("", 0, BarType::Hidden);
 }
 
+/// whether --live-output was used; set once, prior to any scanning
+static LIVE_OUTPUT: AtomicBool = AtomicBool::new(false);
+
+/// --live-output; stop drawing progress bars so that output is printed directly to stdout
+pub fn enable_live_output() {
+    LIVE_OUTPUT.store(true, Ordering::Relaxed);
+    PROGRESS_BAR.set_draw_target(ProgressDrawTarget::hidden());
+}
+
+/// whether output should bypass the progress bars and be printed directly to stdout
+pub fn live_output() -> bool {
+    LIVE_OUTPUT.load(Ordering::Relaxed)
+}
+
 /// Types of ProgressBars that can be added to `PROGRESS_BAR`
 #[derive(Copy, Clone)]
 pub enum BarType {
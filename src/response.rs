@@ -1,23 +1,29 @@
 use std::{
-    collections::HashMap,
+    collections::{hash_map::DefaultHasher, HashMap, HashSet},
     convert::{TryFrom, TryInto},
     fmt,
+    hash::{Hash, Hasher},
     str::FromStr,
     sync::Arc,
 };
 
 use anyhow::{Context, Result};
 use console::style;
+use lazy_static::lazy_static;
+use regex::Regex;
 use reqwest::{
-    header::{HeaderMap, HeaderName, HeaderValue},
+    header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE},
     Method, Response, StatusCode, Url,
 };
+use scraper::{Html, Selector};
 use serde::ser::SerializeStruct;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value;
+use uuid::Uuid;
 
 use crate::{
     config::OutputLevel,
+    cors::CorsVulnerability,
     event_handlers::{Command, Handles},
     traits::FeroxSerialize,
     url::FeroxUrl,
@@ -25,6 +31,109 @@ use crate::{
     CommandSender,
 };
 
+/// minimum body size, in bytes, for a response to count as non-trivial when scoring its interest
+const INTERESTING_MIN_SIZE: u64 = 100;
+
+/// score at or above which a response is considered interesting
+const INTERESTING_THRESHOLD: u32 = 6;
+
+/// words that, when found in a url, make the response more interesting
+const INTERESTING_KEYWORDS: [&str; 4] = ["admin", "api", "config", "backup"];
+
+lazy_static! {
+    /// Regex used to pull query parameter names out of urls embedded in a response body; the
+    /// optional `amp;` accounts for html-encoded ampersands
+    static ref PARAMETER_REGEX: Regex =
+        Regex::new(r"(?:\?|&(?:amp;)?)([A-Za-z0-9_.\-\[\]]{1,64})=").unwrap();
+
+    /// Regex used to pull html comments out of a response body
+    static ref HTML_COMMENT_REGEX: Regex = Regex::new(r"(?s)<!--(.*?)-->").unwrap();
+
+    /// Regex used to pull the contents of the <title> element out of a response body
+    static ref TITLE_REGEX: Regex = Regex::new(r"(?is)<title[^>]*>(.*?)</title>").unwrap();
+
+    /// Regex used to pull the contents of inline scripts out of a response body
+    static ref SCRIPT_REGEX: Regex = Regex::new(r"(?is)<script[^>]*>(.*?)</script>").unwrap();
+
+    /// Regex used to pull block and line comments out of javascript; line comments need to be
+    /// preceded by whitespace or punctuation so that urls (`https://...`) aren't matched
+    static ref JS_COMMENT_REGEX: Regex =
+        Regex::new(r"(?s)/\*(.*?)\*/|(?m:^|[\s;{}(),])//([^\r\n]*)").unwrap();
+}
+
+/// where the word that produced a `FeroxResponse` came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiscoveryMethod {
+    /// the user's wordlist, or words collected while scanning
+    Wordlist,
+
+    /// the built-in list of high-value paths requested by --collect-interesting-paths
+    BuiltinList,
+}
+
+/// implement a default for DiscoveryMethod
+impl Default for DiscoveryMethod {
+    /// return Wordlist
+    fn default() -> Self {
+        Self::Wordlist
+    }
+}
+
+/// read at most `max_size` bytes of the given response's body, discarding anything after that;
+/// returns the (lossily decoded) body and whether any of it went unread
+async fn read_limited_body(mut response: Response, max_size: u64) -> (String, bool) {
+    let max_size = usize::try_from(max_size).unwrap_or(usize::MAX);
+    let mut body = Vec::new();
+    let mut truncated = false;
+
+    while let Ok(Some(chunk)) = response.chunk().await {
+        let remaining = max_size - body.len();
+
+        if chunk.len() > remaining {
+            body.extend_from_slice(&chunk[..remaining]);
+            truncated = true;
+            break;
+        }
+
+        body.extend_from_slice(&chunk);
+    }
+
+    (String::from_utf8_lossy(&body).into_owned(), truncated)
+}
+
+/// destination of the first `<meta http-equiv="refresh">` tag in the given html, as written in
+/// its `content` attribute (ex: `0; url=/login` -> `/login`); refreshes without a url are ignored
+pub fn parse_meta_refresh(html: &str) -> Option<String> {
+    let html = Html::parse_document(html);
+    let selector = Selector::parse("meta[http-equiv][content]").unwrap();
+
+    html.select(&selector)
+        .map(|element| element.value())
+        .filter(|element| {
+            element
+                .attr("http-equiv")
+                .is_some_and(|value| value.trim().eq_ignore_ascii_case("refresh"))
+        })
+        .find_map(|element| {
+            // delay, then an optional separator, then the (optionally quoted) url
+            let content = element.attr("content")?;
+            let (_, url) = content.split_once([';', ','])?;
+            let url = url.trim();
+
+            let url = match url.get(..3) {
+                Some(prefix) if prefix.eq_ignore_ascii_case("url") => {
+                    url[3..].trim_start().strip_prefix('=')?
+                }
+                _ => url,
+            };
+
+            let url = url.trim().trim_matches(['\'', '"']).trim();
+
+            (!url.is_empty()).then(|| url.to_string())
+        })
+}
+
 /// A `FeroxResponse`, derived from a `Response` to a submitted `Request`
 #[derive(Debug, Clone)]
 pub struct FeroxResponse {
@@ -46,6 +155,9 @@ pub struct FeroxResponse {
     /// The content-length of this response, if known
     content_length: u64,
 
+    /// whether the body was larger than --max-response-size and wasn't (fully) read
+    body_truncated: bool,
+
     /// The number of lines contained in the body of this response, if known
     line_count: usize,
 
@@ -63,6 +175,53 @@ pub struct FeroxResponse {
 
     /// Url's file extension, if one exists
     pub(crate) extension: Option<String>,
+
+    /// whether the url accepted a websocket upgrade when probed by --websocket-discovery
+    supports_websocket: bool,
+
+    /// CORS misconfiguration found when probed by --cors-discovery, if any
+    cors: Option<CorsVulnerability>,
+
+    /// methods listed in the Allow header of an OPTIONS request sent by --options-discovery
+    allowed_methods: Vec<String>,
+
+    /// number of milliseconds between sending the request and receiving the response
+    response_time_ms: u64,
+
+    /// contents of the html <title> element, collected by --collect-page-titles
+    page_title: Option<String>,
+
+    /// name of the file uploaded by --multipart-wordlist to produce this response
+    upload_filename: Option<String>,
+
+    /// `content` of the html <meta> tags, keyed by their `name` or `property`, collected by
+    /// --collect-meta-tags
+    meta_tags: HashMap<String, String>,
+
+    /// unique id assigned when the response is created, used to correlate output with
+    /// --replay-proxy logs
+    request_id: String,
+
+    /// where the word that produced this response came from
+    discovered_via: DiscoveryMethod,
+
+    /// hash of the response body, used by --filter-duplicate-content; 0 when the body is empty
+    content_hash: u64,
+}
+
+/// hash of the given response body; empty bodies hash to 0 so they're never considered
+/// duplicates of one another (i.e. redirects)
+///
+/// std's `DefaultHasher` is used instead of `ahash`; it's fast enough for one hash per
+/// response and doesn't add a dependency
+fn hash_content(text: &str) -> u64 {
+    if text.is_empty() {
+        return 0;
+    }
+
+    let mut hasher = DefaultHasher::new();
+    text.hash(&mut hasher);
+    hasher.finish()
 }
 
 /// implement Default trait for FeroxResponse
@@ -76,12 +235,23 @@ impl Default for FeroxResponse {
             method: Method::default(),
             text: "".to_string(),
             content_length: 0,
+            body_truncated: false,
             line_count: 0,
             word_count: 0,
             headers: Default::default(),
             wildcard: false,
             output_level: Default::default(),
             extension: None,
+            supports_websocket: false,
+            cors: None,
+            allowed_methods: Vec::new(),
+            response_time_ms: 0,
+            page_title: None,
+            upload_filename: None,
+            meta_tags: HashMap::new(),
+            request_id: Uuid::new_v4().to_string(),
+            discovered_via: DiscoveryMethod::default(),
+            content_hash: 0,
         }
     }
 }
@@ -138,6 +308,11 @@ impl FeroxResponse {
         self.content_length
     }
 
+    /// whether the body was larger than --max-response-size and wasn't (fully) read
+    pub fn body_truncated(&self) -> bool {
+        self.body_truncated
+    }
+
     /// Set `FeroxResponse`'s `url` attribute, has no affect if an error occurs
     pub fn set_url(&mut self, url: &str) {
         match Url::parse(url) {
@@ -155,6 +330,110 @@ impl FeroxResponse {
         self.wildcard = is_wildcard;
     }
 
+    /// getter for `supports_websocket`
+    pub fn supports_websocket(&self) -> bool {
+        self.supports_websocket
+    }
+
+    /// set `supports_websocket` attribute
+    pub fn set_supports_websocket(&mut self, supports_websocket: bool) {
+        self.supports_websocket = supports_websocket;
+    }
+
+    /// getter for `cors`
+    pub fn cors(&self) -> Option<CorsVulnerability> {
+        self.cors
+    }
+
+    /// set `cors` attribute
+    pub fn set_cors(&mut self, cors: Option<CorsVulnerability>) {
+        self.cors = cors;
+    }
+
+    /// getter for `allowed_methods`
+    pub fn allowed_methods(&self) -> &[String] {
+        &self.allowed_methods
+    }
+
+    /// set `allowed_methods` attribute
+    pub fn set_allowed_methods(&mut self, allowed_methods: Vec<String>) {
+        self.allowed_methods = allowed_methods;
+    }
+
+    /// getter for `response_time_ms`
+    pub fn response_time_ms(&self) -> u64 {
+        self.response_time_ms
+    }
+
+    /// set `response_time_ms` attribute
+    pub fn set_response_time_ms(&mut self, response_time_ms: u64) {
+        self.response_time_ms = response_time_ms;
+    }
+
+    /// number of headers the response was sent with, counting repeated headers once per value
+    pub fn header_count(&self) -> usize {
+        self.headers.len()
+    }
+
+    /// getter for `page_title`
+    pub fn page_title(&self) -> Option<&str> {
+        self.page_title.as_deref()
+    }
+
+    /// set `page_title` attribute
+    pub fn set_page_title(&mut self, page_title: Option<String>) {
+        self.page_title = page_title;
+    }
+
+    /// getter for `upload_filename`
+    pub fn upload_filename(&self) -> Option<&str> {
+        self.upload_filename.as_deref()
+    }
+
+    /// set `upload_filename` attribute
+    pub fn set_upload_filename(&mut self, upload_filename: Option<String>) {
+        self.upload_filename = upload_filename;
+    }
+
+    /// getter for `meta_tags`
+    pub fn meta_tags(&self) -> &HashMap<String, String> {
+        &self.meta_tags
+    }
+
+    /// set `meta_tags` attribute
+    pub fn set_meta_tags(&mut self, meta_tags: HashMap<String, String>) {
+        self.meta_tags = meta_tags;
+    }
+
+    /// getter for `request_id`
+    pub fn request_id(&self) -> &str {
+        &self.request_id
+    }
+
+    /// getter for `discovered_via`
+    pub fn discovered_via(&self) -> DiscoveryMethod {
+        self.discovered_via
+    }
+
+    /// set `discovered_via` attribute
+    pub fn set_discovered_via(&mut self, discovered_via: DiscoveryMethod) {
+        self.discovered_via = discovered_via;
+    }
+
+    /// getter for `content_hash`
+    pub fn content_hash(&self) -> u64 {
+        self.content_hash
+    }
+
+    /// allowed methods other than GET and HEAD, which are worth a closer look
+    pub fn flagged_methods(&self) -> Vec<&str> {
+        self.allowed_methods
+            .iter()
+            .map(String::as_str)
+            .filter(|method| !matches!(*method, "GET" | "HEAD"))
+            .collect()
+    }
+
     /// set `text` attribute; update words/lines/content_length
     #[cfg(test)]
     pub fn set_text(&mut self, text: &str) {
@@ -166,6 +445,7 @@ impl FeroxResponse {
             .lines()
             .map(|s| s.split_whitespace().count())
             .sum();
+        self.content_hash = hash_content(&self.text);
     }
 
     /// free the `text` data, reducing memory usage
@@ -181,8 +461,8 @@ impl FeroxResponse {
     /// Additionally, inspects query parameters, as they're also often indicative of a file
     pub fn is_file(&self) -> bool {
         let has_extension = match self.url.path_segments() {
-            Some(path) => {
-                if let Some(last) = path.last() {
+            Some(mut path) => {
+                if let Some(last) = path.next_back() {
                     last.contains('.') // last segment has some sort of extension, probably
                 } else {
                     false
@@ -205,26 +485,41 @@ impl FeroxResponse {
     }
 
     /// Create a new `FeroxResponse` from the given `Response`
+    ///
+    /// a non-zero `max_response_size` limits how much of the body is read; bodies whose
+    /// Content-Length is larger aren't read at all, and bodies without one are cut off once
+    /// that many bytes have been read
     pub async fn from(
         response: Response,
         original_url: &str,
         method: &str,
         output_level: OutputLevel,
+        max_response_size: u64,
     ) -> Self {
         let url = response.url().clone();
         let status = response.status();
         let headers = response.headers().clone();
         let content_length = response.content_length().unwrap_or(0);
 
-        // .text() consumes the response, must be called last
-        let text = response
-            .text()
-            .await
-            .with_context(|| "Could not parse body from response")
-            .unwrap_or_default();
+        // .text() and read_limited_body consume the response, must be called last
+        let (text, body_truncated) = if max_response_size == 0 {
+            let text = response
+                .text()
+                .await
+                .with_context(|| "Could not parse body from response")
+                .unwrap_or_default();
+
+            (text, false)
+        } else if content_length > max_response_size {
+            // too big to bother reading
+            (String::new(), true)
+        } else {
+            read_limited_body(response, max_response_size).await
+        };
 
         let line_count = text.lines().count();
         let word_count = text.lines().map(|s| s.split_whitespace().count()).sum();
+        let content_hash = hash_content(&text);
 
         FeroxResponse {
             url,
@@ -232,6 +527,7 @@ impl FeroxResponse {
             status,
             method: Method::from_bytes(method.as_bytes()).unwrap_or(Method::GET),
             content_length,
+            body_truncated,
             text,
             headers,
             line_count,
@@ -239,6 +535,16 @@ impl FeroxResponse {
             output_level,
             wildcard: false,
             extension: None,
+            supports_websocket: false,
+            cors: None,
+            allowed_methods: Vec::new(),
+            response_time_ms: 0,
+            page_title: None,
+            upload_filename: None,
+            meta_tags: HashMap::new(),
+            request_id: Uuid::new_v4().to_string(),
+            discovered_via: DiscoveryMethod::default(),
+            content_hash,
         }
     }
 
@@ -259,7 +565,7 @@ impl FeroxResponse {
         //     (which may be empty).
         //
         // meaning: the two unwraps here are fine, the worst outcome is an empty string
-        let filename = self.url.path_segments().unwrap().last().unwrap();
+        let filename = self.url.path_segments().unwrap().next_back().unwrap();
 
         if !filename.is_empty() {
             // non-empty string, try to get extension
@@ -282,6 +588,8 @@ impl FeroxResponse {
                 .contains(&self.status().as_u16())  // in -s list
                 // or -C was used, and -s should be all responses that aren't filtered
                 || !handles.config.filter_status.is_empty()
+                // or --include-all-status was used, and every response is allowed
+                || handles.config.include_all_status
             {
                 // only add extensions to those responses that pass our checks; filtered out
                 // status codes are handled by should_filter, but we need to still check against
@@ -332,6 +640,188 @@ impl FeroxResponse {
         false
     }
 
+    /// collect the names of any query parameters found in urls within the response's body or
+    /// `Location` header
+    pub(crate) fn parameter_names(&self) -> HashSet<String> {
+        let location = self
+            .headers()
+            .get("Location")
+            .and_then(|loc| loc.to_str().ok())
+            .unwrap_or_default();
+
+        [self.text(), location]
+            .iter()
+            .flat_map(|haystack| PARAMETER_REGEX.captures_iter(haystack))
+            .map(|captures| captures[1].to_string())
+            .collect()
+    }
+
+    /// score the response on how likely it is to be worth a closer look; used by
+    /// `is_interesting` for automated triage
+    ///
+    /// - status code: 200 (+2), 403 (+1)
+    /// - content type: html (+2), json (+1)
+    /// - body of at least `INTERESTING_MIN_SIZE` bytes (+1)
+    /// - path at least two segments deep (+1)
+    /// - url contains one of `INTERESTING_KEYWORDS` (+3)
+    pub fn interest_score(&self) -> u32 {
+        let mut score = match self.status().as_u16() {
+            200 => 2,
+            403 => 1,
+            _ => 0,
+        };
+
+        let content_type = self
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default()
+            .to_lowercase();
+
+        if content_type.contains("html") {
+            score += 2;
+        } else if content_type.contains("json") {
+            score += 1;
+        }
+
+        if self.content_length() >= INTERESTING_MIN_SIZE {
+            score += 1;
+        }
+
+        let depth = self
+            .url()
+            .path_segments()
+            .map_or(0, |segments| segments.filter(|s| !s.is_empty()).count());
+
+        if depth >= 2 {
+            score += 1;
+        }
+
+        let url = self.url().as_str().to_lowercase();
+
+        if INTERESTING_KEYWORDS
+            .iter()
+            .any(|keyword| url.contains(keyword))
+        {
+            score += 3;
+        }
+
+        score
+    }
+
+    /// whether the response's `interest_score` reaches `INTERESTING_THRESHOLD`
+    pub fn is_interesting(&self) -> bool {
+        self.interest_score() >= INTERESTING_THRESHOLD
+    }
+
+    /// collect the html comments in the response's body, followed by any javascript comments
+    /// found in its inline scripts; empty comments are skipped
+    pub(crate) fn comments(&self) -> Vec<String> {
+        let html_comments = HTML_COMMENT_REGEX
+            .captures_iter(self.text())
+            .map(|captures| captures[1].to_string());
+
+        let js_comments = SCRIPT_REGEX.captures_iter(self.text()).flat_map(|script| {
+            JS_COMMENT_REGEX
+                .captures_iter(&script[1])
+                .filter_map(|captures| captures.get(1).or_else(|| captures.get(2)))
+                .map(|comment| comment.as_str().to_string())
+                .collect::<Vec<_>>()
+        });
+
+        html_comments
+            .chain(js_comments)
+            .map(|comment| comment.trim().to_string())
+            .filter(|comment| !comment.is_empty())
+            .collect()
+    }
+
+    /// whether the response is a 200 with an html Content-Type
+    pub(crate) fn is_html_page(&self) -> bool {
+        Self::is_html_status_and_headers(self.status(), self.headers())
+    }
+
+    /// whether the given status and headers belong to a 200 with an html Content-Type; lets a
+    /// raw HEAD response be checked before deciding whether its body is worth a GET
+    pub(crate) fn is_html_status_and_headers(status: &StatusCode, headers: &HeaderMap) -> bool {
+        status == &StatusCode::OK
+            && headers
+                .get(CONTENT_TYPE)
+                .and_then(|value| value.to_str().ok())
+                .is_some_and(|value| value.to_lowercase().contains("text/html"))
+    }
+
+    /// contents of the <title> element of a 200 html response, with whitespace collapsed;
+    /// `None` for any other response, or when the title is missing or empty
+    pub(crate) fn extract_page_title(&self) -> Option<String> {
+        if !self.is_html_page() {
+            return None;
+        }
+
+        let captures = TITLE_REGEX.captures(self.text())?;
+        let title = captures[1].split_whitespace().collect::<Vec<_>>().join(" ");
+
+        if title.is_empty() {
+            return None;
+        }
+
+        Some(title)
+    }
+
+    /// `content` of each <meta> tag in a 200 html response, keyed by the tag's `name` or
+    /// `property` (lowercased); empty for any other response
+    pub(crate) fn extract_meta_tags(&self) -> HashMap<String, String> {
+        let mut meta_tags = HashMap::new();
+
+        if !self.is_html_page() {
+            return meta_tags;
+        }
+
+        let html = Html::parse_document(self.text());
+        let selector = Selector::parse("meta[content]").unwrap();
+
+        for element in html.select(&selector) {
+            let element = element.value();
+
+            let name = match element.attr("name").or_else(|| element.attr("property")) {
+                Some(name) if !name.trim().is_empty() => name.trim().to_lowercase(),
+                _ => continue,
+            };
+
+            if let Some(content) = element.attr("content") {
+                meta_tags.insert(name, content.trim().to_string());
+            }
+        }
+
+        meta_tags
+    }
+
+    /// absolute `Url` pointed to by a 3xx response's `Location` header, if any
+    ///
+    /// relative locations are resolved against the response's url
+    pub fn redirect_location(&self) -> Option<Url> {
+        if !self.status().is_redirection() {
+            return None;
+        }
+
+        let location = self.headers().get("Location")?.to_str().ok()?;
+
+        self.url().join(location).ok()
+    }
+
+    /// absolute `Url` pointed to by a 200 html response's meta refresh tag, if any
+    ///
+    /// relative urls are resolved against the response's url
+    pub fn meta_refresh_location(&self) -> Option<Url> {
+        if !self.is_html_page() {
+            return None;
+        }
+
+        let location = parse_meta_refresh(self.text())?;
+
+        self.url().join(&location).ok()
+    }
+
     /// Helper function to determine suitability for recursion
     ///
     /// handles 2xx and 3xx responses by either checking if the url ends with a / (2xx)
@@ -428,6 +918,38 @@ impl FeroxSerialize for FeroxResponse {
             }
         };
 
+        if self.supports_websocket && !matches!(self.output_level, OutputLevel::Silent) {
+            // --websocket-discovery found an endpoint that accepts upgrades; --silent output is
+            // left as bare urls
+            url_with_redirect = format!("{} {}", url_with_redirect, style("[WS]").magenta());
+        }
+
+        let flagged_methods = self.flagged_methods();
+
+        if !flagged_methods.is_empty() && !matches!(self.output_level, OutputLevel::Silent) {
+            // --options-discovery found methods beyond GET/HEAD
+            let methods = format!("[{}]", flagged_methods.join(", "));
+            url_with_redirect = format!("{} {}", url_with_redirect, style(methods).magenta());
+        }
+
+        if let (Some(title), false) = (
+            self.page_title(),
+            matches!(self.output_level, OutputLevel::Silent),
+        ) {
+            // --collect-page-titles found a <title>
+            let title = format!("({})", title);
+            url_with_redirect = format!("{} {}", url_with_redirect, style(title).cyan());
+        }
+
+        if let (Some(filename), false) = (
+            self.upload_filename(),
+            matches!(self.output_level, OutputLevel::Silent),
+        ) {
+            // --multipart-wordlist uploaded a file to get this response
+            let filename = format!("[file: {}]", filename);
+            url_with_redirect = format!("{} {}", url_with_redirect, style(filename).blue());
+        }
+
         if self.wildcard && matches!(self.output_level, OutputLevel::Default | OutputLevel::Quiet) {
             // --silent was not used and response is a wildcard, special messages abound when
             // this is the case...
@@ -533,6 +1055,7 @@ impl Serialize for FeroxResponse {
         state.serialize_field("status", &self.status.as_u16())?;
         state.serialize_field("method", &self.method.as_str())?;
         state.serialize_field("content_length", &self.content_length)?;
+        state.serialize_field("body_truncated", &self.body_truncated)?;
         state.serialize_field("line_count", &self.line_count)?;
         state.serialize_field("word_count", &self.word_count)?;
         state.serialize_field("headers", &headers)?;
@@ -540,6 +1063,16 @@ impl Serialize for FeroxResponse {
             "extension",
             self.extension.as_ref().unwrap_or(&String::new()),
         )?;
+        state.serialize_field("supports_websocket", &self.supports_websocket)?;
+        state.serialize_field("cors", &self.cors)?;
+        state.serialize_field("allowed_methods", &self.allowed_methods)?;
+        state.serialize_field("response_time_ms", &self.response_time_ms)?;
+        state.serialize_field("page_title", &self.page_title)?;
+        state.serialize_field("upload_filename", &self.upload_filename)?;
+        state.serialize_field("meta_tags", &self.meta_tags)?;
+        state.serialize_field("request_id", &self.request_id)?;
+        state.serialize_field("discovered_via", &self.discovered_via)?;
+        state.serialize_field("content_hash", &self.content_hash)?;
 
         state.end()
     }
@@ -559,12 +1092,23 @@ impl<'de> Deserialize<'de> for FeroxResponse {
             method: Method::GET,
             text: String::new(),
             content_length: 0,
+            body_truncated: false,
             headers: HeaderMap::new(),
             wildcard: false,
             output_level: Default::default(),
             line_count: 0,
             word_count: 0,
             extension: None,
+            supports_websocket: false,
+            cors: None,
+            allowed_methods: Vec::new(),
+            response_time_ms: 0,
+            page_title: None,
+            upload_filename: None,
+            meta_tags: HashMap::new(),
+            request_id: Uuid::new_v4().to_string(),
+            discovered_via: DiscoveryMethod::default(),
+            content_hash: 0,
         };
 
         let map: HashMap<String, Value> = HashMap::deserialize(deserializer)?;
@@ -602,6 +1146,11 @@ impl<'de> Deserialize<'de> for FeroxResponse {
                         response.content_length = num;
                     }
                 }
+                "body_truncated" => {
+                    if let Some(result) = value.as_bool() {
+                        response.body_truncated = result;
+                    }
+                }
                 "line_count" => {
                     if let Some(num) = value.as_u64() {
                         response.line_count = num.try_into().unwrap_or_default();
@@ -638,6 +1187,64 @@ impl<'de> Deserialize<'de> for FeroxResponse {
                         response.extension = Some(result.to_string());
                     }
                 }
+                "supports_websocket" => {
+                    if let Some(result) = value.as_bool() {
+                        response.supports_websocket = result;
+                    }
+                }
+                "cors" => {
+                    if let Ok(cors) = serde_json::from_value(value.clone()) {
+                        response.cors = cors;
+                    }
+                }
+                "allowed_methods" => {
+                    if let Some(methods) = value.as_array() {
+                        response.allowed_methods = methods
+                            .iter()
+                            .filter_map(|method| method.as_str().map(String::from))
+                            .collect();
+                    }
+                }
+                "response_time_ms" => {
+                    if let Some(result) = value.as_u64() {
+                        response.response_time_ms = result;
+                    }
+                }
+                "page_title" => {
+                    if let Some(result) = value.as_str() {
+                        response.page_title = Some(result.to_string());
+                    }
+                }
+                "upload_filename" => {
+                    if let Some(result) = value.as_str() {
+                        response.upload_filename = Some(result.to_string());
+                    }
+                }
+                "meta_tags" => {
+                    if let Some(tags) = value.as_object() {
+                        response.meta_tags = tags
+                            .iter()
+                            .filter_map(|(name, content)| {
+                                Some((name.to_owned(), content.as_str()?.to_string()))
+                            })
+                            .collect();
+                    }
+                }
+                "request_id" => {
+                    if let Some(result) = value.as_str() {
+                        response.request_id = result.to_string();
+                    }
+                }
+                "discovered_via" => {
+                    if let Ok(discovered_via) = serde_json::from_value(value.clone()) {
+                        response.discovered_via = discovered_via;
+                    }
+                }
+                "content_hash" => {
+                    if let Some(result) = value.as_u64() {
+                        response.content_hash = result;
+                    }
+                }
                 _ => {}
             }
         }
@@ -652,6 +1259,266 @@ mod tests {
     use crate::config::Configuration;
     use std::default::Default;
 
+    #[test]
+    /// parameter names should be pulled from urls in the body (html-encoded or not) and from
+    /// the Location header
+    fn parameter_names_finds_names_in_body_and_location() {
+        let mut headers = HeaderMap::new();
+        headers.insert("Location", "/login?next=/admin".parse().unwrap());
+
+        let mut response = FeroxResponse {
+            url: Url::parse("http://localhost/").unwrap(),
+            status: StatusCode::FOUND,
+            headers,
+            ..Default::default()
+        };
+        response.set_text(
+            r#"<a href="/search?q=stuff&amp;page=2">x</a><script>fetch("/api?id=1&sort=asc")</script>"#,
+        );
+
+        let mut names: Vec<_> = response.parameter_names().into_iter().collect();
+        names.sort();
+
+        assert_eq!(names, vec!["id", "next", "page", "q", "sort"]);
+    }
+
+    #[test]
+    /// a 200 html page needs a bit more than its status and content type to be interesting,
+    /// while keywords in the url count for a lot
+    fn is_interesting_scores_responses() {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, "text/html".parse().unwrap());
+
+        let mut response = FeroxResponse {
+            url: Url::parse("http://localhost/about").unwrap(),
+            headers: headers.clone(),
+            ..Default::default()
+        };
+        response.set_text(&"x".repeat(200));
+
+        assert_eq!(response.interest_score(), 5);
+        assert!(!response.is_interesting());
+
+        response.set_url("http://localhost/company/about");
+        assert_eq!(response.interest_score(), 6);
+        assert!(response.is_interesting());
+
+        let forbidden = FeroxResponse {
+            url: Url::parse("http://localhost/admin").unwrap(),
+            status: StatusCode::FORBIDDEN,
+            headers,
+            ..Default::default()
+        };
+        assert_eq!(forbidden.interest_score(), 6);
+        assert!(forbidden.is_interesting());
+
+        let not_found = FeroxResponse {
+            url: Url::parse("http://localhost/js/app.js").unwrap(),
+            status: StatusCode::NOT_FOUND,
+            ..Default::default()
+        };
+        assert_eq!(not_found.interest_score(), 1);
+        assert!(!not_found.is_interesting());
+    }
+
+    #[test]
+    /// html comments and javascript comments from inline scripts should be collected, without
+    /// mistaking urls for line comments
+    fn comments_finds_html_and_javascript_comments() {
+        let mut response = FeroxResponse::default();
+        response.set_text(
+            r#"<html><!-- TODO: remove /backup before release --><!---->
+<script>
+    /* api key rotated in march */
+    fetch("https://localhost/api"); // temporary endpoint
+</script>
+<p>https://localhost/not-a-comment</p>
+</html>"#,
+        );
+
+        assert_eq!(
+            response.comments(),
+            vec![
+                "TODO: remove /backup before release",
+                "api key rotated in march",
+                "temporary endpoint"
+            ]
+        );
+    }
+
+    #[test]
+    /// the title of a 200 html page should be extracted with its whitespace collapsed; other
+    /// responses, and empty titles, have no title
+    fn extract_page_title_only_reads_html_pages() {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, "text/html; charset=UTF-8".parse().unwrap());
+
+        let mut response = FeroxResponse {
+            status: StatusCode::OK,
+            headers,
+            ..Default::default()
+        };
+
+        response.set_text("<html><head><TITLE>\n  Admin\n  Panel </TITLE></head></html>");
+        assert_eq!(
+            response.extract_page_title(),
+            Some("Admin Panel".to_string())
+        );
+
+        response.set_text("<html><head><title> </title></head></html>");
+        assert_eq!(response.extract_page_title(), None);
+
+        response.set_text("<html><head><title>Admin Panel</title></head></html>");
+        response.status = StatusCode::NOT_FOUND;
+        assert_eq!(response.extract_page_title(), None);
+
+        response.status = StatusCode::OK;
+        response.headers = HeaderMap::new();
+        assert_eq!(response.extract_page_title(), None);
+    }
+
+    #[test]
+    /// meta tags of a 200 html page should be keyed by their name or property, tags without
+    /// content or a name are skipped, and other responses have no meta tags
+    fn extract_meta_tags_only_reads_html_pages() {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, "text/html".parse().unwrap());
+
+        let mut response = FeroxResponse {
+            status: StatusCode::OK,
+            headers,
+            ..Default::default()
+        };
+
+        response.set_text(
+            r#"<html><head>
+            <meta name="Generator" content="WordPress 6.1">
+            <meta property="og:title" content=" Blog ">
+            <meta charset="utf-8">
+            <meta name="robots">
+            <meta http-equiv="refresh" content="5">
+            </head></html>"#,
+        );
+
+        let meta_tags = response.extract_meta_tags();
+        assert_eq!(meta_tags.len(), 2);
+        assert_eq!(meta_tags["generator"], "WordPress 6.1");
+        assert_eq!(meta_tags["og:title"], "Blog");
+
+        response.status = StatusCode::NOT_FOUND;
+        assert!(response.extract_meta_tags().is_empty());
+    }
+
+    #[test]
+    /// meta tags are read from the parsed html, so attribute order and quoting don't matter,
+    /// and tags inside comments or scripts aren't picked up
+    fn extract_meta_tags_reads_parsed_html() {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, "text/html".parse().unwrap());
+
+        let mut response = FeroxResponse {
+            status: StatusCode::OK,
+            headers,
+            ..Default::default()
+        };
+
+        response.set_text(
+            r#"<html><head>
+            <META CONTENT='Jane Doe' NAME=author>
+            <meta
+                content="admin, login"
+                name="keywords"
+            >
+            <!-- <meta name="generator" content="Drupal 7"> -->
+            <script>document.write('<meta name="description" content="injected">');</script>
+            </head></html>"#,
+        );
+
+        let meta_tags = response.extract_meta_tags();
+        assert_eq!(meta_tags.len(), 2);
+        assert_eq!(meta_tags["author"], "Jane Doe");
+        assert_eq!(meta_tags["keywords"], "admin, login");
+    }
+
+    #[test]
+    /// the url of a meta refresh should be pulled out of its content attribute, whatever the
+    /// separator, casing, and quoting used
+    fn parse_meta_refresh_reads_content_url() {
+        let refresh = |content: &str| {
+            parse_meta_refresh(&format!(
+                r#"<html><head><meta http-equiv="Refresh" content="{}"></head></html>"#,
+                content
+            ))
+        };
+
+        assert_eq!(refresh("0; url=/login"), Some(String::from("/login")));
+        assert_eq!(refresh("0;URL='/login'"), Some(String::from("/login")));
+        assert_eq!(
+            refresh("5, url = next.html"),
+            Some(String::from("next.html"))
+        );
+        assert_eq!(refresh("0; /login"), Some(String::from("/login")));
+        assert_eq!(refresh("5"), None);
+        assert_eq!(refresh("0; url="), None);
+
+        let html = r#"<html><head>
+            <meta name="refresh" content="0; url=/nope">
+            <meta http-equiv="refresh" content="0; url=http://localhost/yep">
+            </head></html>"#;
+        assert_eq!(
+            parse_meta_refresh(html),
+            Some(String::from("http://localhost/yep"))
+        );
+    }
+
+    #[test]
+    /// a meta refresh's url should be resolved against the response's url, and only 200 html
+    /// responses have one
+    fn meta_refresh_location_resolves_relative_urls() {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, "text/html".parse().unwrap());
+
+        let mut response = FeroxResponse {
+            url: Url::parse("http://localhost/one/two").unwrap(),
+            status: StatusCode::OK,
+            headers,
+            ..Default::default()
+        };
+
+        response.set_text(r#"<meta http-equiv="refresh" content="0; url=../new-home/">"#);
+
+        assert_eq!(
+            response.meta_refresh_location().unwrap().as_str(),
+            "http://localhost/new-home/"
+        );
+
+        response.status = StatusCode::NOT_FOUND;
+        assert!(response.meta_refresh_location().is_none());
+    }
+
+    #[test]
+    /// a relative Location header should be resolved against the response's url, non-3xx
+    /// responses never have a redirect location
+    fn redirect_location_resolves_relative_locations() {
+        let mut headers = HeaderMap::new();
+        headers.insert("Location", "../new-home/".parse().unwrap());
+
+        let mut response = FeroxResponse {
+            url: Url::parse("http://localhost/one/two").unwrap(),
+            status: StatusCode::FOUND,
+            headers,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            response.redirect_location().unwrap().as_str(),
+            "http://localhost/new-home/"
+        );
+
+        response.status = StatusCode::OK;
+        assert!(response.redirect_location().is_none());
+    }
+
     #[test]
     /// call reached_max_depth with max depth of zero, which is infinite recursion, expect false
     fn reached_max_depth_returns_early_on_zero() {
@@ -782,4 +1649,73 @@ mod tests {
 
         assert_eq!(response.extension, None);
     }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    /// bodies should only be read up to the limit, and only marked truncated if data was left
+    async fn read_limited_body_stops_at_max_size() {
+        let srv = httpmock::MockServer::start();
+
+        srv.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/big");
+            then.status(200).body("0123456789");
+        });
+
+        let response = reqwest::get(srv.url("/big")).await.unwrap();
+        assert_eq!(
+            read_limited_body(response, 4).await,
+            ("0123".to_string(), true)
+        );
+
+        let response = reqwest::get(srv.url("/big")).await.unwrap();
+        assert_eq!(
+            read_limited_body(response, 10).await,
+            ("0123456789".to_string(), false)
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    /// converting a reqwest Response should read the body, count its lines and words, and keep
+    /// the response's url, status, and headers
+    async fn from_converts_reqwest_response() {
+        let srv = httpmock::MockServer::start();
+
+        srv.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/page");
+            then.status(403)
+                .header("Content-Type", "text/html")
+                .body("one two\nthree");
+        });
+
+        let response = reqwest::get(srv.url("/page")).await.unwrap();
+        let ferox_response =
+            FeroxResponse::from(response, &srv.url("/"), "GET", OutputLevel::Quiet, 0).await;
+
+        assert_eq!(ferox_response.url().as_str(), srv.url("/page"));
+        assert_eq!(ferox_response.original_url, srv.url("/"));
+        assert_eq!(ferox_response.status(), &StatusCode::FORBIDDEN);
+        assert_eq!(ferox_response.method(), &Method::GET);
+        assert_eq!(ferox_response.text(), "one two\nthree");
+        assert_eq!(ferox_response.line_count(), 2);
+        assert_eq!(ferox_response.word_count(), 3);
+        assert_eq!(ferox_response.content_length(), 13);
+        assert!(!ferox_response.body_truncated());
+        assert_eq!(
+            ferox_response.headers().get("content-type").unwrap(),
+            "text/html"
+        );
+        assert_eq!(ferox_response.response_time_ms(), 0);
+        assert_eq!(
+            ferox_response.content_hash(),
+            hash_content("one two\nthree")
+        );
+    }
+
+    #[test]
+    /// identical bodies should hash the same, while empty bodies always hash to 0
+    fn hash_content_matches_identical_bodies_only() {
+        assert_eq!(hash_content("not found"), hash_content("not found"));
+        assert_ne!(hash_content("not found"), hash_content("Not Found"));
+        assert_ne!(hash_content("not found"), 0);
+        assert_eq!(hash_content(""), 0);
+    }
 }
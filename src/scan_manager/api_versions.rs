@@ -0,0 +1,167 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+use reqwest::Url;
+
+use super::HostCollector;
+
+/// number of version increments that are probed past each detected api version
+pub const NUM_PROBED_VERSIONS: u64 = 10;
+
+lazy_static! {
+    /// `/v1/` and `/v1.2/` style path segments
+    static ref PREFIXED_VERSION_REGEX: Regex = Regex::new(r"/v(\d+)(?:\.(\d+))?(?:/|$)").unwrap();
+
+    /// `/api/1/` style path segments
+    static ref API_VERSION_REGEX: Regex = Regex::new(r"/api/(\d+)(?:/|$)").unwrap();
+
+    /// api version roots discovered via --collect-api-versions, in the order they were found
+    pub static ref API_VERSIONS: HostCollector<Vec<String>> = HostCollector::default();
+}
+
+/// an api version detected in a url's path by --collect-api-versions, along with everything
+/// needed to build the urls of the versions that follow it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionProber {
+    /// url up to (and including) the slash preceding the version segment
+    base: String,
+
+    /// text preceding the version number in the path segment, i.e. `v` or nothing
+    prefix: &'static str,
+
+    /// major version number
+    major: u64,
+
+    /// minor version number, if the version had one (`v1.2`)
+    minor: Option<u64>,
+}
+
+/// Implementation of VersionProber
+impl VersionProber {
+    /// detect a versioned api path (`/v1/`, `/v1.2/`, or `/api/1/`) in the given url; returns
+    /// `None` when the url isn't versioned
+    pub fn from_url(url: &Url) -> Option<Self> {
+        let path = url.path();
+
+        let (captures, prefix) = match PREFIXED_VERSION_REGEX.captures(path) {
+            Some(captures) => (captures, "v"),
+            None => (API_VERSION_REGEX.captures(path)?, ""),
+        };
+
+        let major_match = captures.get(1)?;
+        let major = major_match.as_str().parse().ok()?;
+        let minor = match captures.get(2) {
+            Some(minor) => Some(minor.as_str().parse().ok()?),
+            None => None,
+        };
+
+        // everything before the version segment, i.e. http://localhost/api/ for /api/v1/users
+        let segment_start = major_match.start() - prefix.len();
+
+        let mut base = url.clone();
+        base.set_path(&path[..segment_start]);
+        base.set_query(None);
+        base.set_fragment(None);
+
+        Some(Self {
+            base: base.to_string(),
+            prefix,
+            major,
+            minor,
+        })
+    }
+
+    /// the detected version, i.e. `v1`, `v1.2`, or `1`
+    pub fn version(&self) -> String {
+        self.format_version(self.major, self.minor)
+    }
+
+    /// url of the detected version's root, i.e. http://localhost/api/v1/
+    pub fn version_url(&self) -> String {
+        format!("{}{}/", self.base, self.version())
+    }
+
+    /// urls of the next `NUM_PROBED_VERSIONS` versions; the minor version is incremented when the
+    /// detected version had one (v1.2 -> v1.3), otherwise the major version is (v1 -> v2)
+    pub fn next_versions(&self) -> Vec<String> {
+        (1..=NUM_PROBED_VERSIONS)
+            .map(|increment| {
+                let version = match self.minor {
+                    Some(minor) => self.format_version(self.major, Some(minor + increment)),
+                    None => self.format_version(self.major + increment, None),
+                };
+
+                format!("{}{}/", self.base, version)
+            })
+            .collect()
+    }
+
+    /// internal helper to stay DRY
+    fn format_version(&self, major: u64, minor: Option<u64>) -> String {
+        match minor {
+            Some(minor) => format!("{}{}.{}", self.prefix, major, minor),
+            None => format!("{}{}", self.prefix, major),
+        }
+    }
+}
+
+/// given a host and the root of an api version (i.e. /api/v2/), add it to `API_VERSIONS`
+/// returns `true` if the version wasn't previously known, `false` otherwise
+pub fn add_api_version(host: &str, version_path: &str) -> bool {
+    API_VERSIONS.update(host, |known| {
+        if known.iter().any(|path| path == version_path) {
+            return false;
+        }
+
+        log::info!("discovered new api version: {}{}", host, version_path);
+        known.push(version_path.to_string());
+        true
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// helper to create a VersionProber from a url string
+    fn prober(url: &str) -> Option<VersionProber> {
+        VersionProber::from_url(&Url::parse(url).unwrap())
+    }
+
+    #[test]
+    /// each supported pattern should be detected, and unversioned urls should be ignored
+    fn version_prober_detects_versioned_paths() {
+        let detected = prober("http://localhost/api/v1/users?id=1").unwrap();
+        assert_eq!(detected.version(), "v1");
+        assert_eq!(detected.version_url(), "http://localhost/api/v1/");
+
+        let detected = prober("http://localhost/v2.1").unwrap();
+        assert_eq!(detected.version(), "v2.1");
+        assert_eq!(detected.version_url(), "http://localhost/v2.1/");
+
+        let detected = prober("http://localhost/api/3/").unwrap();
+        assert_eq!(detected.version(), "3");
+        assert_eq!(detected.version_url(), "http://localhost/api/3/");
+
+        assert!(prober("http://localhost/api/users").is_none());
+        assert!(prober("http://localhost/video/").is_none());
+        assert!(prober("http://localhost/api/v1beta/").is_none());
+    }
+
+    #[test]
+    /// the next NUM_PROBED_VERSIONS versions should be generated, bumping the minor version
+    /// when there is one
+    fn version_prober_generates_next_versions() {
+        let next = prober("http://localhost/api/v1/users")
+            .unwrap()
+            .next_versions();
+        assert_eq!(next.len(), NUM_PROBED_VERSIONS as usize);
+        assert_eq!(next[0], "http://localhost/api/v2/");
+        assert_eq!(next[9], "http://localhost/api/v11/");
+
+        let next = prober("http://localhost/v1.2/").unwrap().next_versions();
+        assert_eq!(next[0], "http://localhost/v1.3/");
+
+        let next = prober("http://localhost/api/1/").unwrap().next_versions();
+        assert_eq!(next[0], "http://localhost/api/2/");
+    }
+}
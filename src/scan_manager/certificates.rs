@@ -0,0 +1,242 @@
+use std::{
+    net::{TcpStream, ToSocketAddrs},
+    time::Duration,
+};
+
+use lazy_static::lazy_static;
+use openssl::{
+    asn1::Asn1Time,
+    ssl::{SslConnector, SslMethod, SslVerifyMode},
+    x509::{X509NameRef, X509Ref},
+};
+use serde::Serialize;
+
+use super::HostCollector;
+
+/// certificates that expire within this many days are called out to the user
+pub const CERT_EXPIRY_WARNING_DAYS: i32 = 30;
+
+/// how long to wait on the tcp connection / tls handshake used to fetch a certificate
+const CERT_FETCH_TIMEOUT: Duration = Duration::from_secs(7);
+
+lazy_static! {
+    /// tls certificates fetched via --collect-certificates; `None` until the certificate has been
+    /// fetched, or if fetching it failed
+    pub static ref CERTIFICATES: HostCollector<Option<CertInfo>> = HostCollector::default();
+}
+
+/// details of a server's tls certificate collected via --collect-certificates
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct CertInfo {
+    /// certificate's subject, e.g. `CN=example.com, O=Example`
+    pub subject: String,
+
+    /// certificate's issuer, in the same format as `subject`
+    pub issuer: String,
+
+    /// dns names and ip addresses from the certificate's subject alternative names
+    pub san: Vec<String>,
+
+    /// certificate's notAfter date, e.g. `Jan  1 00:00:00 2030 GMT`
+    pub expiry: String,
+
+    /// number of whole days until the certificate expires; negative if it already has
+    pub days_until_expiry: i32,
+}
+
+/// Implementation of CertInfo
+impl CertInfo {
+    /// pull the interesting bits out of a parsed certificate
+    pub fn from_x509(cert: &X509Ref) -> Option<Self> {
+        let san = cert
+            .subject_alt_names()
+            .map(|names| {
+                names
+                    .iter()
+                    .filter_map(|name| {
+                        name.dnsname().map(String::from).or_else(|| {
+                            name.ipaddress().and_then(|ip| match ip.len() {
+                                4 => <[u8; 4]>::try_from(ip)
+                                    .ok()
+                                    .map(|octets| std::net::Ipv4Addr::from(octets).to_string()),
+                                16 => <[u8; 16]>::try_from(ip)
+                                    .ok()
+                                    .map(|octets| std::net::Ipv6Addr::from(octets).to_string()),
+                                _ => None,
+                            })
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let now = Asn1Time::days_from_now(0).ok()?;
+        let days_until_expiry = now.diff(cert.not_after()).ok()?.days;
+
+        Some(Self {
+            subject: format_name(cert.subject_name()),
+            issuer: format_name(cert.issuer_name()),
+            san,
+            expiry: cert.not_after().to_string(),
+            days_until_expiry,
+        })
+    }
+
+    /// whether the certificate expires within `CERT_EXPIRY_WARNING_DAYS` (or already has)
+    pub fn is_expiring_soon(&self) -> bool {
+        self.days_until_expiry <= CERT_EXPIRY_WARNING_DAYS
+    }
+}
+
+/// join a name's entries into a single `KEY=value, KEY=value` string
+fn format_name(name: &X509NameRef) -> String {
+    name.entries()
+        .filter_map(|entry| {
+            let key = entry.object().nid().short_name().ok()?;
+            let value = entry.data().as_utf8().ok()?;
+            Some(format!("{}={}", key, value))
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// connect to `host`:`port`, perform a tls handshake and return the server's certificate
+///
+/// the certificate isn't validated, since invalid certificates are just as interesting as valid
+/// ones. `host` is sent as the SNI value, so name-based virtual hosts return their own
+/// certificate. this is a blocking call; returns `None` on any failure
+pub fn fetch_certificate(host: &str, port: u16) -> Option<CertInfo> {
+    log::trace!("enter: fetch_certificate({}, {})", host, port);
+
+    let addr = (host, port).to_socket_addrs().ok()?.next()?;
+
+    let stream = TcpStream::connect_timeout(&addr, CERT_FETCH_TIMEOUT).ok()?;
+    stream.set_read_timeout(Some(CERT_FETCH_TIMEOUT)).ok()?;
+    stream.set_write_timeout(Some(CERT_FETCH_TIMEOUT)).ok()?;
+
+    let mut builder = SslConnector::builder(SslMethod::tls()).ok()?;
+    builder.set_verify(SslVerifyMode::NONE);
+
+    let mut connector = builder.build().configure().ok()?;
+    connector.set_verify_hostname(false);
+
+    let tls_stream = match connector.connect(host, stream) {
+        Ok(tls_stream) => tls_stream,
+        Err(e) => {
+            log::warn!(
+                "Could not complete tls handshake with {}:{}: {}",
+                host,
+                port,
+                e
+            );
+            return None;
+        }
+    };
+
+    let cert_info = tls_stream
+        .ssl()
+        .peer_certificate()
+        .and_then(|cert| CertInfo::from_x509(&cert));
+
+    log::trace!("exit: fetch_certificate -> {:?}", cert_info);
+    cert_info
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use openssl::{
+        asn1::Asn1Time,
+        ec::{EcGroup, EcKey},
+        hash::MessageDigest,
+        nid::Nid,
+        pkey::{PKey, Private},
+        ssl::{SslAcceptor, SslMethod},
+        x509::{extension::SubjectAlternativeName, X509NameBuilder, X509},
+    };
+    use std::{net::TcpListener, thread};
+
+    /// build a self-signed certificate for localhost that expires `days` from now
+    fn self_signed(days: u32) -> (X509, PKey<Private>) {
+        let group = EcGroup::from_curve_name(Nid::X9_62_PRIME256V1).unwrap();
+        let key = PKey::from_ec_key(EcKey::generate(&group).unwrap()).unwrap();
+
+        let mut name = X509NameBuilder::new().unwrap();
+        name.append_entry_by_text("CN", "localhost").unwrap();
+        name.append_entry_by_text("O", "feroxbuster").unwrap();
+        let name = name.build();
+
+        let mut builder = X509::builder().unwrap();
+        builder.set_version(2).unwrap();
+        builder.set_subject_name(&name).unwrap();
+        builder.set_issuer_name(&name).unwrap();
+        builder.set_pubkey(&key).unwrap();
+        builder
+            .set_not_before(&Asn1Time::days_from_now(0).unwrap())
+            .unwrap();
+        builder
+            .set_not_after(&Asn1Time::days_from_now(days).unwrap())
+            .unwrap();
+
+        let san = SubjectAlternativeName::new()
+            .dns("localhost")
+            .ip("127.0.0.1")
+            .build(&builder.x509v3_context(None, None))
+            .unwrap();
+        builder.append_extension(san).unwrap();
+
+        builder.sign(&key, MessageDigest::sha256()).unwrap();
+
+        (builder.build(), key)
+    }
+
+    #[test]
+    /// from_x509 should pull out the subject, issuer, sans and expiry
+    fn cert_info_from_x509_reads_certificate_fields() {
+        let (cert, _) = self_signed(10);
+
+        let info = CertInfo::from_x509(&cert).unwrap();
+
+        assert_eq!(info.subject, "CN=localhost, O=feroxbuster");
+        assert_eq!(info.issuer, info.subject);
+        assert_eq!(info.san, ["localhost", "127.0.0.1"]);
+        assert_eq!(info.expiry, cert.not_after().to_string());
+        assert_eq!(info.days_until_expiry, 10);
+        assert!(info.is_expiring_soon());
+
+        let (cert, _) = self_signed(365);
+        assert!(!CertInfo::from_x509(&cert).unwrap().is_expiring_soon());
+    }
+
+    #[test]
+    /// fetch_certificate should return the certificate of a local tls server, and None when
+    /// nothing is listening
+    fn fetch_certificate_returns_server_certificate() {
+        let (cert, key) = self_signed(90);
+
+        let mut acceptor = SslAcceptor::mozilla_intermediate_v5(SslMethod::tls()).unwrap();
+        acceptor.set_private_key(&key).unwrap();
+        acceptor.set_certificate(&cert).unwrap();
+        let acceptor = acceptor.build();
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        thread::spawn(move || {
+            if let Ok((stream, _)) = listener.accept() {
+                let _ = acceptor.accept(stream);
+            }
+        });
+
+        let info = fetch_certificate("127.0.0.1", port).unwrap();
+
+        assert_eq!(info.subject, "CN=localhost, O=feroxbuster");
+        assert_eq!(info.days_until_expiry, 90);
+
+        let closed = TcpListener::bind("127.0.0.1:0").unwrap();
+        let closed_port = closed.local_addr().unwrap().port();
+        drop(closed);
+
+        assert!(fetch_certificate("127.0.0.1", closed_port).is_none());
+    }
+}
@@ -0,0 +1,195 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::RwLock,
+};
+
+use reqwest::Url;
+
+/// key that values found on `url` are collected under, i.e. `localhost` or `localhost:8443`
+pub fn host_key(url: &Url) -> Option<String> {
+    let host = url.host_str()?;
+
+    match url.port() {
+        Some(port) => Some(format!("{}:{}", host, port)),
+        None => Some(host.to_string()),
+    }
+}
+
+/// values collected while scanning (page titles, certificates, etc), grouped by the host they
+/// were found on
+#[derive(Debug)]
+pub struct HostCollector<T> {
+    /// each host's collected values, in host order
+    hosts: RwLock<BTreeMap<String, T>>,
+}
+
+/// implement Default trait for HostCollector
+impl<T> Default for HostCollector<T> {
+    fn default() -> Self {
+        Self {
+            hosts: RwLock::new(BTreeMap::new()),
+        }
+    }
+}
+
+/// Implementation of HostCollector
+impl<T: Default> HostCollector<T> {
+    /// add an empty entry for `host`; returns `true` if the host wasn't previously known
+    pub fn add_host(&self, host: &str) -> bool {
+        if let Ok(hosts) = self.hosts.read() {
+            // quicker to allow most to read and return and then reopen for write if necessary
+            if hosts.contains_key(host) {
+                return false;
+            }
+        }
+
+        match self.hosts.write() {
+            Ok(mut hosts) => {
+                if hosts.contains_key(host) {
+                    return false;
+                }
+
+                hosts.insert(host.to_string(), T::default());
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// run `update` against `host`'s entry, adding an empty one first if needed, and return
+    /// whatever `update` returns
+    pub fn update<R: Default>(&self, host: &str, update: impl FnOnce(&mut T) -> R) -> R {
+        match self.hosts.write() {
+            Ok(mut hosts) => update(hosts.entry(host.to_string()).or_default()),
+            Err(_) => R::default(),
+        }
+    }
+}
+
+/// Implementation of HostCollector
+impl<T: Clone> HostCollector<T> {
+    /// (host, collected values) pairs, sorted by host
+    pub fn by_host(&self) -> Vec<(String, T)> {
+        match self.hosts.read() {
+            Ok(hosts) => hosts
+                .iter()
+                .map(|(host, values)| (host.to_owned(), values.clone()))
+                .collect(),
+            Err(_) => Vec::new(),
+        }
+    }
+}
+
+/// Implementation of HostCollector for values that are collected as a set
+impl<V: Ord> HostCollector<BTreeSet<V>> {
+    /// add `values` to `host`'s set; returns the number of values that weren't previously known
+    ///
+    /// hosts are only added once there's at least one value for them
+    pub fn extend(&self, host: &str, values: impl IntoIterator<Item = V>) -> usize {
+        let mut values = values.into_iter().peekable();
+
+        if values.peek().is_none() {
+            return 0;
+        }
+
+        self.update(host, |known| {
+            values.fold(0, |num_added, value| match known.insert(value) {
+                true => num_added + 1,
+                false => num_added,
+            })
+        })
+    }
+}
+
+/// Implementation of HostCollector for values that are collected by some key (i.e. a url)
+impl<K: Ord, V> HostCollector<BTreeMap<K, V>> {
+    /// set `key` to `value` in `host`'s map; returns `true` if the key wasn't previously known
+    pub fn insert(&self, host: &str, key: K, value: V) -> bool {
+        self.update(host, |known| known.insert(key, value).is_none())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// the port should only be part of the key when the url has a non-default one
+    fn host_key_includes_non_default_port() {
+        let key = |url: &str| host_key(&Url::parse(url).unwrap());
+
+        assert_eq!(
+            key("https://localhost/login"),
+            Some(String::from("localhost"))
+        );
+        assert_eq!(
+            key("https://localhost:443/"),
+            Some(String::from("localhost"))
+        );
+        assert_eq!(key("http://[::1]:8080/"), Some(String::from("[::1]:8080")));
+        assert_eq!(key("data:text/plain,stuff"), None);
+    }
+
+    #[test]
+    /// each host gets its own entry, created on first use, and hosts come back sorted
+    fn host_collector_tracks_values_per_host() {
+        let collector: HostCollector<Vec<&str>> = HostCollector::default();
+
+        collector.update("otherhost", |values| values.push("a"));
+        collector.update("localhost", |values| values.push("b"));
+        let len = collector.update("localhost", |values| {
+            values.push("c");
+            values.len()
+        });
+
+        assert_eq!(len, 2);
+        assert!(collector.add_host("example.com"));
+        assert!(!collector.add_host("example.com"));
+        assert!(!collector.add_host("localhost"));
+
+        assert_eq!(
+            collector.by_host(),
+            [
+                (String::from("example.com"), vec![]),
+                (String::from("localhost"), vec!["b", "c"]),
+                (String::from("otherhost"), vec!["a"]),
+            ]
+        );
+    }
+
+    #[test]
+    /// sets should only count values that are new, and skip hosts that have nothing to add
+    fn host_collector_extend_counts_new_values() {
+        let collector: HostCollector<BTreeSet<&str>> = HostCollector::default();
+
+        assert_eq!(collector.extend("localhost", ["id", "page"]), 2);
+        assert_eq!(collector.extend("localhost", ["page", "sort", "sort"]), 1);
+        assert_eq!(collector.extend("otherhost", []), 0);
+
+        assert_eq!(
+            collector.by_host(),
+            [(
+                String::from("localhost"),
+                BTreeSet::from(["id", "page", "sort"])
+            )]
+        );
+    }
+
+    #[test]
+    /// keyed values should be replaced, but only report being added the first time
+    fn host_collector_insert_reports_new_keys() {
+        let collector: HostCollector<BTreeMap<&str, &str>> = HostCollector::default();
+
+        assert!(collector.insert("localhost", "/login", "Login"));
+        assert!(!collector.insert("localhost", "/login", "Sign In"));
+        assert!(collector.insert("localhost", "/admin", "Admin"));
+
+        assert_eq!(
+            collector.by_host(),
+            [(
+                String::from("localhost"),
+                BTreeMap::from([("/admin", "Admin"), ("/login", "Sign In")])
+            )]
+        );
+    }
+}
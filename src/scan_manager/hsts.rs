@@ -0,0 +1,108 @@
+use std::{collections::BTreeMap, fmt};
+
+use lazy_static::lazy_static;
+use reqwest::header::{HeaderMap, STRICT_TRANSPORT_SECURITY};
+
+use super::HostCollector;
+
+/// smallest max-age (in seconds) that --collect-hsts doesn't flag as weak; 120 days
+pub const HSTS_MIN_MAX_AGE: u64 = 10_368_000;
+
+lazy_static! {
+    /// https urls flagged by --collect-hsts for a missing or weak Strict-Transport-Security
+    /// header, keyed by url
+    pub static ref HSTS_FINDINGS: HostCollector<BTreeMap<String, HstsStatus>> =
+        HostCollector::default();
+}
+
+/// result of checking an https response's Strict-Transport-Security header via --collect-hsts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HstsStatus {
+    /// no Strict-Transport-Security header was sent
+    Missing,
+
+    /// the header's max-age is below `HSTS_MIN_MAX_AGE`, or couldn't be parsed (shown as 0)
+    Weak(u64),
+
+    /// the header's max-age is at least `HSTS_MIN_MAX_AGE`
+    Strong,
+}
+
+/// Display implementation for HstsStatus
+impl fmt::Display for HstsStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HstsStatus::Missing => write!(f, "missing"),
+            HstsStatus::Weak(max_age) => write!(f, "weak (max-age={})", max_age),
+            HstsStatus::Strong => write!(f, "strong"),
+        }
+    }
+}
+
+/// the value of the max-age directive in the given Strict-Transport-Security header value
+fn parse_max_age(value: &str) -> Option<u64> {
+    value.split(';').find_map(|directive| {
+        let (name, age) = directive.split_once('=')?;
+
+        if !name.trim().eq_ignore_ascii_case("max-age") {
+            return None;
+        }
+
+        age.trim().trim_matches('"').parse().ok()
+    })
+}
+
+/// check the Strict-Transport-Security header of the response to `url`
+pub fn check_hsts(headers: &HeaderMap, url: &str) -> HstsStatus {
+    let status = match headers.get(STRICT_TRANSPORT_SECURITY) {
+        None => HstsStatus::Missing,
+        Some(value) => {
+            let max_age = value.to_str().ok().and_then(parse_max_age).unwrap_or(0);
+
+            if max_age < HSTS_MIN_MAX_AGE {
+                HstsStatus::Weak(max_age)
+            } else {
+                HstsStatus::Strong
+            }
+        }
+    };
+
+    log::debug!("{} has {} hsts", url, status);
+    status
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// headers should be classified by whether they're present and how long their max-age is
+    fn check_hsts_classifies_max_age() {
+        let url = "https://localhost/";
+        let mut headers = HeaderMap::new();
+        assert_eq!(check_hsts(&headers, url), HstsStatus::Missing);
+
+        headers.insert(STRICT_TRANSPORT_SECURITY, "max-age=86400".parse().unwrap());
+        assert_eq!(check_hsts(&headers, url), HstsStatus::Weak(86400));
+
+        headers.insert(
+            STRICT_TRANSPORT_SECURITY,
+            "includeSubDomains; Max-Age=\"31536000\"; preload"
+                .parse()
+                .unwrap(),
+        );
+        assert_eq!(check_hsts(&headers, url), HstsStatus::Strong);
+
+        headers.insert(
+            STRICT_TRANSPORT_SECURITY,
+            "max-age=10368000".parse().unwrap(),
+        );
+        assert_eq!(check_hsts(&headers, url), HstsStatus::Strong);
+
+        headers.insert(
+            STRICT_TRANSPORT_SECURITY,
+            "includeSubDomains".parse().unwrap(),
+        );
+        assert_eq!(check_hsts(&headers, url), HstsStatus::Weak(0));
+    }
+}
@@ -1,5 +1,5 @@
 use crate::filters::filter_lookup;
-use crate::progress::PROGRESS_BAR;
+use crate::progress::{live_output, PROGRESS_BAR};
 use crate::traits::FeroxFilter;
 use console::{measure_text_width, pad_str, style, Alignment, Term};
 use indicatif::ProgressDrawTarget;
@@ -14,11 +14,26 @@ pub enum MenuCmd {
     /// user wants to cancel one or more active scans
     Cancel(Vec<usize>, bool),
 
+    /// user wants to cancel every active scan
+    CancelAll(bool),
+
     /// user wants to create a new filter
     AddFilter(Box<dyn FeroxFilter>),
 
     /// user wants to remove one or more active filters
     RemoveFilter(Vec<usize>),
+
+    /// user wants to pause one or more active scans, leaving the others running
+    PauseScans(Vec<usize>),
+
+    /// user wants to unpause one or more scans paused with `PauseScans`
+    ResumeScans(Vec<usize>),
+
+    /// user wants to leave the menu and continue scanning
+    Resume,
+
+    /// user wants to stop scanning altogether
+    Quit,
 }
 
 /// Data container for a command result to be used internally by the ferox_scanner
@@ -86,29 +101,64 @@ impl Menu {
         );
 
         let valid_filters = format!(
-            "    FILTER_TYPEs: {}, {}, {}, {}, {}, {}\n",
+            "    FILTER_TYPEs: {}, {}, {}, {}, {}, {}, {}, {}, {}, {}\n",
             style("status").yellow(),
             style("lines").yellow(),
+            style("lines-range").yellow(),
             style("size").yellow(),
+            style("size-range").yellow(),
             style("words").yellow(),
+            style("words-range").yellow(),
             style("regex").yellow(),
+            style("extension").yellow(),
             style("similarity").yellow()
         );
 
         let rm_filter_cmd = format!(
-            "  {}[{}] FILTER_ID[-FILTER_ID[,...]] (ex: {} 1-4,8,9-13 or {} 3)",
+            "  {}[{}] FILTER_ID[-FILTER_ID[,...]] (ex: {} 1-4,8,9-13 or {} 3)\n",
             style("r").red(),
             style("m-filter").red(),
             style("rm-filter").red(),
             style("r").red(),
         );
 
+        let pause_cmd = format!(
+            "  {}[{}] SCAN_ID[-SCAN_ID[,...]] (ex: {} 1-4,8 or {} 3)\n",
+            style("p").yellow(),
+            style("ause").yellow(),
+            style("pause").yellow(),
+            style("p").yellow(),
+        );
+
+        let resume_scans_cmd = format!(
+            "  {}[{}] SCAN_ID[-SCAN_ID[,...]] (ex: {} 1-4,8 or {} 3)",
+            style("u").green(),
+            style("npause").green(),
+            style("unpause").green(),
+            style("u").green(),
+        );
+
+        let canx_all_cmd = format!("  {} [-f] (cancel every running scan)\n", style("a").red(),);
+
+        let resume_cmd = format!("\n  {} or ENTER (resume scanning)\n", style("r").green(),);
+
+        let quit_cmd = format!(
+            "  {}[{}] (stop scanning and exit)",
+            style("q").red(),
+            style("uit").red(),
+        );
+
         let mut commands = format!("{}:\n", style("Commands").bright().blue());
         commands.push_str(&add_cmd);
         commands.push_str(&canx_cmd);
+        commands.push_str(&canx_all_cmd);
         commands.push_str(&new_filter_cmd);
         commands.push_str(&valid_filters);
         commands.push_str(&rm_filter_cmd);
+        commands.push_str(&pause_cmd);
+        commands.push_str(&resume_scans_cmd);
+        commands.push_str(&resume_cmd);
+        commands.push_str(&quit_cmd);
 
         let longest = measure_text_width(&canx_cmd).max(measure_text_width(&name));
 
@@ -149,6 +199,11 @@ impl Menu {
 
     /// set PROGRESS_BAR bar target to hidden
     pub(super) fn show_progress_bars(&self) {
+        if live_output() {
+            // --live-output; progress bars stay hidden
+            return;
+        }
+
         PROGRESS_BAR.set_draw_target(ProgressDrawTarget::stdout());
     }
 
@@ -222,10 +277,28 @@ impl Menu {
         nums
     }
 
-    /// get input from the user and translate it to a `MenuCmd`
+    /// split a list of scan/filter ids given to a menu command into a vec of usizes; returns None
+    /// when the list is empty or contains anything other than numbers, commas, and dashes
+    fn split_to_ids(&self, line: &str) -> Option<Vec<usize>> {
+        let re = Regex::new(r"^[\d,\-\s]+$").unwrap();
+
+        if !re.is_match(line.trim()) {
+            return None;
+        }
+
+        Some(self.split_to_nums(line))
+    }
+
+    /// get input from the user and translate it to a `MenuCmd`; returns None when the input
+    /// isn't a valid command
     pub(super) fn get_command_input_from_user(&self, line: &str) -> Option<MenuCmd> {
         let line = line.trim(); // normalize input if there are leading spaces
 
+        if line.is_empty() {
+            // user just hit enter
+            return Some(MenuCmd::Resume);
+        }
+
         match line.chars().next().unwrap_or('_').to_ascii_lowercase() {
             'c' => {
                 // cancel command; start by determining if -f was used
@@ -237,9 +310,18 @@ impl Menu {
                 let line = line.replace("-f", "");
                 let line = re.replace(&line, "").to_string();
 
-                Some(MenuCmd::Cancel(self.split_to_nums(&line), force))
+                let indices = self.split_to_ids(&line)?;
+
+                Some(MenuCmd::Cancel(indices, force))
             }
             'a' => {
+                // a lone `a` (optionally with -f) cancels every running scan
+                let re = Regex::new(r"^[aA]\s*(-f)?$").unwrap();
+
+                if let Some(captures) = re.captures(line) {
+                    return Some(MenuCmd::CancelAll(captures.get(1).is_some()));
+                }
+
                 // add command
                 // similar to cancel, we need to remove the a[dd] substring, the rest should be
                 // a url
@@ -265,6 +347,26 @@ impl Menu {
                 }
                 None
             }
+            'p' => {
+                // pause command; remove p[ause] from the command so it can be passed to the
+                // number splitter
+                let re = Regex::new(r"^[pP][auseAUSE]*").unwrap();
+                let line = re.replace(line, "").to_string();
+
+                Some(MenuCmd::PauseScans(self.split_to_ids(&line)?))
+            }
+            'u' => {
+                // unpause command; remove u[npause] from the command so it can be passed to the
+                // number splitter
+                let re = Regex::new(r"^[uU][npauseNPAUSE]*").unwrap();
+                let line = re.replace(line, "").to_string();
+
+                Some(MenuCmd::ResumeScans(self.split_to_ids(&line)?))
+            }
+            'r' if line.len() == 1 => {
+                // a lone `r` resumes scanning, same as ENTER
+                Some(MenuCmd::Resume)
+            }
             'r' => {
                 // remove filter command
 
@@ -276,10 +378,20 @@ impl Menu {
                 let line = line.replace("-f", "");
                 let line = re.replace(&line, "").to_string();
 
-                let indices = self.split_to_nums(&line);
+                let indices = self.split_to_ids(&line)?;
 
                 Some(MenuCmd::RemoveFilter(indices))
             }
+            'q' => {
+                // quit command
+                let re = Regex::new(r"^[qQ]([uU][iI][tT])?$").unwrap();
+
+                if re.is_match(line) {
+                    Some(MenuCmd::Quit)
+                } else {
+                    None
+                }
+            }
             _ => {
                 // invalid input
                 None
@@ -0,0 +1,52 @@
+use std::collections::{BTreeMap, HashMap};
+
+use lazy_static::lazy_static;
+
+use super::HostCollector;
+
+/// meta tags that --collect-meta-tags calls out in its end-of-scan summary
+pub const INTERESTING_META_TAGS: [&str; 4] = ["generator", "author", "description", "keywords"];
+
+/// (name, content) pairs of a single page's meta tags
+pub type MetaTags = HashMap<String, String>;
+
+/// meta tags of the pages of a single host, keyed by url, with urls and names in sorted order
+pub type PageMetaTags = BTreeMap<String, BTreeMap<String, String>>;
+
+lazy_static! {
+    /// each html page's <meta> tags collected via --collect-meta-tags
+    pub static ref META_TAGS: HostCollector<PageMetaTags> = HostCollector::default();
+}
+
+/// (name, content) pairs of the given tags that are in `INTERESTING_META_TAGS`, in the order
+/// they appear there
+pub fn interesting_meta_tags(tags: &BTreeMap<String, String>) -> Vec<(&str, &str)> {
+    INTERESTING_META_TAGS
+        .iter()
+        .filter_map(|name| Some((*name, tags.get(*name)?.as_str())))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// only interesting tags should be returned, in the order of INTERESTING_META_TAGS
+    fn interesting_meta_tags_skips_other_tags() {
+        let tags: BTreeMap<_, _> = [
+            ("viewport", "width=device-width"),
+            ("keywords", "admin, login"),
+            ("generator", "WordPress 6.1"),
+        ]
+        .into_iter()
+        .map(|(name, content)| (name.to_string(), content.to_string()))
+        .collect();
+
+        assert_eq!(
+            interesting_meta_tags(&tags),
+            [("generator", "WordPress 6.1"), ("keywords", "admin, login")]
+        );
+        assert!(interesting_meta_tags(&BTreeMap::new()).is_empty());
+    }
+}
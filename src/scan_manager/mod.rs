@@ -1,18 +1,60 @@
+mod api_versions;
+mod certificates;
+mod host_collector;
+mod hsts;
 mod scan_container;
 mod response_container;
 mod scan;
+mod scan_stats;
+mod security_headers;
 mod menu;
+mod meta_tags;
+mod page_titles;
+mod parameters;
+mod redirect_targets;
 mod utils;
 mod order;
+mod source_maps;
 mod state;
+mod subpath_words;
+mod tech_stack;
+mod timing;
 #[cfg(test)]
 mod tests;
 
-pub(self) use menu::Menu;
+pub use api_versions::{add_api_version, VersionProber, API_VERSIONS, NUM_PROBED_VERSIONS};
+pub use certificates::{fetch_certificate, CertInfo, CERTIFICATES};
+pub use host_collector::{host_key, HostCollector};
+pub use hsts::{check_hsts, HstsStatus, HSTS_FINDINGS, HSTS_MIN_MAX_AGE};
+use menu::Menu;
 pub use menu::{MenuCmd, MenuCmdResult};
+pub use meta_tags::{
+    interesting_meta_tags, MetaTags, PageMetaTags, INTERESTING_META_TAGS, META_TAGS,
+};
 pub use order::ScanOrder;
+pub use page_titles::PAGE_TITLES;
+pub use parameters::COLLECTED_PARAMETERS;
+pub use redirect_targets::REDIRECT_TARGETS;
 pub use response_container::FeroxResponses;
-pub use scan::{FeroxScan, ScanStatus, ScanType};
+pub use scan::{detect_scan_type, FeroxScan, ScanStatus, ScanType};
 pub use scan_container::{FeroxScans, PAUSE_SCAN};
-pub use state::FeroxState;
-pub use utils::{resume_scan, start_max_time_thread};
+pub use scan_stats::ScanStats;
+pub use security_headers::{
+    check_security_headers, MissingSecurityHeaders, MISSING_SECURITY_HEADERS, SECURITY_HEADERS,
+};
+pub use source_maps::SOURCE_MAP_PATHS;
+pub use state::{
+    load_state, print_state_diff, remove_state_file, save_state, split_state_filename,
+    state_filename, write_state_file, FeroxState, FeroxStateDiff, SavedState,
+};
+pub use subpath_words::SUBPATH_WORDS;
+pub use tech_stack::{detect_technologies, TechStack, TECH_STACKS};
+use timing::ResponseTimes;
+pub use timing::ScanTimingStats;
+pub use utils::{
+    export_metasploit, report_api_versions, report_cancelled_scans, report_certificates,
+    report_collected_parameters, report_failed_scans, report_hsts, report_meta_tags,
+    report_page_titles, report_redirect_targets, report_scan_timings, report_security_headers,
+    report_source_map_paths, report_subpath_words, report_technology_stack, resume_scan,
+    start_checkpoint_thread, start_max_time_thread, start_status_thread,
+};
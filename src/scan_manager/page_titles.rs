@@ -0,0 +1,10 @@
+use std::collections::BTreeMap;
+
+use lazy_static::lazy_static;
+
+use super::HostCollector;
+
+lazy_static! {
+    /// page titles collected via --collect-page-titles, keyed by url
+    pub static ref PAGE_TITLES: HostCollector<BTreeMap<String, String>> = HostCollector::default();
+}
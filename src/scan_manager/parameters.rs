@@ -0,0 +1,10 @@
+use std::collections::BTreeSet;
+
+use lazy_static::lazy_static;
+
+use super::HostCollector;
+
+lazy_static! {
+    /// query parameter names discovered via --collect-parameters
+    pub static ref COLLECTED_PARAMETERS: HostCollector<BTreeSet<String>> = HostCollector::default();
+}
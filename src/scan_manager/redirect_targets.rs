@@ -0,0 +1,11 @@
+use std::collections::BTreeSet;
+
+use lazy_static::lazy_static;
+
+use super::HostCollector;
+
+lazy_static! {
+    /// (source, destination) pairs of 3xx responses collected via --collect-301-targets
+    pub static ref REDIRECT_TARGETS: HostCollector<BTreeSet<(String, String)>> =
+        HostCollector::default();
+}
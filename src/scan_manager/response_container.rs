@@ -1,12 +1,19 @@
-use crate::response::FeroxResponse;
+use crate::{response::FeroxResponse, utils::normalize_case};
 use serde::{ser::SerializeSeq, Serialize, Serializer};
-use std::sync::{Arc, RwLock};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, RwLock,
+};
 
 /// Container around a locked vector of `FeroxResponse`s, adds wrappers for insertion and search
 #[derive(Debug, Default)]
 pub struct FeroxResponses {
     /// Internal structure: locked hashset of `FeroxScan`s
     pub responses: Arc<RwLock<Vec<FeroxResponse>>>,
+
+    /// whether responses with the same body as a known response are dropped on insertion;
+    /// set from --filter-duplicate-content
+    filter_duplicate_content: AtomicBool,
 }
 
 /// Serialize implementation for FeroxResponses
@@ -34,22 +41,113 @@ impl Serialize for FeroxResponses {
 
 /// Implementation of `FeroxResponses`
 impl FeroxResponses {
+    /// serialize the known responses as a JSON array, i.e. the `responses` entry of a state file
+    pub fn to_json_array(&self) -> String {
+        serde_json::to_string(self).unwrap_or_else(|_| String::from("[]"))
+    }
+
+    /// turn dropping of responses with duplicate content on or off, i.e. --filter-duplicate-content
+    pub fn set_filter_duplicate_content(&self, enabled: bool) {
+        self.filter_duplicate_content
+            .store(enabled, Ordering::Relaxed);
+    }
+
     /// Add a `FeroxResponse` to the internal container
-    pub fn insert(&self, response: FeroxResponse) {
+    ///
+    /// when --filter-duplicate-content is enabled, a response whose body matches that of a known
+    /// response is silently dropped; returns whether the response was added
+    pub fn insert(&self, response: FeroxResponse) -> bool {
+        if self.filter_duplicate_content.load(Ordering::Relaxed)
+            && self.contains_content(response.content_hash())
+        {
+            return false;
+        }
+
         if let Ok(mut responses) = self.responses.write() {
             responses.push(response);
+            return true;
+        }
+        false
+    }
+
+    /// whether any known response has a body with the given hash; responses with an empty body
+    /// (hash of 0) never match
+    pub fn contains_content(&self, content_hash: u64) -> bool {
+        if content_hash == 0 {
+            return false;
+        }
+
+        match self.responses.read() {
+            Ok(responses) => responses
+                .iter()
+                .any(|response| response.content_hash() == content_hash),
+            Err(_) => false,
         }
     }
 
-    /// Simple check for whether or not a FeroxResponse is contained within the inner container
-    pub fn contains(&self, other: &FeroxResponse) -> bool {
+    /// Simple check for whether or not a FeroxResponse is contained within the inner container;
+    /// when `case_insensitive` is true, urls differing only in the case of their path are equal
+    pub fn contains(&self, other: &FeroxResponse, case_insensitive: bool) -> bool {
+        let other_url = normalize_case(other.url().as_str(), case_insensitive);
+
         if let Ok(responses) = self.responses.read() {
             for response in responses.iter() {
-                if response.url() == other.url() && response.method() == other.method() {
+                if normalize_case(response.url().as_str(), case_insensitive) == other_url
+                    && response.method() == other.method()
+                    && response.upload_filename() == other.upload_filename()
+                {
                     return true;
                 }
             }
         }
         false
     }
+
+    /// clones of the known responses whose status code is one of `codes`
+    pub fn filter_by_status(&self, codes: &[u16]) -> Vec<FeroxResponse> {
+        match self.responses.read() {
+            Ok(responses) => responses
+                .iter()
+                .filter(|response| codes.contains(&response.status().as_u16()))
+                .cloned()
+                .collect(),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    /// clones of the known responses whose url is `prefix` or falls beneath it; `prefix` is
+    /// treated as a path, so http://localhost/js doesn't match http://localhost/json
+    pub fn filter_by_url_prefix(&self, prefix: &str) -> Vec<FeroxResponse> {
+        match self.responses.read() {
+            Ok(responses) => responses
+                .iter()
+                .filter(|response| is_beneath(response, prefix))
+                .cloned()
+                .collect(),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    /// number of known responses whose url is `prefix` or falls beneath it; same matching as
+    /// `filter_by_url_prefix`, without cloning anything
+    pub fn count_by_url_prefix(&self, prefix: &str) -> usize {
+        match self.responses.read() {
+            Ok(responses) => responses
+                .iter()
+                .filter(|response| is_beneath(response, prefix))
+                .count(),
+            Err(_) => 0,
+        }
+    }
+}
+
+/// whether the response's url is `prefix` or falls beneath it, treating `prefix` as a path
+fn is_beneath(response: &FeroxResponse, prefix: &str) -> bool {
+    let directory = prefix.trim_end_matches('/');
+
+    response
+        .url()
+        .as_str()
+        .strip_prefix(directory)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with(['/', '?', '#']))
 }
@@ -2,24 +2,42 @@ use super::*;
 use crate::{
     config::OutputLevel,
     progress::{add_bar, BarType},
+    response::FeroxResponse,
     scanner::PolicyTrigger,
 };
 use anyhow::Result;
 use console::style;
 use indicatif::ProgressBar;
+use reqwest::{header::CONTENT_TYPE, StatusCode, Url};
 use serde::{ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value;
 use std::{
     collections::HashMap,
     fmt,
     sync::{Arc, Mutex},
-    time::Instant,
+    time::{Duration, Instant},
 };
 
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, AtomicUsize, Ordering};
 use tokio::{sync, task::JoinHandle};
 use uuid::Uuid;
 
+/// how often a rate limited scan's token bucket is refilled
+const RATE_LIMIT_REFILL_PERIOD: Duration = Duration::from_millis(100);
+
+/// number of permits to add to the token bucket of a scan limited to `limit` requests per second
+/// on each refill; the fraction of a permit that doesn't fit is carried over to the next refill
+/// in `remainder`, so that i.e. a limit of 15 adds 15 permits per second instead of 10
+fn refill_amount(limit: usize, remainder: &mut usize) -> usize {
+    let refills_per_second = (1000 / RATE_LIMIT_REFILL_PERIOD.as_millis()) as usize;
+
+    *remainder += limit;
+    let amount = *remainder / refills_per_second;
+    *remainder %= refills_per_second;
+
+    amount
+}
+
 /// Struct to hold scan-related state
 ///
 /// The purpose of this container is to open up the pathway to aborting currently running tasks and
@@ -29,11 +47,15 @@ pub struct FeroxScan {
     /// UUID that uniquely ID's the scan
     pub(super) id: String,
 
+    /// ID of the scan that spawned this one (i.e. its parent directory's scan), if any
+    pub(super) parent_id: Option<String>,
+
     /// The URL that to be scanned
     pub(super) url: String,
 
-    /// The type of scan
-    pub scan_type: ScanType,
+    /// The type of scan; a scan of an Unknown type is updated once a response for its url
+    /// arrives
+    pub(super) scan_type: Mutex<ScanType>,
 
     /// The order in which the scan was received
     pub(crate) scan_order: ScanOrder,
@@ -41,12 +63,27 @@ pub struct FeroxScan {
     /// Number of requests to populate the progress bar with
     pub(super) num_requests: u64,
 
+    /// Number of requests made by this scan before it was saved to a state file; only non-zero
+    /// for scans restored via --resume-from
+    pub(super) requests_made_so_far: u64,
+
+    /// Number of times this scan has been resumed from a state file without completing
+    pub(super) retry_count: u32,
+
     /// Status of this scan
     pub status: Mutex<ScanStatus>,
 
     /// The spawned tokio task performing this scan (uses tokio::sync::Mutex)
     pub(super) task: sync::Mutex<Option<JoinHandle<()>>>,
 
+    /// whether an abort was requested for this scan; a scan that was asked to stop is marked
+    /// Cancelled instead of Complete, even if its task runs to the end before it can be aborted
+    pub(super) abort_requested: AtomicBool,
+
+    /// whether the user paused this scan from the interactive menu; unlike `PAUSE_SCAN`, only
+    /// this scan stops making requests. shared with the scan's request tasks
+    pub(super) paused: Arc<AtomicBool>,
+
     /// The progress bar associated with this scan
     pub(super) progress_bar: Mutex<Option<ProgressBar>>,
 
@@ -62,8 +99,42 @@ pub struct FeroxScan {
     /// tracker for total number of errors encountered by the FeroxScan instance
     pub(super) errors: AtomicUsize,
 
+    /// tracker for number of responses found (i.e. reported) by the FeroxScan instance
+    pub(super) found_count: AtomicU32,
+
+    /// tracker for number of requests that got a response for the FeroxScan instance; unlike
+    /// the progress bar's position, it only counts requests made by this scan
+    pub(super) requests_made: AtomicUsize,
+
+    /// tracker for number of response body bytes read by the FeroxScan instance
+    pub(super) bytes_received: AtomicU64,
+
     /// tracker for the time at which this scan was started
     pub(super) start_time: Instant,
+
+    /// running aggregates of the response times of the requests made by this scan
+    pub(super) response_times: ResponseTimes,
+
+    /// response time stats for this scan; computed when the scan finishes
+    pub(super) timing: Mutex<ScanTimingStats>,
+
+    /// requests per second made by this scan; recorded when the scan finishes or is aborted
+    pub(super) words_per_second: Mutex<f64>,
+
+    /// when the current run of this scan started, i.e. when its progress bar was created; cleared
+    /// once the run's time has been added to `elapsed`
+    pub(super) started_at: Mutex<Option<Instant>>,
+
+    /// time spent running this scan, not counting the current run; recorded when the scan
+    /// finishes or is aborted
+    pub(super) elapsed: Mutex<Duration>,
+
+    /// maximum number of requests per second this scan may make; 0 means no limit
+    pub(super) rate_limit: u32,
+
+    /// token bucket that enforces `rate_limit`; each request spends a permit, and permits are
+    /// refilled by the task returned from `start_rate_limiter`
+    pub(super) rate_limiter: Option<Arc<sync::Semaphore>>,
 }
 
 /// Default implementation for FeroxScan
@@ -74,18 +145,33 @@ impl Default for FeroxScan {
 
         FeroxScan {
             id: new_id,
+            parent_id: None,
             task: sync::Mutex::new(None), // tokio mutex
+            abort_requested: AtomicBool::new(false),
+            paused: Arc::new(AtomicBool::new(false)),
             status: Mutex::new(ScanStatus::default()),
             num_requests: 0,
+            requests_made_so_far: 0,
+            retry_count: 0,
             scan_order: ScanOrder::Latest,
             url: String::new(),
             progress_bar: Mutex::new(None),
-            scan_type: ScanType::File,
+            scan_type: Mutex::new(ScanType::File),
             output_level: Default::default(),
             errors: Default::default(),
+            found_count: Default::default(),
+            requests_made: Default::default(),
+            bytes_received: Default::default(),
             status_429s: Default::default(),
             status_403s: Default::default(),
             start_time: Instant::now(),
+            response_times: ResponseTimes::default(),
+            timing: Mutex::new(ScanTimingStats::default()),
+            words_per_second: Mutex::new(0.0),
+            started_at: Mutex::new(None),
+            elapsed: Mutex::new(Duration::default()),
+            rate_limit: 0,
+            rate_limiter: None,
         }
     }
 }
@@ -96,16 +182,24 @@ impl FeroxScan {
     pub async fn abort(&self) -> Result<()> {
         log::trace!("enter: abort");
 
+        self.abort_requested.store(true, Ordering::Relaxed);
+
+        // request tasks waiting on a paused scan would otherwise never wake up
+        self.resume();
+
         match self.task.try_lock() {
             Ok(mut guard) => {
-                if let Some(task) = std::mem::replace(&mut *guard, None) {
+                if let Some(task) = guard.take() {
                     log::trace!("aborting {:?}", self);
+                    self.record_words_per_second();
+                    self.record_elapsed();
                     task.abort();
                     self.set_status(ScanStatus::Cancelled)?;
                     self.stop_progress_bar();
                 }
             }
             Err(e) => {
+                // the abort is still recorded, so the scan is marked Cancelled when it finishes
                 log::warn!("Could not acquire lock to abort scan (we're already waiting for its results): {:?} {}", self, e);
             }
         }
@@ -118,10 +212,34 @@ impl FeroxScan {
         &self.url
     }
 
+    /// getter for id
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// getter for scan_type
+    pub fn scan_type(&self) -> ScanType {
+        self.scan_type
+            .lock()
+            .map_or(ScanType::default(), |scan_type| *scan_type)
+    }
+
+    /// small wrapper to set ScanType
+    pub fn set_scan_type(&self, scan_type: ScanType) {
+        if let Ok(mut guard) = self.scan_type.lock() {
+            *guard = scan_type;
+        }
+    }
+
+    /// getter for parent_id
+    pub fn parent_id(&self) -> Option<&str> {
+        self.parent_id.as_deref()
+    }
+
     /// small wrapper to set the JoinHandle
     pub async fn set_task(&self, task: JoinHandle<()>) -> Result<()> {
         let mut guard = self.task.lock().await;
-        let _ = std::mem::replace(&mut *guard, Some(task));
+        let _ = guard.replace(task);
         Ok(())
     }
 
@@ -157,8 +275,9 @@ impl FeroxScan {
 
                     let pb = add_bar(&self.url, self.num_requests, bar_type);
                     pb.reset_elapsed();
+                    self.start_timer();
 
-                    let _ = std::mem::replace(&mut *guard, Some(pb.clone()));
+                    let _ = guard.replace(pb.clone());
 
                     pb
                 }
@@ -174,6 +293,7 @@ impl FeroxScan {
 
                 let pb = add_bar(&self.url, self.num_requests, bar_type);
                 pb.reset_elapsed();
+                self.start_timer();
 
                 pb
             }
@@ -188,21 +308,127 @@ impl FeroxScan {
         num_requests: u64,
         output_level: OutputLevel,
         pb: Option<ProgressBar>,
+        requests_per_second: Option<u32>,
     ) -> Arc<Self> {
-        Arc::new(Self {
-            url: url.to_string(),
+        Self::new_with_parent(
+            url,
             scan_type,
             scan_order,
             num_requests,
             output_level,
+            pb,
+            None,
+            requests_per_second,
+        )
+    }
+
+    /// same as `new`, but records the ID of the scan that spawned this one
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_with_parent(
+        url: &str,
+        scan_type: ScanType,
+        scan_order: ScanOrder,
+        num_requests: u64,
+        output_level: OutputLevel,
+        pb: Option<ProgressBar>,
+        parent_id: Option<String>,
+        requests_per_second: Option<u32>,
+    ) -> Arc<Self> {
+        let mut scan = Self {
+            url: url.to_string(),
+            parent_id,
+            scan_type: Mutex::new(scan_type),
+            scan_order,
+            num_requests,
+            output_level,
             progress_bar: Mutex::new(pb),
             ..Default::default()
-        })
+        };
+
+        scan.set_rate_limit(requests_per_second);
+
+        Arc::new(scan)
+    }
+
+    /// limit this scan to the given number of requests per second; None (or 0) removes the limit
+    pub(super) fn set_rate_limit(&mut self, requests_per_second: Option<u32>) {
+        match requests_per_second {
+            Some(limit) if limit > 0 => {
+                // like the Requester's leaky bucket, start with half of a second's worth of
+                // permits to reduce the initial burst
+                let initial = (limit / 2).max(1) as usize;
+
+                self.rate_limit = limit;
+                self.rate_limiter = Some(Arc::new(sync::Semaphore::new(initial)));
+            }
+            _ => {
+                self.rate_limit = 0;
+                self.rate_limiter = None;
+            }
+        }
+    }
+
+    /// the token bucket that limits this scan's requests per second, if it has one
+    pub fn rate_limiter(&self) -> Option<Arc<sync::Semaphore>> {
+        self.rate_limiter.clone()
     }
 
-    /// Mark the scan as complete and stop the scan's progress bar
+    /// wait for one of this scan's rate limit tokens and spend it; returns immediately when the
+    /// scan isn't rate limited
+    pub async fn limit(&self) {
+        if let Some(limiter) = &self.rate_limiter {
+            if let Ok(permit) = limiter.acquire().await {
+                permit.forget();
+            }
+        }
+    }
+
+    /// spawn the task that refills this scan's token bucket, if it has one; the bucket never
+    /// holds more than one second's worth of permits
+    ///
+    /// the task stops on its own once the scan is complete, cancelled, or failed
+    pub fn start_rate_limiter(self: &Arc<Self>) -> Option<JoinHandle<()>> {
+        let limiter = self.rate_limiter()?;
+        let scan = Arc::downgrade(self);
+        let limit = self.rate_limit as usize;
+
+        Some(tokio::spawn(async move {
+            let mut interval = tokio::time::interval(RATE_LIMIT_REFILL_PERIOD);
+            interval.tick().await; // first tick completes immediately
+
+            let mut remainder = 0;
+
+            loop {
+                interval.tick().await;
+
+                match scan.upgrade() {
+                    Some(scan)
+                        if !scan.is_complete() && !scan.is_cancelled() && !scan.is_failed() => {}
+                    _ => break,
+                }
+
+                let amount = refill_amount(limit, &mut remainder);
+                let room = limit.saturating_sub(limiter.available_permits());
+                limiter.add_permits(amount.min(room));
+            }
+        }))
+    }
+
+    /// Mark the scan as complete (or cancelled, if it was aborted), compute its response time
+    /// stats, and stop the scan's progress bar
     pub fn finish(&self) -> Result<()> {
-        self.set_status(ScanStatus::Complete)?;
+        self.record_words_per_second();
+        self.record_elapsed();
+        self.set_status(self.final_status())?;
+
+        // a scan restored via --resume-from keeps the stats from its previous run until it has
+        // response times of its own
+        if !self.response_times.is_empty() {
+            if let Ok(mut timing) = self.timing.lock() {
+                *timing = self.response_times.stats();
+            }
+        }
+
         self.stop_progress_bar();
         Ok(())
     }
@@ -212,7 +438,7 @@ impl FeroxScan {
     pub fn is_active(&self) -> bool {
         if let Ok(guard) = self.status.lock() {
             return matches!(
-                (self.scan_type, *guard),
+                (self.scan_type(), *guard),
                 (ScanType::Directory, ScanStatus::Running)
                     | (ScanType::Directory, ScanStatus::NotStarted)
             );
@@ -228,15 +454,76 @@ impl FeroxScan {
         false
     }
 
+    /// small wrapper to inspect ScanStatus and see if it's Cancelled
+    pub fn is_cancelled(&self) -> bool {
+        if let Ok(guard) = self.status.lock() {
+            return matches!(*guard, ScanStatus::Cancelled);
+        }
+        false
+    }
+
+    /// whether an abort was requested for this scan, regardless of whether its task was stopped
+    pub fn abort_requested(&self) -> bool {
+        self.abort_requested.load(Ordering::Relaxed)
+    }
+
+    /// stop this scan from making new requests until `resume` is called; requests that are
+    /// already in-flight are allowed to finish
+    pub fn pause(&self) {
+        self.paused.store(true, Ordering::Relaxed);
+    }
+
+    /// let a scan stopped by `pause` continue making requests
+    pub fn resume(&self) {
+        self.paused.store(false, Ordering::Relaxed);
+    }
+
+    /// whether this scan was stopped by `pause`
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::Relaxed)
+    }
+
+    /// the flag behind `pause`/`resume`, for request tasks that can't borrow the scan
+    pub(crate) fn paused_flag(&self) -> Arc<AtomicBool> {
+        self.paused.clone()
+    }
+
+    /// status a scan should have once its task is done: Cancelled if it was aborted, Complete
+    /// otherwise
+    fn final_status(&self) -> ScanStatus {
+        if self.abort_requested() {
+            ScanStatus::Cancelled
+        } else {
+            ScanStatus::Complete
+        }
+    }
+
+    /// small wrapper to inspect ScanStatus and see if it's Failed
+    pub fn is_failed(&self) -> bool {
+        if let Ok(guard) = self.status.lock() {
+            return matches!(*guard, ScanStatus::Failed);
+        }
+        false
+    }
+
+    /// getter for retry_count
+    pub fn retry_count(&self) -> u32 {
+        self.retry_count
+    }
+
     /// await a task's completion, similar to a thread's join; perform necessary bookkeeping
     pub async fn join(&self) {
         log::trace!("enter join({:?})", self);
         let mut guard = self.task.lock().await;
 
         if guard.is_some() {
-            if let Some(task) = std::mem::replace(&mut *guard, None) {
-                task.await.unwrap();
-                self.set_status(ScanStatus::Complete)
+            if let Some(task) = guard.take() {
+                let status = match task.await {
+                    Err(e) if e.is_cancelled() => ScanStatus::Cancelled,
+                    _ => self.final_status(),
+                };
+
+                self.set_status(status)
                     .unwrap_or_else(|e| log::warn!("Could not mark scan complete: {}", e))
             }
         }
@@ -267,6 +554,32 @@ impl FeroxScan {
         }
     }
 
+    /// increment the number of responses found by this scan; returns the new total
+    pub(crate) fn add_found(&self) -> u32 {
+        self.found_count.fetch_add(1, Ordering::Relaxed) + 1
+    }
+
+    /// return the number of responses found by this scan
+    pub fn found_count(&self) -> u32 {
+        self.found_count.load(Ordering::Relaxed)
+    }
+
+    /// count a request made by this scan whose response had a body of `bytes` bytes
+    pub(crate) fn add_request(&self, bytes: u64) {
+        self.requests_made.fetch_add(1, Ordering::Relaxed);
+        self.bytes_received.fetch_add(bytes, Ordering::Relaxed);
+    }
+
+    /// return the number of requests made by this scan that got a response
+    pub fn requests_made(&self) -> usize {
+        self.requests_made.load(Ordering::Relaxed)
+    }
+
+    /// return the number of response body bytes read by this scan
+    pub fn bytes_received(&self) -> u64 {
+        self.bytes_received.load(Ordering::Relaxed)
+    }
+
     /// return the number of errors seen by this scan
     fn errors(&self) -> usize {
         self.errors.load(Ordering::Relaxed)
@@ -294,10 +607,115 @@ impl FeroxScan {
         reqs.checked_div(seconds).unwrap_or(0)
     }
 
+    /// return the number of requests made per second by this scan's scanner; computed from the
+    /// scan's progress while it's running, and the value recorded when it stopped otherwise
+    pub fn words_per_second(&self) -> f64 {
+        if let Ok(guard) = self.status.lock() {
+            if !matches!(*guard, ScanStatus::Running) {
+                return self.words_per_second.lock().map_or(0.0, |wps| *wps);
+            }
+        }
+
+        self.current_words_per_second()
+    }
+
+    /// requests made during this run of the scan divided by the seconds it has been running;
+    /// time spent queued behind --scan-limit doesn't count, and a scan that hasn't started yet
+    /// has a rate of 0
+    fn current_words_per_second(&self) -> f64 {
+        // don't create a progress bar just to read its position
+        let requests = match self.progress_bar.lock() {
+            Ok(guard) => guard.as_ref().map_or(0, |pb| {
+                pb.position().saturating_sub(self.requests_made_so_far)
+            }),
+            Err(_) => 0,
+        };
+
+        let seconds = self
+            .started_at
+            .lock()
+            .ok()
+            .and_then(|started_at| started_at.map(|t| t.elapsed().as_secs_f64()))
+            .unwrap_or_default();
+
+        if seconds > 0.0 {
+            requests as f64 / seconds
+        } else {
+            0.0
+        }
+    }
+
+    /// save the scan's current words per second so it's kept once the scan stops
+    fn record_words_per_second(&self) {
+        let current = self.current_words_per_second();
+
+        if let Ok(mut wps) = self.words_per_second.lock() {
+            *wps = current;
+        }
+    }
+
+    /// start timing the current run of this scan, unless it's already timed or has stopped
+    fn start_timer(&self) {
+        let stopped = self.status.lock().map_or(true, |status| {
+            !matches!(*status, ScanStatus::NotStarted | ScanStatus::Running)
+        });
+
+        if let Ok(mut started_at) = self.started_at.lock() {
+            if started_at.is_none() && !stopped {
+                *started_at = Some(Instant::now());
+            }
+        }
+    }
+
+    /// add the current run's time to the time recorded for this scan and stop timing it
+    fn record_elapsed(&self) {
+        let run = self
+            .started_at
+            .lock()
+            .ok()
+            .and_then(|mut started_at| started_at.take())
+            .map(|started_at| started_at.elapsed())
+            .unwrap_or_default();
+
+        if let Ok(mut elapsed) = self.elapsed.lock() {
+            *elapsed += run;
+        }
+    }
+
+    /// return how long this scan has spent running, including any runs before it was resumed
+    pub fn elapsed(&self) -> Duration {
+        let recorded = self.elapsed.lock().map_or(Duration::default(), |e| *e);
+
+        let current = self
+            .started_at
+            .lock()
+            .ok()
+            .and_then(|started_at| started_at.map(|t| t.elapsed()))
+            .unwrap_or_default();
+
+        recorded + current
+    }
+
     /// return the number of requests performed by this scan's scanner
     pub fn requests(&self) -> u64 {
         self.progress_bar().position()
     }
+
+    /// record how long a single request made by this scan took to get a response
+    pub(crate) fn add_response_time(&self, elapsed: Duration) {
+        self.response_times
+            .add(elapsed.as_millis().try_into().unwrap_or(u64::MAX));
+    }
+
+    /// return the scan's response time stats; all zeroes until the scan finishes
+    pub fn timing(&self) -> ScanTimingStats {
+        self.timing.lock().map(|timing| *timing).unwrap_or_default()
+    }
+
+    /// return the number of requests this scan had performed when its state was saved
+    pub fn requests_made_so_far(&self) -> u64 {
+        self.requests_made_so_far
+    }
 }
 
 /// Display implementation
@@ -308,13 +726,34 @@ impl fmt::Display for FeroxScan {
                 ScanStatus::NotStarted => style("not started").bright().blue(),
                 ScanStatus::Complete => style("complete").green(),
                 ScanStatus::Cancelled => style("cancelled").red(),
+                ScanStatus::Running if self.is_paused() => style("paused").yellow(),
                 ScanStatus::Running => style("running").bright().yellow(),
+                ScanStatus::Failed => style("failed").red().bright(),
             }
         } else {
             style("unknown").red()
         };
 
-        write!(f, "{:12} {}", status, self.url)
+        write!(f, "{:12} {}", status, self.url)?;
+
+        let elapsed = self.elapsed();
+
+        if !elapsed.is_zero() {
+            write!(f, " ({:.1}s)", elapsed.as_secs_f64())?;
+        }
+
+        let requests_made = self.requests_made();
+
+        if requests_made > 0 {
+            write!(
+                f,
+                " [{} requests, {} bytes]",
+                requests_made,
+                self.bytes_received()
+            )?;
+        }
+
+        Ok(())
     }
 }
 
@@ -332,13 +771,33 @@ impl Serialize for FeroxScan {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("FeroxScan", 4)?;
+        let mut state = serializer.serialize_struct("FeroxScan", 13)?;
+
+        // don't create a progress bar just to serialize it; scans that never started (or were
+        // restored and haven't been picked back up yet) fall back to the previously known value
+        let requests_made_so_far = match self.progress_bar.lock() {
+            Ok(guard) => guard
+                .as_ref()
+                .map_or(self.requests_made_so_far, |pb| pb.position()),
+            Err(_) => self.requests_made_so_far,
+        };
 
         state.serialize_field("id", &self.id)?;
+        state.serialize_field("parent_id", &self.parent_id)?;
         state.serialize_field("url", &self.url)?;
-        state.serialize_field("scan_type", &self.scan_type)?;
+        state.serialize_field("scan_type", &self.scan_type())?;
         state.serialize_field("status", &self.status)?;
         state.serialize_field("num_requests", &self.num_requests)?;
+        state.serialize_field("requests_made_so_far", &requests_made_so_far)?;
+        state.serialize_field("retry_count", &self.retry_count)?;
+        state.serialize_field("timing", &self.timing())?;
+        state.serialize_field("words_per_second", &self.words_per_second())?;
+        state.serialize_field(
+            "elapsed_ms",
+            &u64::try_from(self.elapsed().as_millis()).unwrap_or(u64::MAX),
+        )?;
+        state.serialize_field("requests_made", &self.requests_made())?;
+        state.serialize_field("bytes_received", &self.bytes_received())?;
 
         state.end()
     }
@@ -362,13 +821,17 @@ impl<'de> Deserialize<'de> for FeroxScan {
                         scan.id = id.to_string();
                     }
                 }
+                "parent_id" => {
+                    scan.parent_id = value.as_str().map(String::from);
+                }
                 "scan_type" => {
                     if let Some(scan_type) = value.as_str() {
-                        scan.scan_type = match scan_type {
+                        scan.scan_type = Mutex::new(match scan_type {
                             "File" => ScanType::File,
                             "Directory" => ScanType::Directory,
+                            "Unknown" => ScanType::Unknown,
                             _ => ScanType::File,
-                        }
+                        })
                     }
                 }
                 "status" => {
@@ -378,6 +841,7 @@ impl<'de> Deserialize<'de> for FeroxScan {
                             "Running" => ScanStatus::Running,
                             "Complete" => ScanStatus::Complete,
                             "Cancelled" => ScanStatus::Cancelled,
+                            "Failed" => ScanStatus::Failed,
                             _ => ScanStatus::default(),
                         })
                     }
@@ -392,6 +856,42 @@ impl<'de> Deserialize<'de> for FeroxScan {
                         scan.num_requests = num_requests;
                     }
                 }
+                "requests_made_so_far" => {
+                    if let Some(requests_made_so_far) = value.as_u64() {
+                        scan.requests_made_so_far = requests_made_so_far;
+                    }
+                }
+                "retry_count" => {
+                    if let Some(retry_count) = value.as_u64() {
+                        scan.retry_count = retry_count.try_into().unwrap_or(u32::MAX);
+                    }
+                }
+                "timing" => {
+                    if let Ok(timing) = ScanTimingStats::deserialize(value) {
+                        scan.timing = Mutex::new(timing);
+                    }
+                }
+                "words_per_second" => {
+                    if let Some(wps) = value.as_f64() {
+                        scan.words_per_second = Mutex::new(wps);
+                    }
+                }
+                "elapsed_ms" => {
+                    if let Some(elapsed_ms) = value.as_u64() {
+                        scan.elapsed = Mutex::new(Duration::from_millis(elapsed_ms));
+                    }
+                }
+                "requests_made" => {
+                    if let Some(requests_made) = value.as_u64() {
+                        scan.requests_made =
+                            AtomicUsize::new(requests_made.try_into().unwrap_or(usize::MAX));
+                    }
+                }
+                "bytes_received" => {
+                    if let Some(bytes_received) = value.as_u64() {
+                        scan.bytes_received = AtomicU64::new(bytes_received);
+                    }
+                }
                 _ => {}
             }
         }
@@ -408,6 +908,9 @@ pub enum ScanType {
 
     /// A an entire directory that might be scanned
     Directory,
+
+    /// Couldn't yet tell whether the url is a file or a directory
+    Unknown,
 }
 
 /// Default implementation for ScanType
@@ -418,8 +921,52 @@ impl Default for ScanType {
     }
 }
 
+/// classify the given url as a file or directory based on its response
+///
+/// a url is a directory when the response says it's suitable for recursion, or when a 2xx, 401,
+/// or 403 was returned for a url ending in a slash; it's a file when its last path segment has an
+/// extension, it has a query string, or a successful response has a Content-Type other than html.
+/// anything else (i.e. /api/users returning a 200 html page) is Unknown
+pub fn detect_scan_type(url: &str, response: &FeroxResponse) -> ScanType {
+    let status = response.status();
+
+    if response.is_directory()
+        || (url.ends_with('/')
+            && (status.is_success()
+                || matches!(*status, StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN)))
+    {
+        return ScanType::Directory;
+    }
+
+    if let Ok(parsed) = Url::parse(url) {
+        let has_extension = parsed
+            .path_segments()
+            .and_then(|mut segments| segments.next_back())
+            .is_some_and(|last| last.contains('.'));
+
+        if has_extension || parsed.query().is_some() {
+            return ScanType::File;
+        }
+    }
+
+    if status.is_success() {
+        let content_type = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok());
+
+        if let Some(content_type) = content_type {
+            if !content_type.to_lowercase().contains("text/html") {
+                return ScanType::File;
+            }
+        }
+    }
+
+    ScanType::Unknown
+}
+
 #[derive(Debug, Serialize, Deserialize, Copy, Clone)]
-/// Simple enum to represent a scan's current status ([in]complete, cancelled)
+/// Simple enum to represent a scan's current status ([in]complete, cancelled, failed)
 pub enum ScanStatus {
     /// Scan hasn't started yet
     NotStarted,
@@ -432,6 +979,9 @@ pub enum ScanStatus {
 
     /// Scan has started, but hasn't finished, nor been cancelled
     Running,
+
+    /// Scan was resumed more than --max-retries times without finishing; it won't be resumed again
+    Failed,
 }
 
 /// Default implementation for ScanStatus
@@ -460,6 +1010,7 @@ mod tests {
             1000,
             OutputLevel::Default,
             None,
+            None,
         );
 
         scan.add_error();
@@ -474,23 +1025,211 @@ mod tests {
         assert_eq!(scan.num_errors(PolicyTrigger::Status429), 3);
     }
 
+    #[test]
+    /// add_found should return the running total seen by found_count
+    fn add_found_increments_found_count() {
+        let scan = FeroxScan::new(
+            "http://localhost",
+            ScanType::Directory,
+            ScanOrder::Latest,
+            1000,
+            OutputLevel::Default,
+            None,
+            None,
+        );
+
+        assert_eq!(scan.found_count(), 0);
+        assert_eq!(scan.add_found(), 1);
+        assert_eq!(scan.add_found(), 2);
+        assert_eq!(scan.found_count(), 2);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    /// a scan created with a rate limit should hand out half a second's worth of tokens right
+    /// away, and after that, only as many tokens per second as its limit allows
+    async fn rate_limited_scan_spaces_out_requests() {
+        let scan = FeroxScan::new(
+            "http://localhost",
+            ScanType::Directory,
+            ScanOrder::Latest,
+            1000,
+            OutputLevel::Default,
+            None,
+            Some(4),
+        );
+        scan.set_status(ScanStatus::Running).unwrap();
+
+        let refill = scan.start_rate_limiter().unwrap();
+        let start = Instant::now();
+
+        // 2 tokens up front, then one every 250ms
+        for _ in 0..4 {
+            scan.limit().await;
+        }
+
+        let elapsed = start.elapsed();
+        assert!(elapsed >= Duration::from_millis(450), "{:?}", elapsed);
+        assert!(elapsed < Duration::from_millis(1500), "{:?}", elapsed);
+
+        // the refill task stops once the scan is done
+        scan.finish().unwrap();
+        tokio::time::timeout(Duration::from_secs(1), refill)
+            .await
+            .unwrap()
+            .unwrap();
+    }
+
+    #[test]
+    /// each second's worth of refills should add exactly the scan's limit, even when the limit
+    /// isn't a multiple of the number of refills per second
+    fn refill_amount_carries_fractional_permits() {
+        for limit in [1, 4, 10, 15, 25, 99] {
+            let mut remainder = 0;
+            let added: usize = (0..10).map(|_| refill_amount(limit, &mut remainder)).sum();
+
+            assert_eq!(added, limit, "limit {}", limit);
+        }
+
+        // 15 requests per second alternates between 1 and 2 permits per refill
+        let mut remainder = 0;
+        let amounts: Vec<_> = (0..4).map(|_| refill_amount(15, &mut remainder)).collect();
+        assert_eq!(amounts, [1, 2, 1, 2]);
+    }
+
+    #[test]
+    /// a scan without a rate limit shouldn't have a token bucket or refill task
+    fn scan_without_rate_limit_has_no_rate_limiter() {
+        let scan = FeroxScan::new(
+            "http://localhost",
+            ScanType::Directory,
+            ScanOrder::Latest,
+            1000,
+            OutputLevel::Default,
+            None,
+            Some(0),
+        );
+
+        assert!(scan.rate_limiter().is_none());
+        assert!(scan.start_rate_limiter().is_none());
+    }
+
+    #[test]
+    /// detect_scan_type should classify urls by their trailing slash, extension, status code,
+    /// and Content-Type header
+    fn detect_scan_type_classifies_urls() {
+        for (url, status, content_type, expected) in [
+            (
+                "http://localhost/admin/",
+                200,
+                "text/html",
+                ScanType::Directory,
+            ),
+            ("http://localhost/admin/", 403, "", ScanType::Directory),
+            (
+                "http://localhost/admin/",
+                404,
+                "text/html",
+                ScanType::Unknown,
+            ),
+            (
+                "http://localhost/index.php",
+                200,
+                "text/html",
+                ScanType::File,
+            ),
+            (
+                "http://localhost/search?q=1",
+                200,
+                "text/html",
+                ScanType::File,
+            ),
+            (
+                "http://localhost/api",
+                200,
+                "application/json",
+                ScanType::File,
+            ),
+            (
+                "http://localhost/api",
+                200,
+                "text/html; charset=utf-8",
+                ScanType::Unknown,
+            ),
+            (
+                "http://localhost/api",
+                500,
+                "application/json",
+                ScanType::Unknown,
+            ),
+            ("http://localhost/api", 200, "", ScanType::Unknown),
+        ] {
+            let mut json = serde_json::json!({"url": url, "status": status});
+
+            if !content_type.is_empty() {
+                json["headers"] = serde_json::json!({ "content-type": content_type });
+            }
+
+            let response: FeroxResponse = serde_json::from_value(json).unwrap();
+
+            assert_eq!(
+                format!("{:?}", detect_scan_type(url, &response)),
+                format!("{:?}", expected),
+                "{} {} {}",
+                url,
+                status,
+                content_type
+            );
+        }
+    }
+
+    #[test]
+    /// an Unknown scan type should survive a round trip through the state file
+    fn unknown_scan_type_is_serialized() {
+        let scan = FeroxScan {
+            scan_type: Mutex::new(ScanType::Unknown),
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&scan).unwrap();
+        assert!(json.contains(r#""scan_type":"Unknown""#));
+
+        let deserialized: FeroxScan = serde_json::from_str(&json).unwrap();
+        assert!(matches!(deserialized.scan_type(), ScanType::Unknown));
+        assert!(!deserialized.is_active());
+    }
+
     #[test]
     /// ensure that requests_per_second returns the correct values
     fn requests_per_second_returns_correct_values() {
         let scan = FeroxScan {
             id: "".to_string(),
+            parent_id: None,
             url: "".to_string(),
-            scan_type: ScanType::Directory,
+            scan_type: Mutex::new(ScanType::Directory),
             scan_order: ScanOrder::Initial,
             num_requests: 0,
+            requests_made_so_far: 0,
+            retry_count: 0,
             status: Mutex::new(ScanStatus::Running),
             task: Default::default(),
+            abort_requested: Default::default(),
+            paused: Default::default(),
             progress_bar: Mutex::new(None),
             output_level: Default::default(),
             status_403s: Default::default(),
             status_429s: Default::default(),
             errors: Default::default(),
+            found_count: Default::default(),
+            requests_made: Default::default(),
+            bytes_received: Default::default(),
             start_time: Instant::now(),
+            response_times: Default::default(),
+            timing: Default::default(),
+            words_per_second: Default::default(),
+            started_at: Default::default(),
+            elapsed: Default::default(),
+            rate_limit: 0,
+            rate_limiter: None,
         };
 
         let pb = scan.progress_bar();
@@ -505,4 +1244,132 @@ mod tests {
         scan.finish().unwrap();
         assert_eq!(scan.requests_per_second(), 0);
     }
+
+    #[test]
+    /// time a scan spends waiting to start shouldn't count against its words per second
+    fn words_per_second_ignores_time_spent_queued() {
+        let scan = FeroxScan {
+            status: Mutex::new(ScanStatus::Running),
+            progress_bar: Mutex::new(Some(ProgressBar::hidden())),
+            start_time: Instant::now() - Duration::from_secs(60),
+            ..Default::default()
+        };
+
+        // not started yet
+        assert_eq!(scan.words_per_second(), 0.0);
+
+        *scan.started_at.lock().unwrap() = Some(Instant::now() - Duration::from_secs(1));
+        scan.progress_bar().set_position(100);
+
+        let running = scan.words_per_second();
+        assert!(running > 50.0 && running <= 100.0, "{}", running);
+    }
+
+    #[test]
+    /// words_per_second should be computed while the scan runs, kept once it finishes, and
+    /// survive a round trip through the state file
+    fn words_per_second_is_recorded_when_scan_finishes() {
+        let scan = FeroxScan {
+            status: Mutex::new(ScanStatus::Running),
+            progress_bar: Mutex::new(Some(ProgressBar::hidden())),
+            started_at: Mutex::new(Some(Instant::now())),
+            ..Default::default()
+        };
+
+        scan.progress_bar().set_position(100);
+
+        sleep(Duration::new(1, 0));
+
+        let running = scan.words_per_second();
+        assert!(running > 50.0 && running <= 100.0);
+
+        scan.finish().unwrap();
+
+        let finished = scan.words_per_second();
+        assert!(finished > 50.0 && finished <= running);
+
+        sleep(Duration::from_millis(100));
+        assert_eq!(scan.words_per_second(), finished);
+
+        let json = serde_json::to_string(&scan).unwrap();
+        let deserialized: FeroxScan = serde_json::from_str(&json).unwrap();
+
+        assert!((deserialized.words_per_second() - finished).abs() < 0.001);
+    }
+
+    #[test]
+    /// requests and bytes counted for a scan should survive a round trip through the state file
+    fn add_request_counts_requests_and_bytes() {
+        let scan = FeroxScan::default();
+
+        scan.add_request(100);
+        scan.add_request(0);
+        scan.add_request(50);
+
+        assert_eq!(scan.requests_made(), 3);
+        assert_eq!(scan.bytes_received(), 150);
+
+        let json = serde_json::to_string(&scan).unwrap();
+        assert!(json.contains(r#""requests_made":3,"bytes_received":150"#));
+
+        let restored: FeroxScan = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.requests_made(), 3);
+        assert_eq!(restored.bytes_received(), 150);
+    }
+
+    #[test]
+    /// pause and resume should toggle the flag shared with the scan's request tasks, and a
+    /// paused running scan should be displayed as such
+    fn pause_and_resume_toggle_paused_flag() {
+        let scan = FeroxScan::default();
+        scan.set_status(ScanStatus::Running).unwrap();
+        let flag = scan.paused_flag();
+
+        assert!(!scan.is_paused());
+
+        scan.pause();
+        assert!(scan.is_paused());
+        assert!(flag.load(Ordering::Relaxed));
+        assert!(scan.to_string().contains("paused"));
+
+        scan.resume();
+        assert!(!scan.is_paused());
+        assert!(!flag.load(Ordering::Relaxed));
+        assert!(scan.to_string().contains("running"));
+    }
+
+    #[test]
+    /// finish should compute the scan's timing stats from its response times, and the stats
+    /// should survive a round trip through the state file
+    fn finish_computes_timing_stats() {
+        let scan = FeroxScan::default();
+
+        for millis in [30, 10, 20] {
+            scan.add_response_time(Duration::from_millis(millis));
+        }
+
+        assert_eq!(scan.timing(), ScanTimingStats::default());
+
+        scan.finish().unwrap();
+
+        let expected = ScanTimingStats {
+            min_ms: 10,
+            max_ms: 30,
+            avg_ms: 20,
+            p50_ms: 20,
+            p95_ms: 30,
+        };
+        assert_eq!(scan.timing(), expected);
+
+        let json = serde_json::to_string(&scan).unwrap();
+        assert!(json
+            .contains(r#""timing":{"min_ms":10,"max_ms":30,"avg_ms":20,"p50_ms":20,"p95_ms":30}"#));
+
+        let restored: FeroxScan = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.timing(), expected);
+
+        // a restored scan that finishes without new response times keeps its previous stats
+        restored.finish().unwrap();
+        assert_eq!(restored.timing(), expected);
+    }
 }
@@ -1,30 +1,33 @@
-use super::scan::ScanType;
+use super::scan::{detect_scan_type, ScanType};
 use super::*;
-use crate::event_handlers::Handles;
+use crate::event_handlers::{Handles, TermInputHandler};
 use crate::filters::{
-    EmptyFilter, LinesFilter, RegexFilter, SimilarityFilter, SizeFilter, StatusCodeFilter,
-    WildcardFilter, WordsFilter,
+    EmptyFilter, ExtensionFilter, FeroxFilters, HeaderCountFilter, JsonPathFilter, LinesFilter,
+    LinesRangeFilter, RegexFilter, ResponseTimeFilter, SimilarityFilter, SizeFilter,
+    SizeRatioFilter, StatusCodeFilter, WildcardFilter, WordsFilter, WordsRangeFilter,
 };
 use crate::traits::FeroxFilter;
 use crate::Command::AddFilter;
 use crate::{
-    config::OutputLevel,
+    config::{Configuration, OutputLevel},
     progress::PROGRESS_PRINTER,
     progress::{add_bar, BarType},
+    response::FeroxResponse,
     scan_manager::{MenuCmd, MenuCmdResult},
-    scanner::RESPONSES,
+    scanner::{flush_error_log, RESPONSES},
+    statistics::Stats,
     traits::FeroxSerialize,
+    utils::{ferox_print, normalize_case, normalize_url},
     Command, SLEEP_DURATION,
 };
 use anyhow::Result;
 use console::style;
+use dashmap::{mapref::entry::Entry, DashMap};
 use reqwest::StatusCode;
 use serde::{ser::SerializeSeq, Serialize, Serializer};
 use std::{
     collections::HashSet,
     convert::TryInto,
-    fs::File,
-    io::BufReader,
     ops::Index,
     sync::{
         atomic::{AtomicBool, AtomicUsize, Ordering},
@@ -44,9 +47,16 @@ pub static PAUSE_SCAN: AtomicBool = AtomicBool::new(false);
 /// Container around a locked hashset of `FeroxScan`s, adds wrappers for insertion and searching
 #[derive(Debug, Default)]
 pub struct FeroxScans {
-    /// Internal structure: locked hashset of `FeroxScan`s
+    /// Internal structure: locked list of `FeroxScan`s, in the order they were added
     pub scans: RwLock<Vec<Arc<FeroxScan>>>,
 
+    /// the same `FeroxScan`s as `scans`, keyed by url (normalized per --case-insensitive-dedup);
+    /// lets lookups by url skip locking and walking `scans`
+    by_url: DashMap<String, Arc<FeroxScan>>,
+
+    /// the same `FeroxScan`s as `scans`, keyed by id
+    by_id: DashMap<String, Arc<FeroxScan>>,
+
     /// menu used for providing a way for users to cancel a scan
     menu: Menu,
 
@@ -57,8 +67,34 @@ pub struct FeroxScans {
     /// whether or not the user passed --silent|--quiet on the command line
     output_level: OutputLevel,
 
+    /// requests per second each scan is limited to (--rate-limit), if any
+    rate_limit: Option<u32>,
+
     /// vector of extensions discovered and collected during scans
     pub(crate) collected_extensions: RwLock<HashSet<String>>,
+
+    /// form actions discovered via --collect-forms; tracked separately from `scans`
+    pub(crate) form_urls: RwLock<HashSet<String>>,
+
+    /// urls of extracted links without their query strings or fragments; used by
+    /// --deduplicate-by-path
+    pub(crate) seen_paths: RwLock<HashSet<String>>,
+
+    /// source map urls fetched via --collect-source-maps; tracked so each is only fetched once
+    pub(crate) source_maps: RwLock<HashSet<String>>,
+
+    /// version urls probed via --collect-api-versions; tracked so each is only requested once
+    pub(crate) api_version_probes: RwLock<HashSet<String>>,
+
+    /// urls found with a non-error status in the state file given to --check-previous-state
+    pub(crate) previous_urls: RwLock<HashSet<String>>,
+
+    /// number of requests skipped due to their url being in `previous_urls`
+    pub(crate) previous_skipped: AtomicUsize,
+
+    /// whether urls differing only in the case of their path are the same scan
+    /// (--case-insensitive-dedup)
+    case_insensitive_dedup: bool,
 }
 
 /// Serialize implementation for FeroxScans
@@ -75,7 +111,7 @@ impl Serialize for FeroxScans {
                 let mut seq = serializer.serialize_seq(Some(scans.len() + 1))?;
 
                 for scan in scans.iter() {
-                    seq.serialize_element(&*scan).unwrap_or_default();
+                    seq.serialize_element(&**scan).unwrap_or_default();
                 }
                 seq.end()
             }
@@ -90,10 +126,22 @@ impl Serialize for FeroxScans {
 
 /// Implementation of `FeroxScans`
 impl FeroxScans {
-    /// given an OutputLevel, create a new FeroxScans object
-    pub fn new(output_level: OutputLevel) -> Self {
+    /// serialize the known scans as a JSON array, i.e. the `scans` entry of a state file
+    pub fn to_json_array(&self) -> String {
+        serde_json::to_string(self).unwrap_or_else(|_| String::from("[]"))
+    }
+
+    /// given an OutputLevel, the requests per second each scan is limited to, and whether urls
+    /// differing only in case are the same scan, create a new FeroxScans object
+    pub fn new(
+        output_level: OutputLevel,
+        rate_limit: Option<u32>,
+        case_insensitive_dedup: bool,
+    ) -> Self {
         Self {
             output_level,
+            rate_limit,
+            case_insensitive_dedup,
             ..Default::default()
         }
     }
@@ -102,35 +150,63 @@ impl FeroxScans {
     ///
     /// If the internal container did NOT contain the scan, true is returned; else false
     pub fn insert(&self, scan: Arc<FeroxScan>) -> bool {
-        // If the container did contain the scan, set sentry to false
-        // If the container did not contain the scan, set sentry to true
-        let sentry = !self.contains(&scan.url);
-
-        if sentry {
-            // can't update the internal container while the scan itself is locked, so first
-            // lock the scan and check the container for the scan's presence, then add if
-            // not found
-            match self.scans.write() {
-                Ok(mut scans) => {
-                    scans.push(scan);
-                }
-                Err(e) => {
-                    log::warn!("FeroxScans' container's mutex is poisoned: {}", e);
-                    return false;
-                }
+        // `by_url` and `by_id` are only written while holding the `scans` write lock, so all
+        // three always agree on which scans are known
+        let mut scans = match self.scans.write() {
+            Ok(scans) => scans,
+            Err(e) => {
+                log::warn!("FeroxScans' container's mutex is poisoned: {}", e);
+                return false;
+            }
+        };
+
+        match self
+            .by_url
+            .entry(normalize_case(&scan.url, self.case_insensitive_dedup))
+        {
+            Entry::Occupied(_) => return false,
+            Entry::Vacant(entry) => {
+                entry.insert(scan.clone());
             }
         }
 
-        sentry
+        self.by_id.insert(scan.id.clone(), scan.clone());
+        scans.push(scan);
+
+        true
+    }
+
+    /// Remove the `FeroxScan` with the given URL from the internal container and cancel it; the
+    /// removed scan is returned
+    ///
+    /// once removed, the scan no longer shows up in reports or in the state file, so it isn't
+    /// attempted again when resuming
+    pub async fn remove(&self, url: &str) -> Option<Arc<FeroxScan>> {
+        let key = normalize_case(url, self.case_insensitive_dedup);
+
+        let scan = {
+            // same as insert, the indexes are only updated while holding the write lock
+            let mut scans = self.scans.write().ok()?;
+
+            // same as get_scan_by_url, urls only differing by case aren't removed
+            let (_, scan) = self.by_url.remove_if(&key, |_, scan| scan.url == url)?;
+            self.by_id.remove(&scan.id);
+            scans.retain(|known| known.id != scan.id);
+
+            scan
+        };
+
+        scan.abort()
+            .await
+            .unwrap_or_else(|e| log::warn!("Could not cancel task: {}", e));
+
+        Some(scan)
     }
 
     /// load serialized FeroxScan(s) and any previously collected extensions into this FeroxScans  
     pub fn add_serialized_scans(&self, filename: &str, handles: Arc<Handles>) -> Result<()> {
         log::trace!("enter: add_serialized_scans({})", filename);
-        let file = File::open(filename)?;
-
-        let reader = BufReader::new(file);
-        let state: serde_json::Value = serde_json::from_reader(reader)?;
+        let state = load_state(filename)?;
 
         if let Some(scans) = state.get("scans") {
             if let Some(arr_scans) = scans.as_array() {
@@ -144,6 +220,27 @@ impl FeroxScans {
                     // without the line below
                     deser_scan.output_level = self.output_level;
 
+                    // rate limiters aren't serialized, have to remake them from the config
+                    deser_scan.set_rate_limit(self.rate_limit);
+
+                    if matches!(deser_scan.scan_type(), ScanType::Directory)
+                        && !deser_scan.is_complete()
+                        && !deser_scan.is_failed()
+                    {
+                        // unfinished directory scans get resumed, which is effectively a retry;
+                        // once they've been retried too many times, they're given up on
+                        deser_scan.retry_count += 1;
+
+                        if deser_scan.retry_count > handles.config.max_retries {
+                            log::warn!(
+                                "{} was resumed {} times without finishing, marking it as failed",
+                                deser_scan.url,
+                                handles.config.max_retries
+                            );
+                            deser_scan.set_status(ScanStatus::Failed)?;
+                        }
+                    }
+
                     self.insert(Arc::new(deser_scan));
                 }
             }
@@ -193,6 +290,34 @@ impl FeroxScans {
                         serde_json::from_value::<StatusCodeFilter>(filter.clone())
                     {
                         Box::new(deserialized)
+                    } else if let Ok(deserialized) =
+                        serde_json::from_value::<ExtensionFilter>(filter.clone())
+                    {
+                        Box::new(deserialized)
+                    } else if let Ok(deserialized) =
+                        serde_json::from_value::<WordsRangeFilter>(filter.clone())
+                    {
+                        Box::new(deserialized)
+                    } else if let Ok(deserialized) =
+                        serde_json::from_value::<LinesRangeFilter>(filter.clone())
+                    {
+                        Box::new(deserialized)
+                    } else if let Ok(deserialized) =
+                        serde_json::from_value::<ResponseTimeFilter>(filter.clone())
+                    {
+                        Box::new(deserialized)
+                    } else if let Ok(deserialized) =
+                        serde_json::from_value::<HeaderCountFilter>(filter.clone())
+                    {
+                        Box::new(deserialized)
+                    } else if let Ok(deserialized) =
+                        serde_json::from_value::<SizeRatioFilter>(filter.clone())
+                    {
+                        Box::new(deserialized)
+                    } else if let Ok(deserialized) =
+                        serde_json::from_value::<JsonPathFilter>(filter.clone())
+                    {
+                        Box::new(deserialized)
                     } else {
                         Box::new(EmptyFilter {})
                     };
@@ -209,29 +334,182 @@ impl FeroxScans {
         Ok(())
     }
 
-    /// Simple check for whether or not a FeroxScan is contained within the inner container based
-    /// on the given URL
-    pub fn contains(&self, url: &str) -> bool {
-        if let Ok(scans) = self.scans.read() {
-            for scan in scans.iter() {
-                if scan.url == url {
-                    return true;
+    /// Given a state file, add the url of every response that didn't have an error (4xx/5xx)
+    /// status to `previous_urls`; returns the number of urls added
+    pub fn add_previous_responses(&self, filename: &str) -> Result<usize> {
+        log::trace!("enter: add_previous_responses({})", filename);
+        let state = load_state(filename)?;
+
+        let mut num_added = 0;
+
+        if let Some(responses) = state.get("responses") {
+            if let Some(arr_responses) = responses.as_array() {
+                if let Ok(mut urls) = self.previous_urls.write() {
+                    for response in arr_responses {
+                        let deser_resp: FeroxResponse =
+                            match serde_json::from_value(response.clone()) {
+                                Ok(deser_resp) => deser_resp,
+                                Err(_) => continue,
+                            };
+
+                        let status = deser_resp.status();
+
+                        if (status.is_client_error() || status.is_server_error())
+                            || !urls.insert(deser_resp.url().to_string())
+                        {
+                            continue;
+                        }
+
+                        num_added += 1;
+                    }
                 }
             }
         }
-        false
+
+        log::trace!("exit: add_previous_responses -> {}", num_added);
+        Ok(num_added)
+    }
+
+    /// determine whether the given url was found in the --check-previous-state file; each
+    /// positive result is counted in `previous_skipped`
+    pub fn skip_previously_found(&self, url: &str) -> bool {
+        let found = self
+            .previous_urls
+            .read()
+            .map(|urls| urls.contains(url))
+            .unwrap_or_default();
+
+        if found {
+            self.previous_skipped.fetch_add(1, Ordering::Relaxed);
+        }
+
+        found
+    }
+
+    /// number of requests skipped due to --check-previous-state
+    pub fn num_previous_skipped(&self) -> usize {
+        self.previous_skipped.load(Ordering::Relaxed)
+    }
+
+    /// Simple check for whether or not a FeroxScan is contained within the inner container based
+    /// on the given URL; the comparison ignores the case of the path when --case-insensitive-dedup
+    /// is in use
+    pub fn contains(&self, url: &str) -> bool {
+        self.by_url
+            .contains_key(&normalize_case(url, self.case_insensitive_dedup))
+    }
+
+    /// whether the given URL, or a directory above it, is already being scanned; trailing slashes
+    /// are ignored, so `http://localhost/api` and `http://localhost/api/` both match a scan of
+    /// `http://localhost/api/`
+    ///
+    /// the comparison ignores the case of the path when --case-insensitive-dedup is in use
+    pub fn contains_prefix(&self, url: &str) -> bool {
+        let url = normalize_case(url, self.case_insensitive_dedup);
+        let url = url.trim_end_matches('/');
+
+        self.by_url.iter().any(|entry| {
+            if !matches!(entry.value().scan_type(), ScanType::Directory) {
+                return false;
+            }
+
+            let prefix = entry.key().trim_end_matches('/');
+
+            url == prefix
+                || url
+                    .strip_prefix(prefix)
+                    .is_some_and(|rest| rest.starts_with('/'))
+        })
     }
 
     /// Find and return a `FeroxScan` based on the given URL
     pub fn get_scan_by_url(&self, url: &str) -> Option<Arc<FeroxScan>> {
+        let scan = self
+            .by_url
+            .get(&normalize_case(url, self.case_insensitive_dedup))?;
+
+        // urls only differing by case share an entry under --case-insensitive-dedup, but this
+        // lookup is exact
+        (scan.url == url).then(|| scan.clone())
+    }
+
+    /// Find and return a `FeroxScan` based on the given ID
+    pub fn get_scan_by_id(&self, id: &str) -> Option<Arc<FeroxScan>> {
+        self.by_id.get(id).map(|scan| scan.clone())
+    }
+
+    /// number of scans between the scan with the given ID and the initial target it descends
+    /// from, found by following each scan's `parent_id`; initial targets and unknown IDs are 0
+    pub fn depth_of(&self, scan_id: &str) -> usize {
+        let mut depth = 0;
+        let mut current = self.get_scan_by_id(scan_id);
+
+        while let Some(parent) = current
+            .as_ref()
+            .and_then(|scan| scan.parent_id())
+            .and_then(|parent_id| self.get_scan_by_id(parent_id))
+        {
+            depth += 1;
+            current = Some(parent);
+        }
+
+        depth
+    }
+
+    /// Return every scan spawned directly by the scan with the given ID
+    pub fn get_by_parent(&self, parent_id: &str) -> Vec<Arc<FeroxScan>> {
         if let Ok(guard) = self.scans.read() {
-            for scan in guard.iter() {
-                if scan.url == url {
-                    return Some(scan.clone());
-                }
+            return guard
+                .iter()
+                .filter(|scan| scan.parent_id() == Some(parent_id))
+                .cloned()
+                .collect();
+        }
+
+        Vec::new()
+    }
+
+    /// Return every scan beneath the scan with the given ID (children, grandchildren, etc),
+    /// parents before their children
+    pub fn get_subtree(&self, id: &str) -> Vec<Arc<FeroxScan>> {
+        let mut subtree = Vec::new();
+
+        for child in self.get_by_parent(id) {
+            let descendants = self.get_subtree(child.id());
+            subtree.push(child);
+            subtree.extend(descendants);
+        }
+
+        subtree
+    }
+
+    /// order `scans` so that each scan is followed by the scans it spawned, returning each
+    /// scan's index in `scans` along with its depth in the tree
+    fn scan_tree(&self, scans: &[Arc<FeroxScan>]) -> Vec<(usize, usize)> {
+        let mut tree = Vec::with_capacity(scans.len());
+
+        let index_of = |scan: &Arc<FeroxScan>| scans.iter().position(|s| Arc::ptr_eq(s, scan));
+
+        // scans whose parent is unknown (i.e. not in `scans`) are treated as roots
+        let roots = scans.iter().filter(|scan| match scan.parent_id() {
+            Some(parent_id) => !scans.iter().any(|s| s.id() == parent_id),
+            None => true,
+        });
+
+        let mut stack: Vec<(Arc<FeroxScan>, usize)> =
+            roots.rev().map(|scan| (scan.clone(), 0)).collect();
+
+        while let Some((scan, depth)) = stack.pop() {
+            if let Some(index) = index_of(&scan) {
+                tree.push((index, depth));
+            }
+
+            for child in self.get_by_parent(scan.id()).into_iter().rev() {
+                stack.push((child, depth + 1));
             }
         }
-        None
+
+        tree
     }
 
     pub fn get_base_scan_by_url(&self, url: &str) -> Option<Arc<FeroxScan>> {
@@ -310,20 +588,24 @@ impl FeroxScans {
 
         let mut printed = 0;
 
-        for (i, scan) in scans.iter().enumerate() {
+        for (i, depth) in self.scan_tree(&scans) {
+            let scan = &scans[i];
+
             if matches!(scan.scan_order, ScanOrder::Initial) || scan.task.try_lock().is_err() {
                 // original target passed in via either -u or --stdin
                 continue;
             }
 
-            if matches!(scan.scan_type, ScanType::Directory) {
+            if matches!(scan.scan_type(), ScanType::Directory) {
                 if printed == 0 {
                     self.menu
                         .println(&format!("{}:", style("Scans").bright().blue()));
                 }
                 // we're only interested in displaying directory scans, as those are
                 // the only ones that make sense to be stopped
-                let scan_msg = format!("{:3}: {}", i, scan);
+                // the original targets aren't shown, so their children aren't indented
+                let indent = "  ".repeat(depth.saturating_sub(1));
+                let scan_msg = format!("{:3}: {}{}", i, indent, scan);
                 self.menu.println(&scan_msg);
                 printed += 1;
             }
@@ -334,28 +616,41 @@ impl FeroxScans {
         }
     }
 
-    /// Given a list of indexes, cancel their associated FeroxScans
+    /// Given a list of indexes, cancel their associated FeroxScans and remove them (along with
+    /// any scans they spawned) from the container, so they aren't reported or resumed
     async fn cancel_scans(&self, indexes: Vec<usize>, force: bool) -> usize {
         let menu_pause_duration = Duration::from_millis(SLEEP_DURATION);
 
         let mut num_cancelled = 0_usize;
 
-        for num in indexes {
-            let selected = match self.scans.read() {
-                Ok(u_scans) => {
-                    // check if number provided is out of range
-                    if num >= u_scans.len() {
-                        // usize can't be negative, just need to handle exceeding bounds
-                        self.menu
-                            .println(&format!("The number {} is not a valid choice.", num));
-                        sleep(menu_pause_duration);
-                        continue;
-                    }
-                    u_scans.index(num).clone()
+        // the indexes shown by the menu are positions in `scans`, which shift as scans are
+        // removed, so every selection is resolved before anything is removed; the menu is
+        // renumbered the next time it's drawn
+        let selections: Vec<_> = match self.scans.read() {
+            Ok(u_scans) => indexes
+                .into_iter()
+                .map(|num| (num, u_scans.get(num).cloned()))
+                .collect(),
+            Err(..) => return num_cancelled,
+        };
+
+        for (num, selected) in selections {
+            let selected = match selected {
+                Some(selected) => selected,
+                None => {
+                    // usize can't be negative, just need to handle exceeding bounds
+                    self.menu
+                        .println(&format!("The number {} is not a valid choice.", num));
+                    sleep(menu_pause_duration);
+                    continue;
                 }
-                Err(..) => continue,
             };
 
+            if selected.is_cancelled() {
+                // already stopped, i.e. a child of a scan cancelled earlier in this same command
+                continue;
+            }
+
             let input = if force {
                 'y'
             } else {
@@ -363,15 +658,17 @@ impl FeroxScans {
             };
 
             if input == 'y' || input == '\n' {
-                self.menu.println(&format!("Stopping {}...", selected.url));
+                // scans spawned by the cancelled scan would only find more of what was just
+                // cancelled, so they're stopped as well
+                let children = self.get_subtree(selected.id());
 
-                selected
-                    .abort()
-                    .await
-                    .unwrap_or_else(|e| log::warn!("Could not cancel task: {}", e));
+                num_cancelled += self.cancel_and_remove(&selected).await;
 
-                let pb = selected.progress_bar();
-                num_cancelled += pb.length() as usize - pb.position() as usize
+                for child in children {
+                    if child.is_active() {
+                        num_cancelled += self.cancel_and_remove(&child).await;
+                    }
+                }
             } else {
                 self.menu.println("Ok, doing nothing...");
             }
@@ -382,6 +679,70 @@ impl FeroxScans {
         num_cancelled
     }
 
+    /// internal helper for `cancel_scans`; stop the given scan and remove it from the container,
+    /// returning the number of requests it had left to make
+    async fn cancel_and_remove(&self, scan: &Arc<FeroxScan>) -> usize {
+        self.menu.println(&format!("Stopping {}...", scan.url));
+
+        if self.remove(&scan.url).await.is_none() {
+            // not in the container (anymore), but the scan itself should still be stopped
+            scan.abort()
+                .await
+                .unwrap_or_else(|e| log::warn!("Could not cancel task: {}", e));
+        }
+
+        let pb = scan.progress_bar();
+        pb.length().saturating_sub(pb.position()) as usize
+    }
+
+    /// Given a list of indexes, pause (or resume) their associated FeroxScans; other scans keep
+    /// running
+    fn set_scans_paused(&self, indexes: Vec<usize>, paused: bool) {
+        let menu_pause_duration = Duration::from_millis(SLEEP_DURATION);
+
+        for num in indexes {
+            let selected = match self.scans.read() {
+                Ok(u_scans) => {
+                    if num >= u_scans.len() {
+                        self.menu
+                            .println(&format!("The number {} is not a valid choice.", num));
+                        sleep(menu_pause_duration);
+                        continue;
+                    }
+                    u_scans.index(num).clone()
+                }
+                Err(..) => continue,
+            };
+
+            if paused {
+                self.menu.println(&format!("Pausing {}...", selected.url));
+                selected.pause();
+            } else {
+                self.menu.println(&format!("Resuming {}...", selected.url));
+                selected.resume();
+            }
+
+            sleep(menu_pause_duration);
+        }
+    }
+
+    /// indexes of the scans shown by `display_scans` that are still running or waiting to run
+    fn active_scan_indices(&self) -> Vec<usize> {
+        match self.scans.read() {
+            Ok(scans) => scans
+                .iter()
+                .enumerate()
+                .filter(|(_, scan)| {
+                    !matches!(scan.scan_order, ScanOrder::Initial)
+                        && scan.is_active()
+                        && scan.task.try_lock().is_ok()
+                })
+                .map(|(i, _)| i)
+                .collect(),
+            Err(_) => Vec::new(),
+        }
+    }
+
     fn display_filters(&self, handles: Arc<Handles>) {
         let mut printed = 0;
 
@@ -406,16 +767,38 @@ impl FeroxScans {
     /// CLI menu that allows for interactive cancellation of recursed-into directories
     async fn interactive_menu(&self, handles: Arc<Handles>) -> Option<MenuCmdResult> {
         self.menu.hide_progress_bars();
-        self.menu.clear_screen();
-        self.menu.print_header();
-        self.display_scans().await;
-        self.display_filters(handles.clone());
-        self.menu.print_footer();
 
-        let menu_cmd = if let Ok(line) = self.menu.term.read_line() {
-            self.menu.get_command_input_from_user(&line)
-        } else {
-            None
+        let mut error: Option<String> = None;
+
+        let menu_cmd = loop {
+            self.menu.clear_screen();
+            self.menu.print_header();
+            self.menu.println(&self.stats().to_string());
+            self.menu.print_border();
+            self.display_scans().await;
+            self.display_filters(handles.clone());
+            self.menu.print_footer();
+
+            if let Some(error) = &error {
+                self.menu.println(error);
+            }
+
+            let line = match self.menu.term.read_line() {
+                Ok(line) => line,
+                Err(_) => break None, // can't read from the terminal, just resume
+            };
+
+            match self.menu.get_command_input_from_user(&line) {
+                Some(cmd) => break Some(cmd),
+                None => {
+                    // invalid input; show the menu again along with what went wrong
+                    error = Some(format!(
+                        "{} Invalid command: {}",
+                        style("[!]").red(),
+                        line.trim()
+                    ));
+                }
+            }
         };
 
         let result = match menu_cmd {
@@ -424,6 +807,25 @@ impl FeroxScans {
                 let num_cancelled = self.cancel_scans(indices, should_force).await;
                 Some(MenuCmdResult::NumCancelled(num_cancelled))
             }
+            Some(MenuCmd::CancelAll(should_force)) => {
+                let indices = self.active_scan_indices();
+                let num_cancelled = self.cancel_scans(indices, should_force).await;
+                Some(MenuCmdResult::NumCancelled(num_cancelled))
+            }
+            Some(MenuCmd::Quit) => {
+                self.menu.clear_screen();
+                self.menu.show_progress_bars();
+
+                if handles.config.save_state {
+                    TermInputHandler::save_state_and_exit(handles, "quit")
+                        .unwrap_or_else(|e| log::warn!("Could not save scan state: {}", e));
+                }
+
+                flush_error_log()
+                    .unwrap_or_else(|e| log::warn!("Could not flush connection error log: {}", e));
+
+                std::process::exit(1);
+            }
             Some(MenuCmd::AddUrl(url)) => Some(MenuCmdResult::Url(url)),
             Some(MenuCmd::AddFilter(filter)) => Some(MenuCmdResult::Filter(filter)),
             Some(MenuCmd::RemoveFilter(indices)) => {
@@ -433,7 +835,15 @@ impl FeroxScans {
                     .unwrap_or_default();
                 None
             }
-            None => None,
+            Some(MenuCmd::PauseScans(indices)) => {
+                self.set_scans_paused(indices, true);
+                None
+            }
+            Some(MenuCmd::ResumeScans(indices)) => {
+                self.set_scans_paused(indices, false);
+                None
+            }
+            Some(MenuCmd::Resume) | None => None,
         };
 
         self.menu.clear_screen();
@@ -465,18 +875,14 @@ impl FeroxScans {
             OutputLevel::Silent => return Ok(()), // fast exit when --silent was used
         };
 
-        if let Ok(scans) = self.scans.read() {
-            for scan in scans.iter() {
-                if scan.is_complete() {
-                    // these scans are complete, and just need to be shown to the user
-                    let pb = add_bar(
-                        &scan.url,
-                        bar_length.try_into().unwrap_or_default(),
-                        bar_type,
-                    );
-                    pb.finish();
-                }
-            }
+        for scan in self.get_completed() {
+            // these scans are complete, and just need to be shown to the user
+            let pb = add_bar(
+                &scan.url,
+                bar_length.try_into().unwrap_or_default(),
+                bar_type,
+            );
+            pb.finish();
         }
         Ok(())
     }
@@ -516,9 +922,13 @@ impl FeroxScans {
             if !PAUSE_SCAN.load(Ordering::Acquire) {
                 // PAUSE_SCAN is false, so we can exit the busy loop
 
-                if INTERACTIVE_BARRIER.load(Ordering::Relaxed) == 1 {
-                    INTERACTIVE_BARRIER.fetch_sub(1, Ordering::Relaxed);
-                }
+                // only the first scan out releases the barrier
+                let _ = INTERACTIVE_BARRIER.compare_exchange(
+                    1,
+                    0,
+                    Ordering::Relaxed,
+                    Ordering::Relaxed,
+                );
 
                 log::trace!("exit: pause_scan -> {:?}", command_result);
                 return command_result;
@@ -526,6 +936,43 @@ impl FeroxScans {
         }
     }
 
+    /// --pause-on-rate-limit; pause every scan for the given duration without opening the
+    /// interactive menu
+    ///
+    /// returns `false` without pausing if the scans are already paused, either by another 429 or
+    /// by the user
+    pub async fn pause_for(&self, duration: Duration, url: &str) -> bool {
+        // claiming the barrier before setting PAUSE_SCAN keeps scans that notice the pause from
+        // opening the interactive menu; they wait in `pause` until PAUSE_SCAN is cleared instead
+        if INTERACTIVE_BARRIER
+            .compare_exchange(0, 1, Ordering::Relaxed, Ordering::Relaxed)
+            .is_err()
+        {
+            return false;
+        }
+
+        PAUSE_SCAN.store(true, Ordering::Release);
+
+        if !matches!(self.output_level, OutputLevel::Silent) {
+            let msg = format!(
+                "{} received a 429 from {}, pausing all scans for {}s",
+                style("[!]").yellow(),
+                url,
+                duration.as_secs()
+            );
+            ferox_print(&msg, &PROGRESS_PRINTER);
+        }
+
+        time::sleep(duration).await;
+
+        PAUSE_SCAN.store(false, Ordering::Release);
+
+        // scans waiting in `pause` may have already released the barrier on their way out
+        let _ = INTERACTIVE_BARRIER.compare_exchange(1, 0, Ordering::Relaxed, Ordering::Relaxed);
+
+        true
+    }
+
     /// set the bar length of FeroxScans
     pub fn set_bar_length(&self, bar_length: u64) {
         if let Ok(mut guard) = self.bar_length.lock() {
@@ -564,16 +1011,27 @@ impl FeroxScans {
 
                 Some(progress_bar)
             }
-            ScanType::File => None,
+            ScanType::File | ScanType::Unknown => None,
+        };
+
+        // recursion only ever spawns scans beneath the directory being scanned, so the deepest
+        // existing scan whose url is a prefix of this one is the scan that spawned it
+        let parent_id = match scan_order {
+            ScanOrder::Initial => None,
+            ScanOrder::Latest => self
+                .get_base_scan_by_url(url.trim_end_matches('/'))
+                .map(|parent| parent.id().to_string()),
         };
 
-        let ferox_scan = FeroxScan::new(
+        let ferox_scan = FeroxScan::new_with_parent(
             url,
             scan_type,
             scan_order,
             bar_length,
             self.output_level,
             bar,
+            parent_id,
+            self.rate_limit,
         );
 
         // If the set did not contain the scan, true is returned.
@@ -601,6 +1059,34 @@ impl FeroxScans {
         self.add_scan(url, ScanType::File, scan_order)
     }
 
+    /// Given a url, create a new `FeroxScan` and add it to `FeroxScans` as an Unknown Scan
+    ///
+    /// If `FeroxScans` did not already contain the scan, return true; otherwise return false
+    ///
+    /// Also return a reference to the new `FeroxScan`
+    pub fn add_unknown_scan(&self, url: &str, scan_order: ScanOrder) -> (bool, Arc<FeroxScan>) {
+        self.add_scan(url, ScanType::Unknown, scan_order)
+    }
+
+    /// if the given response's url belongs to a scan of an Unknown type, classify the scan using
+    /// the response; returns the scan's new type, if it was updated
+    pub fn resolve_unknown_scan_type(&self, response: &FeroxResponse) -> Option<ScanType> {
+        let scan = self.get_scan_by_url(response.url().as_str())?;
+
+        // check and update under the same lock, so only the first response is used
+        let mut guard = scan.scan_type.lock().ok()?;
+
+        if !matches!(*guard, ScanType::Unknown) {
+            return None;
+        }
+
+        *guard = detect_scan_type(response.url().as_str(), response);
+
+        log::debug!("{} detected as {:?}", scan.url(), *guard);
+
+        Some(*guard)
+    }
+
     /// small helper to determine whether any scans are active or not
     pub fn has_active_scans(&self) -> bool {
         if let Ok(guard) = self.scans.read() {
@@ -628,6 +1114,82 @@ impl FeroxScans {
         scans
     }
 
+    /// Retrieve all scans that are marked complete
+    pub fn get_completed(&self) -> Vec<Arc<FeroxScan>> {
+        match self.scans.read() {
+            Ok(guard) => guard
+                .iter()
+                .filter(|scan| scan.is_complete())
+                .cloned()
+                .collect(),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    /// snapshot of how the overall scan is progressing: how many scans are known, how many of
+    /// those are complete, and how many responses have been found
+    pub fn stats(&self) -> ScanStats {
+        let (total, complete) = match self.scans.read() {
+            Ok(guard) => (
+                guard.len(),
+                guard.iter().filter(|scan| scan.is_complete()).count(),
+            ),
+            Err(_) => (0, 0),
+        };
+
+        let total_responses = match RESPONSES.responses.read() {
+            Ok(responses) => responses.len(),
+            Err(_) => 0,
+        };
+
+        ScanStats {
+            total,
+            complete,
+            incomplete: total - complete,
+            total_responses,
+        }
+    }
+
+    /// Retrieve all scans that aren't marked complete, whether they're running, waiting to start,
+    /// or were stopped early
+    pub fn get_incomplete(&self) -> Vec<Arc<FeroxScan>> {
+        match self.scans.read() {
+            Ok(guard) => guard
+                .iter()
+                .filter(|scan| !scan.is_complete())
+                .cloned()
+                .collect(),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    /// Retrieve all scans that were aborted before they could finish
+    pub fn get_cancelled_scans(&self) -> Vec<Arc<FeroxScan>> {
+        match self.scans.read() {
+            Ok(guard) => guard
+                .iter()
+                .filter(|scan| scan.is_cancelled())
+                .cloned()
+                .collect(),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    /// Retrieve all scans that were marked as failed after exceeding --max-retries
+    pub fn get_failed_scans(&self) -> Vec<Arc<FeroxScan>> {
+        let mut scans = vec![];
+
+        if let Ok(guard) = self.scans.read() {
+            for scan in guard.iter() {
+                if !scan.is_failed() {
+                    continue;
+                }
+                scans.push(scan.clone());
+            }
+        }
+        scans
+    }
+
     /// given an extension, add it to `collected_extensions` if all constraints are met
     /// returns `true` if an extension was added, `false` otherwise
     pub fn add_discovered_extension(&self, extension: String) -> bool {
@@ -654,16 +1216,169 @@ impl FeroxScans {
         log::trace!("exit: add_discovered_extension -> {}", extension_added);
         extension_added
     }
+
+    /// given a form action's url, add it to `form_urls`
+    /// returns `true` if the url wasn't previously known, `false` otherwise
+    pub fn add_form_url(&self, url: &str) -> bool {
+        log::trace!("enter: add_form_url({})", url);
+        let mut url_added = false;
+
+        if let Ok(urls) = self.form_urls.read() {
+            if urls.contains(url) {
+                return url_added;
+            }
+        }
+
+        if let Ok(mut urls) = self.form_urls.write() {
+            log::info!("discovered new form action: {}", url);
+            url_added = urls.insert(url.to_string());
+        }
+
+        log::trace!("exit: add_form_url -> {}", url_added);
+        url_added
+    }
+
+    /// given an extracted link, add it to `seen_paths` without its query string or fragment
+    /// returns `true` if the path wasn't previously known, `false` otherwise
+    pub fn add_seen_path(&self, url: &str) -> bool {
+        let path = normalize_url(url);
+
+        if let Ok(paths) = self.seen_paths.read() {
+            if paths.contains(&path) {
+                return false;
+            }
+        }
+
+        if let Ok(mut paths) = self.seen_paths.write() {
+            return paths.insert(path);
+        }
+
+        false
+    }
+
+    /// given a source map's url, add it to `source_maps`
+    /// returns `true` if the url wasn't previously known, `false` otherwise
+    pub fn add_source_map(&self, url: &str) -> bool {
+        log::trace!("enter: add_source_map({})", url);
+        let mut url_added = false;
+
+        if let Ok(urls) = self.source_maps.read() {
+            if urls.contains(url) {
+                return url_added;
+            }
+        }
+
+        if let Ok(mut urls) = self.source_maps.write() {
+            log::info!("discovered new source map: {}", url);
+            url_added = urls.insert(url.to_string());
+        }
+
+        log::trace!("exit: add_source_map -> {}", url_added);
+        url_added
+    }
+
+    /// given the url of an api version, add it to `api_version_probes`
+    /// returns `true` if the url wasn't previously known, `false` otherwise
+    pub fn add_api_version_probe(&self, url: &str) -> bool {
+        log::trace!("enter: add_api_version_probe({})", url);
+        let mut url_added = false;
+
+        if let Ok(urls) = self.api_version_probes.read() {
+            if urls.contains(url) {
+                return url_added;
+            }
+        }
+
+        if let Ok(mut urls) = self.api_version_probes.write() {
+            url_added = urls.insert(url.to_string());
+        }
+
+        log::trace!("exit: add_api_version_probe -> {}", url_added);
+        url_added
+    }
+
+    /// write the current scan state to a new state file, in the same format used when a scan
+    /// is stopped with ctrl+c, so that it can later be given to --resume-from; returns the
+    /// name of the file written
+    pub fn export_to_file(
+        &self,
+        config: &Configuration,
+        responses: &FeroxResponses,
+        statistics: &Stats,
+        filters: &FeroxFilters,
+    ) -> Result<String, std::io::Error> {
+        let filename = state_filename(config);
+        self.export_to(&filename, config, responses, statistics, filters)?;
+        Ok(filename)
+    }
+
+    /// write the current scan state to `filename`; any existing file is overwritten
+    pub(super) fn export_to(
+        &self,
+        filename: &str,
+        config: &Configuration,
+        responses: &FeroxResponses,
+        statistics: &Stats,
+        filters: &FeroxFilters,
+    ) -> Result<(), std::io::Error> {
+        log::trace!("enter: export_to({})", filename);
+
+        let state = FeroxState::new(self, config, responses, statistics, filters);
+
+        save_state(&state, filename).map_err(std::io::Error::other)?;
+
+        log::trace!("exit: export_to");
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    /// a scan of an Unknown type should be classified by the first response for its url, and
+    /// left alone afterwards
+    fn resolve_unknown_scan_type_updates_scan_once() {
+        let scans = FeroxScans::new(OutputLevel::Default, None, false);
+        let (_, scan) = scans.add_unknown_scan("http://localhost/api", ScanOrder::Latest);
+
+        let response = |content_type: &str| -> FeroxResponse {
+            serde_json::from_value(serde_json::json!({
+                "url": "http://localhost/api",
+                "status": 200,
+                "headers": {"content-type": content_type},
+            }))
+            .unwrap()
+        };
+
+        // still ambiguous, stays unknown and can be resolved later
+        assert!(matches!(
+            scans.resolve_unknown_scan_type(&response("text/html")),
+            Some(ScanType::Unknown)
+        ));
+
+        assert!(matches!(
+            scans.resolve_unknown_scan_type(&response("application/json")),
+            Some(ScanType::File)
+        ));
+        assert!(matches!(scan.scan_type(), ScanType::File));
+
+        // already classified
+        assert!(scans
+            .resolve_unknown_scan_type(&response("application/json"))
+            .is_none());
+
+        // no scan for the url
+        let mut other = FeroxResponse::default();
+        other.set_url("http://localhost/other");
+        assert!(scans.resolve_unknown_scan_type(&other).is_none());
+    }
+
     #[test]
     /// unknown extension should be added to collected_extensions
     fn unknown_extension_is_added_to_collected_extensions() {
-        let scans = FeroxScans::new(OutputLevel::Default);
+        let scans = FeroxScans::new(OutputLevel::Default, None, false);
 
         assert_eq!(0, scans.collected_extensions.read().unwrap().len());
 
@@ -673,10 +1388,399 @@ mod tests {
         assert_eq!(1, scans.collected_extensions.read().unwrap().len());
     }
 
+    #[test]
+    /// with --case-insensitive-dedup, directory scans differing only in path case are duplicates
+    fn case_insensitive_dedup_skips_differently_cased_scans() {
+        let scans = FeroxScans::new(OutputLevel::Default, None, false);
+
+        let (added, _) = scans.add_directory_scan("http://localhost/Admin", ScanOrder::Latest);
+        assert!(added);
+        let (added, _) = scans.add_directory_scan("http://localhost/admin", ScanOrder::Latest);
+        assert!(added);
+
+        let scans = FeroxScans::new(OutputLevel::Default, None, true);
+
+        let (added, _) = scans.add_directory_scan("http://localhost/Admin", ScanOrder::Latest);
+        assert!(added);
+        let (added, _) = scans.add_directory_scan("http://localhost/admin", ScanOrder::Latest);
+        assert!(!added);
+        assert!(scans.contains("http://localhost/ADMIN"));
+
+        // lookups by url stay exact
+        assert!(scans.get_scan_by_url("http://localhost/Admin").is_some());
+        assert!(scans.get_scan_by_url("http://localhost/ADMIN").is_none());
+    }
+
+    #[test]
+    /// when the same url is inserted from several threads at once, only one insert should win
+    fn concurrent_inserts_of_same_url_add_one_scan() {
+        let scans = Arc::new(FeroxScans::new(OutputLevel::Default, None, false));
+
+        let threads: Vec<_> = (0..8)
+            .map(|_| {
+                let scans = scans.clone();
+                std::thread::spawn(move || {
+                    scans
+                        .add_directory_scan("http://localhost/race", ScanOrder::Latest)
+                        .0
+                })
+            })
+            .collect();
+
+        let num_added = threads
+            .into_iter()
+            .map(|thread| thread.join().unwrap())
+            .filter(|added| *added)
+            .count();
+
+        assert_eq!(num_added, 1);
+        assert_eq!(scans.scans.read().unwrap().len(), 1);
+
+        // the list and both indexes should all hold the same scan
+        let scan = scans.get_scan_by_url("http://localhost/race").unwrap();
+        assert!(Arc::ptr_eq(&scans.scans.read().unwrap()[0], &scan));
+        assert!(Arc::ptr_eq(
+            &scans.get_scan_by_id(scan.id()).unwrap(),
+            &scan
+        ));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    /// cancelling scans from the menu should remove them and the scans they spawned, and
+    /// indexes later in the same command should still point at the scans that were shown
+    async fn cancelled_scans_are_removed() {
+        let scans = FeroxScans::new(OutputLevel::Default, None, false);
+
+        let (_, parent) = scans.add_directory_scan("http://localhost/a", ScanOrder::Latest);
+        let child = FeroxScan::new_with_parent(
+            "http://localhost/a/b",
+            ScanType::Directory,
+            ScanOrder::Latest,
+            0,
+            OutputLevel::Default,
+            None,
+            Some(parent.id().to_string()),
+            None,
+        );
+        scans.insert(child.clone());
+        scans.add_directory_scan("http://localhost/c", ScanOrder::Latest);
+        let (_, last) = scans.add_directory_scan("http://localhost/d", ScanOrder::Latest);
+
+        for scan in [&parent, &child, &last] {
+            scan.set_task(tokio::spawn(async {})).await.unwrap();
+            scan.set_status(ScanStatus::Running).unwrap();
+        }
+
+        // index 3 is http://localhost/d when the menu is shown, even though removing
+        // http://localhost/a and its child first leaves it at index 1
+        scans.cancel_scans(vec![0, 3], true).await;
+
+        assert!(parent.is_cancelled());
+        assert!(child.is_cancelled());
+        assert!(last.is_cancelled());
+
+        for url in [
+            "http://localhost/a",
+            "http://localhost/a/b",
+            "http://localhost/d",
+        ] {
+            assert!(!scans.contains(url), "{} wasn't removed", url);
+        }
+        assert!(scans.contains("http://localhost/c"));
+        assert_eq!(scans.scans.read().unwrap().len(), 1);
+    }
+
+    #[test]
+    /// source maps should only be added once
+    fn source_maps_are_only_added_once() {
+        let scans = FeroxScans::new(OutputLevel::Default, None, false);
+
+        assert!(scans.add_source_map("http://localhost/app.js.map"));
+        assert!(!scans.add_source_map("http://localhost/app.js.map"));
+    }
+
+    #[test]
+    /// api version probes should only be added once
+    fn api_version_probes_are_only_added_once() {
+        let scans = FeroxScans::new(OutputLevel::Default, None, false);
+
+        assert!(scans.add_api_version_probe("http://localhost/api/v2/"));
+        assert!(!scans.add_api_version_probe("http://localhost/api/v2/"));
+    }
+
+    #[test]
+    /// exported state should be readable by the same code that loads a ctrl+c state file
+    fn exported_state_can_be_imported() {
+        let scans = FeroxScans::new(OutputLevel::Default, None, false);
+        scans.insert(FeroxScan::new(
+            "http://localhost/js",
+            ScanType::Directory,
+            ScanOrder::Latest,
+            0,
+            OutputLevel::Default,
+            None,
+            None,
+        ));
+        scans.add_discovered_extension(String::from("php"));
+
+        let json = r#"{"type":"response","url":"http://localhost/js/app","path":"/js/app","wildcard":false,"status":200,"method":"GET","content_length":0,"line_count":0,"word_count":0,"headers":{},"extension":""}"#;
+        let responses = FeroxResponses::default();
+        responses.insert(serde_json::from_str(json).unwrap());
+
+        let config = Configuration::new().unwrap();
+        let tfile = tempfile::NamedTempFile::new().unwrap();
+        let filename = tfile.path().to_str().unwrap();
+
+        scans
+            .export_to(
+                filename,
+                &config,
+                &responses,
+                &Stats::new(config.json),
+                &FeroxFilters::default(),
+            )
+            .unwrap();
+
+        let state: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(filename).unwrap()).unwrap();
+        for key in [
+            "scans",
+            "config",
+            "responses",
+            "statistics",
+            "collected_extensions",
+            "filters",
+        ] {
+            assert!(state.get(key).is_some(), "missing {}", key);
+        }
+
+        let imported = FeroxScans::new(OutputLevel::Default, None, false);
+        let handles = Arc::new(Handles::for_testing(None, None).0);
+
+        imported.add_serialized_scans(filename, handles).unwrap();
+
+        assert!(imported.contains("http://localhost/js"));
+        assert!(imported
+            .collected_extensions
+            .read()
+            .unwrap()
+            .contains("php"));
+        assert_eq!(imported.add_previous_responses(filename).unwrap(), 1);
+        assert!(imported.skip_previously_found("http://localhost/js/app"));
+    }
+
+    #[test]
+    /// checkpoints should keep the scan's statistics and any filters added while it ran
+    fn exported_state_keeps_statistics_and_filters() {
+        let scans = FeroxScans::new(OutputLevel::Default, None, false);
+        let responses = FeroxResponses::default();
+        let config = Configuration::new().unwrap();
+
+        let statistics = Stats::new(config.json);
+        statistics.add_request();
+        statistics.add_request();
+
+        let filters = FeroxFilters::default();
+        filters
+            .push(Box::new(StatusCodeFilter { filter_code: 404 }))
+            .unwrap();
+
+        let tfile = tempfile::NamedTempFile::new().unwrap();
+        let filename = tfile.path().to_str().unwrap();
+
+        scans
+            .export_to(filename, &config, &responses, &statistics, &filters)
+            .unwrap();
+
+        let state: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(filename).unwrap()).unwrap();
+        assert_eq!(state["filters"][0]["filter_code"], 404);
+
+        let resumed = Stats::new(config.json);
+        resumed.merge_from(filename).unwrap();
+        assert_eq!(resumed.requests(), 2);
+    }
+
+    #[test]
+    /// --split-state should write scans and responses to their own files as JSON arrays, and
+    /// the state file should still be readable by the code that loads a combined state file
+    fn split_state_can_be_imported() {
+        let scans = FeroxScans::new(OutputLevel::Default, None, false);
+        scans.insert(FeroxScan::new(
+            "http://localhost/js",
+            ScanType::Directory,
+            ScanOrder::Latest,
+            0,
+            OutputLevel::Default,
+            None,
+            None,
+        ));
+
+        let json = r#"{"type":"response","url":"http://localhost/js/app","path":"/js/app","wildcard":false,"status":200,"method":"GET","content_length":0,"line_count":0,"word_count":0,"headers":{},"extension":""}"#;
+        let responses = FeroxResponses::default();
+        responses.insert(serde_json::from_str(json).unwrap());
+
+        let config = Configuration {
+            split_state: true,
+            ..Configuration::new().unwrap()
+        };
+        let tmp_dir = tempfile::TempDir::new().unwrap();
+        let path = tmp_dir.path().join("ferox-http_localhost-1600000000.state");
+        let filename = path.to_str().unwrap();
+
+        scans
+            .export_to(
+                filename,
+                &config,
+                &responses,
+                &Stats::new(config.json),
+                &FeroxFilters::default(),
+            )
+            .unwrap();
+
+        let state: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(filename).unwrap()).unwrap();
+        assert!(state.get("scans").is_none());
+        assert!(state.get("responses").is_none());
+        assert!(state.get("config").is_some());
+        assert_eq!(
+            state["scans_file"],
+            "ferox-scans-http_localhost-1600000000.state"
+        );
+        assert_eq!(
+            state["responses_file"],
+            "ferox-responses-http_localhost-1600000000.state"
+        );
+
+        for (key, url) in [
+            ("scans", "http://localhost/js"),
+            ("responses", "http://localhost/js/app"),
+        ] {
+            let split_file = tmp_dir
+                .path()
+                .join(format!("ferox-{}-http_localhost-1600000000.state", key));
+            let entries: serde_json::Value =
+                serde_json::from_str(&std::fs::read_to_string(split_file).unwrap()).unwrap();
+            let entries = entries.as_array().unwrap();
+
+            assert_eq!(entries.len(), 1);
+            assert_eq!(entries[0]["url"], url);
+        }
+
+        let imported = FeroxScans::new(OutputLevel::Default, None, false);
+        let handles = Arc::new(Handles::for_testing(None, None).0);
+
+        imported.add_serialized_scans(filename, handles).unwrap();
+
+        assert!(imported.contains("http://localhost/js"));
+        assert_eq!(imported.add_previous_responses(filename).unwrap(), 1);
+    }
+
+    /// helper to add a three level deep tree of directory scans, returning the scans in the
+    /// order they were added: root, a, c, a/b
+    fn add_scan_tree(scans: &FeroxScans) -> Vec<Arc<FeroxScan>> {
+        let (_, root) = scans.add_directory_scan("http://localhost/", ScanOrder::Initial);
+        let (_, a) = scans.add_directory_scan("http://localhost/a/", ScanOrder::Latest);
+        let (_, c) = scans.add_directory_scan("http://localhost/c", ScanOrder::Latest);
+        let (_, b) = scans.add_directory_scan("http://localhost/a/b/", ScanOrder::Latest);
+
+        vec![root, a, c, b]
+    }
+
+    #[test]
+    /// scans should be linked to the deepest scan whose url contains theirs
+    fn get_by_parent_returns_direct_children() {
+        let scans = FeroxScans::new(OutputLevel::Silent, None, false);
+        let added = add_scan_tree(&scans);
+        let (root, a, c, b) = (&added[0], &added[1], &added[2], &added[3]);
+
+        assert_eq!(root.parent_id(), None);
+        assert_eq!(a.parent_id(), Some(root.id()));
+        assert_eq!(b.parent_id(), Some(a.id()));
+
+        assert_eq!(scans.get_by_parent(root.id()), vec![a.clone(), c.clone()]);
+        assert_eq!(scans.get_by_parent(a.id()), vec![b.clone()]);
+        assert!(scans.get_by_parent(b.id()).is_empty());
+        assert!(scans.get_by_parent("unknown").is_empty());
+    }
+
+    #[test]
+    /// depth_of should count the scans between the given scan and its initial target
+    fn depth_of_follows_parent_chain() {
+        let scans = FeroxScans::new(OutputLevel::Silent, None, false);
+        let (_, root) = scans.add_directory_scan("http://localhost/", ScanOrder::Initial);
+
+        let mut url = String::from("http://localhost");
+        let mut chain = vec![root];
+
+        for level in 1..=5 {
+            url.push_str(&format!("/level{}", level));
+            let (_, scan) = scans.add_directory_scan(&url, ScanOrder::Latest);
+            chain.push(scan);
+        }
+
+        for (depth, scan) in chain.iter().enumerate() {
+            assert_eq!(scans.depth_of(scan.id()), depth);
+        }
+
+        assert_eq!(scans.depth_of("unknown"), 0);
+    }
+
+    #[test]
+    /// a subtree should contain every descendant, with parents before their children
+    fn get_subtree_returns_all_descendants() {
+        let scans = FeroxScans::new(OutputLevel::Silent, None, false);
+        let added = add_scan_tree(&scans);
+        let (root, a, c, b) = (&added[0], &added[1], &added[2], &added[3]);
+
+        assert_eq!(
+            scans.get_subtree(root.id()),
+            vec![a.clone(), b.clone(), c.clone()]
+        );
+        assert_eq!(scans.get_subtree(a.id()), vec![b.clone()]);
+        assert!(scans.get_subtree(c.id()).is_empty());
+    }
+
+    #[test]
+    /// scan_tree should list each scan, by index, directly after its parent along with its depth
+    fn scan_tree_orders_children_after_parents() {
+        let scans = FeroxScans::new(OutputLevel::Silent, None, false);
+        add_scan_tree(&scans);
+
+        let snapshot = scans.scans.read().unwrap().clone();
+
+        assert_eq!(
+            scans.scan_tree(&snapshot),
+            vec![(0, 0), (1, 1), (3, 2), (2, 1)]
+        );
+    }
+
+    #[test]
+    /// the same path should only be added once, regardless of query string or fragment
+    fn seen_path_ignores_query_and_fragment() {
+        let scans = FeroxScans::new(OutputLevel::Default, None, false);
+
+        assert!(scans.add_seen_path("http://localhost/item?id=1"));
+        assert!(!scans.add_seen_path("http://localhost/item?id=2"));
+        assert!(!scans.add_seen_path("http://localhost/item#reviews"));
+        assert!(scans.add_seen_path("http://localhost/other?id=1"));
+        assert_eq!(2, scans.seen_paths.read().unwrap().len());
+    }
+
+    #[test]
+    /// form urls should only be reported as added the first time they're seen
+    fn form_url_is_only_added_once() {
+        let scans = FeroxScans::new(OutputLevel::Default, None, false);
+
+        assert!(scans.add_form_url("http://localhost/login"));
+        assert!(!scans.add_form_url("http://localhost/login"));
+        assert_eq!(1, scans.form_urls.read().unwrap().len());
+    }
+
     #[test]
     /// known extension should not be added to collected_extensions
     fn known_extension_is_added_to_collected_extensions() {
-        let scans = FeroxScans::new(OutputLevel::Default);
+        let scans = FeroxScans::new(OutputLevel::Default, None, false);
         scans
             .collected_extensions
             .write()
@@ -0,0 +1,47 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+/// snapshot of how the overall scan is progressing, as returned by `FeroxScans::stats`
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScanStats {
+    /// number of known scans
+    pub total: usize,
+
+    /// number of scans marked complete
+    pub complete: usize,
+
+    /// number of scans that aren't complete, whether they're running, waiting to start, or were
+    /// stopped early
+    pub incomplete: usize,
+
+    /// number of responses found so far, across every scan
+    pub total_responses: usize,
+}
+
+/// implementation of ScanStats
+impl ScanStats {
+    /// --json-status; a single line of JSON, i.e. `{"type":"status","total":3,...}`
+    pub fn as_json(&self) -> String {
+        json!({
+            "type": "status",
+            "total": self.total,
+            "complete": self.complete,
+            "incomplete": self.incomplete,
+            "total_responses": self.total_responses,
+        })
+        .to_string()
+    }
+}
+
+/// Display implementation for ScanStats
+impl fmt::Display for ScanStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}/{} scans complete ({} remaining), {} responses found",
+            self.complete, self.total, self.incomplete, self.total_responses
+        )
+    }
+}
@@ -0,0 +1,62 @@
+use std::collections::BTreeMap;
+
+use lazy_static::lazy_static;
+use reqwest::header::HeaderMap;
+
+use super::HostCollector;
+
+/// headers that --collect-security-headers expects every html page to set
+pub const SECURITY_HEADERS: [&str; 5] = [
+    "Content-Security-Policy",
+    "X-Frame-Options",
+    "X-Content-Type-Options",
+    "Strict-Transport-Security",
+    "Permissions-Policy",
+];
+
+/// names of the missing security headers of each of a single host's pages, keyed by url
+pub type MissingSecurityHeaders = BTreeMap<String, Vec<String>>;
+
+lazy_static! {
+    /// security headers missing from each html page checked via --collect-security-headers;
+    /// pages that weren't missing any headers are included
+    pub static ref MISSING_SECURITY_HEADERS: HostCollector<MissingSecurityHeaders> =
+        HostCollector::default();
+}
+
+/// names of the security headers missing from the given headers, in the order they appear in
+/// `SECURITY_HEADERS`
+pub fn check_security_headers(headers: &HeaderMap) -> Vec<String> {
+    SECURITY_HEADERS
+        .iter()
+        .filter(|header| !headers.contains_key(**header))
+        .map(|header| header.to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// only headers that are absent should be reported, regardless of the case they're sent in
+    fn check_security_headers_reports_missing_headers() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(check_security_headers(&headers), SECURITY_HEADERS);
+
+        headers.insert("x-frame-options", "DENY".parse().unwrap());
+        headers.insert(
+            "Content-Security-Policy",
+            "default-src 'self'".parse().unwrap(),
+        );
+
+        assert_eq!(
+            check_security_headers(&headers),
+            [
+                "X-Content-Type-Options",
+                "Strict-Transport-Security",
+                "Permissions-Policy"
+            ]
+        );
+    }
+}
@@ -0,0 +1,10 @@
+use std::collections::BTreeSet;
+
+use lazy_static::lazy_static;
+
+use super::HostCollector;
+
+lazy_static! {
+    /// original source file paths found in source maps via --collect-source-maps
+    pub static ref SOURCE_MAP_PATHS: HostCollector<BTreeSet<String>> = HostCollector::default();
+}
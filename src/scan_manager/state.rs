@@ -1,42 +1,59 @@
 use super::*;
 use crate::filters::FeroxFilters;
-use crate::{config::Configuration, statistics::Stats, traits::FeroxSerialize, utils::fmt_err};
-use anyhow::{Context, Result};
+use crate::{
+    config::Configuration,
+    response::FeroxResponse,
+    scanner::RESPONSES,
+    statistics::Stats,
+    traits::FeroxSerialize,
+    utils::{fmt_err, slugify_filename, write_to},
+};
+use anyhow::{bail, Context, Result};
 use serde::Serialize;
-use std::collections::HashSet;
-use std::sync::Arc;
+use serde_json::Value;
+use std::{
+    collections::HashSet,
+    fmt,
+    fs::{self, File},
+    io::{BufReader, BufWriter, Write},
+    path::Path,
+    sync::Arc,
+};
+
+/// parts of a state file that --split-state writes to their own files
+const SPLIT_STATE_KEYS: [&str; 2] = ["scans", "responses"];
 
 /// Data container for (de)?serialization of multiple items
 #[derive(Serialize, Debug)]
-pub struct FeroxState {
+pub struct FeroxState<'a> {
     /// Known scans
-    scans: Arc<FeroxScans>,
+    scans: &'a FeroxScans,
 
     /// Current running config
-    config: Arc<Configuration>,
+    config: &'a Configuration,
 
     /// Known responses
-    responses: &'static FeroxResponses,
+    responses: &'a FeroxResponses,
 
     /// Gathered statistics
-    statistics: Arc<Stats>,
+    statistics: &'a Stats,
 
     /// collected extensions
     collected_extensions: HashSet<String>,
 
     /// runtime filters, as they may differ from original config
-    filters: Arc<FeroxFilters>,
+    filters: &'a FeroxFilters,
 }
 
 /// implementation of FeroxState
-impl FeroxState {
+impl<'a> FeroxState<'a> {
     /// create new FeroxState object
     pub fn new(
-        scans: Arc<FeroxScans>,
-        config: Arc<Configuration>,
-        responses: &'static FeroxResponses,
-        statistics: Arc<Stats>,
-        filters: Arc<FeroxFilters>,
+        scans: &'a FeroxScans,
+        config: &'a Configuration,
+        responses: &'a FeroxResponses,
+        statistics: &'a Stats,
+        filters: &'a FeroxFilters,
     ) -> Self {
         let collected_extensions = match scans.collected_extensions.read() {
             Ok(extensions) => extensions.clone(),
@@ -52,10 +69,322 @@ impl FeroxState {
             filters,
         }
     }
+
+    /// compare this state to a later one (i.e. `other` came from resuming this one), collecting
+    /// the responses and scans that differ between the two
+    ///
+    /// responses are matched on url, method, and uploaded filename; scans on url
+    pub fn diff(&self, other: &FeroxState) -> FeroxStateDiff {
+        let missing_from = |responses: &FeroxResponses, state: &FeroxState| -> Vec<FeroxResponse> {
+            match responses.responses.read() {
+                Ok(responses) => responses
+                    .iter()
+                    .filter(|response| !state.responses.contains(response, false))
+                    .cloned()
+                    .collect(),
+                Err(_) => Vec::new(),
+            }
+        };
+
+        let new_scans = match other.scans.scans.read() {
+            Ok(scans) => scans
+                .iter()
+                .filter(|scan| !self.scans.contains(scan.url()))
+                .cloned()
+                .collect(),
+            Err(_) => Vec::new(),
+        };
+
+        FeroxStateDiff {
+            new_responses: missing_from(other.responses, self),
+            removed_responses: missing_from(self.responses, other),
+            new_scans,
+        }
+    }
+}
+
+/// differences between two `FeroxState`s, as found by `FeroxState::diff`
+#[derive(Debug, Default)]
+pub struct FeroxStateDiff {
+    /// responses found in the later state, but not the earlier one
+    pub new_responses: Vec<FeroxResponse>,
+
+    /// responses found in the earlier state, but not the later one
+    pub removed_responses: Vec<FeroxResponse>,
+
+    /// scans started in the later state, but not the earlier one
+    pub new_scans: Vec<Arc<FeroxScan>>,
+}
+
+/// Display implementation for FeroxStateDiff
+impl fmt::Display for FeroxStateDiff {
+    /// one section each for new responses, removed responses, and new scans; entries are marked
+    /// with + or - depending on whether they were gained or lost
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let response_line = |marker: &str, response: &FeroxResponse| {
+            format!(
+                "  {} {} {:>7} {}",
+                marker,
+                response.status().as_str(),
+                response.method().as_str(),
+                response.url()
+            )
+        };
+
+        writeln!(f, "New responses ({}):", self.new_responses.len())?;
+        for response in &self.new_responses {
+            writeln!(f, "{}", response_line("+", response))?;
+        }
+
+        writeln!(f, "Removed responses ({}):", self.removed_responses.len())?;
+        for response in &self.removed_responses {
+            writeln!(f, "{}", response_line("-", response))?;
+        }
+
+        writeln!(f, "New scans ({}):", self.new_scans.len())?;
+        for scan in &self.new_scans {
+            writeln!(f, "  + {}", scan.url())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// the scans and responses of a state file read from disk, owned so that a `FeroxState` can be
+/// built on top of them; used by --diff
+#[derive(Debug, Default)]
+pub struct SavedState {
+    /// scans read from the state file
+    pub(super) scans: FeroxScans,
+
+    /// responses read from the state file
+    pub(super) responses: FeroxResponses,
+
+    /// stand-in config; only needed to build a `FeroxState`
+    config: Configuration,
+
+    /// stand-in statistics; only needed to build a `FeroxState`
+    statistics: Stats,
+
+    /// stand-in filters; only needed to build a `FeroxState`
+    filters: FeroxFilters,
+}
+
+/// implementation of SavedState
+impl SavedState {
+    /// read the scans and responses of the state file at `filename`; entries that can't be
+    /// parsed are skipped
+    pub fn from_file(filename: &str) -> Result<Self> {
+        let state = load_state(filename)?;
+        let saved = Self::default();
+
+        if let Some(scans) = state.get("scans").and_then(Value::as_array) {
+            for scan in scans {
+                if let Ok(scan) = serde_json::from_value::<FeroxScan>(scan.clone()) {
+                    saved.scans.insert(Arc::new(scan));
+                }
+            }
+        }
+
+        if let Some(responses) = state.get("responses").and_then(Value::as_array) {
+            for response in responses {
+                if let Ok(response) = serde_json::from_value(response.clone()) {
+                    saved.responses.insert(response);
+                }
+            }
+        }
+
+        Ok(saved)
+    }
+
+    /// borrow the saved scans and responses as a `FeroxState`
+    pub fn as_state(&self) -> FeroxState<'_> {
+        FeroxState::new(
+            &self.scans,
+            &self.config,
+            &self.responses,
+            &self.statistics,
+            &self.filters,
+        )
+    }
+}
+
+/// print the differences between two state files, `filenames[0]` being the earlier of the two;
+/// used by --diff
+pub fn print_state_diff(filenames: &[String]) -> Result<()> {
+    if filenames.len() != 2 {
+        bail!("--diff expects exactly two state files");
+    }
+
+    let before = SavedState::from_file(&filenames[0])?;
+    let after = SavedState::from_file(&filenames[1])?;
+
+    print!("{}", before.as_state().diff(&after.as_state()));
+
+    Ok(())
+}
+
+/// name of the file that scan state is written to, based on the scan's target
+pub fn state_filename(config: &Configuration) -> String {
+    if !config.target_url.is_empty() {
+        // target url populated
+        slugify_filename(&config.target_url, "ferox", "state")
+    } else {
+        // stdin used
+        slugify_filename("stdin", "ferox", "state")
+    }
+}
+
+/// write the current state of the scan to a state file, named the same way as the one written
+/// when a scan is stopped with ctrl+c, returning the file's name
+pub fn write_state_file(
+    config: &Configuration,
+    scans: &FeroxScans,
+    stats: &Stats,
+    filters: &FeroxFilters,
+) -> Result<String> {
+    let filename = state_filename(config);
+    let state = FeroxState::new(scans, config, &RESPONSES, stats, filters);
+
+    save_state(&state, &filename)?;
+
+    Ok(filename)
+}
+
+/// name of the file that --split-state writes the given part (scans or responses) of a state
+/// file to; it's kept next to the state file
+///
+/// example: ferox-http_localhost-1600000000.state -> ferox-scans-http_localhost-1600000000.state
+pub fn split_state_filename(filename: &str, key: &str) -> String {
+    let path = Path::new(filename);
+
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    let split_name = match name.strip_prefix("ferox-") {
+        Some(rest) => format!("ferox-{}-{}", key, rest),
+        None => format!("{}-{}", key, name),
+    };
+
+    path.with_file_name(split_name)
+        .to_string_lossy()
+        .to_string()
+}
+
+/// remove the given state file, along with any files --split-state wrote next to it
+pub fn remove_state_file(filename: &str) {
+    for split_filename in SPLIT_STATE_KEYS.map(|key| split_state_filename(filename, key)) {
+        // only there when --split-state was used
+        let _ = fs::remove_file(split_filename);
+    }
+
+    if let Err(e) = fs::remove_file(filename) {
+        log::warn!("Could not remove {}: {}", filename, e);
+    }
+}
+
+/// create (or truncate) the given file for writing state to it
+fn create_state_file(filename: &str) -> Result<BufWriter<File>> {
+    let file =
+        File::create(filename).with_context(|| fmt_err(&format!("Could not open {}", filename)))?;
+
+    Ok(BufWriter::new(file))
+}
+
+/// write the given state to `filename`; any existing file is overwritten
+///
+/// when --split-state is used, the state's scans and responses are written to their own files as
+/// JSON arrays, and the state file records the names of those files instead
+pub fn save_state(state: &FeroxState, filename: &str) -> Result<()> {
+    let mut writer = create_state_file(filename)?;
+
+    if !state.config.split_state {
+        return write_to(state, &mut writer, true);
+    }
+
+    // scans and responses are written on their own below, so they're left out of the state file
+    let without_split_entries = FeroxState {
+        scans: &FeroxScans::default(),
+        responses: &FeroxResponses::default(),
+        collected_extensions: state.collected_extensions.clone(),
+        ..*state
+    };
+
+    let mut combined = serde_json::to_value(&without_split_entries)
+        .with_context(|| fmt_err("Could not convert scan's running state to JSON"))?;
+
+    let split_entries = [state.scans.to_json_array(), state.responses.to_json_array()];
+
+    for (key, entries) in SPLIT_STATE_KEYS.into_iter().zip(split_entries) {
+        if let Some(map) = combined.as_object_mut() {
+            map.remove(key);
+        }
+
+        let split_filename = split_state_filename(filename, key);
+
+        let mut split_writer = create_state_file(&split_filename)?;
+        writeln!(split_writer, "{}", entries)?;
+        split_writer.flush()?;
+
+        // only the name is kept, the file is looked for next to the state file when resuming
+        let split_name = Path::new(&split_filename)
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or(split_filename);
+
+        combined[format!("{}_file", key)] = Value::from(split_name);
+    }
+
+    writeln!(writer, "{}", combined)?;
+    writer.flush()?;
+
+    Ok(())
+}
+
+/// read the state file at `filename`
+///
+/// scans and responses that were written to their own files by --split-state are read back in,
+/// so the returned state looks the same whether or not --split-state was used
+pub fn load_state(filename: &str) -> Result<Value> {
+    let file =
+        File::open(filename).with_context(|| fmt_err(&format!("Could not open {}", filename)))?;
+
+    let mut state: Value = serde_json::from_reader(BufReader::new(file))
+        .with_context(|| fmt_err(&format!("Could not parse state file {}", filename)))?;
+
+    for key in SPLIT_STATE_KEYS {
+        if state.get(key).is_some() {
+            continue;
+        }
+
+        let split_name = match state.get(format!("{}_file", key)).and_then(Value::as_str) {
+            Some(split_name) => split_name.to_string(),
+            None => continue,
+        };
+
+        let split_filename = Path::new(filename).with_file_name(split_name);
+
+        let split_file = File::open(&split_filename)
+            .with_context(|| fmt_err(&format!("Could not open {}", split_filename.display())))?;
+
+        let entries: Value =
+            serde_json::from_reader(BufReader::new(split_file)).with_context(|| {
+                fmt_err(&format!(
+                    "Could not parse state file {}",
+                    split_filename.display()
+                ))
+            })?;
+
+        state[key] = entries;
+    }
+
+    Ok(state)
 }
 
 /// FeroxSerialize implementation for FeroxState
-impl FeroxSerialize for FeroxState {
+impl FeroxSerialize for FeroxState<'_> {
     /// Simply return debug format of FeroxState to satisfy as_str
     fn as_str(&self) -> String {
         format!("{:?}", self)
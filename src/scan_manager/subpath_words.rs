@@ -0,0 +1,10 @@
+use std::collections::BTreeSet;
+
+use lazy_static::lazy_static;
+
+use super::HostCollector;
+
+lazy_static! {
+    /// path components of found urls collected via --collect-subpath-wordlist
+    pub static ref SUBPATH_WORDS: HostCollector<BTreeSet<String>> = HostCollector::default();
+}
@@ -0,0 +1,140 @@
+use lazy_static::lazy_static;
+use reqwest::header::{HeaderMap, SET_COOKIE};
+use serde::Serialize;
+
+use super::HostCollector;
+use crate::response::FeroxResponse;
+
+lazy_static! {
+    /// technologies detected via --collect-technology-stack
+    pub static ref TECH_STACKS: HostCollector<TechStack> = HostCollector::default();
+}
+
+/// cookie name prefixes and the technology they imply
+const COOKIE_SIGNATURES: [(&str, &str); 12] = [
+    ("PHPSESSID", "PHP"),
+    ("JSESSIONID", "Java"),
+    ("ASP.NET_SessionId", "ASP.NET"),
+    ("ASPSESSIONID", "ASP"),
+    ("laravel_session", "Laravel"),
+    ("ci_session", "CodeIgniter"),
+    ("csrftoken", "Django"),
+    ("connect.sid", "Express"),
+    ("rack.session", "Rack"),
+    ("CFID", "ColdFusion"),
+    ("wordpress_", "WordPress"),
+    ("wp-settings-", "WordPress"),
+];
+
+/// strings found in response bodies and the technology they imply
+const BODY_SIGNATURES: [(&str, &str); 10] = [
+    ("/wp-content/", "WordPress"),
+    ("/wp-includes/", "WordPress"),
+    ("Drupal.settings", "Drupal"),
+    ("/sites/default/files/", "Drupal"),
+    ("/media/jui/", "Joomla"),
+    ("csrfmiddlewaretoken", "Django"),
+    ("__NEXT_DATA__", "Next.js"),
+    ("__NUXT__", "Nuxt.js"),
+    ("ng-version=", "Angular"),
+    ("data-reactroot", "React"),
+];
+
+/// headers whose values are reported as-is
+const VALUE_HEADERS: [&str; 4] = ["Server", "X-Powered-By", "X-AspNet-Version", "X-Generator"];
+
+/// technologies detected for a single host via --collect-technology-stack
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct TechStack {
+    /// technologies seen across all of the host's responses, in the order they were first seen
+    pub detected_technologies: Vec<String>,
+}
+
+/// Implementation of TechStack
+impl TechStack {
+    /// add `technology` to the stack; returns `true` if it wasn't previously known
+    pub fn add(&mut self, technology: &str) -> bool {
+        if self
+            .detected_technologies
+            .iter()
+            .any(|known| known == technology)
+        {
+            return false;
+        }
+
+        self.detected_technologies.push(technology.to_string());
+        true
+    }
+}
+
+/// names of all cookies set by the given headers
+fn cookie_names(headers: &HeaderMap) -> impl Iterator<Item = &str> {
+    headers
+        .get_all(SET_COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .filter_map(|cookie| cookie.split('=').next())
+        .map(str::trim)
+}
+
+/// fingerprint the technologies used to serve the given response, based on its `Server`,
+/// `X-Powered-By`, etc headers, the names of any cookies it sets, and patterns in its body
+pub fn detect_technologies(response: &FeroxResponse) -> Vec<String> {
+    let mut stack = TechStack::default();
+    let headers = response.headers();
+
+    for header in VALUE_HEADERS {
+        if let Some(value) = headers.get(header).and_then(|value| value.to_str().ok()) {
+            if !value.trim().is_empty() {
+                stack.add(value.trim());
+            }
+        }
+    }
+
+    for name in cookie_names(headers) {
+        for (prefix, technology) in COOKIE_SIGNATURES {
+            if name.starts_with(prefix) {
+                stack.add(technology);
+            }
+        }
+    }
+
+    let body = response.text();
+
+    for (needle, technology) in BODY_SIGNATURES {
+        if body.contains(needle) {
+            stack.add(technology);
+        }
+    }
+
+    stack.detected_technologies
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// adding the same technology twice should only keep the first
+    fn tech_stack_add_ignores_duplicates() {
+        let mut stack = TechStack::default();
+
+        assert!(stack.add("nginx"));
+        assert!(stack.add("PHP"));
+        assert!(!stack.add("nginx"));
+        assert_eq!(stack.detected_technologies, ["nginx", "PHP"]);
+    }
+
+    #[test]
+    /// headers, cookies, and body patterns should all be detected
+    fn detect_technologies_finds_headers_cookies_and_body_patterns() {
+        let json = r#"{"type":"response","url":"http://localhost/","path":"/","wildcard":false,"status":200,"method":"GET","content_length":0,"line_count":0,"word_count":0,"headers":{"server":"nginx/1.16.1","x-powered-by":"PHP/7.4.3","set-cookie":"PHPSESSID=abc; path=/"},"extension":""}"#;
+        let mut response: FeroxResponse = serde_json::from_str(json).unwrap();
+        response.set_text(r#"<link rel="stylesheet" href="/wp-content/themes/x.css">"#);
+
+        assert_eq!(
+            detect_technologies(&response),
+            ["nginx/1.16.1", "PHP/7.4.3", "PHP", "WordPress"]
+        );
+    }
+}
@@ -5,8 +5,9 @@ use crate::filters::{
 };
 use crate::{
     config::{Configuration, OutputLevel},
+    cors::CorsVulnerability,
     event_handlers::Handles,
-    response::FeroxResponse,
+    response::{DiscoveryMethod, FeroxResponse},
     scanner::RESPONSES,
     statistics::Stats,
     traits::FeroxSerialize,
@@ -25,7 +26,7 @@ use tokio::time::{self, Duration};
 fn default_scantype_is_file() {
     match ScanType::default() {
         ScanType::File => {}
-        ScanType::Directory => panic!(),
+        ScanType::Directory | ScanType::Unknown => panic!(),
     }
 }
 
@@ -75,6 +76,7 @@ fn add_url_to_list_of_scanned_urls_with_known_url() {
         pb.length(),
         OutputLevel::Default,
         Some(pb),
+        None,
     );
 
     assert!(urls.insert(scan));
@@ -97,6 +99,7 @@ fn stop_progress_bar_stops_bar() {
         pb.length(),
         OutputLevel::Default,
         Some(pb),
+        None,
     );
 
     assert!(!scan
@@ -131,6 +134,7 @@ fn add_url_to_list_of_scanned_urls_with_known_url_without_slash() {
         0,
         OutputLevel::Default,
         None,
+        None,
     );
 
     assert!(urls.insert(scan));
@@ -155,6 +159,7 @@ async fn call_display_scans() {
         pb.length(),
         OutputLevel::Default,
         Some(pb),
+        None,
     );
     let scan_two = FeroxScan::new(
         url_two,
@@ -163,6 +168,7 @@ async fn call_display_scans() {
         pb_two.length(),
         OutputLevel::Default,
         Some(pb_two),
+        None,
     );
 
     scan_two.finish().unwrap(); // one complete, one incomplete
@@ -190,6 +196,7 @@ fn partial_eq_compares_the_id_field() {
         0,
         OutputLevel::Default,
         None,
+        None,
     );
     let scan_two = FeroxScan::new(
         url,
@@ -198,6 +205,7 @@ fn partial_eq_compares_the_id_field() {
         0,
         OutputLevel::Default,
         None,
+        None,
     );
 
     assert!(!scan.eq(&scan_two));
@@ -226,21 +234,21 @@ fn ferox_scan_get_progress_bar_when_none_is_set() {
 fn ferox_scan_deserialize() {
     let fs_json = r#"{"id":"057016a14769414aac9a7a62707598cb","url":"https://spiritanimal.com","scan_type":"Directory","status":"Complete"}"#;
     let fs_json_two = r#"{"id":"057016a14769414aac9a7a62707598cb","url":"https://spiritanimal.com","scan_type":"Not Correct","status":"Cancelled"}"#;
-    let fs_json_three = r#"{"id":"057016a14769414aac9a7a62707598cb","url":"https://spiritanimal.com","scan_type":"Not Correct","status":"","num_requests":42}"#;
+    let fs_json_three = r#"{"id":"057016a14769414aac9a7a62707598cb","url":"https://spiritanimal.com","scan_type":"Not Correct","status":"","num_requests":42,"requests_made_so_far":21}"#;
 
     let fs: FeroxScan = serde_json::from_str(fs_json).unwrap();
     let fs_two: FeroxScan = serde_json::from_str(fs_json_two).unwrap();
     let fs_three: FeroxScan = serde_json::from_str(fs_json_three).unwrap();
     assert_eq!(fs.url, "https://spiritanimal.com");
 
-    match fs.scan_type {
+    match fs.scan_type() {
         ScanType::Directory => {}
-        ScanType::File => {
+        ScanType::File | ScanType::Unknown => {
             panic!();
         }
     }
-    match fs_two.scan_type {
-        ScanType::Directory => {
+    match fs_two.scan_type() {
+        ScanType::Directory | ScanType::Unknown => {
             panic!();
         }
         ScanType::File => {}
@@ -262,9 +270,24 @@ fn ferox_scan_deserialize() {
         ScanStatus::NotStarted
     ));
     assert_eq!(fs_three.num_requests, 42);
+    assert_eq!(fs_three.requests_made_so_far(), 21);
+    assert_eq!(fs.requests_made_so_far(), 0);
+    assert_eq!(fs.retry_count(), 0);
     assert_eq!(fs.id, "057016a14769414aac9a7a62707598cb");
 }
 
+#[test]
+/// a scan that was marked as failed should deserialize with its status and retry count intact
+fn ferox_scan_deserialize_failed_scan_with_retries() {
+    let fs_json = r#"{"id":"057016a14769414aac9a7a62707598cb","url":"https://spiritanimal.com","scan_type":"Directory","status":"Failed","retry_count":4}"#;
+
+    let fs: FeroxScan = serde_json::from_str(fs_json).unwrap();
+
+    assert!(fs.is_failed());
+    assert!(!fs.is_active());
+    assert_eq!(fs.retry_count(), 4);
+}
+
 #[test]
 /// given a FeroxScan, test that it serializes into the proper JSON entry
 fn ferox_scan_serialize() {
@@ -275,9 +298,10 @@ fn ferox_scan_serialize() {
         0,
         OutputLevel::Default,
         None,
+        None,
     );
     let fs_json = format!(
-        r#"{{"id":"{}","url":"https://spiritanimal.com","scan_type":"Directory","status":"NotStarted","num_requests":0}}"#,
+        r#"{{"id":"{}","parent_id":null,"url":"https://spiritanimal.com","scan_type":"Directory","status":"NotStarted","num_requests":0,"requests_made_so_far":0,"retry_count":0,"timing":{{"min_ms":0,"max_ms":0,"avg_ms":0,"p50_ms":0,"p95_ms":0}},"words_per_second":0.0,"elapsed_ms":0,"requests_made":0,"bytes_received":0}}"#,
         fs.id
     );
     assert_eq!(fs_json, serde_json::to_string(&*fs).unwrap());
@@ -293,23 +317,25 @@ fn ferox_scans_serialize() {
         0,
         OutputLevel::Default,
         None,
+        None,
     );
     let ferox_scans = FeroxScans::default();
     let ferox_scans_json = format!(
-        r#"[{{"id":"{}","url":"https://spiritanimal.com","scan_type":"Directory","status":"NotStarted","num_requests":0}}]"#,
+        r#"[{{"id":"{}","parent_id":null,"url":"https://spiritanimal.com","scan_type":"Directory","status":"NotStarted","num_requests":0,"requests_made_so_far":0,"retry_count":0,"timing":{{"min_ms":0,"max_ms":0,"avg_ms":0,"p50_ms":0,"p95_ms":0}},"words_per_second":0.0,"elapsed_ms":0,"requests_made":0,"bytes_received":0}}]"#,
         ferox_scan.id
     );
-    ferox_scans.scans.write().unwrap().push(ferox_scan);
+    ferox_scans.insert(ferox_scan);
     assert_eq!(
         ferox_scans_json,
         serde_json::to_string(&ferox_scans).unwrap()
     );
+    assert_eq!(ferox_scans_json, ferox_scans.to_json_array());
 }
 
 #[test]
 /// given a FeroxResponses, test that it serializes into the proper JSON entry
 fn ferox_responses_serialize() {
-    let json_response = r#"{"type":"response","url":"https://nerdcore.com/css","original_url":"https://nerdcore.com","path":"/css","wildcard":true,"status":301,"method":"GET","content_length":173,"line_count":10,"word_count":16,"headers":{"server":"nginx/1.16.1"},"extension":""}"#;
+    let json_response = r#"{"type":"response","url":"https://nerdcore.com/css","original_url":"https://nerdcore.com","path":"/css","wildcard":true,"status":301,"method":"GET","content_length":173,"body_truncated":false,"line_count":10,"word_count":16,"headers":{"server":"nginx/1.16.1"},"extension":"","supports_websocket":false,"cors":null,"allowed_methods":[],"response_time_ms":0,"page_title":null,"upload_filename":null,"meta_tags":{},"request_id":"7c9e6679-7425-40de-944b-e07fc1f90ae7","discovered_via":"wordlist","content_hash":0}"#;
     let response: FeroxResponse = serde_json::from_str(json_response).unwrap();
 
     let responses = FeroxResponses::default();
@@ -321,13 +347,14 @@ fn ferox_responses_serialize() {
 
     let serialized = serde_json::to_string(&responses).unwrap();
     assert_eq!(expected, serialized);
+    assert_eq!(expected, responses.to_json_array());
 }
 
 #[test]
 /// given a FeroxResponse, test that it serializes into the proper JSON entry
 fn ferox_response_serialize_and_deserialize() {
     // deserialize
-    let json_response = r#"{"type":"response","url":"https://nerdcore.com/css","original_url":"https://nerdcore.com","path":"/css","wildcard":true,"status":301,"method":"GET","content_length":173,"line_count":10,"word_count":16,"headers":{"server":"nginx/1.16.1"},"extension":""}"#;
+    let json_response = r#"{"type":"response","url":"https://nerdcore.com/css","original_url":"https://nerdcore.com","path":"/css","wildcard":true,"status":301,"method":"GET","content_length":173,"body_truncated":true,"line_count":10,"word_count":16,"headers":{"server":"nginx/1.16.1"},"extension":"","supports_websocket":true,"cors":"wildcard","allowed_methods":["GET","PUT"],"response_time_ms":42,"page_title":"Admin Panel","upload_filename":"shell.php","meta_tags":{},"request_id":"7c9e6679-7425-40de-944b-e07fc1f90ae7","discovered_via":"builtin_list","content_hash":1234}"#;
     let response: FeroxResponse = serde_json::from_str(json_response).unwrap();
 
     assert_eq!(response.url().as_str(), "https://nerdcore.com/css");
@@ -335,6 +362,20 @@ fn ferox_response_serialize_and_deserialize() {
     assert!(response.wildcard());
     assert_eq!(response.status().as_u16(), 301);
     assert_eq!(response.content_length(), 173);
+    assert!(response.body_truncated());
+    assert!(response.supports_websocket());
+    assert_eq!(response.cors(), Some(CorsVulnerability::Wildcard));
+    assert_eq!(response.allowed_methods(), ["GET", "PUT"]);
+    assert_eq!(response.flagged_methods(), ["PUT"]);
+    assert_eq!(response.response_time_ms(), 42);
+    assert_eq!(response.page_title(), Some("Admin Panel"));
+    assert_eq!(response.upload_filename(), Some("shell.php"));
+    assert_eq!(
+        response.request_id(),
+        "7c9e6679-7425-40de-944b-e07fc1f90ae7"
+    );
+    assert_eq!(response.discovered_via(), DiscoveryMethod::BuiltinList);
+    assert_eq!(response.content_hash(), 1234);
     assert_eq!(response.line_count(), 10);
     assert_eq!(response.word_count(), 16);
     assert_eq!(response.headers().get("server").unwrap(), "nginx/1.16.1");
@@ -354,6 +395,7 @@ fn feroxstates_feroxserialize_implementation() {
         0,
         OutputLevel::Default,
         None,
+        None,
     );
     let ferox_scans = FeroxScans::default();
     let saved_id = ferox_scan.id.clone();
@@ -405,13 +447,7 @@ fn feroxstates_feroxserialize_implementation() {
         }))
         .unwrap();
 
-    let ferox_state = FeroxState::new(
-        Arc::new(ferox_scans),
-        Arc::new(config),
-        &RESPONSES,
-        stats,
-        Arc::new(filters),
-    );
+    let ferox_state = FeroxState::new(&ferox_scans, &config, &RESPONSES, &stats, &filters);
 
     let expected_strs = predicates::str::contains("scans: FeroxScans").and(
         predicate::str::contains("config: Configuration")
@@ -555,18 +591,33 @@ async fn start_max_time_thread_returns_immediately_with_too_large_input() {
 fn feroxscan_display() {
     let scan = FeroxScan {
         id: "".to_string(),
+        parent_id: None,
         url: String::from("http://localhost"),
         scan_order: ScanOrder::Latest,
         scan_type: Default::default(),
         num_requests: 0,
+        requests_made_so_far: 0,
+        retry_count: 0,
         start_time: Instant::now(),
+        response_times: Default::default(),
+        timing: Default::default(),
+        words_per_second: Default::default(),
+        started_at: Default::default(),
+        elapsed: Default::default(),
         output_level: OutputLevel::Default,
         status_403s: Default::default(),
         status_429s: Default::default(),
         status: Default::default(),
         task: tokio::sync::Mutex::new(None),
+        abort_requested: Default::default(),
+        paused: Default::default(),
         progress_bar: std::sync::Mutex::new(None),
         errors: Default::default(),
+        found_count: Default::default(),
+        requests_made: Default::default(),
+        bytes_received: Default::default(),
+        rate_limit: 0,
+        rate_limiter: None,
     };
 
     let not_started = format!("{}", scan);
@@ -591,7 +642,13 @@ fn feroxscan_display() {
     let running = format!("{}", scan);
     assert!(predicate::str::contains("running")
         .and(predicate::str::contains("localhost"))
+        .and(predicate::str::contains("requests").not())
         .eval(&running));
+
+    scan.add_request(100);
+    scan.add_request(24);
+    let counted = format!("{}", scan);
+    assert!(predicate::str::contains("[2 requests, 124 bytes]").eval(&counted));
 }
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
@@ -599,20 +656,35 @@ fn feroxscan_display() {
 async fn ferox_scan_abort() {
     let scan = FeroxScan {
         id: "".to_string(),
+        parent_id: None,
         url: String::from("http://localhost"),
         scan_order: ScanOrder::Latest,
         scan_type: Default::default(),
         num_requests: 0,
+        requests_made_so_far: 0,
+        retry_count: 0,
         start_time: Instant::now(),
+        response_times: Default::default(),
+        timing: Default::default(),
+        words_per_second: Default::default(),
+        started_at: Default::default(),
+        elapsed: Default::default(),
         output_level: OutputLevel::Default,
         status_403s: Default::default(),
         status_429s: Default::default(),
         status: std::sync::Mutex::new(ScanStatus::Running),
+        abort_requested: Default::default(),
+        paused: Default::default(),
         task: tokio::sync::Mutex::new(Some(tokio::spawn(async move {
             sleep(Duration::from_millis(SLEEP_DURATION * 2));
         }))),
         progress_bar: std::sync::Mutex::new(None),
         errors: Default::default(),
+        found_count: Default::default(),
+        requests_made: Default::default(),
+        bytes_received: Default::default(),
+        rate_limit: 0,
+        rate_limiter: None,
     };
 
     scan.abort().await.unwrap();
@@ -623,6 +695,167 @@ async fn ferox_scan_abort() {
     ));
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+/// a scan whose task couldn't be aborted because its lock was held should still be marked
+/// cancelled, not complete, once it finishes; the state file should reflect that as well
+async fn ferox_scan_abort_while_task_locked_finishes_as_cancelled() {
+    let scan = FeroxScan::new(
+        "http://localhost",
+        ScanType::Directory,
+        ScanOrder::Latest,
+        0,
+        OutputLevel::Default,
+        None,
+        None,
+    );
+    scan.set_task(tokio::spawn(async {})).await.unwrap();
+    scan.set_status(ScanStatus::Running).unwrap();
+
+    {
+        let _guard = scan.task.lock().await;
+        scan.abort().await.unwrap();
+    }
+
+    assert!(scan.abort_requested());
+    assert!(!scan.is_cancelled());
+
+    scan.finish().unwrap();
+
+    assert!(scan.is_cancelled());
+    assert!(!scan.is_complete());
+    assert!(serde_json::to_string(&*scan)
+        .unwrap()
+        .contains(r#""status":"Cancelled""#));
+
+    let scans = FeroxScans::default();
+    scans.insert(scan.clone());
+    scans.insert(FeroxScan::new(
+        "http://localhost/done",
+        ScanType::Directory,
+        ScanOrder::Latest,
+        0,
+        OutputLevel::Default,
+        None,
+        None,
+    ));
+
+    let cancelled = scans.get_cancelled_scans();
+    assert_eq!(cancelled.len(), 1);
+    assert_eq!(cancelled[0].url(), "http://localhost");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+/// FeroxScans::remove should cancel the scan and drop it from the container, leaving other scans
+/// alone
+async fn ferox_scans_remove_cancels_and_drops_scan() {
+    let scans = FeroxScans::default();
+
+    let (_, removed) = scans.add_directory_scan("http://localhost/a", ScanOrder::Latest);
+    scans.add_directory_scan("http://localhost/b", ScanOrder::Latest);
+
+    removed
+        .set_task(tokio::spawn(async move {
+            sleep(Duration::from_millis(SLEEP_DURATION * 2));
+        }))
+        .await
+        .unwrap();
+    removed.set_status(ScanStatus::Running).unwrap();
+
+    assert!(scans.remove("http://localhost/c").await.is_none());
+
+    let scan = scans.remove("http://localhost/a").await.unwrap();
+
+    assert_eq!(scan.id(), removed.id());
+    assert!(scan.is_cancelled());
+    assert!(!scans.contains("http://localhost/a"));
+    assert!(scans.get_scan_by_id(removed.id()).is_none());
+    assert!(scans.contains("http://localhost/b"));
+    assert_eq!(scans.scans.read().unwrap().len(), 1);
+    assert!(!scans.to_json_array().contains("http://localhost/a"));
+
+    // once removed, the url can be scanned again
+    assert!(scans.insert(FeroxScan::new(
+        "http://localhost/a",
+        ScanType::Directory,
+        ScanOrder::Latest,
+        0,
+        OutputLevel::Default,
+        None,
+        None,
+    )));
+}
+
+#[test]
+/// stats should count every known scan, splitting them into complete and incomplete
+fn ferox_scans_stats_counts_complete_and_incomplete_scans() {
+    let scans = FeroxScans::default();
+
+    let (_, complete) = scans.add_directory_scan("http://localhost/a", ScanOrder::Latest);
+    scans.add_directory_scan("http://localhost/b", ScanOrder::Latest);
+    scans.add_file_scan("http://localhost/c.php", ScanOrder::Latest);
+
+    complete.finish().unwrap();
+
+    let stats = scans.stats();
+
+    assert_eq!(stats.total, 3);
+    assert_eq!(stats.complete, 1);
+    assert_eq!(stats.incomplete, 2);
+
+    let stats = ScanStats {
+        total_responses: 12,
+        ..stats
+    };
+
+    assert_eq!(
+        stats.to_string(),
+        "1/3 scans complete (2 remaining), 12 responses found"
+    );
+    assert_eq!(
+        stats.as_json(),
+        r#"{"complete":1,"incomplete":2,"total":3,"total_responses":12,"type":"status"}"#
+    );
+}
+
+#[test]
+/// contains_prefix should match urls at or beneath a known directory scan, ignoring trailing
+/// slashes, but not siblings that merely share a prefix
+fn ferox_scans_contains_prefix_matches_nested_urls() {
+    let scans = FeroxScans::default();
+
+    scans.add_directory_scan("http://localhost/api/v1/", ScanOrder::Latest);
+    scans.add_file_scan("http://localhost/files/index.php", ScanOrder::Latest);
+
+    assert!(scans.contains_prefix("http://localhost/api/v1"));
+    assert!(scans.contains_prefix("http://localhost/api/v1/"));
+    assert!(scans.contains_prefix("http://localhost/api/v1/users/"));
+    assert!(!scans.contains_prefix("http://localhost/api/v10/"));
+    assert!(!scans.contains_prefix("http://localhost/api/"));
+
+    // file scans never have anything beneath them
+    assert!(!scans.contains_prefix("http://localhost/files/index.php/x"));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+/// a scan that wasn't aborted should be marked complete when it finishes
+async fn ferox_scan_finish_without_abort_is_complete() {
+    let scan = FeroxScan::new(
+        "http://localhost",
+        ScanType::Directory,
+        ScanOrder::Latest,
+        0,
+        OutputLevel::Default,
+        None,
+        None,
+    );
+    scan.set_task(tokio::spawn(async {})).await.unwrap();
+
+    scan.join().await;
+
+    assert!(!scan.abort_requested());
+    assert!(scan.is_complete());
+}
+
 #[test]
 /// call a few menu functions for coverage's sake
 ///
@@ -696,6 +929,78 @@ fn menu_get_command_input_from_user_returns_add() {
     }
 }
 
+/// ensure a lone a, r, q, or empty line results in the correct MenuCmd returned
+#[test]
+fn menu_get_command_input_from_user_returns_cancel_all_resume_and_quit() {
+    let menu = Menu::new();
+
+    for (cmd, expected_force) in [("a\n", false), ("A -f\n", true), ("a-f\n", true)] {
+        let result = menu.get_command_input_from_user(cmd).unwrap();
+        assert!(matches!(result, MenuCmd::CancelAll(force) if force == expected_force));
+    }
+
+    for cmd in ["\n", "   \n", "r\n", "R\n"] {
+        let result = menu.get_command_input_from_user(cmd).unwrap();
+        assert!(matches!(result, MenuCmd::Resume));
+    }
+
+    for cmd in ["q\n", "quit\n", "QUIT\n"] {
+        let result = menu.get_command_input_from_user(cmd).unwrap();
+        assert!(matches!(result, MenuCmd::Quit));
+    }
+
+    // rm-filter with indices is unaffected by a lone r resuming the scan
+    for cmd in ["r 2\n", "rm 2\n", "rm-filter 2\n"] {
+        let result = menu.get_command_input_from_user(cmd).unwrap();
+        assert!(matches!(result, MenuCmd::RemoveFilter(indices) if indices == vec![2]));
+    }
+}
+
+/// ensure p/u followed by scan ids result in the correct MenuCmd returned
+#[test]
+fn menu_get_command_input_from_user_returns_pause_and_resume_scans() {
+    let menu = Menu::new();
+
+    for cmd in ["p 1,3\n", "P 1,3\n", "pause 1,3\n"] {
+        let result = menu.get_command_input_from_user(cmd).unwrap();
+        assert!(matches!(result, MenuCmd::PauseScans(indices) if indices == vec![1, 3]));
+    }
+
+    for cmd in ["u 2-3\n", "U 2-3\n", "unpause 2-3\n"] {
+        let result = menu.get_command_input_from_user(cmd).unwrap();
+        assert!(matches!(result, MenuCmd::ResumeScans(indices) if indices == vec![2, 3]));
+    }
+
+    for cmd in ["rm-filter 2\n", "RM 2\n", "R 2\n"] {
+        let result = menu.get_command_input_from_user(cmd).unwrap();
+        assert!(matches!(result, MenuCmd::RemoveFilter(indices) if indices == vec![2]));
+    }
+}
+
+/// ensure invalid input isn't translated to a MenuCmd
+#[test]
+fn menu_get_command_input_from_user_rejects_invalid_input() {
+    let menu = Menu::new();
+
+    for cmd in [
+        "x\n",
+        "qq\n",
+        "quitt\n",
+        "n bogus 1\n",
+        "n\n",
+        "p\n",
+        "u\n",
+        "c\n",
+        "c abc\n",
+        "cancel -f abc\n",
+        "p abc\n",
+        "rm abc\n",
+        "rm\n",
+    ] {
+        assert!(menu.get_command_input_from_user(cmd).is_none());
+    }
+}
+
 #[test]
 /// ensure spaces are trimmed and numbers are returned from split_to_nums
 fn split_to_nums_is_correct() {
@@ -776,3 +1081,268 @@ fn get_base_scan_by_url_finds_correct_scan_with_trailing_slash() {
         scan.id
     );
 }
+
+#[test]
+/// 200s should get a single curl check each and admin-looking urls should get an http_login run
+fn to_metasploit_rc_emits_curl_checks_and_admin_modules() {
+    let response = |url: &str, status: u16| -> FeroxResponse {
+        let json = format!(
+            r#"{{"type":"response","url":"{}","path":"","wildcard":false,"status":{},"method":"GET","content_length":0,"line_count":0,"word_count":0,"headers":{{}},"extension":""}}"#,
+            url, status
+        );
+        serde_json::from_str(&json).unwrap()
+    };
+
+    let responses = FeroxResponses::default();
+    responses.insert(response("http://localhost/stuff", 200));
+    responses.insert(response("http://localhost/stuff", 200));
+    responses.insert(response("http://localhost/things", 301));
+    responses.insert(response("https://localhost/Admin/", 403));
+
+    let script = utils::to_metasploit_rc(&responses);
+
+    assert_eq!(
+        script
+            .matches(r#"curl -s -o /dev/null -w "%{http_code}" 'http://localhost/stuff'"#)
+            .count(),
+        1
+    );
+    assert!(!script.contains("http://localhost/things"));
+    assert!(script.contains(
+        "use auxiliary/scanner/http/http_login\nset RHOSTS localhost\nset RPORT 443\nset SSL true\nset AUTH_URI /Admin/\nrun\nback\n"
+    ));
+}
+
+#[test]
+/// per-host reports should be timestamped, have the host's port and brackets replaced, and be
+/// written next to the -o|--output file when one is given
+fn per_host_filename_is_timestamped_and_follows_output() {
+    let filename = Regex::new(r"^ferox-params-___1__8080-\d+\.txt$").unwrap();
+
+    let config = Configuration::new().unwrap();
+    let name = utils::per_host_filename(&config, "params", "[::1]:8080", "txt");
+    assert!(filename.is_match(&name), "{}", name);
+
+    let config = Configuration {
+        output: String::from("/tmp/results/scan.txt"),
+        ..Configuration::new().unwrap()
+    };
+    let name = utils::per_host_filename(&config, "params", "[::1]:8080", "txt");
+    let name = name.strip_prefix("/tmp/results/").unwrap();
+    assert!(filename.is_match(name), "{}", name);
+}
+
+#[test]
+/// get_completed and get_incomplete should split scans on whether they're marked complete
+fn ferox_scans_get_completed_and_incomplete() {
+    let scans = FeroxScans::default();
+
+    for (url, status) in [
+        ("http://localhost/done", ScanStatus::Complete),
+        ("http://localhost/running", ScanStatus::Running),
+        ("http://localhost/waiting", ScanStatus::NotStarted),
+        ("http://localhost/stopped", ScanStatus::Cancelled),
+    ] {
+        let scan = FeroxScan::new(
+            url,
+            ScanType::Directory,
+            ScanOrder::Latest,
+            0,
+            OutputLevel::Default,
+            None,
+            None,
+        );
+        scan.set_status(status).unwrap();
+        scans.insert(scan);
+    }
+
+    let urls = |scans: Vec<Arc<FeroxScan>>| -> Vec<String> {
+        scans.iter().map(|scan| scan.url().to_owned()).collect()
+    };
+
+    assert_eq!(urls(scans.get_completed()), ["http://localhost/done"]);
+    assert_eq!(
+        urls(scans.get_incomplete()),
+        [
+            "http://localhost/running",
+            "http://localhost/waiting",
+            "http://localhost/stopped"
+        ]
+    );
+}
+
+#[test]
+/// a scan should be timed from when its progress bar is created until it finishes, and that time
+/// should survive a trip through the state file
+fn ferox_scan_elapsed_is_recorded_and_serialized() {
+    let scan = FeroxScan::new(
+        "http://localhost/timed",
+        ScanType::Directory,
+        ScanOrder::Latest,
+        0,
+        OutputLevel::Default,
+        None,
+        None,
+    );
+    assert!(scan.elapsed().is_zero());
+
+    scan.set_status(ScanStatus::Running).unwrap();
+    scan.progress_bar();
+    sleep(Duration::from_millis(20));
+    assert!(scan.elapsed() >= Duration::from_millis(20));
+
+    scan.finish().unwrap();
+    let elapsed = scan.elapsed();
+    sleep(Duration::from_millis(10));
+    assert_eq!(scan.elapsed(), elapsed);
+
+    let json = serde_json::to_string(&*scan).unwrap();
+    assert!(json.contains(&format!(r#""elapsed_ms":{}"#, elapsed.as_millis())));
+
+    let restored: FeroxScan = serde_json::from_str(
+        r#"{"id":"057016a14769414aac9a7a62707598cb","url":"http://localhost/timed","scan_type":"Directory","status":"Complete","elapsed_ms":1500}"#,
+    )
+    .unwrap();
+    assert_eq!(restored.elapsed(), Duration::from_millis(1500));
+    assert!(format!("{}", restored).contains("http://localhost/timed (1.5s)"));
+}
+
+#[test]
+/// responses should be narrowed down (or counted) by status code, or by the path they fall under
+fn ferox_responses_filter_by_status_and_url_prefix() {
+    let responses = FeroxResponses::default();
+
+    for (url, status) in [
+        ("http://localhost/js", 301),
+        ("http://localhost/js/app.js", 200),
+        ("http://localhost/json", 200),
+        ("http://localhost/admin", 403),
+    ] {
+        let response: FeroxResponse =
+            serde_json::from_value(serde_json::json!({"url": url, "status": status})).unwrap();
+        responses.insert(response);
+    }
+
+    let urls = |responses: Vec<FeroxResponse>| -> Vec<String> {
+        responses.iter().map(|r| r.url().to_string()).collect()
+    };
+
+    assert_eq!(
+        urls(responses.filter_by_status(&[200, 403])),
+        [
+            "http://localhost/js/app.js",
+            "http://localhost/json",
+            "http://localhost/admin"
+        ]
+    );
+    assert!(responses.filter_by_status(&[500]).is_empty());
+
+    assert_eq!(
+        urls(responses.filter_by_url_prefix("http://localhost/js")),
+        ["http://localhost/js", "http://localhost/js/app.js"]
+    );
+    assert_eq!(
+        urls(responses.filter_by_url_prefix("http://localhost/js/")),
+        ["http://localhost/js", "http://localhost/js/app.js"]
+    );
+    assert_eq!(responses.filter_by_url_prefix("http://localhost/").len(), 4);
+
+    assert_eq!(responses.count_by_url_prefix("http://localhost/js"), 2);
+    assert_eq!(responses.count_by_url_prefix("http://localhost/"), 4);
+    assert_eq!(responses.count_by_url_prefix("http://localhost/css"), 0);
+}
+
+#[test]
+/// diffing two states should find responses and scans gained or lost between them, matching
+/// responses on url and method
+fn ferox_state_diff_finds_new_and_removed_entries() {
+    let response = |url: &str, method: &str| {
+        let json = format!(
+            r#"{{"type":"response","url":"{}","method":"{}"}}"#,
+            url, method
+        );
+        serde_json::from_str::<FeroxResponse>(&json).unwrap()
+    };
+
+    let before = SavedState::default();
+    let after = SavedState::default();
+
+    let scan = |url: &str| {
+        FeroxScan::new(
+            url,
+            ScanType::Directory,
+            ScanOrder::Latest,
+            0,
+            OutputLevel::Default,
+            None,
+            None,
+        )
+    };
+
+    before.scans.insert(scan("http://localhost/"));
+    after.scans.insert(scan("http://localhost/"));
+    after.scans.insert(scan("http://localhost/js/"));
+
+    before
+        .responses
+        .insert(response("http://localhost/css", "GET"));
+    before
+        .responses
+        .insert(response("http://localhost/old", "GET"));
+    after
+        .responses
+        .insert(response("http://localhost/css", "GET"));
+    after
+        .responses
+        .insert(response("http://localhost/css", "POST"));
+
+    let diff = before.as_state().diff(&after.as_state());
+
+    assert_eq!(diff.new_responses.len(), 1);
+    assert_eq!(diff.new_responses[0].method().as_str(), "POST");
+    assert_eq!(diff.removed_responses.len(), 1);
+    assert_eq!(
+        diff.removed_responses[0].url().as_str(),
+        "http://localhost/old"
+    );
+    assert_eq!(diff.new_scans.len(), 1);
+    assert_eq!(diff.new_scans[0].url(), "http://localhost/js/");
+
+    let displayed = diff.to_string();
+    assert!(displayed.contains("+ 200    POST http://localhost/css"));
+    assert!(displayed.contains("- 200     GET http://localhost/old"));
+    assert!(displayed.contains("  + http://localhost/js/"));
+
+    let unchanged = after.as_state().diff(&after.as_state());
+    assert_eq!(
+        unchanged.to_string(),
+        "New responses (0):\nRemoved responses (0):\nNew scans (0):\n"
+    );
+}
+
+#[test]
+/// with --filter-duplicate-content, responses whose body was already seen are dropped on insert,
+/// while empty bodies are never treated as duplicates
+fn ferox_responses_insert_drops_duplicate_content() {
+    let response = |url: &str, content_hash: u64| -> FeroxResponse {
+        serde_json::from_value(serde_json::json!({"url": url, "content_hash": content_hash}))
+            .unwrap()
+    };
+
+    let responses = FeroxResponses::default();
+
+    // off by default
+    assert!(responses.insert(response("http://localhost/one", 42)));
+    assert!(responses.insert(response("http://localhost/two", 42)));
+
+    responses.set_filter_duplicate_content(true);
+
+    assert!(responses.contains_content(42));
+    assert!(!responses.contains_content(0));
+    assert!(!responses.insert(response("http://localhost/three", 42)));
+    assert!(responses.insert(response("http://localhost/four", 7)));
+    assert!(responses.insert(response("http://localhost/five", 0)));
+    assert!(responses.insert(response("http://localhost/six", 0)));
+
+    assert_eq!(responses.responses.read().unwrap().len(), 5);
+}
@@ -0,0 +1,247 @@
+use std::{
+    fmt,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use serde::{Deserialize, Serialize};
+
+/// response times below this are counted in a bucket of their own
+const NUM_EXACT_BUCKETS: u64 = 100;
+
+/// number of buckets per power of ten past `NUM_EXACT_BUCKETS`, i.e. one per two significant digits
+const BUCKETS_PER_DECADE: u64 = 90;
+
+/// number of powers of ten past `NUM_EXACT_BUCKETS` that get buckets (100ms up to 100s); anything
+/// slower ends up in the last bucket
+const NUM_DECADES: u64 = 3;
+
+/// total number of histogram buckets
+const NUM_BUCKETS: usize = (NUM_EXACT_BUCKETS + NUM_DECADES * BUCKETS_PER_DECADE) as usize;
+
+/// summary of the response times (in milliseconds) of every request made by a single scan
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScanTimingStats {
+    /// fastest response
+    pub min_ms: u64,
+
+    /// slowest response
+    pub max_ms: u64,
+
+    /// mean response time
+    pub avg_ms: u64,
+
+    /// median response time
+    pub p50_ms: u64,
+
+    /// 95th percentile response time
+    pub p95_ms: u64,
+}
+
+/// running aggregates of the response times (in milliseconds) seen by a single scan
+///
+/// times under 100ms are counted exactly, slower ones to two significant digits, so percentiles
+/// are approximate past 100ms while memory stays fixed no matter how many requests are made
+pub struct ResponseTimes {
+    /// number of response times seen
+    count: AtomicU64,
+
+    /// sum of every response time seen
+    sum: AtomicU64,
+
+    /// fastest response time seen
+    min: AtomicU64,
+
+    /// slowest response time seen
+    max: AtomicU64,
+
+    /// number of response times seen per bucket
+    buckets: Box<[AtomicU64]>,
+}
+
+/// implement Default trait for ResponseTimes
+impl Default for ResponseTimes {
+    fn default() -> Self {
+        Self {
+            count: AtomicU64::new(0),
+            sum: AtomicU64::new(0),
+            min: AtomicU64::new(u64::MAX),
+            max: AtomicU64::new(0),
+            buckets: (0..NUM_BUCKETS).map(|_| AtomicU64::new(0)).collect(),
+        }
+    }
+}
+
+/// implement Debug trait for ResponseTimes; the buckets are left out to keep scan logging readable
+impl fmt::Debug for ResponseTimes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResponseTimes")
+            .field("count", &self.count.load(Ordering::Relaxed))
+            .field("sum", &self.sum.load(Ordering::Relaxed))
+            .field("min", &self.min.load(Ordering::Relaxed))
+            .field("max", &self.max.load(Ordering::Relaxed))
+            .finish()
+    }
+}
+
+/// Implementation of ResponseTimes
+impl ResponseTimes {
+    /// record a single response time
+    pub fn add(&self, millis: u64) {
+        self.count.fetch_add(1, Ordering::Relaxed);
+        self.sum.fetch_add(millis, Ordering::Relaxed);
+        self.min.fetch_min(millis, Ordering::Relaxed);
+        self.max.fetch_max(millis, Ordering::Relaxed);
+        self.buckets[bucket_index(millis)].fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// whether any response times have been recorded
+    pub fn is_empty(&self) -> bool {
+        self.count.load(Ordering::Relaxed) == 0
+    }
+
+    /// compute the stats for the recorded response times; all zeroes when there aren't any
+    pub fn stats(&self) -> ScanTimingStats {
+        let count = self.count.load(Ordering::Relaxed);
+
+        if count == 0 {
+            return ScanTimingStats::default();
+        }
+
+        let min_ms = self.min.load(Ordering::Relaxed);
+        let max_ms = self.max.load(Ordering::Relaxed);
+
+        ScanTimingStats {
+            min_ms,
+            max_ms,
+            avg_ms: self.sum.load(Ordering::Relaxed) / count,
+            p50_ms: self.percentile(count, 50).clamp(min_ms, max_ms),
+            p95_ms: self.percentile(count, 95).clamp(min_ms, max_ms),
+        }
+    }
+
+    /// nearest-rank percentile, as the lower bound of the bucket it falls in; the last bucket has
+    /// no upper bound, so anything that lands there is reported as the slowest response
+    fn percentile(&self, count: u64, percent: u64) -> u64 {
+        let rank = (percent * count).div_ceil(100).max(1);
+        let mut seen = 0;
+
+        for (index, bucket) in self.buckets.iter().enumerate().take(NUM_BUCKETS - 1) {
+            seen += bucket.load(Ordering::Relaxed);
+
+            if seen >= rank {
+                return bucket_lower_bound(index);
+            }
+        }
+
+        u64::MAX
+    }
+}
+
+/// index of the bucket that `millis` is counted in
+fn bucket_index(millis: u64) -> usize {
+    if millis < NUM_EXACT_BUCKETS {
+        return millis as usize;
+    }
+
+    // reduce to two significant digits (10..=99), counting the powers of ten dropped along the way
+    let mut digits = millis;
+    let mut decade = 0;
+
+    while digits >= NUM_EXACT_BUCKETS {
+        digits /= 10;
+        decade += 1;
+    }
+
+    let index = NUM_EXACT_BUCKETS + (decade - 1) * BUCKETS_PER_DECADE + (digits - 10);
+    (index as usize).min(NUM_BUCKETS - 1)
+}
+
+/// smallest response time counted in the bucket at `index`
+fn bucket_lower_bound(index: usize) -> u64 {
+    let index = index as u64;
+
+    if index < NUM_EXACT_BUCKETS {
+        return index;
+    }
+
+    let offset = index - NUM_EXACT_BUCKETS;
+    let decade = offset / BUCKETS_PER_DECADE + 1;
+    let digits = offset % BUCKETS_PER_DECADE + 10;
+
+    digits * 10u64.pow(decade as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// helper to record the given response times
+    fn response_times(times: impl IntoIterator<Item = u64>) -> ResponseTimes {
+        let response_times = ResponseTimes::default();
+
+        for time in times {
+            response_times.add(time);
+        }
+
+        response_times
+    }
+
+    #[test]
+    /// stats should be computed from the given times regardless of their order
+    fn stats_computes_stats() {
+        let stats = response_times((1..=100).rev()).stats();
+
+        assert_eq!(
+            stats,
+            ScanTimingStats {
+                min_ms: 1,
+                max_ms: 100,
+                avg_ms: 50,
+                p50_ms: 50,
+                p95_ms: 95,
+            }
+        );
+    }
+
+    #[test]
+    /// no response times means all zeroes, and a single one is every stat
+    fn stats_handles_empty_and_single() {
+        let empty = ResponseTimes::default();
+        assert!(empty.is_empty());
+        assert_eq!(empty.stats(), ScanTimingStats::default());
+
+        let stats = response_times([42]).stats();
+        assert_eq!(stats.min_ms, 42);
+        assert_eq!(stats.max_ms, 42);
+        assert_eq!(stats.avg_ms, 42);
+        assert_eq!(stats.p50_ms, 42);
+        assert_eq!(stats.p95_ms, 42);
+    }
+
+    #[test]
+    /// slow response times should be kept to two significant digits, with the min, max, and
+    /// average staying exact, and anything past the last bucket reported as the max
+    fn stats_approximates_slow_percentiles() {
+        let stats = response_times([1234, 1234, 5678, 250_000]).stats();
+
+        assert_eq!(stats.min_ms, 1234);
+        assert_eq!(stats.max_ms, 250_000);
+        assert_eq!(stats.avg_ms, 64_536);
+        assert_eq!(stats.p50_ms, 1234);
+        assert_eq!(stats.p95_ms, 250_000);
+    }
+
+    #[test]
+    /// each bucket's lower bound should map back to that bucket
+    fn bucket_bounds_round_trip() {
+        for index in 0..NUM_BUCKETS {
+            assert_eq!(bucket_index(bucket_lower_bound(index)), index);
+        }
+
+        assert_eq!(bucket_index(99), 99);
+        assert_eq!(bucket_index(100), 100);
+        assert_eq!(bucket_index(109), 100);
+        assert_eq!(bucket_index(110), 101);
+        assert_eq!(bucket_index(u64::MAX), NUM_BUCKETS - 1);
+    }
+}
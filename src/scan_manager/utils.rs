@@ -1,12 +1,50 @@
 #[cfg(not(test))]
 use crate::event_handlers::TermInputHandler;
 use crate::{
-    config::Configuration, event_handlers::Handles, parser::TIMESPEC_REGEX, scanner::RESPONSES,
+    config::{Configuration, OutputLevel},
+    event_handlers::{Command, Handles},
+    parser::TIMESPEC_REGEX,
+    scanner::RESPONSES,
+    utils::slugify_filename,
 };
 
-use std::{fs::File, io::BufReader, sync::Arc};
+use super::{
+    interesting_meta_tags, load_state, remove_state_file, FeroxResponses, FeroxScans,
+    ScanTimingStats, API_VERSIONS, CERTIFICATES, COLLECTED_PARAMETERS, HSTS_FINDINGS, META_TAGS,
+    MISSING_SECURITY_HEADERS, PAGE_TITLES, REDIRECT_TARGETS, SECURITY_HEADERS, SOURCE_MAP_PATHS,
+    SUBPATH_WORDS, TECH_STACKS,
+};
+use anyhow::Result;
+use console::style;
+use serde::Serialize;
+use std::{
+    collections::HashSet,
+    fmt::Display,
+    fs::File,
+    io::{stderr, Write},
+    path::Path,
+    sync::Arc,
+};
 use tokio::time;
 
+/// Given a string representing some number of seconds, minutes, hours, or days (ex: 10m, 30s,
+/// 1h), convert that representation to seconds
+fn timespec_to_secs(timespec: &str) -> Option<u64> {
+    let captures = TIMESPEC_REGEX.captures(timespec)?;
+    let length = captures.get(1)?.as_str().parse::<u64>().ok()?;
+    let measurement = captures.get(2)?.as_str().to_ascii_lowercase();
+
+    let secs = match measurement.as_str() {
+        "s" => length,
+        "m" => length * 60,           // minutes
+        "h" => length * 60 * 60,      // hours
+        "d" => length * 60 * 60 * 24, // days
+        _ => length,
+    };
+
+    Some(secs)
+}
+
 /// Given a string representing some number of seconds, minutes, hours, or days, convert
 /// that representation to seconds and then wait for those seconds to elapse.  Once that period
 /// of time has elapsed, kill all currently running scans and dump a state file to disk that can
@@ -14,22 +52,9 @@ use tokio::time;
 pub async fn start_max_time_thread(handles: Arc<Handles>) {
     log::trace!("enter: start_max_time_thread({:?})", handles);
 
-    // as this function has already made it through the parser, which calls is_match on
-    // the value passed to --time-limit using TIMESPEC_REGEX; we can safely assume that
-    // the capture groups are populated; can expect something like 10m, 30s, 1h, etc...
-    let captures = TIMESPEC_REGEX.captures(&handles.config.time_limit).unwrap();
-    let length_match = captures.get(1).unwrap();
-    let measurement_match = captures.get(2).unwrap();
-
-    if let Ok(length) = length_match.as_str().parse::<u64>() {
-        let length_in_secs = match measurement_match.as_str().to_ascii_lowercase().as_str() {
-            "s" => length,
-            "m" => length * 60,           // minutes
-            "h" => length * 60 * 60,      // hours
-            "d" => length * 60 * 60 * 24, // days
-            _ => length,
-        };
-
+    // the value passed to --time-limit has already been validated by the parser using
+    // TIMESPEC_REGEX, so this should only fail on a value that overflows a u64
+    if let Some(length_in_secs) = timespec_to_secs(&handles.config.time_limit) {
         log::debug!(
             "max time limit as string: {} and as seconds: {}",
             handles.config.time_limit,
@@ -52,20 +77,102 @@ pub async fn start_max_time_thread(handles: Arc<Handles>) {
     );
 }
 
+/// Every second, take a snapshot of the overall scan's progress and show it on the overall progress
+/// bar; with --json-status, the snapshot is also printed to stderr as JSON whenever it changes
+pub async fn start_status_thread(handles: Arc<Handles>) {
+    log::trace!("enter: start_status_thread({:?})", handles);
+
+    let mut interval = time::interval(time::Duration::from_secs(1));
+    let mut previous = None;
+
+    loop {
+        interval.tick().await;
+
+        let scans = match handles.ferox_scans() {
+            Ok(scans) => scans,
+            Err(_) => continue, // scan handler isn't ready yet
+        };
+
+        let stats = scans.stats();
+
+        if handles.stats.send(Command::UpdateScanStats(stats)).is_err() {
+            // stats handler has exited, the scan is over
+            break;
+        }
+
+        if handles.config.json_status && previous != Some(stats) {
+            eprintln!("{}", stats.as_json());
+        }
+
+        previous = Some(stats);
+    }
+
+    log::trace!("exit: start_status_thread");
+}
+
+/// Write a state file every --checkpoint-interval, so that a scan that's killed without a
+/// chance to save its state (OOM, power loss, etc) can still be resumed from the last checkpoint.
+/// Only the latest checkpoint is kept on disk.
+pub async fn start_checkpoint_thread(handles: Arc<Handles>) {
+    log::trace!("enter: start_checkpoint_thread({:?})", handles);
+
+    let interval = match timespec_to_secs(&handles.config.checkpoint_interval) {
+        Some(interval) if interval > 0 => time::Duration::from_secs(interval),
+        _ => {
+            log::warn!(
+                "Could not parse the value provided ({}), can't write checkpoints",
+                handles.config.checkpoint_interval
+            );
+            return;
+        }
+    };
+
+    let mut previous: Option<String> = None;
+
+    loop {
+        time::sleep(interval).await;
+
+        let scans = match handles.ferox_scans() {
+            Ok(scans) => scans,
+            Err(e) => {
+                log::warn!("Could not write checkpoint: {}", e);
+                continue;
+            }
+        };
+
+        match scans.export_to_file(
+            &handles.config,
+            &RESPONSES,
+            &handles.stats.data,
+            &handles.filters.data,
+        ) {
+            Ok(filename) => {
+                log::debug!("wrote checkpoint to {}", filename);
+
+                // state files are named after the second they were written in, so the previous
+                // checkpoint is only removed once the new one is safely on disk
+                if let Some(previous) = previous.replace(filename.clone()) {
+                    if previous != filename {
+                        remove_state_file(&previous);
+                    }
+                }
+            }
+            Err(e) => log::warn!("Could not write checkpoint: {}", e),
+        }
+    }
+}
+
 /// Primary logic used to load a Configuration from disk and populate the appropriate data
 /// structures
 pub fn resume_scan(filename: &str) -> Configuration {
     log::trace!("enter: resume_scan({})", filename);
 
-    let file = File::open(filename).unwrap_or_else(|e| {
-        log::error!("{}", e);
+    let state = load_state(filename).unwrap_or_else(|e| {
+        log::error!("{:?}", e);
         log::error!("Could not open state file, exiting");
         std::process::exit(1);
     });
 
-    let reader = BufReader::new(file);
-    let state: serde_json::Value = serde_json::from_reader(reader).unwrap();
-
     let conf = state.get("config").unwrap_or_else(|| {
         log::error!("Could not load configuration from state file, exiting");
         std::process::exit(1);
@@ -90,3 +197,637 @@ pub fn resume_scan(filename: &str) -> Configuration {
     log::trace!("exit: resume_scan -> {:?}", config);
     config
 }
+
+/// name of the file that the per-host report of the given `kind` (i.e. `params`) is written to
+/// for `host`, i.e. ferox-params-localhost_8080-1600000000.txt
+///
+/// reports are timestamped the same way as state files, so an earlier run's report isn't
+/// overwritten, and written next to the -o|--output file when one was given
+pub(super) fn per_host_filename(
+    config: &Configuration,
+    kind: &str,
+    host: &str,
+    extension: &str,
+) -> String {
+    let host = host.replace([':', '[', ']'], "_");
+    let filename = slugify_filename(&host, &format!("ferox-{}", kind), extension);
+
+    if config.output.is_empty() {
+        filename
+    } else {
+        Path::new(&config.output)
+            .with_file_name(filename)
+            .to_string_lossy()
+            .to_string()
+    }
+}
+
+/// write `lines` to the per-host report of the given `kind` for `host`, one per line, and
+/// return the name of the file written
+pub(super) fn per_host_report<T: Display>(
+    config: &Configuration,
+    kind: &str,
+    host: &str,
+    lines: impl IntoIterator<Item = T>,
+) -> Result<String> {
+    let filename = per_host_filename(config, kind, host, "txt");
+
+    let mut file = File::create(&filename)?;
+    for line in lines {
+        writeln!(file, "{}", line)?;
+    }
+
+    Ok(filename)
+}
+
+/// write `value` as json to the per-host report of the given `kind` for `host`, and return the
+/// name of the file written
+pub(super) fn per_host_json_report<T: Serialize>(
+    config: &Configuration,
+    kind: &str,
+    host: &str,
+    value: &T,
+) -> Result<String> {
+    let filename = per_host_filename(config, kind, host, "json");
+
+    let file = File::create(&filename)?;
+    serde_json::to_writer_pretty(file, value)?;
+
+    Ok(filename)
+}
+
+/// write all query parameter names collected via --collect-parameters to disk, one
+/// `ferox-params-<host>` file per host, and print a summary table to stderr
+pub fn report_collected_parameters(config: &Configuration) -> Result<()> {
+    log::trace!(
+        "enter: report_collected_parameters({:?})",
+        config.output_level
+    );
+
+    let mut summary = Vec::new();
+
+    for (host, names) in COLLECTED_PARAMETERS.by_host() {
+        let filename = per_host_report(config, "params", &host, &names)?;
+        summary.push((host, names, filename));
+    }
+
+    if matches!(config.output_level, OutputLevel::Default) && !summary.is_empty() {
+        let mut writer = stderr();
+        let bar = "─".repeat(70);
+
+        writeln!(writer, "{}", bar)?;
+        writeln!(
+            writer,
+            " {:<30} {:>6}  {}",
+            style("Host").bright().blue(),
+            style("Params").bright().blue(),
+            style("Saved to").bright().blue()
+        )?;
+        writeln!(writer, "{}", bar)?;
+
+        for (host, names, filename) in &summary {
+            writeln!(writer, " {:<30} {:>6}  {}", host, names.len(), filename)?;
+            let names: Vec<_> = names.iter().map(String::as_str).collect();
+            writeln!(writer, "   {}", style(names.join(", ")).cyan())?;
+        }
+
+        writeln!(writer, "{}", bar)?;
+    }
+
+    log::trace!("exit: report_collected_parameters");
+    Ok(())
+}
+
+/// name of the file to which --collect-hsts writes the urls it flagged, named after the scan's
+/// target (i.e. ferox-hsts-missing-http_localhost-1600000000.txt)
+pub(super) fn hsts_filename(config: &Configuration) -> String {
+    let target = if config.target_url.is_empty() {
+        "stdin"
+    } else {
+        &config.target_url
+    };
+
+    slugify_filename(target, "ferox-hsts-missing", "txt")
+}
+
+/// write every https url flagged via --collect-hsts for a missing or weak
+/// Strict-Transport-Security header to disk, and note where they were saved on stderr
+pub fn report_hsts(config: &Configuration) -> Result<()> {
+    log::trace!("enter: report_hsts({:?})", config.output_level);
+
+    let hosts = HSTS_FINDINGS.by_host();
+
+    if hosts.is_empty() {
+        log::trace!("exit: report_hsts (nothing to report)");
+        return Ok(());
+    }
+
+    let filename = hsts_filename(config);
+    let mut num_urls = 0;
+
+    let mut file = File::create(&filename)?;
+    for (host, pages) in &hosts {
+        for (url, status) in pages {
+            writeln!(file, "{} {} {}", host, url, status)?;
+            num_urls += 1;
+        }
+    }
+
+    if matches!(config.output_level, OutputLevel::Default) {
+        eprintln!(
+            "Saved {} urls with missing or weak HSTS on {} hosts to {}",
+            style(num_urls).cyan(),
+            style(hosts.len()).cyan(),
+            filename
+        );
+    }
+
+    log::trace!("exit: report_hsts");
+    Ok(())
+}
+
+/// write all original source paths collected via --collect-source-maps to disk, one
+/// `ferox-sourcemaps-<host>` file per host, and note where they were saved on stderr
+pub fn report_source_map_paths(config: &Configuration) -> Result<()> {
+    log::trace!("enter: report_source_map_paths({:?})", config.output_level);
+
+    for (host, paths) in SOURCE_MAP_PATHS.by_host() {
+        let filename = per_host_report(config, "sourcemaps", &host, &paths)?;
+
+        if matches!(config.output_level, OutputLevel::Default) {
+            eprintln!(
+                "Saved {} source paths for {} to {}",
+                style(paths.len()).cyan(),
+                host,
+                filename
+            );
+        }
+    }
+
+    log::trace!("exit: report_source_map_paths");
+    Ok(())
+}
+
+/// write all page titles collected via --collect-page-titles to disk, one
+/// `ferox-titles-<host>` file per host, and note where they were saved on stderr
+pub fn report_page_titles(config: &Configuration) -> Result<()> {
+    log::trace!("enter: report_page_titles({:?})", config.output_level);
+
+    for (host, titles) in PAGE_TITLES.by_host() {
+        let lines = titles
+            .iter()
+            .map(|(url, title)| format!("{} ({})", url, title));
+        let filename = per_host_report(config, "titles", &host, lines)?;
+
+        if matches!(config.output_level, OutputLevel::Default) {
+            eprintln!(
+                "Saved {} page titles for {} to {}",
+                style(titles.len()).cyan(),
+                host,
+                filename
+            );
+        }
+    }
+
+    log::trace!("exit: report_page_titles");
+    Ok(())
+}
+
+/// write all meta tags collected via --collect-meta-tags to disk, one `ferox-metatags-<host>`
+/// json file per host, and print the interesting ones (generator, author, etc) on stderr
+pub fn report_meta_tags(config: &Configuration) -> Result<()> {
+    log::trace!("enter: report_meta_tags({:?})", config.output_level);
+
+    for (host, pages) in META_TAGS.by_host() {
+        let filename = per_host_json_report(config, "metatags", &host, &pages)?;
+
+        if matches!(config.output_level, OutputLevel::Default) {
+            for (url, tags) in &pages {
+                for (name, content) in interesting_meta_tags(tags) {
+                    eprintln!("{} {}: {}", url, style(name).blue(), content);
+                }
+            }
+
+            eprintln!(
+                "Saved meta tags of {} pages for {} to {}",
+                style(pages.len()).cyan(),
+                host,
+                filename
+            );
+        }
+    }
+
+    log::trace!("exit: report_meta_tags");
+    Ok(())
+}
+
+/// write all words collected via --collect-subpath-wordlist to disk, one
+/// `ferox-discovered-words-<host>` file per host, and note where they were saved on stderr
+pub fn report_subpath_words(config: &Configuration) -> Result<()> {
+    log::trace!("enter: report_subpath_words({:?})", config.output_level);
+
+    for (host, words) in SUBPATH_WORDS.by_host() {
+        let filename = per_host_report(config, "discovered-words", &host, &words)?;
+
+        if matches!(config.output_level, OutputLevel::Default) {
+            eprintln!(
+                "Saved {} words for {} to {}",
+                style(words.len()).cyan(),
+                host,
+                filename
+            );
+        }
+    }
+
+    log::trace!("exit: report_subpath_words");
+    Ok(())
+}
+
+/// write all redirects collected via --collect-301-targets to disk, one
+/// `ferox-redirects-<host>` file per host, and note where they were saved on stderr
+pub fn report_redirect_targets(config: &Configuration) -> Result<()> {
+    log::trace!("enter: report_redirect_targets({:?})", config.output_level);
+
+    for (host, targets) in REDIRECT_TARGETS.by_host() {
+        let lines = targets
+            .iter()
+            .map(|(source, destination)| format!("{} → {}", source, destination));
+        let filename = per_host_report(config, "redirects", &host, lines)?;
+
+        if matches!(config.output_level, OutputLevel::Default) {
+            eprintln!(
+                "Saved {} redirects for {} to {}",
+                style(targets.len()).cyan(),
+                host,
+                filename
+            );
+        }
+    }
+
+    log::trace!("exit: report_redirect_targets");
+    Ok(())
+}
+
+/// write each certificate collected via --collect-certificates to disk, one
+/// `ferox-certs-<host>` json file per host, and note where they were saved on stderr
+pub fn report_certificates(config: &Configuration) -> Result<()> {
+    log::trace!("enter: report_certificates({:?})", config.output_level);
+
+    let fetched = CERTIFICATES
+        .by_host()
+        .into_iter()
+        .filter_map(|(host, cert_info)| Some((host, cert_info?)));
+
+    for (host, cert_info) in fetched {
+        let filename = per_host_json_report(config, "certs", &host, &cert_info)?;
+
+        if matches!(config.output_level, OutputLevel::Default) {
+            eprintln!(
+                "Saved certificate for {} to {}",
+                style(&host).cyan(),
+                filename
+            );
+        }
+    }
+
+    log::trace!("exit: report_certificates");
+    Ok(())
+}
+
+/// print a table of the technologies detected for each host via --collect-technology-stack
+pub fn report_technology_stack(output_level: OutputLevel) -> Result<()> {
+    log::trace!("enter: report_technology_stack({:?})", output_level);
+
+    let stacks = TECH_STACKS.by_host();
+
+    if matches!(output_level, OutputLevel::Silent) || stacks.is_empty() {
+        log::trace!("exit: report_technology_stack (nothing to report)");
+        return Ok(());
+    }
+
+    let mut writer = stderr();
+    let bar = "─".repeat(70);
+
+    writeln!(writer, "{}", bar)?;
+    writeln!(
+        writer,
+        " {:<30} {}",
+        style("Host").bright().blue(),
+        style("Technologies").bright().blue()
+    )?;
+    writeln!(writer, "{}", bar)?;
+
+    for (host, stack) in &stacks {
+        writeln!(
+            writer,
+            " {:<30} {}",
+            host,
+            style(stack.detected_technologies.join(", ")).cyan()
+        )?;
+    }
+
+    writeln!(writer, "{}", bar)?;
+
+    log::trace!("exit: report_technology_stack");
+    Ok(())
+}
+
+/// print a table of how many of each host's html pages were missing each security header, as
+/// checked via --collect-security-headers
+pub fn report_security_headers(output_level: OutputLevel) -> Result<()> {
+    log::trace!("enter: report_security_headers({:?})", output_level);
+
+    let hosts = MISSING_SECURITY_HEADERS.by_host();
+
+    if matches!(output_level, OutputLevel::Silent) || hosts.is_empty() {
+        log::trace!("exit: report_security_headers (nothing to report)");
+        return Ok(());
+    }
+
+    let mut writer = stderr();
+    let bar = "─".repeat(70);
+
+    writeln!(writer, "{}", bar)?;
+    writeln!(
+        writer,
+        " {:<30} {:<28} {}",
+        style("Host").bright().blue(),
+        style("Missing Header").bright().blue(),
+        style("Pages").bright().blue()
+    )?;
+    writeln!(writer, "{}", bar)?;
+
+    for (host, pages) in &hosts {
+        let mut all_present = true;
+
+        for header in SECURITY_HEADERS {
+            let num_missing = pages
+                .iter()
+                .filter(|(_, missing)| missing.iter().any(|name| name == header))
+                .count();
+
+            if num_missing == 0 {
+                continue;
+            }
+
+            all_present = false;
+
+            writeln!(
+                writer,
+                " {:<30} {:<28} {}/{}",
+                host,
+                style(header).yellow(),
+                num_missing,
+                pages.len()
+            )?;
+        }
+
+        if all_present {
+            writeln!(
+                writer,
+                " {:<30} {:<28} 0/{}",
+                host,
+                style("none").green(),
+                pages.len()
+            )?;
+        }
+    }
+
+    writeln!(writer, "{}", bar)?;
+
+    log::trace!("exit: report_security_headers");
+    Ok(())
+}
+
+/// print a table of the api versions discovered on each host via --collect-api-versions
+pub fn report_api_versions(output_level: OutputLevel) -> Result<()> {
+    log::trace!("enter: report_api_versions({:?})", output_level);
+
+    let api_versions = API_VERSIONS.by_host();
+
+    if matches!(output_level, OutputLevel::Silent) || api_versions.is_empty() {
+        log::trace!("exit: report_api_versions (nothing to report)");
+        return Ok(());
+    }
+
+    let mut writer = stderr();
+    let bar = "─".repeat(70);
+
+    writeln!(writer, "{}", bar)?;
+    writeln!(
+        writer,
+        " {:<30} {}",
+        style("Host").bright().blue(),
+        style("API Versions").bright().blue()
+    )?;
+    writeln!(writer, "{}", bar)?;
+
+    for (host, paths) in &api_versions {
+        writeln!(writer, " {:<30} {}", host, style(paths.join(", ")).cyan())?;
+    }
+
+    writeln!(writer, "{}", bar)?;
+
+    log::trace!("exit: report_api_versions");
+    Ok(())
+}
+
+/// print the url of every scan that was marked as failed after being resumed more than
+/// `max_retries` times
+pub fn report_failed_scans(scans: &FeroxScans, max_retries: u32, output_level: OutputLevel) {
+    let failed_scans = scans.get_failed_scans();
+
+    if failed_scans.is_empty() || matches!(output_level, OutputLevel::Silent) {
+        return;
+    }
+
+    eprintln!(
+        "{} scan(s) permanently failed after being resumed {} times without finishing:",
+        failed_scans.len(),
+        max_retries
+    );
+
+    for scan in failed_scans {
+        eprintln!("  {}", scan.url());
+    }
+}
+
+/// print the url of each scan that was cancelled before it finished to stderr, so they can be
+/// told apart from scans that ran to completion
+pub fn report_cancelled_scans(scans: &FeroxScans, output_level: OutputLevel) {
+    let cancelled_scans = scans.get_cancelled_scans();
+
+    if cancelled_scans.is_empty() || matches!(output_level, OutputLevel::Silent) {
+        return;
+    }
+
+    eprintln!(
+        "{} scan(s) were cancelled before finishing:",
+        cancelled_scans.len()
+    );
+
+    for scan in cancelled_scans {
+        eprintln!("  {}", scan.url());
+    }
+}
+
+/// print a table of the response time stats of each scan that made requests to stderr
+pub fn report_scan_timings(scans: &FeroxScans, output_level: OutputLevel) -> Result<()> {
+    log::trace!("enter: report_scan_timings({:?})", output_level);
+
+    if !matches!(output_level, OutputLevel::Default) {
+        log::trace!("exit: report_scan_timings");
+        return Ok(());
+    }
+
+    let timings: Vec<_> = match scans.scans.read() {
+        Ok(guard) => guard
+            .iter()
+            .map(|scan| {
+                (
+                    scan.url().to_string(),
+                    scan.timing(),
+                    scan.words_per_second(),
+                    scan.elapsed(),
+                    RESPONSES.count_by_url_prefix(scan.url()),
+                )
+            })
+            .filter(|(_, timing, _, _, _)| *timing != ScanTimingStats::default())
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+
+    if !timings.is_empty() {
+        let mut writer = stderr();
+        let bar = "─".repeat(92);
+
+        writeln!(writer, "{}", bar)?;
+        writeln!(
+            writer,
+            " {:<34} {:>6} {:>6} {:>6} {:>6} {:>6} {:>7} {:>7} {:>5}",
+            style("Scan").bright().blue(),
+            style("Min").bright().blue(),
+            style("Avg").bright().blue(),
+            style("P50").bright().blue(),
+            style("P95").bright().blue(),
+            style("Max").bright().blue(),
+            style("Req/s").bright().blue(),
+            style("Secs").bright().blue(),
+            style("Found").bright().blue()
+        )?;
+        writeln!(writer, "{}", bar)?;
+
+        for (url, timing, wps, elapsed, found) in &timings {
+            writeln!(
+                writer,
+                " {:<34} {:>6} {:>6} {:>6} {:>6} {:>6} {:>7.1} {:>7.1} {:>5}",
+                url,
+                timing.min_ms,
+                timing.avg_ms,
+                timing.p50_ms,
+                timing.p95_ms,
+                timing.max_ms,
+                wps,
+                elapsed.as_secs_f64(),
+                found
+            )?;
+        }
+
+        let avg_wps =
+            timings.iter().map(|(_, _, wps, _, _)| wps).sum::<f64>() / timings.len() as f64;
+
+        writeln!(writer, "{}", bar)?;
+        writeln!(
+            writer,
+            " response times in milliseconds; average of {:.1} req/s per scan",
+            avg_wps
+        )?;
+    }
+
+    log::trace!("exit: report_scan_timings");
+    Ok(())
+}
+
+/// words that, when found in a url, mark it as a likely admin panel for --export-metasploit
+const ADMIN_PANEL_KEYWORDS: [&str; 3] = ["admin", "dashboard", "panel"];
+
+/// build a Metasploit resource script from the given responses
+///
+/// every unique url that returned a 200 gets a `curl` status check, and every unique url that
+/// looks like an admin panel gets an `auxiliary/scanner/http/http_login` module run against it
+pub(super) fn to_metasploit_rc(responses: &FeroxResponses) -> String {
+    let mut script = String::from("# resource script generated by feroxbuster\n");
+
+    let ok_responses = responses.filter_by_status(&[200]);
+
+    let mut seen = HashSet::new();
+    let checks: Vec<_> = ok_responses
+        .iter()
+        .filter(|response| seen.insert(response.url().as_str()))
+        .collect();
+
+    if !checks.is_empty() {
+        script.push_str("\n# urls that responded with 200\n");
+
+        for response in checks {
+            // single quotes keep the shell from interpreting &, $, etc; any single quotes in the
+            // url itself are percent-encoded, which is equivalent as far as the server's concerned
+            script.push_str(&format!(
+                "curl -s -o /dev/null -w \"%{{http_code}}\" '{}'\n",
+                response.url().as_str().replace('\'', "%27")
+            ));
+        }
+    }
+
+    let responses = match responses.responses.read() {
+        Ok(responses) => responses.clone(),
+        Err(_) => Vec::new(),
+    };
+
+    let mut seen = HashSet::new();
+    let panels: Vec<_> = responses
+        .iter()
+        .filter(|response| {
+            let url = response.url().as_str().to_lowercase();
+            ADMIN_PANEL_KEYWORDS.iter().any(|word| url.contains(word))
+        })
+        .filter(|response| seen.insert(response.url().as_str()))
+        .collect();
+
+    if !panels.is_empty() {
+        script.push_str("\n# potential admin panels\n");
+
+        for response in panels {
+            let url = response.url();
+
+            script.push_str("use auxiliary/scanner/http/http_login\n");
+            script.push_str(&format!(
+                "set RHOSTS {}\n",
+                url.host_str().unwrap_or_default()
+            ));
+            script.push_str(&format!(
+                "set RPORT {}\n",
+                url.port_or_known_default().unwrap_or(80)
+            ));
+            script.push_str(&format!("set SSL {}\n", url.scheme() == "https"));
+            script.push_str(&format!("set AUTH_URI {}\n", url.path()));
+            script.push_str("run\n");
+            script.push_str("back\n");
+        }
+    }
+
+    script
+}
+
+/// write every response the scan found to `filename` as a Metasploit resource script
+pub fn export_metasploit(filename: &str) -> Result<()> {
+    log::trace!("enter: export_metasploit({})", filename);
+
+    let script = to_metasploit_rc(&RESPONSES);
+
+    let mut file = File::create(filename)?;
+    file.write_all(script.as_bytes())?;
+
+    log::trace!("exit: export_metasploit");
+    Ok(())
+}
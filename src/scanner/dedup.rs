@@ -0,0 +1,43 @@
+use dashmap::DashSet;
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+/// tracks the requests that have been sent across all scans, so that recursive scans that reach
+/// the same url via different parent directories don't request it more than once
+#[derive(Debug, Default)]
+pub(crate) struct RequestDeduplicator {
+    /// hash of the method and url of every request seen so far; only the hash is kept, so memory
+    /// use doesn't grow with the length of the urls
+    requested: DashSet<u64>,
+}
+
+/// implementation of RequestDeduplicator
+impl RequestDeduplicator {
+    /// record the given request; returns `false` if an identical one was already recorded, in
+    /// which case it shouldn't be sent again
+    pub(crate) fn should_request(&self, method: &str, url: &str) -> bool {
+        let mut hasher = DefaultHasher::new();
+        method.hash(&mut hasher);
+        url.hash(&mut hasher);
+
+        self.requested.insert(hasher.finish())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// only the first of several identical requests should be allowed through
+    fn should_request_only_allows_first_identical_request() {
+        let dedup = RequestDeduplicator::default();
+
+        assert!(dedup.should_request("GET", "http://localhost/admin"));
+        assert!(!dedup.should_request("GET", "http://localhost/admin"));
+        assert!(dedup.should_request("POST", "http://localhost/admin"));
+        assert!(dedup.should_request("GET", "http://localhost/admin/"));
+    }
+}
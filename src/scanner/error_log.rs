@@ -0,0 +1,100 @@
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    sync::Mutex,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::Result;
+use lazy_static::lazy_static;
+use reqwest::Url;
+use serde::Serialize;
+
+use crate::utils::open_file;
+
+lazy_static! {
+    /// writer used by --connection-errors-to-file; `None` when the option wasn't used
+    static ref ERROR_LOG: Mutex<Option<BufWriter<File>>> = Mutex::new(None);
+}
+
+/// single line written to the --connection-errors-to-file log
+#[derive(Serialize, Debug)]
+struct ConnectionErrorEntry<'a> {
+    /// url that was being requested
+    url: &'a str,
+
+    /// kind of error encountered (`timeout` or `connection`)
+    error_type: &'a str,
+
+    /// seconds since the unix epoch
+    timestamp: u64,
+}
+
+/// open the --connection-errors-to-file log for appending; all subsequent calls to
+/// `log_connection_error` are written to it
+pub fn initialize_error_log(filename: &str) -> Result<()> {
+    log::trace!("enter: initialize_error_log({})", filename);
+
+    let writer = open_file(filename)?;
+
+    if let Ok(mut guard) = ERROR_LOG.lock() {
+        guard.replace(writer);
+    }
+
+    log::trace!("exit: initialize_error_log");
+    Ok(())
+}
+
+/// write a single NDJSON entry describing a connection/timeout error to the error log, if
+/// one was initialized
+pub(crate) fn log_connection_error(url: &Url, error_type: &str) {
+    if let Ok(mut guard) = ERROR_LOG.lock() {
+        if let Some(writer) = guard.as_mut() {
+            let entry = ConnectionErrorEntry {
+                url: url.as_str(),
+                error_type,
+                timestamp: SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map(|duration| duration.as_secs())
+                    .unwrap_or_default(),
+            };
+
+            if let Ok(json) = serde_json::to_string(&entry) {
+                if let Err(e) = writeln!(writer, "{}", json) {
+                    log::warn!("Could not write to connection error log: {}", e);
+                }
+            }
+        }
+    }
+}
+
+/// flush any buffered entries in the error log to disk
+pub fn flush_error_log() -> Result<()> {
+    if let Ok(mut guard) = ERROR_LOG.lock() {
+        if let Some(writer) = guard.as_mut() {
+            writer.flush()?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// entries should serialize to the documented one-line json shape
+    fn connection_error_entry_serializes_expected_keys() {
+        let entry = ConnectionErrorEntry {
+            url: "http://localhost/stuff",
+            error_type: "timeout",
+            timestamp: 1,
+        };
+
+        assert_eq!(
+            serde_json::to_string(&entry).unwrap(),
+            r#"{"url":"http://localhost/stuff","error_type":"timeout","timestamp":1}"#
+        );
+    }
+}
@@ -1,9 +1,14 @@
 use std::sync::atomic::AtomicBool;
-use std::{ops::Deref, sync::atomic::Ordering, sync::Arc, time::Instant};
+use std::{
+    ops::Deref,
+    sync::atomic::Ordering,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use anyhow::{bail, Result};
 use console::style;
-use futures::{stream, StreamExt};
+use futures::{future, stream, stream::BoxStream, StreamExt};
 use indicatif::ProgressBar;
 use lazy_static::lazy_static;
 use tokio::sync::Semaphore;
@@ -17,22 +22,31 @@ use crate::{
     },
     extractor::{ExtractionTarget, ExtractorBuilder},
     heuristics,
-    scan_manager::{FeroxResponses, FeroxScans, MenuCmdResult, ScanOrder, ScanStatus, PAUSE_SCAN},
+    progress::PROGRESS_PRINTER,
+    scan_manager::{
+        FeroxResponses, FeroxScan, FeroxScans, MenuCmdResult, ScanOrder, ScanStatus, PAUSE_SCAN,
+    },
     scanner::requester::TF_IDF,
     statistics::{
         StatError::Other,
         StatField::{DirScanTimes, TotalExpected},
     },
-    utils::fmt_err,
-    Command,
+    utils::{ferox_print, fmt_err},
+    Command, SLEEP_DURATION,
 };
 
-use super::requester::Requester;
+use super::{
+    dedup::RequestDeduplicator, requester::Requester, wordlist_adaptor::WordlistAdaptor,
+    wordlist_watcher::WATCHED_WORDS,
+};
 
 lazy_static! {
     /// Vector of FeroxResponse objects
     pub static ref RESPONSES: FeroxResponses = FeroxResponses::default();
     // todo consider removing this
+
+    /// every request sent by any scan, used to skip identical requests unless --no-request-dedup
+    pub(crate) static ref REQUESTED: RequestDeduplicator = RequestDeduplicator::default();
 }
 
 /// check to see if `pause_flag` is set to true. when true; enter a busy loop that only exits
@@ -57,13 +71,11 @@ async fn check_for_user_input(
                     .send_scan_command(Command::ScanNewUrl(url))
                     .unwrap_or_else(|e| log::warn!("Could not add scan to scan queue: {}", e))
             }
-            Some(MenuCmdResult::NumCancelled(num_canx)) => {
-                if num_canx > 0 {
-                    handles
-                        .stats
-                        .send(SubtractFromUsizeField(TotalExpected, num_canx))
-                        .unwrap_or_else(|e| log::warn!("Could not update overall scan bar: {}", e));
-                }
+            Some(MenuCmdResult::NumCancelled(num_canx)) if num_canx > 0 => {
+                handles
+                    .stats
+                    .send(SubtractFromUsizeField(TotalExpected, num_canx))
+                    .unwrap_or_else(|e| log::warn!("Could not update overall scan bar: {}", e));
             }
             Some(MenuCmdResult::Filter(mut filter)) => {
                 let url = if let Some(SimilarityFilter { original_url, .. }) =
@@ -103,6 +115,13 @@ async fn check_for_user_input(
     log::trace!("exit: check_for_user_input");
 }
 
+/// when a single scan was paused from the interactive menu, sleep until it's resumed
+async fn wait_while_paused(paused: &AtomicBool) {
+    while paused.load(Ordering::Relaxed) {
+        tokio::time::sleep(Duration::from_millis(SLEEP_DURATION)).await;
+    }
+}
+
 /// handles the main muscle movement of scanning a url
 pub struct FeroxScanner {
     /// handles to handlers and config
@@ -120,6 +139,9 @@ pub struct FeroxScanner {
 
     /// limiter that restricts the number of active FeroxScanners
     scan_limiter: Arc<Semaphore>,
+
+    /// limiter that restricts the number of active FeroxScanners against the target's host
+    host_limiter: Option<Arc<Semaphore>>,
 }
 
 /// FeroxScanner implementation
@@ -130,6 +152,7 @@ impl FeroxScanner {
         order: ScanOrder,
         wordlist: Arc<Vec<String>>,
         scan_limiter: Arc<Semaphore>,
+        host_limiter: Option<Arc<Semaphore>>,
         handles: Arc<Handles>,
     ) -> Self {
         Self {
@@ -137,32 +160,90 @@ impl FeroxScanner {
             handles,
             wordlist,
             scan_limiter,
+            host_limiter,
             target_url: target_url.to_string(),
         }
     }
 
+    /// whether --max-urls-per-scan is in use and the given scan has found that many urls
+    fn reached_max_urls(&self, ferox_scan: &FeroxScan) -> bool {
+        let max_urls = self.handles.config.max_urls_per_scan;
+        max_urls > 0 && ferox_scan.found_count() >= max_urls
+    }
+
+    /// --threads-burst; limiter that allows `threads_burst` concurrent requests until
+    /// `threads_burst_duration` milliseconds have passed, at which point it shrinks to `threads`;
+    /// created once per scan, so the burst only happens at the start of the scan
+    ///
+    /// returns None when no burst is configured (or it's no bigger than --threads)
+    fn burst_limiter(&self) -> Option<Arc<Semaphore>> {
+        let config = &self.handles.config;
+
+        if config.threads_burst <= config.threads {
+            return None;
+        }
+
+        let limiter = Arc::new(Semaphore::new(config.threads_burst));
+        let to_shrink = limiter.clone();
+        let excess = (config.threads_burst - config.threads) as u32;
+        let duration = Duration::from_millis(config.threads_burst_duration);
+
+        tokio::spawn(async move {
+            tokio::time::sleep(duration).await;
+
+            // the excess permits are claimed as in-flight requests finish, and never returned
+            if let Ok(permits) = to_shrink.acquire_many(excess).await {
+                permits.forget();
+            }
+        });
+
+        Some(limiter)
+    }
+
     /// produces and awaits tasks (mp of mpsc); responsible for making requests
     async fn stream_requests(
         &self,
-        looping_words: Arc<Vec<String>>,
+        words: BoxStream<'_, String>,
         progress_bar: ProgressBar,
         scanned_urls: Arc<FeroxScans>,
         requester: Arc<Requester>,
+        ferox_scan: &FeroxScan,
+        burst_limiter: Option<Arc<Semaphore>>,
     ) {
         log::trace!("enter: stream_requests(params too verbose to print)");
 
-        let producers = stream::iter(looping_words.deref().to_owned())
+        let concurrency = self
+            .handles
+            .config
+            .threads
+            .max(self.handles.config.threads_burst);
+
+        let producers = words
+            // stop handing out words once --max-urls-per-scan has been reached; requests that
+            // are already in-flight are allowed to finish
+            .take_while(|_| future::ready(!self.reached_max_urls(ferox_scan)))
             .map(|word| {
                 let pb = progress_bar.clone(); // progress bar is an Arc around internal state
                 let scanned_urls_clone = scanned_urls.clone();
                 let requester_clone = requester.clone();
                 let handles_clone = self.handles.clone();
+                let burst_limiter_clone = burst_limiter.clone();
+                let paused = ferox_scan.paused_flag();
                 (
                     tokio::spawn(async move {
                         // for every word in the wordlist, check to see if user has pressed enter
                         // in order to go into the interactive menu
                         check_for_user_input(&PAUSE_SCAN, scanned_urls_clone, handles_clone).await;
 
+                        // this scan alone may have been paused from the interactive menu
+                        wait_while_paused(&paused).await;
+
+                        // when --threads-burst is used, the permit is held until the request is done
+                        let _permit = match &burst_limiter_clone {
+                            Some(limiter) => limiter.acquire().await.ok(),
+                            None => None,
+                        };
+
                         // after checking for user input, send the request
                         requester_clone
                             .request(&word)
@@ -172,7 +253,7 @@ impl FeroxScanner {
                     pb,
                 )
             })
-            .for_each_concurrent(self.handles.config.threads, |(resp, bar)| async move {
+            .for_each_concurrent(concurrency, |(resp, bar)| async move {
                 match resp.await {
                     Ok(_) => {
                         let increment_len = self.handles.expected_num_requests_multiplier() as u64;
@@ -192,6 +273,82 @@ impl FeroxScanner {
         log::trace!("exit: stream_requests");
     }
 
+    /// --watch-wordlist; request the words added to the wordlist since this scan started,
+    /// repeating until no more have shown up
+    async fn request_watched_words(
+        &self,
+        progress_bar: &ProgressBar,
+        scanned_urls: Arc<FeroxScans>,
+        requester: Arc<Requester>,
+        ferox_scan: &FeroxScan,
+        burst_limiter: Option<Arc<Semaphore>>,
+    ) {
+        let mut num_requested = 0;
+
+        while !self.reached_max_urls(ferox_scan) {
+            let new_words = WATCHED_WORDS.since(num_requested);
+
+            if new_words.is_empty() {
+                break;
+            }
+
+            num_requested += new_words.len();
+
+            let num_expected = new_words.len() * self.handles.expected_num_requests_multiplier();
+
+            progress_bar.set_length(progress_bar.length() + num_expected as u64);
+
+            self.handles
+                .stats
+                .send(AddToUsizeField(TotalExpected, num_expected))
+                .unwrap_or_default();
+
+            log::info!(
+                "{} requesting {} words added to the wordlist",
+                self.target_url,
+                new_words.len()
+            );
+
+            self.stream_requests(
+                stream::iter(new_words).boxed(),
+                progress_bar.clone(),
+                scanned_urls.clone(),
+                requester.clone(),
+                ferox_scan,
+                burst_limiter.clone(),
+            )
+            .await;
+        }
+    }
+
+    /// --verbose-scan-start; print the url, recursion depth, and wordlist of a scan that's
+    /// about to begin, along with the url of the scan that spawned it, if any
+    fn print_scan_start(&self, scanned_urls: &FeroxScans, ferox_scan: &FeroxScan) {
+        let wordlist = if self.handles.config.multipart_wordlist.is_empty() {
+            &self.handles.config.wordlist
+        } else {
+            &self.handles.config.multipart_wordlist
+        };
+
+        let mut message = format!(
+            "[+] Starting scan: {} (depth: {}, wordlist: {}",
+            ferox_scan.url(),
+            scanned_urls.depth_of(ferox_scan.id()),
+            wordlist
+        );
+
+        if let Some(parent) = ferox_scan
+            .parent_id()
+            .and_then(|id| scanned_urls.get_scan_by_id(id))
+        {
+            message.push_str(&format!(", parent: {}", parent.url()));
+        }
+
+        message.push(')');
+
+        ferox_print(&message, &PROGRESS_PRINTER);
+    }
+
     /// Scan a given url using a given wordlist
     ///
     /// This is the primary entrypoint for the scanner
@@ -234,13 +391,26 @@ impl FeroxScanner {
         // returns a permit. However, if no remaining permits are available, acquire (asynchronously)
         // waits until an outstanding permit is dropped, at which point, the freed permit is assigned
         // to the caller.
+        //
+        // the per-host permit (--scan-limit-per-host) is acquired first, so that a scan waiting on
+        // its host doesn't hold one of the global --scan-limit permits, which would keep scans
+        // against other hosts from starting
+        let _host_permit = match &self.host_limiter {
+            Some(limiter) => Some(limiter.acquire().await),
+            None => None,
+        };
+
         let _permit = self.scan_limiter.acquire().await;
 
-        if self.handles.config.scan_limit > 0 {
+        if self.handles.config.scan_limit > 0 || self.handles.config.scan_limit_per_host > 0 {
             scan_timer = Instant::now();
             progress_bar.reset();
         }
 
+        if self.handles.config.verbose_scan_start {
+            self.print_scan_start(&scanned_urls, &ferox_scan);
+        }
+
         {
             // heuristics test block
             let test = heuristics::HeuristicTests::new(self.handles.clone());
@@ -249,69 +419,105 @@ impl FeroxScanner {
                 progress_bar.inc(num_reqs);
             }
 
-            if let Ok(dirlist_result) = test.directory_listing(&self.target_url).await {
-                if dirlist_result.is_some() {
-                    let dirlist_result = dirlist_result.unwrap();
-                    // at this point, we have a DirListingType, and it's not the None variant
-                    // which means we found directory listing based on the heuristic; now we need
-                    // to process the links that are available if --extract-links was used
+            if let Ok(Some(dirlist_result)) = test.directory_listing(&self.target_url).await {
+                // at this point, we have a DirListingType, and it's not the None variant
+                // which means we found directory listing based on the heuristic; now we need
+                // to process the links that are available if --extract-links was used
 
-                    if self.handles.config.extract_links {
-                        let mut extractor = ExtractorBuilder::default()
-                            .response(&dirlist_result.response)
-                            .target(ExtractionTarget::DirectoryListing)
-                            .url(&self.target_url)
-                            .handles(self.handles.clone())
-                            .build()?;
+                if self.handles.config.extract_links {
+                    let mut extractor = ExtractorBuilder::default()
+                        .response(&dirlist_result.response)
+                        .target(ExtractionTarget::DirectoryListing)
+                        .url(&self.target_url)
+                        .handles(self.handles.clone())
+                        .build()?;
 
-                        let result = extractor.extract_from_dir_listing().await?;
+                    let result = extractor.extract_from_dir_listing().await?;
 
-                        extractor.request_links(result).await?;
+                    extractor.request_links(result).await?;
 
-                        log::trace!("exit: scan_url -> Directory listing heuristic");
+                    log::trace!("exit: scan_url -> Directory listing heuristic");
 
-                        self.handles.stats.send(AddToF64Field(
-                            DirScanTimes,
-                            scan_timer.elapsed().as_secs_f64(),
-                        ))?;
+                    self.handles.stats.send(AddToF64Field(
+                        DirScanTimes,
+                        scan_timer.elapsed().as_secs_f64(),
+                    ))?;
 
-                        self.handles.stats.send(SubtractFromUsizeField(
-                            TotalExpected,
-                            progress_bar.length() as usize,
-                        ))?;
-                    }
+                    self.handles.stats.send(SubtractFromUsizeField(
+                        TotalExpected,
+                        progress_bar.length() as usize,
+                    ))?;
+                }
 
-                    let mut message = format!("=> {}", style("Directory listing").blue().bright());
+                let mut message = format!("=> {}", style("Directory listing").blue().bright());
 
-                    if !self.handles.config.extract_links {
-                        message
-                            .push_str(&format!(" (add {} to scan)", style("-e").bright().yellow()))
-                    }
+                if !self.handles.config.extract_links {
+                    message.push_str(&format!(" (add {} to scan)", style("-e").bright().yellow()))
+                }
 
-                    progress_bar.reset_eta();
-                    progress_bar.finish_with_message(&message);
+                progress_bar.reset_eta();
+                progress_bar.finish_with_message(&message);
 
-                    ferox_scan.finish()?;
+                ferox_scan.finish()?;
 
-                    return Ok(());
-                }
+                return Ok(());
             }
         }
 
+        if ferox_scan.requests_made_so_far() > 0 {
+            // resumed scan; start the progress bar where the previous run left off
+            progress_bar.set_position(ferox_scan.requests_made_so_far());
+        }
+
         // Arc clones to be passed around to the various scans
         let looping_words = self.wordlist.clone();
 
-        let requester = Arc::new(Requester::from(self, ferox_scan.clone())?);
+        // --adaptive-wordlist hands out words from a queue that's reordered as urls are found,
+        // instead of in wordlist order
+        let adaptor = self
+            .handles
+            .config
+            .adaptive_wordlist
+            .then(|| Arc::new(WordlistAdaptor::new(&looping_words)));
+
+        let requester = Arc::new(Requester::from(self, ferox_scan.clone(), adaptor.clone())?);
+
+        let words = match adaptor {
+            Some(adaptor) => stream::unfold(adaptor, |adaptor| async move {
+                adaptor.next_word().map(|word| (word, adaptor))
+            })
+            .boxed(),
+            None => stream::iter(looping_words.deref().to_owned()).boxed(),
+        };
+
+        // --rate-limit; the scan's token bucket is refilled for as long as the scan runs
+        ferox_scan.start_rate_limiter();
+
+        // --threads-burst; shared by every batch of words this scan requests
+        let burst_limiter = self.burst_limiter();
 
         self.stream_requests(
-            looping_words.clone(),
+            words,
             progress_bar.clone(),
             scanned_urls.clone(),
             requester.clone(),
+            &ferox_scan,
+            burst_limiter.clone(),
         )
         .await;
 
-        if self.handles.config.collect_words {
+        if self.handles.config.watch_wordlist {
+            self.request_watched_words(
+                &progress_bar,
+                scanned_urls.clone(),
+                requester.clone(),
+                &ferox_scan,
+                burst_limiter.clone(),
+            )
+            .await;
+        }
+
+        if self.handles.config.collect_words && !self.reached_max_urls(&ferox_scan) {
             let new_words = TF_IDF.read().unwrap().all_words();
             let new_words_len = new_words.len();
 
@@ -328,18 +534,38 @@ impl FeroxScanner {
             log::info!(
                 "requesting {} collected words: {:?}...",
                 new_words_len,
-                &new_words[..new_words_len.min(3) as usize]
+                &new_words[..new_words_len.min(3)]
             );
 
             self.stream_requests(
-                Arc::new(new_words),
+                stream::iter(new_words).boxed(),
                 progress_bar.clone(),
                 scanned_urls.clone(),
                 requester.clone(),
+                &ferox_scan,
+                burst_limiter.clone(),
             )
             .await;
         }
 
+        if self.reached_max_urls(&ferox_scan) {
+            log::info!(
+                "{} found {} urls, stopping scan early (--max-urls-per-scan)",
+                self.target_url,
+                ferox_scan.found_count()
+            );
+
+            // the words that weren't requested never will be, so they shouldn't be expected
+            let unrequested = progress_bar
+                .length()
+                .saturating_sub(progress_bar.position());
+
+            self.handles
+                .stats
+                .send(SubtractFromUsizeField(TotalExpected, unrequested as usize))
+                .unwrap_or_default();
+        }
+
         self.handles.stats.send(AddToF64Field(
             DirScanTimes,
             scan_timer.elapsed().as_secs_f64(),
@@ -1,12 +1,20 @@
 mod ferox_scanner;
+mod dedup;
+mod wordlist_adaptor;
+mod wordlist_watcher;
+mod error_log;
 mod utils;
 mod init;
 #[cfg(test)]
 mod tests;
 mod limit_heap;
 mod policy_data;
+mod rate_limit_backoff;
 mod requester;
 
+pub(crate) use self::error_log::log_connection_error;
+pub use self::error_log::{flush_error_log, initialize_error_log};
 pub use self::ferox_scanner::{FeroxScanner, RESPONSES};
 pub use self::init::initialize;
 pub use self::utils::PolicyTrigger;
+pub use self::wordlist_watcher::watch_wordlist;
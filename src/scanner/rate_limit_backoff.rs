@@ -0,0 +1,125 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use crate::{atomic_load, atomic_store};
+
+/// 429s received this soon after backing off were most likely sent before the new limit took
+/// effect, so they don't cause the limit to be halved again
+const BACKOFF_GRACE_PERIOD: Duration = Duration::from_secs(1);
+
+/// feedback loop used by --rate-limit-detect to adjust a scan's requests/second limit; the limit
+/// is halved each time a 429 is received, and raised back toward the original rate after each
+/// --rate-limit-recovery period without one
+#[derive(Debug, Default)]
+pub(super) struct RateLimitBackoff {
+    /// requests/second the scan was running at before the first 429; 0 until one is received
+    original: AtomicUsize,
+
+    /// current requests/second limit; 0 when the scan isn't being limited
+    current: AtomicUsize,
+
+    /// when `current` was last changed, either by a 429 or by recovery
+    last_change: Mutex<Option<Instant>>,
+}
+
+/// implementation of RateLimitBackoff
+impl RateLimitBackoff {
+    /// halve the current limit in response to a 429; `rate` is the scan's requests/second, used
+    /// as the starting point when the scan isn't already being limited
+    ///
+    /// returns the new limit, or `None` if the 429 was ignored
+    pub(super) fn back_off(&self, rate: usize) -> Option<usize> {
+        let mut last_change = self.last_change.lock().unwrap();
+
+        if last_change.is_some_and(|changed| changed.elapsed() < BACKOFF_GRACE_PERIOD) {
+            return None;
+        }
+
+        let current = match atomic_load!(self.current) {
+            0 => {
+                atomic_store!(self.original, rate.max(1));
+                rate.max(1)
+            }
+            current => current,
+        };
+
+        let new_limit = (current / 2).max(1);
+
+        atomic_store!(self.current, new_limit);
+        *last_change = Some(Instant::now());
+
+        Some(new_limit)
+    }
+
+    /// raise the current limit by a quarter of the original rate, as long as `recovery` has
+    /// passed since it was last changed
+    ///
+    /// returns the new limit, where `Some(0)` means the original rate was reached and the scan
+    /// should no longer be limited, or `None` if nothing changed
+    pub(super) fn recover(&self, recovery: Duration) -> Option<usize> {
+        let current = atomic_load!(self.current);
+
+        if current == 0 {
+            return None;
+        }
+
+        let mut last_change = self.last_change.lock().unwrap();
+
+        if last_change.is_some_and(|changed| changed.elapsed() < recovery) {
+            return None;
+        }
+
+        let original = atomic_load!(self.original);
+        let step = (original / 4).max(1);
+
+        let new_limit = if current + step >= original {
+            0
+        } else {
+            current + step
+        };
+
+        atomic_store!(self.current, new_limit);
+        *last_change = Some(Instant::now());
+
+        Some(new_limit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// a 429 should halve the limit, starting from the given rate, but not within the grace period
+    fn back_off_halves_limit_outside_of_grace_period() {
+        let backoff = RateLimitBackoff::default();
+
+        assert_eq!(backoff.back_off(100), Some(50));
+        assert_eq!(backoff.back_off(100), None);
+
+        *backoff.last_change.lock().unwrap() = Some(Instant::now() - BACKOFF_GRACE_PERIOD);
+
+        assert_eq!(backoff.back_off(100), Some(25));
+        assert_eq!(atomic_load!(backoff.original), 100);
+    }
+
+    #[test]
+    /// recovery should raise the limit by a quarter of the original until it's reached
+    fn recover_steps_back_up_to_original_rate() {
+        let backoff = RateLimitBackoff::default();
+
+        assert_eq!(backoff.recover(Duration::ZERO), None);
+
+        backoff.back_off(100);
+
+        assert_eq!(backoff.recover(Duration::from_secs(60)), None);
+        assert_eq!(backoff.recover(Duration::ZERO), Some(75));
+        assert_eq!(backoff.recover(Duration::ZERO), Some(0));
+        assert_eq!(backoff.recover(Duration::ZERO), None);
+    }
+}
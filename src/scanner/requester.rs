@@ -2,11 +2,17 @@ use std::{
     cmp::max,
     collections::HashSet,
     sync::{self, atomic::Ordering, Arc, Mutex},
+    time::Instant,
 };
 
 use anyhow::Result;
+use console::style;
 use lazy_static::lazy_static;
 use leaky_bucket::LeakyBucket;
+use reqwest::{
+    header::{HeaderMap, RETRY_AFTER},
+    Response, StatusCode, Url,
+};
 use tokio::{
     sync::RwLock,
     time::{sleep, Duration},
@@ -14,28 +20,56 @@ use tokio::{
 
 use crate::{
     atomic_load, atomic_store,
+    config::OutputLevel,
     config::RequesterPolicy,
+    cors::{probe_cors, CorsVulnerability, CORS_PROBE_ORIGIN},
     event_handlers::{
-        Command::{AddError, SubtractFromUsizeField},
+        Command::{self, AddError, AddToUsizeField, SubtractFromUsizeField},
         Handles,
     },
     extractor::{ExtractionTarget, ExtractorBuilder},
+    message::FeroxMessage,
     nlp::{Document, TfIdf},
-    response::FeroxResponse,
-    scan_manager::{FeroxScan, ScanStatus},
-    statistics::{StatError::Other, StatField::TotalExpected},
+    progress::PROGRESS_PRINTER,
+    response::{DiscoveryMethod, FeroxResponse},
+    scan_manager::{
+        check_hsts, check_security_headers, detect_technologies, fetch_certificate, host_key,
+        FeroxScan, HstsStatus, ScanStatus, CERTIFICATES, COLLECTED_PARAMETERS, HSTS_FINDINGS,
+        META_TAGS, MISSING_SECURITY_HEADERS, PAGE_TITLES, REDIRECT_TARGETS, SUBPATH_WORDS,
+        TECH_STACKS,
+    },
+    statistics::{
+        StatError::Other,
+        StatField::{RequestsDeduplicated, TotalExpected},
+    },
     url::FeroxUrl,
-    utils::{logged_request, send_try_recursion_command, should_deny_url},
+    utils::{
+        check_options, ferox_print, interesting_paths, logged_request, logged_upload,
+        path_components, probe_websocket, send_scan_command_and_sync, send_try_recursion_command,
+        should_deny_url, DANGEROUS_METHODS,
+    },
     HIGH_ERROR_RATIO,
 };
 
-use super::{policy_data::PolicyData, FeroxScanner, PolicyTrigger};
+use super::{
+    ferox_scanner::REQUESTED, policy_data::PolicyData, rate_limit_backoff::RateLimitBackoff,
+    wordlist_adaptor::WordlistAdaptor, FeroxScanner, PolicyTrigger,
+};
 
 lazy_static! {
     /// make sure to note that this is a std rwlock and not tokio
     pub(crate) static ref TF_IDF: Arc<sync::RwLock<TfIdf>> = Arc::new(sync::RwLock::new(TfIdf::new()));
 }
 
+/// seconds that --pause-on-rate-limit pauses for when a 429 doesn't say how long to wait
+const DEFAULT_RATE_LIMIT_PAUSE: u64 = 60;
+
+/// number of seconds given in a 429's Retry-After header; `None` when the header is missing or is
+/// an http date rather than a number of seconds
+fn retry_after_seconds(headers: &HeaderMap) -> Option<u64> {
+    headers.get(RETRY_AFTER)?.to_str().ok()?.trim().parse().ok()
+}
+
 /// Makes multiple requests based on the presence of extensions
 pub(super) struct Requester {
     /// handles to handlers and config
@@ -64,20 +98,22 @@ pub(super) struct Requester {
     /// seen; this will satisfy the non-mut self constraint (due to us being behind an Arc, and
     /// the need for a counter)
     tuning_lock: Mutex<usize>,
+
+    /// reorders the scan's remaining words when --adaptive-wordlist is used
+    adaptor: Option<Arc<WordlistAdaptor>>,
+
+    /// requests/second feedback loop used by --rate-limit-detect
+    backoff: RateLimitBackoff,
 }
 
 /// Requester implementation
 impl Requester {
     /// given a FeroxScanner, create a Requester
-    pub fn from(scanner: &FeroxScanner, ferox_scan: Arc<FeroxScan>) -> Result<Self> {
-        let limit = scanner.handles.config.rate_limit;
-
-        let rate_limiter = if limit > 0 {
-            Some(Self::build_a_bucket(limit)?)
-        } else {
-            None
-        };
-
+    pub fn from(
+        scanner: &FeroxScanner,
+        ferox_scan: Arc<FeroxScan>,
+        adaptor: Option<Arc<WordlistAdaptor>>,
+    ) -> Result<Self> {
         let policy_data = PolicyData::new(
             scanner.handles.config.requester_policy,
             scanner.handles.config.timeout,
@@ -87,10 +123,14 @@ impl Requester {
             ferox_scan,
             policy_data,
             seen_links: RwLock::new(HashSet::<String>::new()),
-            rate_limiter: RwLock::new(rate_limiter),
+            // --rate-limit itself is enforced by the scan's own token bucket; this one only
+            // comes into play once auto-tune or --rate-limit-detect adjust the scan's rate
+            rate_limiter: RwLock::new(None),
             handles: scanner.handles.clone(),
             target_url: scanner.target_url.to_owned(),
             tuning_lock: Mutex::new(0),
+            adaptor,
+            backoff: RateLimitBackoff::default(),
         })
     }
 
@@ -208,7 +248,7 @@ impl Requester {
             } else {
                 // errors can only be incremented, so an else is sufficient
                 *guard += 1;
-                self.policy_data.adjust_up(&*guard);
+                self.policy_data.adjust_up(&guard);
             }
         }
 
@@ -229,23 +269,234 @@ impl Requester {
     async fn set_rate_limiter(&self, new_limit: Option<usize>) -> Result<()> {
         let mut guard = self.rate_limiter.write().await;
 
-        let new_bucket = if new_limit.is_none() {
+        let new_bucket = match new_limit {
             // got None, need to remove the rate_limiter
-            None
-        } else if guard.is_some() && guard.as_ref().unwrap().max() == new_limit.unwrap() {
-            // new_limit is checked for None in first branch, should be fine to unwrap
+            None => None,
+            Some(limit) if guard.as_ref().map(|bucket| bucket.max()) == Some(limit) => {
+                // this function is called more often than i'd prefer due to Send requirements of
+                // mutex/rwlock primitives and awaits, this will minimize the cost of the extra calls
+                return Ok(());
+            }
+            Some(limit) => Some(Self::build_a_bucket(limit)?),
+        };
+
+        *guard = new_bucket;
+        Ok(())
+    }
+
+    /// --rate-limit-detect; halve the scan's rate limit when a 429 is received, otherwise raise it
+    /// back toward the original rate once --rate-limit-recovery has passed without one
+    async fn detect_rate_limit(&self, status: StatusCode) -> Result<()> {
+        let config = &self.handles.config;
+
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            let rate = if config.rate_limit > 0 {
+                config.rate_limit
+            } else {
+                // the scan's rate isn't known until it's been running for at least a second
+                match self.ferox_scan.requests_per_second() as usize {
+                    0 => config.threads,
+                    rate => rate,
+                }
+            };
+
+            if let Some(limit) = self.backoff.back_off(rate) {
+                log::warn!(
+                    "received a 429 from {}, limiting scan to {} requests/second",
+                    self.target_url,
+                    limit
+                );
+                self.set_rate_limiter(Some(limit)).await?;
+            }
+        } else if let Some(limit) = self
+            .backoff
+            .recover(Duration::from_secs(config.rate_limit_recovery))
+        {
+            let new_limit = match limit {
+                // fully recovered; go back to whatever the user asked for
+                0 if config.rate_limit > 0 => Some(config.rate_limit),
+                0 => None,
+                limit => Some(limit),
+            };
+
+            log::info!(
+                "no 429s from {} recently, raising limit to {:?} requests/second",
+                self.target_url,
+                new_limit
+            );
+            self.set_rate_limiter(new_limit).await?;
+        }
+
+        Ok(())
+    }
+
+    /// --collect-certificates; fetch the tls certificate for `url`'s host the first time the host
+    /// is seen, and alert the user if it's close to expiring
+    async fn collect_certificate(&self, url: &Url) -> Result<()> {
+        let (host, port) = match (url.host_str(), url.port_or_known_default()) {
+            (Some(host), Some(port)) => (host.to_string(), port),
+            _ => return Ok(()),
+        };
+
+        let key = match host_key(url) {
+            Some(key) => key,
+            None => return Ok(()),
+        };
 
-            // this function is called more often than i'd prefer due to Send requirements of
-            // mutex/rwlock primitives and awaits, this will minimize the cost of the extra calls
+        if !CERTIFICATES.add_host(&key) {
             return Ok(());
-        } else {
-            Some(Self::build_a_bucket(new_limit.unwrap())?)
+        }
+
+        let fetch_host = host
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .to_string();
+        let cert_info = match tokio::task::spawn_blocking(move || {
+            fetch_certificate(&fetch_host, port)
+        })
+        .await?
+        {
+            Some(cert_info) => cert_info,
+            None => {
+                log::warn!("Could not fetch the tls certificate for {}", key);
+                return Ok(());
+            }
         };
 
-        let _ = std::mem::replace(&mut *guard, new_bucket);
+        if cert_info.is_expiring_soon()
+            && !matches!(self.handles.config.output_level, OutputLevel::Silent)
+        {
+            let when = match cert_info.days_until_expiry {
+                days if days < 0 => format!("expired {} days ago", -days),
+                days => format!("expires in {} days", days),
+            };
+
+            let msg = format!(
+                "🚨 TLS certificate for {} {} ({})",
+                style(&key).yellow(),
+                when,
+                cert_info.expiry
+            );
+            ferox_print(&msg, &PROGRESS_PRINTER);
+        }
+
+        CERTIFICATES.update(&key, |cert| *cert = Some(cert_info));
+
         Ok(())
     }
 
+    /// --collect-comments-only; send a HEAD request for `url` and only follow it up with a GET
+    /// when the HEAD points to a 200 html page, since that's the only body worth pulling comments
+    /// from. returns the response along with the method used to get it
+    async fn head_then_get(&self, url: &Url) -> Result<(Response, &'static str)> {
+        let response = logged_request(url, "HEAD", None, self.handles.clone()).await?;
+
+        if !FeroxResponse::is_html_status_and_headers(&response.status(), response.headers()) {
+            return Ok((response, "HEAD"));
+        }
+
+        let response = logged_request(url, "GET", None, self.handles.clone()).await?;
+
+        Ok((response, "GET"))
+    }
+
+    /// --collect-comments-only; print the html/javascript comments found in the response and
+    /// write them to --output, if used
+    fn report_comments(&self, response: &FeroxResponse) {
+        for comment in response.comments() {
+            let message = format!("{} {}", response.url(), comment);
+
+            if !matches!(self.handles.config.output_level, OutputLevel::Silent) {
+                let msg = format!("{} {}", style("💬").cyan(), message);
+                ferox_print(&msg, &PROGRESS_PRINTER);
+            }
+
+            let ferox_msg = FeroxMessage {
+                kind: "log".to_string(),
+                message: format!("comment: {}", message),
+                level: "MSG".to_string(),
+                time_offset: 0.0,
+                module: "feroxbuster::scanner::requester".to_string(),
+            };
+
+            self.handles
+                .output
+                .tx_file
+                .send(Command::WriteToDisk(Box::new(ferox_msg)))
+                .unwrap_or_default();
+        }
+    }
+
+    /// print a warning for a CORS misconfiguration found by --cors-discovery
+    fn report_cors(&self, url: &Url, vulnerability: CorsVulnerability) {
+        if matches!(self.handles.config.output_level, OutputLevel::Silent) {
+            return;
+        }
+
+        let msg = format!(
+            "{} {} {} {}",
+            style("⚠️ CORS").yellow(),
+            url,
+            vulnerability,
+            style(format!("(Origin: {})", CORS_PROBE_ORIGIN)).dim()
+        );
+
+        ferox_print(&msg, &PROGRESS_PRINTER);
+    }
+
+    /// record the path components of a found url for --collect-subpath-wordlist; with
+    /// --adaptive-wordlist, components this scan hasn't requested yet are added to its wordlist
+    fn collect_subpath_words(&self, response: &FeroxResponse) -> Result<()> {
+        let host = match host_key(response.url()) {
+            Some(host) => host,
+            None => return Ok(()),
+        };
+
+        let words = path_components(response.url());
+
+        SUBPATH_WORDS.extend(&host, words.iter().cloned());
+
+        if let Some(adaptor) = self.adaptor.as_ref() {
+            let num_added = adaptor.add_words(&words);
+
+            if num_added > 0 {
+                let num_requests = num_added * self.handles.expected_num_requests_multiplier();
+
+                let progress_bar = self.ferox_scan.progress_bar();
+                progress_bar.set_length(progress_bar.length() + num_requests as u64);
+
+                self.handles
+                    .stats
+                    .send(AddToUsizeField(TotalExpected, num_requests))
+                    .unwrap_or_else(|e| log::warn!("Could not update overall scan bar: {}", e));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// print a warning when --options-discovery finds DELETE, PUT, or TRACE allowed on `url`
+    fn report_dangerous_methods(&self, url: &Url, allowed_methods: &[String]) {
+        let dangerous: Vec<&str> = allowed_methods
+            .iter()
+            .map(String::as_str)
+            .filter(|method| DANGEROUS_METHODS.contains(method))
+            .collect();
+
+        if dangerous.is_empty() || matches!(self.handles.config.output_level, OutputLevel::Silent) {
+            return;
+        }
+
+        let msg = format!(
+            "{} {} allows {}",
+            style("⚠️ OPTIONS").yellow(),
+            url,
+            dangerous.join(", ")
+        );
+
+        ferox_print(&msg, &PROGRESS_PRINTER);
+    }
+
     /// enforce auto-tune policy
     async fn tune(&self, trigger: PolicyTrigger) -> Result<()> {
         if atomic_load!(self.policy_data.errors) == 0 {
@@ -302,12 +553,80 @@ impl Requester {
         Ok(())
     }
 
+    /// Wrapper for make_upload_request, used by --multipart-wordlist
+    ///
+    /// uploads a file named `filename` to the scan's target url and sends the unfiltered
+    /// Response to the output handler for processing
+    async fn upload(&self, filename: &str) -> Result<()> {
+        log::trace!("enter: upload({})", filename);
+
+        if filename.is_empty() {
+            // the wordlist always starts with an empty word, which makes no sense as a filename
+            log::trace!("exit: upload");
+            return Ok(());
+        }
+
+        // --rate-limit
+        self.ferox_scan.limit().await;
+
+        let should_limit = (self.handles.config.auto_tune
+            || self.handles.config.rate_limit > 0
+            || self.handles.config.rate_limit_detect)
+            && self.rate_limiter.read().await.is_some();
+
+        if should_limit {
+            if let Err(e) = self.limit().await {
+                log::warn!("Could not rate limit scan: {}", e);
+                self.handles.stats.send(AddError(Other)).unwrap_or_default();
+            }
+        }
+
+        let url = Url::parse(&self.target_url)?;
+
+        let request_start = Instant::now();
+        let response = logged_upload(&url, filename, self.handles.clone()).await?;
+        let response_time = request_start.elapsed();
+        self.ferox_scan.add_response_time(response_time);
+
+        let mut ferox_response = FeroxResponse::from(
+            response,
+            &self.target_url,
+            "POST",
+            self.handles.config.output_level,
+            self.handles.config.max_response_size,
+        )
+        .await;
+        ferox_response.set_response_time_ms(response_time.as_millis() as u64);
+        ferox_response.set_upload_filename(Some(filename.to_string()));
+        self.ferox_scan
+            .add_request(ferox_response.text().len() as u64);
+
+        if !self
+            .handles
+            .filters
+            .data
+            .should_filter_response(&ferox_response, self.handles.stats.tx.clone())
+        {
+            if let Err(e) = ferox_response.send_report(self.handles.output.tx.clone()) {
+                log::warn!("Could not send FeroxResponse to output handler: {}", e);
+            }
+        }
+
+        log::trace!("exit: upload");
+        Ok(())
+    }
+
     /// Wrapper for make_request
     ///
     /// Attempts recursion when appropriate and sends Responses to the output handler for processing
     pub async fn request(&self, word: &str) -> Result<()> {
         log::trace!("enter: request({})", word);
 
+        if !self.handles.config.multipart_wordlist.is_empty() {
+            // --multipart-wordlist words are filenames to upload, not paths to request
+            return self.upload(word).await;
+        }
+
         let collected = self.handles.collected_extensions();
 
         let urls = FeroxUrl::from_string(&self.target_url, self.handles.clone())
@@ -316,14 +635,30 @@ impl Requester {
         let should_test_deny = !self.handles.config.url_denylist.is_empty()
             || !self.handles.config.regex_denylist.is_empty();
 
+        let should_check_previous = !self.handles.config.check_previous_state.is_empty();
+
+        // --collect-interesting-paths added the built-in list to the wordlist; responses to its
+        // paths get flagged
+        let discovered_via = if self.handles.config.collect_interesting_paths
+            && interesting_paths().any(|path| path == word)
+        {
+            DiscoveryMethod::BuiltinList
+        } else {
+            DiscoveryMethod::Wordlist
+        };
+
         for url in urls {
             for method in self.handles.config.methods.iter() {
                 // auto_tune is true, or rate_limit was set (mutually exclusive to user)
                 // and a rate_limiter has been created
                 // short-circuiting the lock access behind the first boolean check
+                // --rate-limit
+                self.ferox_scan.limit().await;
+
                 let should_tune =
                     self.handles.config.auto_tune || self.handles.config.rate_limit > 0;
-                let should_limit = should_tune && self.rate_limiter.read().await.is_some();
+                let should_limit = (should_tune || self.handles.config.rate_limit_detect)
+                    && self.rate_limiter.read().await.is_some();
 
                 if should_limit {
                     // found a rate limiter, limit that junk!
@@ -338,14 +673,64 @@ impl Requester {
                     continue;
                 }
 
+                if should_check_previous
+                    && self
+                        .handles
+                        .ferox_scans()?
+                        .skip_previously_found(url.as_str())
+                {
+                    // url was already found during the scan that produced the
+                    // --check-previous-state file
+                    continue;
+                }
+
+                if !self.handles.config.no_request_dedup
+                    && !REQUESTED.should_request(method, url.as_str())
+                {
+                    // an identical request was already sent, i.e. a recursive scan reached the
+                    // same url via a different parent directory
+                    self.handles
+                        .stats
+                        .send(AddToUsizeField(RequestsDeduplicated, 1))
+                        .unwrap_or_default();
+                    continue;
+                }
+
                 let data = if self.handles.config.data.is_empty() {
                     None
                 } else {
                     Some(self.handles.config.data.as_slice())
                 };
 
-                let response =
-                    logged_request(&url, method.as_str(), data, self.handles.clone()).await?;
+                let request_start = Instant::now();
+
+                let (response, method) = if self.handles.config.collect_comments_only {
+                    self.head_then_get(&url).await?
+                } else {
+                    (
+                        logged_request(&url, method.as_str(), data, self.handles.clone()).await?,
+                        method.as_str(),
+                    )
+                };
+
+                let response_time = request_start.elapsed();
+                self.ferox_scan.add_response_time(response_time);
+
+                if self.handles.config.rate_limit_detect {
+                    self.detect_rate_limit(response.status()).await?;
+                }
+
+                if self.handles.config.pause_on_rate_limit
+                    && response.status() == StatusCode::TOO_MANY_REQUESTS
+                {
+                    let seconds =
+                        retry_after_seconds(response.headers()).unwrap_or(DEFAULT_RATE_LIMIT_PAUSE);
+
+                    self.handles
+                        .ferox_scans()?
+                        .pause_for(Duration::from_secs(seconds), url.as_str())
+                        .await;
+                }
 
                 if (should_tune || self.handles.config.auto_bail)
                     && !atomic_load!(self.policy_data.cooling_down, Ordering::SeqCst)
@@ -374,8 +759,13 @@ impl Requester {
                     &self.target_url,
                     method,
                     self.handles.config.output_level,
+                    self.handles.config.max_response_size,
                 )
                 .await;
+                ferox_response.set_response_time_ms(response_time.as_millis() as u64);
+                ferox_response.set_discovered_via(discovered_via);
+                self.ferox_scan
+                    .add_request(ferox_response.text().len() as u64);
 
                 // do recursion if appropriate
                 if !self.handles.config.no_recursion && !self.handles.config.force_recursion {
@@ -388,6 +778,50 @@ impl Requester {
                         .await?;
                 }
 
+                if self.handles.config.redirect_as_discovery
+                    && ferox_response.status().is_redirection()
+                {
+                    // a redirect's destination is known to exist, regardless of whether the
+                    // redirect itself ends up filtered
+                    send_scan_command_and_sync(
+                        self.handles.clone(),
+                        Command::TryRedirectDiscovery(Box::new(ferox_response.clone())),
+                    )
+                    .await?;
+                }
+
+                if self.handles.config.follow_meta_refresh
+                    && ferox_response.meta_refresh_location().is_some()
+                {
+                    // same as above, but for html pages that redirect via <meta http-equiv>;
+                    // known scans and --depth keep chains of refreshes from looping
+                    send_scan_command_and_sync(
+                        self.handles.clone(),
+                        Command::TryRedirectDiscovery(Box::new(ferox_response.clone())),
+                    )
+                    .await?;
+                }
+
+                if self.handles.config.collect_301_targets {
+                    // every redirect is recorded, filtered or not
+                    if let (Some(host), Some(destination)) = (
+                        host_key(ferox_response.url()),
+                        ferox_response.redirect_location(),
+                    ) {
+                        REDIRECT_TARGETS.extend(
+                            &host,
+                            [(ferox_response.url().to_string(), destination.to_string())],
+                        );
+                    }
+                }
+
+                if self.handles.config.collect_certificates
+                    && ferox_response.url().scheme() == "https"
+                {
+                    // certificates don't depend on the response, so filtered responses count too
+                    self.collect_certificate(ferox_response.url()).await?;
+                }
+
                 // purposefully doing recursion before filtering. the thought process is that
                 // even though this particular url is filtered, subsequent urls may not
                 if self
@@ -404,7 +838,9 @@ impl Requester {
                     // are turned on. It comes after should_filter_response, so those cases
                     // are handled. Now we need to account for -s/-C options.
 
-                    if self.handles.config.filter_status.is_empty() {
+                    if self.handles.config.filter_status.is_empty()
+                        && !self.handles.config.include_all_status
+                    {
                         // -C wasn't used, so -s is the only 'filter' left to account for
                         if self
                             .handles
@@ -419,8 +855,8 @@ impl Requester {
                             .await?;
                         }
                     } else {
-                        // -C was used, that means the filters above would have removed
-                        // those responses, and anything else should be let through
+                        // -C or --include-all-status was used, that means the filters above would
+                        // have removed those responses, and anything else should be let through
                         send_try_recursion_command(self.handles.clone(), ferox_response.clone())
                             .await?;
                     }
@@ -442,6 +878,70 @@ impl Requester {
                     }
                 }
 
+                if self.handles.config.collect_page_titles {
+                    ferox_response.set_page_title(ferox_response.extract_page_title());
+                }
+
+                if self.handles.config.collect_meta_tags {
+                    ferox_response.set_meta_tags(ferox_response.extract_meta_tags());
+                }
+
+                let checks_security_headers =
+                    self.handles.config.collect_security_headers && ferox_response.is_html_page();
+
+                let checks_hsts =
+                    self.handles.config.collect_hsts && ferox_response.url().scheme() == "https";
+
+                if self.handles.config.collect_parameters
+                    || self.handles.config.collect_technology_stack
+                    || ferox_response.page_title().is_some()
+                    || !ferox_response.meta_tags().is_empty()
+                    || checks_security_headers
+                    || checks_hsts
+                {
+                    if let Some(host) = host_key(ferox_response.url()) {
+                        let url = ferox_response.url().to_string();
+
+                        if self.handles.config.collect_parameters {
+                            COLLECTED_PARAMETERS.extend(&host, ferox_response.parameter_names());
+                        }
+
+                        if self.handles.config.collect_technology_stack {
+                            let technologies = detect_technologies(&ferox_response);
+
+                            if !technologies.is_empty() {
+                                TECH_STACKS.update(&host, |stack| {
+                                    for technology in &technologies {
+                                        stack.add(technology);
+                                    }
+                                });
+                            }
+                        }
+
+                        if let Some(title) = ferox_response.page_title() {
+                            PAGE_TITLES.insert(&host, url.clone(), title.to_string());
+                        }
+
+                        if !ferox_response.meta_tags().is_empty() {
+                            let tags = ferox_response.meta_tags().clone().into_iter().collect();
+                            META_TAGS.insert(&host, url.clone(), tags);
+                        }
+
+                        if checks_security_headers {
+                            let missing = check_security_headers(ferox_response.headers());
+                            MISSING_SECURITY_HEADERS.insert(&host, url.clone(), missing);
+                        }
+
+                        if checks_hsts {
+                            let status = check_hsts(ferox_response.headers(), &url);
+
+                            if status != HstsStatus::Strong {
+                                HSTS_FINDINGS.insert(&host, url, status);
+                            }
+                        }
+                    }
+                }
+
                 if self.handles.config.extract_links {
                     let mut extractor = ExtractorBuilder::default()
                         .target(ExtractionTarget::ResponseBody)
@@ -474,10 +974,128 @@ impl Requester {
                     }
                 }
 
+                if self.handles.config.collect_source_maps
+                    && ferox_response.status().is_success()
+                    && ferox_response.url().path().ends_with(".js")
+                {
+                    let mut extractor = ExtractorBuilder::default()
+                        .target(ExtractionTarget::SourceMap)
+                        .response(&ferox_response)
+                        .handles(self.handles.clone())
+                        .build()?;
+
+                    match extractor.extract().await {
+                        Ok(links) => extractor.request_links(links).await?,
+                        Err(e) => log::warn!("Could not collect source map: {}", e),
+                    }
+                }
+
+                if self.handles.config.collect_comments_only && method == "GET" {
+                    // only 200 html pages are fetched with a GET in this mode
+                    self.report_comments(&ferox_response);
+                }
+
+                if self.handles.config.collect_forms && ferox_response.status() == &StatusCode::OK {
+                    let extractor = ExtractorBuilder::default()
+                        .target(ExtractionTarget::Forms)
+                        .response(&ferox_response)
+                        .handles(self.handles.clone())
+                        .build()?;
+
+                    let scans = self.handles.ferox_scans()?;
+
+                    for form_url in extractor.extract().await? {
+                        if scans.add_form_url(&form_url) {
+                            send_scan_command_and_sync(
+                                self.handles.clone(),
+                                Command::TryFormDiscovery(form_url),
+                            )
+                            .await?;
+                        }
+                    }
+                }
+
+                let is_found = self.handles.config.include_all_status
+                    // -C was used, so anything left after the filters above is allowed
+                    || !self.handles.config.filter_status.is_empty()
+                    || self
+                        .handles
+                        .config
+                        .status_codes
+                        .contains(&ferox_response.status().as_u16());
+
+                if is_found && self.handles.config.max_urls_per_scan > 0 {
+                    // counted here, rather than by the output handler, so the scanner can stop
+                    // handing out words as soon as the limit is reached
+                    self.ferox_scan.add_found();
+                }
+
+                if is_found && self.handles.config.websocket_discovery {
+                    let supported =
+                        probe_websocket(&self.handles.config.client, ferox_response.url().as_str())
+                            .await;
+                    ferox_response.set_supports_websocket(supported);
+                }
+
+                if is_found && self.handles.config.cors_discovery {
+                    let cors =
+                        probe_cors(&self.handles.config.client, ferox_response.url().as_str())
+                            .await;
+
+                    if let Some(vulnerability) = cors {
+                        self.report_cors(ferox_response.url(), vulnerability);
+                    }
+
+                    ferox_response.set_cors(cors);
+                }
+
+                if is_found
+                    && self.handles.config.options_discovery
+                    && ferox_response.is_directory()
+                {
+                    // redirects to the directory are probed at the directory itself
+                    let mut directory = ferox_response.url().clone();
+
+                    if !directory.path().ends_with('/') {
+                        directory.set_path(&format!("{}/", directory.path()));
+                    }
+
+                    let allowed_methods =
+                        check_options(&self.handles.config.client, directory.as_str()).await;
+
+                    self.report_dangerous_methods(&directory, &allowed_methods);
+                    ferox_response.set_allowed_methods(allowed_methods);
+                }
+
+                if let (Some(adaptor), true) = (self.adaptor.as_ref(), is_found) {
+                    // --adaptive-wordlist; words similar to this one should be requested sooner
+                    adaptor.promote(word);
+                }
+
+                if is_found && self.handles.config.collect_subpath_wordlist {
+                    self.collect_subpath_words(&ferox_response)?;
+                }
+
+                // --collect-api-versions; probed once the response itself has been reported, so
+                // that the version it belongs to is the first one seen
+                let versioned_response = (is_found && self.handles.config.collect_api_versions)
+                    .then(|| ferox_response.clone());
+
                 // everything else should be reported
                 if let Err(e) = ferox_response.send_report(self.handles.output.tx.clone()) {
                     log::warn!("Could not send FeroxResponse to output handler: {}", e);
                 }
+
+                if let Some(versioned_response) = versioned_response {
+                    let mut extractor = ExtractorBuilder::default()
+                        .target(ExtractionTarget::ApiVersions)
+                        .response(&versioned_response)
+                        .handles(self.handles.clone())
+                        .build()?;
+
+                    let links = extractor.extract().await?;
+                    extractor.request_links(links).await?;
+                }
             }
         }
 
@@ -488,8 +1106,6 @@ impl Requester {
 
 #[cfg(test)]
 mod tests {
-    use std::time::Instant;
-
     use reqwest::StatusCode;
 
     use crate::{
@@ -597,6 +1213,7 @@ mod tests {
             1000,
             OutputLevel::Default,
             None,
+            None,
         );
 
         scan.set_status(ScanStatus::Running).unwrap();
@@ -647,6 +1264,8 @@ mod tests {
             ferox_scan: Arc::new(FeroxScan::default()),
             rate_limiter: RwLock::new(None),
             policy_data: Default::default(),
+            adaptor: None,
+            backoff: Default::default(),
         };
 
         let ferox_scan = Arc::new(FeroxScan::default());
@@ -675,6 +1294,8 @@ mod tests {
             target_url: "http://localhost".to_string(),
             rate_limiter: RwLock::new(None),
             policy_data: Default::default(),
+            adaptor: None,
+            backoff: Default::default(),
         };
 
         increment_errors(requester.handles.clone(), ferox_scan.clone(), 25).await;
@@ -700,6 +1321,8 @@ mod tests {
             target_url: "http://localhost".to_string(),
             rate_limiter: RwLock::new(None),
             policy_data: Default::default(),
+            adaptor: None,
+            backoff: Default::default(),
         };
 
         increment_status_codes(
@@ -740,6 +1363,8 @@ mod tests {
             target_url: "http://localhost".to_string(),
             rate_limiter: RwLock::new(None),
             policy_data: Default::default(),
+            adaptor: None,
+            backoff: Default::default(),
         };
 
         increment_status_codes(
@@ -795,6 +1420,8 @@ mod tests {
             target_url: "http://one/one/stuff.php".to_string(),
             rate_limiter: RwLock::new(None),
             policy_data: Default::default(),
+            adaptor: None,
+            backoff: Default::default(),
         };
 
         requester.bail(PolicyTrigger::Errors).await.unwrap();
@@ -829,6 +1456,8 @@ mod tests {
             target_url: "http://one/one/stuff.php".to_string(),
             rate_limiter: RwLock::new(None),
             policy_data: Default::default(),
+            adaptor: None,
+            backoff: Default::default(),
         };
 
         let result = requester.bail(PolicyTrigger::Status403).await;
@@ -851,6 +1480,8 @@ mod tests {
             target_url: "http://localhost".to_string(),
             rate_limiter: RwLock::new(None),
             policy_data: Default::default(),
+            adaptor: None,
+            backoff: Default::default(),
         };
 
         requester
@@ -874,6 +1505,8 @@ mod tests {
             target_url: "http://localhost".to_string(),
             rate_limiter: RwLock::new(None),
             policy_data: PolicyData::new(RequesterPolicy::AutoBail, 7),
+            adaptor: None,
+            backoff: Default::default(),
         });
 
         let start = Instant::now();
@@ -904,6 +1537,8 @@ mod tests {
             target_url: "http://localhost".to_string(),
             rate_limiter: RwLock::new(None),
             policy_data: PolicyData::new(RequesterPolicy::AutoBail, 7),
+            adaptor: None,
+            backoff: Default::default(),
         };
 
         requester.policy_data.set_reqs_sec(400);
@@ -942,14 +1577,17 @@ mod tests {
             target_url: "http://localhost".to_string(),
             rate_limiter: RwLock::new(Some(limiter)),
             policy_data: PolicyData::new(RequesterPolicy::AutoBail, 7),
+            adaptor: None,
+            backoff: Default::default(),
         };
 
         requester.policy_data.set_reqs_sec(400);
         requester.policy_data.set_errors(1);
 
-        let mut guard = requester.tuning_lock.lock().unwrap();
-        *guard = 2;
-        drop(guard);
+        {
+            let mut guard = requester.tuning_lock.lock().unwrap();
+            *guard = 2;
+        }
 
         requester
             .adjust_limit(PolicyTrigger::Errors, false)
@@ -978,6 +1616,8 @@ mod tests {
             target_url: "http://localhost".to_string(),
             rate_limiter: RwLock::new(None),
             policy_data: PolicyData::new(RequesterPolicy::AutoBail, 7),
+            adaptor: None,
+            backoff: Default::default(),
         };
 
         requester.policy_data.set_reqs_sec(400);
@@ -1006,6 +1646,8 @@ mod tests {
             target_url: "http://localhost".to_string(),
             rate_limiter: RwLock::new(None),
             policy_data: PolicyData::new(RequesterPolicy::AutoBail, 7),
+            adaptor: None,
+            backoff: Default::default(),
         };
 
         assert!(!requester.too_many_status_errors(PolicyTrigger::Errors));
@@ -1049,6 +1691,8 @@ mod tests {
             target_url: "http://localhost".to_string(),
             rate_limiter: RwLock::new(Some(limiter)),
             policy_data: PolicyData::new(RequesterPolicy::AutoBail, 7),
+            adaptor: None,
+            backoff: Default::default(),
         };
 
         requester.set_rate_limiter(Some(200)).await.unwrap();
@@ -1080,6 +1724,7 @@ mod tests {
             1000,
             OutputLevel::Default,
             None,
+            None,
         );
         scan.set_status(ScanStatus::Running).unwrap();
         scan.add_429();
@@ -1092,6 +1737,8 @@ mod tests {
             target_url: "http://localhost".to_string(),
             rate_limiter: RwLock::new(Some(limiter)),
             policy_data: PolicyData::new(RequesterPolicy::AutoTune, 4),
+            adaptor: None,
+            backoff: Default::default(),
         };
 
         let start = Instant::now();
@@ -1115,4 +1762,20 @@ mod tests {
         scan.finish().unwrap();
         assert!(start.elapsed().as_millis() >= 2000);
     }
+
+    #[test]
+    /// only a Retry-After given in seconds should be used by --pause-on-rate-limit
+    fn retry_after_seconds_only_reads_seconds() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(retry_after_seconds(&headers), None);
+
+        headers.insert(RETRY_AFTER, " 120 ".parse().unwrap());
+        assert_eq!(retry_after_seconds(&headers), Some(120));
+
+        headers.insert(
+            RETRY_AFTER,
+            "Wed, 21 Oct 2015 07:28:00 GMT".parse().unwrap(),
+        );
+        assert_eq!(retry_after_seconds(&headers), None);
+    }
 }
@@ -15,13 +15,14 @@ use super::*;
 /// try to hit struct field coverage of FileOutHandler
 async fn get_scan_by_url_bails_on_unfound_url() {
     let sem = Semaphore::new(10);
-    let urls = FeroxScans::new(OutputLevel::Default);
+    let urls = FeroxScans::new(OutputLevel::Default, None, false);
 
     let scanner = FeroxScanner::new(
         "http://localhost",
         ScanOrder::Initial,
         Arc::new(Default::default()),
         Arc::new(sem),
+        None,
         Arc::new(Handles::for_testing(Some(Arc::new(urls)), None).0),
     );
     scanner.scan_url().await.unwrap();
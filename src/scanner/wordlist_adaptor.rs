@@ -0,0 +1,205 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    sync::Mutex,
+};
+
+/// number of positions a word moves toward the front of the queue each time its prefix yields a
+/// positive result
+const PROMOTION_DISTANCE: usize = 100;
+
+/// prefixes shorter than this match too many words to say anything useful about them
+const MIN_PREFIX_LEN: usize = 3;
+
+/// leading alphanumeric portion of a word, e.g. `config` for `config.php` or `config_old`
+fn prefix_of(word: &str) -> Option<&str> {
+    let end = word
+        .find(|c: char| !c.is_alphanumeric())
+        .unwrap_or(word.len());
+
+    let prefix = &word[..end];
+
+    (prefix.chars().count() >= MIN_PREFIX_LEN).then_some(prefix)
+}
+
+/// queue of words for a single scan that's reordered as the scan progresses, used by
+/// --adaptive-wordlist
+///
+/// whenever a word yields a positive result, the words still in the queue that share its prefix
+/// are moved toward the front, so that finding `config.php` gets `configuration` requested
+/// sooner
+#[derive(Debug, Default)]
+pub(crate) struct WordlistAdaptor {
+    /// words that haven't been handed out yet
+    queue: Mutex<VecDeque<String>>,
+
+    /// number of positive results seen per prefix
+    prefix_hits: Mutex<HashMap<String, usize>>,
+
+    /// every word that's been queued, handed out or not, so that added words aren't repeated
+    known: Mutex<HashSet<String>>,
+}
+
+/// implementation of WordlistAdaptor
+impl WordlistAdaptor {
+    /// create a new WordlistAdaptor whose queue starts out in the same order as `words`
+    pub(crate) fn new(words: &[String]) -> Self {
+        Self {
+            queue: Mutex::new(words.iter().cloned().collect()),
+            prefix_hits: Mutex::new(HashMap::new()),
+            known: Mutex::new(words.iter().cloned().collect()),
+        }
+    }
+
+    /// remove and return the word at the front of the queue
+    pub(crate) fn next_word(&self) -> Option<String> {
+        self.queue.lock().unwrap().pop_front()
+    }
+
+    /// add each of `words` that has never been queued to the back of the queue; returns the
+    /// number of words added
+    pub(crate) fn add_words(&self, words: &[String]) -> usize {
+        let mut known = self.known.lock().unwrap();
+        let mut queue = self.queue.lock().unwrap();
+        let mut num_added = 0;
+
+        for word in words {
+            if known.insert(word.to_owned()) {
+                queue.push_back(word.to_owned());
+                num_added += 1;
+            }
+        }
+
+        num_added
+    }
+
+    /// record a positive result for `word` and move each queued word that shares its prefix
+    /// `PROMOTION_DISTANCE` positions earlier; returns the number of words moved
+    pub(crate) fn promote(&self, word: &str) -> usize {
+        let prefix = match prefix_of(word) {
+            Some(prefix) => prefix,
+            None => return 0,
+        };
+
+        let hits = {
+            let mut prefix_hits = self.prefix_hits.lock().unwrap();
+            let hits = prefix_hits.entry(prefix.to_string()).or_insert(0);
+            *hits += 1;
+            *hits
+        };
+
+        let mut queue = self.queue.lock().unwrap();
+
+        // promoted words keep their relative order; when two would land on the same spot, the
+        // later one takes the next open position instead
+        let mut promoted = Vec::new();
+        let mut others = Vec::with_capacity(queue.len());
+        let mut next_open = 0;
+
+        for (index, queued) in queue.drain(..).enumerate() {
+            if queued.starts_with(prefix) {
+                let target = index.saturating_sub(PROMOTION_DISTANCE).max(next_open);
+                next_open = target + 1;
+                promoted.push((target, queued));
+            } else {
+                others.push(queued);
+            }
+        }
+
+        let num_promoted = promoted.len();
+        let mut promoted = promoted.into_iter().peekable();
+        let mut others = others.into_iter();
+
+        while let Some(word) = match promoted.peek() {
+            Some((target, _)) if *target <= queue.len() => promoted.next().map(|(_, word)| word),
+            _ => others
+                .next()
+                .or_else(|| promoted.next().map(|(_, word)| word)),
+        } {
+            queue.push_back(word);
+        }
+
+        log::debug!(
+            "promoted {} words starting with {} ({} positive results)",
+            num_promoted,
+            prefix,
+            hits
+        );
+
+        num_promoted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// drain the adaptor's queue into a vec
+    fn remaining(adaptor: &WordlistAdaptor) -> Vec<String> {
+        std::iter::from_fn(|| adaptor.next_word()).collect()
+    }
+
+    #[test]
+    /// only words that were never queued should be added, at the back of the queue
+    fn add_words_skips_known_words() {
+        let words: Vec<String> = vec![String::from("admin"), String::from("login")];
+        let adaptor = WordlistAdaptor::new(&words);
+
+        assert_eq!(adaptor.next_word(), Some(String::from("admin")));
+
+        let found = vec![
+            String::from("admin"),
+            String::from("api"),
+            String::from("login"),
+            String::from("v1"),
+        ];
+
+        assert_eq!(adaptor.add_words(&found), 2);
+        assert_eq!(adaptor.add_words(&found), 0);
+        assert_eq!(remaining(&adaptor), ["login", "api", "v1"]);
+    }
+
+    #[test]
+    /// prefixes are the leading alphanumerics of a word, and must be at least 3 characters
+    fn prefix_of_returns_leading_alphanumerics() {
+        assert_eq!(prefix_of("config.php"), Some("config"));
+        assert_eq!(prefix_of("config_old"), Some("config"));
+        assert_eq!(prefix_of("admin"), Some("admin"));
+        assert_eq!(prefix_of("js"), None);
+        assert_eq!(prefix_of(".htaccess"), None);
+    }
+
+    #[test]
+    /// words sharing a prefix should move 100 positions earlier, keeping their relative order
+    fn promote_moves_matching_words_earlier() {
+        let mut words: Vec<String> = (0..300).map(|i| format!("word{}", i)).collect();
+        words[150] = String::from("configuration");
+        words[250] = String::from("config.bak");
+
+        let adaptor = WordlistAdaptor::new(&words);
+
+        assert_eq!(adaptor.promote("config.php"), 2);
+
+        let reordered = remaining(&adaptor);
+
+        assert_eq!(reordered.len(), 300);
+        assert_eq!(reordered[50], "configuration");
+        assert_eq!(reordered[150], "config.bak");
+        assert_eq!(reordered[0], "word0");
+        assert_eq!(reordered[51], "word50");
+    }
+
+    #[test]
+    /// words close to the front should stop at the front without passing each other
+    fn promote_keeps_order_at_front_of_queue() {
+        let words: Vec<String> = ["stuff", "admin1", "things", "admin2"]
+            .iter()
+            .map(|word| word.to_string())
+            .collect();
+
+        let adaptor = WordlistAdaptor::new(&words);
+
+        assert_eq!(adaptor.promote("admin"), 2);
+        assert_eq!(adaptor.promote("js"), 0);
+        assert_eq!(remaining(&adaptor), ["admin1", "admin2", "stuff", "things"]);
+    }
+}
@@ -0,0 +1,157 @@
+//! --watch-wordlist; pick up words appended to the wordlist while scans are running
+//!
+//! the wordlist's modification time and size are polled rather than using filesystem events
+//! (i.e. the `notify` crate), which keeps the dependency tree as-is and behaves the same on
+//! every platform, including network mounts where change events aren't delivered
+use std::{
+    collections::HashSet,
+    fs,
+    sync::{Arc, RwLock},
+    time::{Duration, SystemTime},
+};
+
+use encoding_rs::Encoding;
+use lazy_static::lazy_static;
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+
+use crate::{utils::decode_wordlist, SLEEP_DURATION};
+
+lazy_static! {
+    /// words added to the wordlist while --watch-wordlist is in use, in the order they were found
+    pub(crate) static ref WATCHED_WORDS: WatchedWords = WatchedWords::default();
+}
+
+/// append-only list of the words found by the --watch-wordlist watcher; each scan keeps track
+/// of how many of them it has already requested
+#[derive(Debug, Default)]
+pub(crate) struct WatchedWords {
+    /// words that weren't in the wordlist when it was first read
+    words: RwLock<Vec<String>>,
+}
+
+/// implementation of WatchedWords
+impl WatchedWords {
+    /// add the given words to the end of the list
+    pub(crate) fn append(&self, words: Vec<String>) {
+        if let Ok(mut guard) = self.words.write() {
+            guard.extend(words);
+        }
+    }
+
+    /// every word added after the first `start` words
+    pub(crate) fn since(&self, start: usize) -> Vec<String> {
+        match self.words.read() {
+            Ok(guard) => guard.iter().skip(start).cloned().collect(),
+            Err(_) => Vec::new(),
+        }
+    }
+}
+
+/// notification sent by the watcher's polling task when the wordlist looks different
+#[derive(Debug)]
+struct WordlistChanged;
+
+/// modification time and size of the file at `path`, used to tell whether it changed
+fn fingerprint(path: &str) -> Option<(SystemTime, u64)> {
+    let metadata = fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+/// check the file at `path` for changes every `SLEEP_DURATION` milliseconds, sending a
+/// `WordlistChanged` over `tx` for each change seen
+async fn poll_for_changes(path: String, tx: UnboundedSender<WordlistChanged>) {
+    let mut interval = tokio::time::interval(Duration::from_millis(SLEEP_DURATION));
+    let mut last_seen = fingerprint(&path);
+
+    loop {
+        interval.tick().await;
+
+        let current = fingerprint(&path);
+
+        if current.is_some() && current != last_seen {
+            last_seen = current;
+
+            if tx.send(WordlistChanged).is_err() {
+                // receiving end is gone, nothing left to notify
+                break;
+            }
+        }
+    }
+}
+
+/// re-read the wordlist at `path` each time a change is received, appending the words that
+/// weren't seen before to `WATCHED_WORDS`
+async fn reload_on_change(
+    path: String,
+    mut known: HashSet<String>,
+    encoding: &'static Encoding,
+    mut rx: UnboundedReceiver<WordlistChanged>,
+) {
+    while rx.recv().await.is_some() {
+        let lines = match fs::File::open(&path).and_then(|file| decode_wordlist(file, encoding)) {
+            Ok(lines) => lines,
+            Err(e) => {
+                log::warn!("Could not reload {}: {}", path, e);
+                continue;
+            }
+        };
+
+        let new_words: Vec<String> = lines
+            .into_iter()
+            .filter(|line| !line.starts_with('#') && !line.is_empty())
+            .filter(|line| known.insert(line.clone()))
+            .collect();
+
+        if new_words.is_empty() {
+            continue;
+        }
+
+        log::info!(
+            "{} changed, found {} new words: {:?}...",
+            path,
+            new_words.len(),
+            &new_words[..new_words.len().min(3)]
+        );
+
+        WATCHED_WORDS.append(new_words);
+    }
+}
+
+/// --watch-wordlist; start the background tasks that watch the wordlist at `path` for changes
+///
+/// `words` is the wordlist as it was first read; only words that aren't already in it are
+/// handed to scans
+pub fn watch_wordlist(path: &str, words: Arc<Vec<String>>, encoding: &'static Encoding) {
+    log::trace!(
+        "enter: watch_wordlist({}, wordlist[{} words...], {})",
+        path,
+        words.len(),
+        encoding.name()
+    );
+
+    let (tx, rx) = mpsc::unbounded_channel();
+    let known = words.iter().cloned().collect();
+
+    tokio::spawn(poll_for_changes(path.to_string(), tx));
+    tokio::spawn(reload_on_change(path.to_string(), known, encoding, rx));
+
+    log::trace!("exit: watch_wordlist");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// words should be handed out starting from the given position
+    fn watched_words_since_skips_words_already_requested() {
+        let watched = WatchedWords::default();
+
+        watched.append(vec!["one".to_string(), "two".to_string()]);
+        watched.append(vec!["three".to_string()]);
+
+        assert_eq!(watched.since(0), vec!["one", "two", "three"]);
+        assert_eq!(watched.since(2), vec!["three"]);
+        assert!(watched.since(3).is_empty());
+    }
+}
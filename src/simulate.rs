@@ -0,0 +1,130 @@
+use std::fmt;
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+use crate::{config::Configuration, utils::fmt_err};
+
+/// number of requests sent for each word: one for the word itself and one per extension, each
+/// of which is sent once per method
+fn requests_per_word(config: &Configuration) -> usize {
+    (1 + config.extensions.len()) * config.methods.len().max(1)
+}
+
+/// --simulate; estimate the number of requests a scan of `num_targets` targets using a wordlist
+/// of `num_words` words would send
+///
+/// directories found while recursing each get the same number of requests as a target, but
+/// there's no knowing how many will be found, so they aren't counted
+pub fn estimate_request_count(
+    config: &Configuration,
+    num_words: usize,
+    num_targets: usize,
+) -> usize {
+    num_words * requests_per_word(config) * num_targets
+}
+
+/// the numbers behind a --simulate estimate; printed as plain text, or as JSON when
+/// --simulate-json is used
+#[derive(Debug, Serialize)]
+pub struct Simulation {
+    /// number of targets that would be scanned
+    pub targets: usize,
+
+    /// number of words requested against each target
+    pub words: usize,
+
+    /// number of requests sent for each word
+    pub requests_per_word: usize,
+
+    /// number of requests sent for each target, and for each directory recursed into
+    pub requests_per_directory: usize,
+
+    /// estimated number of requests sent, not counting recursion
+    pub total_requests: usize,
+
+    /// whether recursion is enabled, meaning `total_requests` is an underestimate
+    pub recursion: bool,
+}
+
+/// implementation of Simulation
+impl Simulation {
+    /// gather the estimate for a scan of `num_targets` targets using a wordlist of `num_words`
+    /// words
+    pub fn new(config: &Configuration, num_words: usize, num_targets: usize) -> Self {
+        Self {
+            targets: num_targets,
+            words: num_words,
+            requests_per_word: requests_per_word(config),
+            requests_per_directory: estimate_request_count(config, num_words, 1),
+            total_requests: estimate_request_count(config, num_words, num_targets),
+            recursion: !config.no_recursion,
+        }
+    }
+}
+
+/// Display implementation for Simulation
+impl fmt::Display for Simulation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Targets:                {}", self.targets)?;
+        writeln!(f, "Words per target:       {}", self.words)?;
+        writeln!(f, "Requests per word:      {}", self.requests_per_word)?;
+        writeln!(f, "Estimated requests:     {}", self.total_requests)?;
+
+        if self.recursion {
+            writeln!(
+                f,
+                "Recursion is enabled; each directory found adds another {} requests, so the \
+                 estimate above is an underestimate",
+                self.requests_per_directory
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// --simulate; print the estimate for a scan of `num_targets` targets using a wordlist of
+/// `num_words` words, as JSON when --simulate-json is used
+pub fn print_simulation(
+    config: &Configuration,
+    num_words: usize,
+    num_targets: usize,
+) -> Result<()> {
+    let simulation = Simulation::new(config, num_words, num_targets);
+
+    if config.simulate_json {
+        let json = serde_json::to_string(&simulation)
+            .with_context(|| fmt_err("Could not convert simulation to JSON"))?;
+        println!("{}", json);
+    } else {
+        print!("{}", simulation);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// each word is requested as-is and once per extension, for every method and target
+    fn estimate_request_count_accounts_for_extensions_methods_and_targets() {
+        let config = Configuration {
+            extensions: vec!["php".to_string(), "js".to_string()],
+            methods: vec!["GET".to_string(), "POST".to_string()],
+            ..Default::default()
+        };
+
+        assert_eq!(estimate_request_count(&config, 10, 1), 60);
+        assert_eq!(estimate_request_count(&config, 10, 3), 180);
+
+        let simulation = Simulation::new(&config, 10, 3);
+        assert_eq!(simulation.requests_per_word, 6);
+        assert_eq!(simulation.requests_per_directory, 60);
+        assert_eq!(simulation.total_requests, 180);
+        assert!(simulation.recursion);
+        assert!(simulation.to_string().contains("underestimate"));
+    }
+}
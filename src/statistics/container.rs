@@ -1,22 +1,21 @@
 use std::{
     collections::HashMap,
     convert::TryFrom,
-    fs::File,
-    io::BufReader,
     sync::{
         atomic::{AtomicUsize, Ordering},
         Mutex,
     },
 };
 
-use anyhow::{Context, Result};
+use anyhow::Result;
 use reqwest::StatusCode;
 use serde::{ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value;
 
 use crate::{
+    scan_manager::load_state,
     traits::FeroxSerialize,
-    utils::{fmt_err, open_file, write_to},
+    utils::{open_file, write_to},
 };
 
 use super::{error::StatError, field::StatField};
@@ -109,9 +108,15 @@ pub struct Stats {
     /// tracker for overall number of all filtered responses
     responses_filtered: AtomicUsize,
 
+    /// tracker for number of responses filtered because their body matched an earlier response
+    duplicates_filtered: AtomicUsize,
+
     /// tracker for number of files found
     resources_discovered: AtomicUsize,
 
+    /// tracker for number of requests skipped because an identical request was already sent
+    requests_deduplicated: AtomicUsize,
+
     /// tracker for number of errors triggered during URL formatting
     url_format_errors: AtomicUsize,
 
@@ -186,10 +191,18 @@ impl Serialize for Stats {
         state.serialize_field("status_508s", &atomic_load!(self.status_508s))?;
         state.serialize_field("wildcards_filtered", &atomic_load!(self.wildcards_filtered))?;
         state.serialize_field("responses_filtered", &atomic_load!(self.responses_filtered))?;
+        state.serialize_field(
+            "duplicates_filtered",
+            &atomic_load!(self.duplicates_filtered),
+        )?;
         state.serialize_field(
             "resources_discovered",
             &atomic_load!(self.resources_discovered),
         )?;
+        state.serialize_field(
+            "requests_deduplicated",
+            &atomic_load!(self.requests_deduplicated),
+        )?;
         state.serialize_field("url_format_errors", &atomic_load!(self.url_format_errors))?;
         state.serialize_field("redirection_errors", &atomic_load!(self.redirection_errors))?;
         state.serialize_field("connection_errors", &atomic_load!(self.connection_errors))?;
@@ -396,6 +409,20 @@ impl<'a> Deserialize<'a> for Stats {
                         }
                     }
                 }
+                "requests_deduplicated" => {
+                    if let Some(num) = value.as_u64() {
+                        if let Ok(parsed) = usize::try_from(num) {
+                            atomic_increment!(stats.requests_deduplicated, parsed);
+                        }
+                    }
+                }
+                "duplicates_filtered" => {
+                    if let Some(num) = value.as_u64() {
+                        if let Ok(parsed) = usize::try_from(num) {
+                            atomic_increment!(stats.duplicates_filtered, parsed);
+                        }
+                    }
+                }
                 "url_format_errors" => {
                     if let Some(num) = value.as_u64() {
                         if let Ok(parsed) = usize::try_from(num) {
@@ -472,11 +499,26 @@ impl Stats {
         atomic_load!(self.expected_per_scan)
     }
 
+    /// public getter for requests
+    pub fn requests(&self) -> usize {
+        atomic_load!(self.requests)
+    }
+
     /// public getter for resources_discovered
     pub fn resources_discovered(&self) -> usize {
         atomic_load!(self.resources_discovered)
     }
 
+    /// public getter for requests_deduplicated
+    pub fn requests_deduplicated(&self) -> usize {
+        atomic_load!(self.requests_deduplicated)
+    }
+
+    /// public getter for duplicates_filtered
+    pub fn duplicates_filtered(&self) -> usize {
+        atomic_load!(self.duplicates_filtered)
+    }
+
     /// public getter for errors
     pub fn errors(&self) -> usize {
         atomic_load!(self.errors)
@@ -653,9 +695,16 @@ impl Stats {
             StatField::ResponsesFiltered => {
                 atomic_increment!(self.responses_filtered, value);
             }
+            StatField::DuplicatesFiltered => {
+                atomic_increment!(self.duplicates_filtered, value);
+                atomic_increment!(self.responses_filtered, value);
+            }
             StatField::ResourcesDiscovered => {
                 atomic_increment!(self.resources_discovered, value);
             }
+            StatField::RequestsDeduplicated => {
+                atomic_increment!(self.requests_deduplicated, value);
+            }
             StatField::InitialTargets => {
                 atomic_increment!(self.initial_targets, value);
             }
@@ -667,10 +716,7 @@ impl Stats {
     ///
     /// This is only ever called when resuming a scan from disk
     pub fn merge_from(&self, filename: &str) -> Result<()> {
-        let file = File::open(filename)
-            .with_context(|| fmt_err(&format!("Could not open {}", filename)))?;
-        let reader = BufReader::new(file);
-        let state: serde_json::Value = serde_json::from_reader(reader)?;
+        let state = load_state(filename)?;
 
         if let Some(state_stats) = state.get("statistics") {
             let d_stats = serde_json::from_value::<Stats>(state_stats.clone())?;
@@ -708,6 +754,14 @@ impl Stats {
                 self.resources_discovered,
                 atomic_load!(d_stats.resources_discovered)
             );
+            atomic_increment!(
+                self.requests_deduplicated,
+                atomic_load!(d_stats.requests_deduplicated)
+            );
+            atomic_increment!(
+                self.duplicates_filtered,
+                atomic_load!(d_stats.duplicates_filtered)
+            );
             atomic_increment!(
                 self.url_format_errors,
                 atomic_load!(d_stats.url_format_errors)
@@ -853,6 +907,21 @@ mod tests {
         assert_eq!(stats.responses_filtered.load(Ordering::Relaxed), 3);
     }
 
+    #[test]
+    /// when Stats::update_usize_field receives StatField::DuplicatesFiltered, it should increment
+    ///     - duplicates_filtered
+    ///     - responses_filtered
+    fn stats_increments_duplicates_filtered() {
+        let config = Configuration::new().unwrap();
+        let stats = Stats::new(config.json);
+
+        stats.update_usize_field(StatField::DuplicatesFiltered, 1);
+        stats.update_usize_field(StatField::DuplicatesFiltered, 1);
+
+        assert_eq!(stats.responses_filtered.load(Ordering::Relaxed), 2);
+        assert_eq!(stats.duplicates_filtered(), 2);
+    }
+
     #[test]
     /// Stats::merge_from should properly increment expected fields and ignore others
     fn stats_merge_from_alters_correct_fields() {
@@ -28,6 +28,12 @@ pub enum StatField {
     /// Translates to `resources_discovered`
     ResourcesDiscovered,
 
+    /// Translates to `requests_deduplicated`
+    RequestsDeduplicated,
+
+    /// Translates to `duplicates_filtered`
+    DuplicatesFiltered,
+
     /// Translates to `initial_targets`
     InitialTargets,
 
@@ -0,0 +1,240 @@
+use std::{convert::Infallible, fs, net::SocketAddr, sync::Arc};
+
+use anyhow::{Context, Result};
+use hyper::{
+    service::{make_service_fn, service_fn},
+    Body, Request, Response, Server, StatusCode,
+};
+use regex::Regex;
+use serde::Deserialize;
+
+use crate::utils::fmt_err;
+
+/// status code returned by --test-server when no rule matches the requested path
+const DEFAULT_STATUS: u16 = 404;
+
+/// a single `path_pattern -> {status, body}` rule from a --test-server-rules file
+#[derive(Debug, Deserialize)]
+struct RawRule {
+    /// regular expression matched against the requested path
+    path_pattern: String,
+
+    /// status code returned for matching paths
+    #[serde(default = "ok_status")]
+    status: u16,
+
+    /// body returned for matching paths
+    #[serde(default)]
+    body: String,
+}
+
+/// on-disk layout of a --test-server-rules file
+///
+/// ```toml
+/// default_status = 404
+///
+/// [[rules]]
+/// path_pattern = "^/admin"
+/// status = 200
+/// body = "welcome, admin"
+/// ```
+#[derive(Debug, Deserialize)]
+struct RawRules {
+    /// status code returned when no rule matches
+    #[serde(default = "default_status")]
+    default_status: u16,
+
+    /// body returned when no rule matches
+    #[serde(default)]
+    default_body: String,
+
+    /// rules to check, in order; the first matching rule wins
+    #[serde(default)]
+    rules: Vec<RawRule>,
+}
+
+/// serde default for a rule's status
+fn ok_status() -> u16 {
+    200
+}
+
+/// serde default for `default_status`
+fn default_status() -> u16 {
+    DEFAULT_STATUS
+}
+
+/// compiled form of a `RawRule`
+#[derive(Debug)]
+struct Rule {
+    /// compiled `path_pattern`
+    pattern: Regex,
+
+    /// status code returned for matching paths
+    status: StatusCode,
+
+    /// body returned for matching paths
+    body: String,
+}
+
+/// rules used by --test-server to decide how to respond to each request
+#[derive(Debug)]
+pub struct TestServerRules {
+    /// status code returned when no rule matches
+    default_status: StatusCode,
+
+    /// body returned when no rule matches
+    default_body: String,
+
+    /// rules to check, in order; the first matching rule wins
+    rules: Vec<Rule>,
+}
+
+/// Default implementation for TestServerRules; every path gets an empty 404
+impl Default for TestServerRules {
+    fn default() -> Self {
+        Self {
+            default_status: StatusCode::NOT_FOUND,
+            default_body: String::new(),
+            rules: Vec::new(),
+        }
+    }
+}
+
+/// implementation of TestServerRules
+impl TestServerRules {
+    /// parse rules from the contents of a --test-server-rules file
+    pub fn from_toml(contents: &str) -> Result<Self> {
+        let raw: RawRules = toml::from_str(contents)?;
+
+        let mut rules = Vec::with_capacity(raw.rules.len());
+
+        for rule in raw.rules {
+            rules.push(Rule {
+                pattern: Regex::new(&rule.path_pattern).with_context(|| {
+                    fmt_err(&format!("Invalid path_pattern {}", rule.path_pattern))
+                })?,
+                status: StatusCode::from_u16(rule.status)?,
+                body: rule.body,
+            });
+        }
+
+        Ok(Self {
+            default_status: StatusCode::from_u16(raw.default_status)?,
+            default_body: raw.default_body,
+            rules,
+        })
+    }
+
+    /// read rules from the given --test-server-rules file
+    pub fn from_file(filename: &str) -> Result<Self> {
+        let contents = fs::read_to_string(filename)
+            .with_context(|| fmt_err(&format!("Could not read {}", filename)))?;
+
+        Self::from_toml(&contents)
+    }
+
+    /// status and body to respond with for the given path
+    fn respond_to(&self, path: &str) -> (StatusCode, &str) {
+        self.rules
+            .iter()
+            .find(|rule| rule.pattern.is_match(path))
+            .map(|rule| (rule.status, rule.body.as_str()))
+            .unwrap_or((self.default_status, self.default_body.as_str()))
+    }
+}
+
+/// build the response to a single request
+async fn handle(rules: Arc<TestServerRules>, request: Request<Body>) -> Result<Response<Body>> {
+    let (status, body) = rules.respond_to(request.uri().path());
+
+    log::debug!("{} {} -> {}", request.method(), request.uri(), status);
+
+    let response = Response::builder()
+        .status(status)
+        .body(Body::from(body.to_string()))?;
+
+    Ok(response)
+}
+
+/// run --test-server on localhost at the given port until the process is killed
+pub async fn serve(port: u16, rules: TestServerRules) -> Result<()> {
+    log::trace!("enter: serve({}, {:?})", port, rules);
+
+    let addr = SocketAddr::from(([127, 0, 0, 1], port));
+    let rules = Arc::new(rules);
+
+    let make_service = make_service_fn(move |_| {
+        let rules = rules.clone();
+
+        async move { Ok::<_, Infallible>(service_fn(move |request| handle(rules.clone(), request))) }
+    });
+
+    let server = Server::try_bind(&addr)
+        .with_context(|| fmt_err(&format!("Could not listen on {}", addr)))?
+        .serve(make_service);
+
+    eprintln!("test server listening on http://{}", addr);
+
+    server.await?;
+
+    log::trace!("exit: serve");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// the first matching rule should win, and unmatched paths get the defaults
+    fn rules_use_first_match_and_fall_back_to_defaults() {
+        let rules = TestServerRules::from_toml(
+            r#"
+            default_status = 403
+            default_body = "nope"
+
+            [[rules]]
+            path_pattern = "^/admin$"
+            status = 200
+            body = "welcome"
+
+            [[rules]]
+            path_pattern = "^/admin"
+            status = 301
+
+            [[rules]]
+            path_pattern = "\\.php$"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(rules.respond_to("/admin"), (StatusCode::OK, "welcome"));
+        assert_eq!(
+            rules.respond_to("/admin/users"),
+            (StatusCode::MOVED_PERMANENTLY, "")
+        );
+        assert_eq!(rules.respond_to("/index.php"), (StatusCode::OK, ""));
+        assert_eq!(rules.respond_to("/stuff"), (StatusCode::FORBIDDEN, "nope"));
+    }
+
+    #[test]
+    /// no rules means every path is an empty 404
+    fn default_rules_return_404() {
+        let rules = TestServerRules::default();
+
+        assert_eq!(rules.respond_to("/anything"), (StatusCode::NOT_FOUND, ""));
+        assert_eq!(
+            TestServerRules::from_toml("").unwrap().respond_to("/"),
+            (StatusCode::NOT_FOUND, "")
+        );
+    }
+
+    #[test]
+    /// bad patterns and status codes should be reported as errors
+    fn rules_reject_invalid_patterns_and_statuses() {
+        assert!(TestServerRules::from_toml("[[rules]]\npath_pattern = \"(\"").is_err());
+        assert!(
+            TestServerRules::from_toml("[[rules]]\npath_pattern = \"/\"\nstatus = 1000").is_err()
+        );
+    }
+}
@@ -1,7 +1,7 @@
 //! collection of all traits used
 use crate::filters::{
-    LinesFilter, RegexFilter, SimilarityFilter, SizeFilter, StatusCodeFilter, WildcardFilter,
-    WordsFilter,
+    ExtensionFilter, LinesFilter, LinesRangeFilter, RegexFilter, SimilarityFilter, SizeFilter,
+    SizeRangeFilter, StatusCodeFilter, WildcardFilter, WordsFilter, WordsRangeFilter,
 };
 use crate::response::FeroxResponse;
 use anyhow::Result;
@@ -33,8 +33,35 @@ impl Display for dyn FeroxFilter {
             write!(f, "Line count: {}", style(filter.line_count).cyan())
         } else if let Some(filter) = self.as_any().downcast_ref::<WordsFilter>() {
             write!(f, "Word count: {}", style(filter.word_count).cyan())
+        } else if let Some(filter) = self.as_any().downcast_ref::<WordsRangeFilter>() {
+            let (min, max) = filter.word_range;
+            write!(
+                f,
+                "Word count range: {}",
+                style(format!("{}-{}", min, max)).cyan()
+            )
+        } else if let Some(filter) = self.as_any().downcast_ref::<LinesRangeFilter>() {
+            let (min, max) = filter.line_range;
+            write!(
+                f,
+                "Line count range: {}",
+                style(format!("{}-{}", min, max)).cyan()
+            )
         } else if let Some(filter) = self.as_any().downcast_ref::<SizeFilter>() {
             write!(f, "Response size: {}", style(filter.content_length).cyan())
+        } else if let Some(filter) = self.as_any().downcast_ref::<SizeRangeFilter>() {
+            let ranges = filter
+                .ranges
+                .iter()
+                .map(|range| range.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            if filter.keep {
+                write!(f, "Response size outside of: {}", style(ranges).cyan())
+            } else {
+                write!(f, "Response size range: {}", style(ranges).cyan())
+            }
         } else if let Some(filter) = self.as_any().downcast_ref::<RegexFilter>() {
             write!(f, "Regex: {}", style(&filter.raw_string).cyan())
         } else if let Some(filter) = self.as_any().downcast_ref::<WildcardFilter>() {
@@ -45,6 +72,8 @@ impl Display for dyn FeroxFilter {
             }
         } else if let Some(filter) = self.as_any().downcast_ref::<StatusCodeFilter>() {
             write!(f, "Status code: {}", style(filter.filter_code).cyan())
+        } else if let Some(filter) = self.as_any().downcast_ref::<ExtensionFilter>() {
+            write!(f, "Extension: {}", style(&filter.extension).cyan())
         } else if let Some(filter) = self.as_any().downcast_ref::<SimilarityFilter>() {
             write!(
                 f,
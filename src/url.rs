@@ -45,6 +45,20 @@ impl FeroxUrl {
     ) -> Result<Vec<Url>> {
         log::trace!("enter: formatted_urls({})", word);
 
+        if !self.handles.config.prefix_wordlist.is_empty() {
+            // --prefix-wordlist words are inserted in front of the path; extensions don't apply
+            let urls = match self.format_prefix(word) {
+                Ok(url) => vec![url],
+                Err(_) => {
+                    self.handles.stats.send(AddError(UrlFormat))?;
+                    vec![]
+                }
+            };
+
+            log::trace!("exit: formatted_urls -> {:?}", urls);
+            return Ok(urls);
+        }
+
         let mut urls = vec![];
 
         let slash = if self.handles.config.add_slash {
@@ -157,6 +171,42 @@ impl FeroxUrl {
         }
     }
 
+    /// Simple helper to generate a `Url` with `word` inserted in front of the target's path, used
+    /// by --prefix-wordlist
+    ///
+    /// example: http://localhost/api/v1/ + internal -> http://localhost/internal/api/v1/
+    pub fn format_prefix(&self, word: &str) -> Result<Url> {
+        log::trace!("enter: format_prefix({})", word);
+
+        if Url::parse(word).is_ok() {
+            // same reasoning as in format; a full url isn't a path segment
+            let message = format!("word ({}) from wordlist is a URL, skipping...", word);
+            log::warn!("{}", message);
+            log::trace!("exit: format_prefix -> Err({})", message);
+            bail!(message);
+        }
+
+        let mut url = Url::parse(&self.target)?;
+
+        // leading/trailing slashes on the word would result in empty path segments
+        let word = word.trim_matches('/');
+
+        if !word.is_empty() {
+            // the empty word requests the target itself, same as format
+            let path = format!("/{}{}", word, url.path());
+            url.set_path(&path);
+        }
+
+        if self.handles.config.queries.is_empty() {
+            log::trace!("exit: format_prefix -> {}", url);
+            Ok(url)
+        } else {
+            let with_params = Url::parse_with_params(url.as_str(), &self.handles.config.queries)?;
+            log::trace!("exit: format_prefix -> {}", with_params);
+            Ok(with_params)
+        }
+    }
+
     /// Gets the length of a url's path
     pub fn path_length(&self) -> Result<u64> {
         let parsed = Url::parse(&self.target)?;
@@ -171,14 +221,14 @@ impl FeroxUrl {
 
         let path = url.path();
 
-        let segments = if let Some(split) = path.strip_prefix('/') {
+        let mut segments = if let Some(split) = path.strip_prefix('/') {
             split.split_terminator('/')
         } else {
             log::trace!("exit: get_path_length -> 0");
             return 0;
         };
 
-        if let Some(last) = segments.last() {
+        if let Some(last) = segments.next_back() {
             // failure on conversion should be very unlikely. While a usize can absolutely overflow a
             // u64, the generally accepted maximum for the length of a url is ~2000.  so the value we're
             // putting into the u64 should never realistically be anywhere close to producing an
@@ -310,7 +360,7 @@ mod tests {
         let pdf = Url::parse("http://localhost/turbo.pdf").unwrap();
         let tar = Url::parse("http://localhost/turbo.tar.gz").unwrap();
 
-        let expected = vec![
+        let expected = [
             vec![base.clone(), js.clone()],
             vec![base.clone(), js.clone(), php.clone()],
             vec![base.clone(), js.clone(), php.clone(), pdf.clone()],
@@ -501,6 +551,52 @@ mod tests {
         assert!(formatted.is_err());
     }
 
+    #[test]
+    /// word should be inserted in front of the target's path, with slashes around the word
+    /// ignored and the empty word requesting the target itself
+    fn format_prefix_inserts_word_before_path() {
+        let handles = Arc::new(Handles::for_testing(None, None).0);
+        let url = FeroxUrl::from_string("https://localhost/api/v1/", handles.clone());
+
+        for word in ["internal", "/internal/"] {
+            assert_eq!(
+                url.format_prefix(word).unwrap(),
+                Url::parse("https://localhost/internal/api/v1/").unwrap()
+            );
+        }
+
+        assert_eq!(
+            url.format_prefix("").unwrap(),
+            Url::parse("https://localhost/api/v1/").unwrap()
+        );
+        assert!(url.format_prefix("http://schmocalhost").is_err());
+
+        let url = FeroxUrl::from_string("https://localhost", handles);
+        assert_eq!(
+            url.format_prefix("internal").unwrap(),
+            Url::parse("https://localhost/internal/").unwrap()
+        );
+    }
+
+    #[test]
+    /// --prefix-wordlist should produce a single prefixed url, ignoring --add-slash, with any
+    /// --query params still attached
+    fn formatted_urls_with_prefix_wordlist() {
+        let config = Configuration {
+            prefix_wordlist: "/tmp/prefixes.txt".to_string(),
+            add_slash: true,
+            queries: vec![(String::from("stuff"), String::from("things"))],
+            ..Default::default()
+        };
+        let handles = Arc::new(Handles::for_testing(None, Some(Arc::new(config))).0);
+        let url = FeroxUrl::from_string("http://localhost/api/v1", handles);
+
+        assert_eq!(
+            url.formatted_urls("internal", HashSet::new()).unwrap(),
+            [Url::parse("http://localhost/internal/api/v1?stuff=things").unwrap()]
+        );
+    }
+
     #[test]
     /// sending url + word with both an extension and add-slash should get back
     /// two urls, one with '/' appended to the word, and the other with the extension
@@ -1,18 +1,24 @@
 use anyhow::{bail, Context, Result};
+use base64::{engine::general_purpose, Engine};
 use console::{strip_ansi_codes, style, user_attended};
+use encoding_rs::Encoding;
 use indicatif::ProgressBar;
 use regex::Regex;
-use reqwest::{Client, Method, Response, StatusCode, Url};
+use reqwest::{
+    header::{ALLOW, CONNECTION, CONTENT_TYPE, SEC_WEBSOCKET_KEY, SEC_WEBSOCKET_VERSION, UPGRADE},
+    Client, Method, RequestBuilder, Response, StatusCode, Url,
+};
 #[cfg(not(target_os = "windows"))]
 use rlimit::{getrlimit, setrlimit, Resource};
 use std::{
     fs,
-    io::{self, BufWriter, Write},
+    io::{self, BufWriter, Read, Write},
     sync::Arc,
     time::Duration,
     time::{SystemTime, UNIX_EPOCH},
 };
 use tokio::sync::{mpsc::UnboundedSender, oneshot};
+use uuid::Uuid;
 
 use crate::{
     config::Configuration,
@@ -21,12 +27,13 @@ use crate::{
         Command::{self, AddError, AddStatus},
         Handles,
     },
-    progress::PROGRESS_PRINTER,
+    progress::{live_output, PROGRESS_PRINTER},
     response::FeroxResponse,
+    scanner::log_connection_error,
     send_command,
     statistics::StatError::{Connection, Other, Redirection, Request, Timeout},
     traits::FeroxSerialize,
-    USER_AGENTS,
+    INTERESTING_PATHS, REQUEST_ID_HEADER, USER_AGENTS,
 };
 
 /// simple counter for grabbing 'random' user agents
@@ -82,6 +89,21 @@ pub(crate) async fn send_try_recursion_command(
     Ok(())
 }
 
+/// send the given command to the scan handler and wait for it to be processed
+///
+/// used for commands that may start a new scan, so that the scan is known to `FeroxScans` before
+/// the sender moves on
+pub(crate) async fn send_scan_command_and_sync(
+    handles: Arc<Handles>,
+    command: Command,
+) -> Result<()> {
+    handles.send_scan_command(command)?;
+    let (tx, rx) = oneshot::channel::<bool>();
+    handles.send_scan_command(Command::Sync(tx))?;
+    rx.await?;
+    Ok(())
+}
+
 /// Takes in a string and colors it using console::style
 ///
 /// mainly putting this here in case i want to change the color later, making any changes easy
@@ -98,9 +120,16 @@ pub fn module_colorizer(modname: &str) -> String {
 /// If a terminal is not attached, `msg` is printed to stdout, with its ansi
 /// color codes stripped.
 ///
+/// With --live-output, `msg` is printed to stdout as-is. The bars' draw target is hidden before
+/// anything is printed and stays hidden for the rest of the run (even after the interactive
+/// menu closes), so there's nothing on screen for `println!` to overwrite.
+///
 /// additionally, provides a location for future printing options (no color, etc) to be handled
 pub fn ferox_print(msg: &str, bar: &ProgressBar) {
-    if user_attended() {
+    if user_attended() && live_output() {
+        // --live-output; progress bars aren't drawn, so print immediately
+        println!("{}", msg);
+    } else if user_attended() {
         bar.println(msg);
     } else {
         let stripped = strip_ansi_codes(msg);
@@ -122,6 +151,24 @@ pub async fn logged_request(
 
     let response = make_request(client, url, method, data, level, &handles.config, tx_stats).await;
 
+    log_response(url, response, handles)
+}
+
+/// wrapper for make_upload_request, passing error/response codes to FeroxScans just like
+/// logged_request
+pub async fn logged_upload(url: &Url, filename: &str, handles: Arc<Handles>) -> Result<Response> {
+    let client = &handles.config.client;
+    let level = handles.config.output_level;
+    let tx_stats = handles.stats.tx.clone();
+
+    let response =
+        make_upload_request(client, url, filename, level, &handles.config, tx_stats).await;
+
+    log_response(url, response, handles)
+}
+
+/// update the per-scan error/status code counters for the given request's result
+fn log_response(url: &Url, response: Result<Response>, handles: Arc<Handles>) -> Result<Response> {
     let scans = handles.ferox_scans()?;
     match response {
         Ok(resp) => {
@@ -141,12 +188,123 @@ pub async fn logged_request(
     }
 }
 
+/// send a websocket upgrade request to `url`, returning whether the server agreed to switch
+/// protocols (101); any error is treated as no websocket support
+pub async fn probe_websocket(client: &Client, url: &str) -> bool {
+    log::trace!("enter: probe_websocket({})", url);
+
+    let key = general_purpose::STANDARD.encode(Uuid::new_v4().as_bytes());
+
+    let supported = match client
+        .get(url)
+        .header(CONNECTION, "Upgrade")
+        .header(UPGRADE, "websocket")
+        .header(SEC_WEBSOCKET_VERSION, "13")
+        .header(SEC_WEBSOCKET_KEY, key)
+        .send()
+        .await
+    {
+        Ok(response) => response.status() == StatusCode::SWITCHING_PROTOCOLS,
+        Err(e) => {
+            log::debug!("websocket probe of {} failed: {}", url, e);
+            false
+        }
+    };
+
+    log::trace!("exit: probe_websocket -> {}", supported);
+    supported
+}
+
+/// strip the query string and fragment from `url`, leaving everything up to the end of its path
+pub fn normalize_url(url: &str) -> String {
+    match Url::parse(url) {
+        Ok(mut parsed) => {
+            parsed.set_query(None);
+            parsed.set_fragment(None);
+            parsed.to_string()
+        }
+        Err(_) => url.split(['?', '#']).next().unwrap_or_default().to_string(),
+    }
+}
+
+/// lowercase the path of `url` when `enabled` is true (--case-insensitive-dedup), so that urls
+/// differing only in the case of their path compare as equal; the url is returned as-is otherwise
+pub fn normalize_case(url: &str, enabled: bool) -> String {
+    if !enabled {
+        return url.to_string();
+    }
+
+    match Url::parse(url) {
+        Ok(mut parsed) => {
+            let path = parsed.path().to_lowercase();
+            parsed.set_path(&path);
+            parsed.to_string()
+        }
+        Err(_) => url.to_lowercase(),
+    }
+}
+
+/// non-empty components of `url`'s path, in order, without duplicates
+///
+/// example: http://localhost/api/v1/users -> ["api", "v1", "users"]
+pub fn path_components(url: &Url) -> Vec<String> {
+    let mut components: Vec<String> = Vec::new();
+
+    for component in url.path().split('/') {
+        if !component.is_empty() && !components.iter().any(|known| known == component) {
+            components.push(component.to_string());
+        }
+    }
+
+    components
+}
+
+/// methods that --options-discovery warns about when a server allows them
+pub const DANGEROUS_METHODS: [&str; 3] = ["DELETE", "PUT", "TRACE"];
+
+/// split an `Allow` header's value into uppercase method names, in the order given
+pub fn parse_allow_header(allow: &str) -> Vec<String> {
+    let mut methods: Vec<String> = Vec::new();
+
+    for method in allow.split(',') {
+        let method = method.trim().to_uppercase();
+
+        if !method.is_empty() && !methods.contains(&method) {
+            methods.push(method);
+        }
+    }
+
+    methods
+}
+
+/// send an OPTIONS request to `url` and return the methods listed in the response's `Allow`
+/// header; any error, or a missing header, results in no methods
+pub async fn check_options(client: &Client, url: &str) -> Vec<String> {
+    log::trace!("enter: check_options({})", url);
+
+    let methods = match client.request(Method::OPTIONS, url).send().await {
+        Ok(response) => response
+            .headers()
+            .get(ALLOW)
+            .and_then(|allow| allow.to_str().ok())
+            .map(parse_allow_header)
+            .unwrap_or_default(),
+        Err(e) => {
+            log::debug!("OPTIONS request to {} failed: {}", url, e);
+            Vec::new()
+        }
+    };
+
+    log::trace!("exit: check_options -> {:?}", methods);
+    methods
+}
+
 /// Initiate request to the given `Url` using `Client`
 pub async fn make_request(
     client: &Client,
     url: &Url,
     method: &str,
-    mut data: Option<&[u8]>,
+    data: Option<&[u8]>,
     output_level: OutputLevel,
     config: &Configuration,
     tx_stats: UnboundedSender<Command>,
@@ -157,6 +315,44 @@ pub async fn make_request(
         output_level,
         tx_stats
     );
+
+    let request = build_request(client, url, method, data, config)?;
+
+    send_request(request, url, method, output_level, tx_stats).await
+}
+
+/// Replay a request through the --replay-proxy `Client`, tagging it with the `request_id` of the
+/// `FeroxResponse` it reproduces so the proxy's logs can be matched up with feroxbuster's output
+pub async fn make_replay_request(
+    client: &Client,
+    url: &Url,
+    method: &str,
+    data: Option<&[u8]>,
+    request_id: &str,
+    config: &Configuration,
+    tx_stats: UnboundedSender<Command>,
+) -> Result<Response> {
+    log::trace!(
+        "enter: make_replay_request(Configuration::Client, {}, {}, {:?})",
+        url,
+        request_id,
+        tx_stats
+    );
+
+    let request =
+        build_request(client, url, method, data, config)?.header(REQUEST_ID_HEADER, request_id);
+
+    send_request(request, url, method, config.output_level, tx_stats).await
+}
+
+/// create the `RequestBuilder` shared by `make_request` and `make_replay_request`
+fn build_request(
+    client: &Client,
+    url: &Url,
+    method: &str,
+    mut data: Option<&[u8]>,
+    config: &Configuration,
+) -> Result<RequestBuilder> {
     let tmp_workaround: Option<&[u8]> = Some(&[0xd_u8, 0xa]); // \r\n
 
     let mut request = client.request(Method::from_bytes(method.as_bytes())?, url.to_owned());
@@ -196,12 +392,131 @@ pub async fn make_request(
         request = request.header("User-Agent", user_agent);
     }
 
+    Ok(request)
+}
+
+/// paths in the built-in --collect-interesting-paths list, skipping blank lines and comments
+pub fn interesting_paths() -> impl Iterator<Item = &'static str> {
+    INTERESTING_PATHS
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+}
+
+/// placeholder file contents sent with each --multipart-wordlist upload
+const UPLOAD_CONTENTS: &[u8] = b"feroxbuster upload test\n";
+
+/// guess the content type of an uploaded file based on its extension
+pub fn content_type_for(filename: &str) -> &'static str {
+    let extension = match filename.rsplit_once('.') {
+        Some((_, ext)) => ext.to_ascii_lowercase(),
+        None => return "application/octet-stream",
+    };
+
+    match extension.as_str() {
+        "txt" => "text/plain",
+        "htm" | "html" => "text/html",
+        "css" => "text/css",
+        "csv" => "text/csv",
+        "js" => "application/javascript",
+        "json" => "application/json",
+        "xml" => "application/xml",
+        "pdf" => "application/pdf",
+        "zip" => "application/zip",
+        "gz" => "application/gzip",
+        "php" | "phtml" => "application/x-httpd-php",
+        "jpg" | "jpeg" => "image/jpeg",
+        "png" => "image/png",
+        "gif" => "image/gif",
+        "svg" => "image/svg+xml",
+        _ => "application/octet-stream",
+    }
+}
+
+/// build a multipart/form-data body holding a single file part named `field`, returning the
+/// boundary used alongside the body
+pub fn build_multipart_body(field: &str, filename: &str, contents: &[u8]) -> (String, Vec<u8>) {
+    let boundary = format!("ferox-{}", Uuid::new_v4().simple());
+
+    // quotes and newlines would break out of the content-disposition header, so they're
+    // percent-encoded the same way browsers do
+    let escape = |value: &str| {
+        value
+            .replace('"', "%22")
+            .replace('\r', "%0D")
+            .replace('\n', "%0A")
+    };
+
+    let mut body = format!(
+        "--{}\r\nContent-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\nContent-Type: {}\r\n\r\n",
+        boundary,
+        escape(field),
+        escape(filename),
+        content_type_for(filename)
+    )
+    .into_bytes();
+
+    body.extend_from_slice(contents);
+    body.extend_from_slice(format!("\r\n--{}--\r\n", boundary).as_bytes());
+
+    (boundary, body)
+}
+
+/// POST a multipart/form-data upload of a file named `filename` to the given `Url`, using
+/// --multipart-field as the form field's name
+pub async fn make_upload_request(
+    client: &Client,
+    url: &Url,
+    filename: &str,
+    output_level: OutputLevel,
+    config: &Configuration,
+    tx_stats: UnboundedSender<Command>,
+) -> Result<Response> {
+    log::trace!(
+        "enter: make_upload_request(Configuration::Client, {}, {}, {:?}, {:?})",
+        url,
+        filename,
+        output_level,
+        tx_stats
+    );
+
+    let (boundary, body) = build_multipart_body(&config.multipart_field, filename, UPLOAD_CONTENTS);
+
+    let mut request = client
+        .post(url.to_owned())
+        .header(
+            CONTENT_TYPE,
+            format!("multipart/form-data; boundary={}", boundary),
+        )
+        .body(body);
+
+    if config.random_agent {
+        let index = unsafe {
+            USER_AGENT_CTR += 1;
+            USER_AGENT_CTR % USER_AGENTS.len()
+        };
+
+        request = request.header("User-Agent", USER_AGENTS[index]);
+    }
+
+    send_request(request, url, "POST", output_level, tx_stats).await
+}
+
+/// send the given request, recording its status code or error type in the statistics
+async fn send_request(
+    request: RequestBuilder,
+    url: &Url,
+    method: &str,
+    output_level: OutputLevel,
+    tx_stats: UnboundedSender<Command>,
+) -> Result<Response> {
     match request.send().await {
         Err(e) => {
             log::trace!("exit: make_request -> {}", e);
 
             if e.is_timeout() {
                 send_command!(tx_stats, AddError(Timeout));
+                log_connection_error(url, "timeout");
             } else if e.is_redirect() {
                 if let Some(last_redirect) = e.url() {
                     // get where we were headed (last_redirect) and where we came from (url)
@@ -233,6 +548,7 @@ pub async fn make_request(
                 };
             } else if e.is_connect() {
                 send_command!(tx_stats, AddError(Connection));
+                log_connection_error(url, "connection");
             } else if e.is_request() {
                 send_command!(tx_stats, AddError(Request));
             } else {
@@ -526,7 +842,7 @@ pub fn slugify_filename(url: &str, prefix: &str, suffix: &str) -> String {
         String::new()
     };
 
-    let slug = url.replace("://", "_").replace('/', "_").replace('.', "_");
+    let slug = url.replace("://", "_").replace(['/', '.'], "_");
 
     let filename = format!("{}{}-{}.{}", altered_prefix, slug, ts, suffix);
 
@@ -534,6 +850,89 @@ pub fn slugify_filename(url: &str, prefix: &str, suffix: &str) -> String {
     filename
 }
 
+/// number of bytes read from a wordlist at a time by `decode_wordlist`
+const WORDLIST_CHUNK_SIZE: usize = 64 * 1024;
+
+/// decode a wordlist using the given encoding and split it into lines; the wordlist is read in
+/// chunks, so only the decoded lines are held in memory, not the raw bytes as well
+///
+/// a byte order mark, when present, takes precedence over `encoding`. anything that isn't valid
+/// in the encoding is replaced with U+FFFD, and a single warning is logged afterwards with the
+/// number of lines affected, so that mangled entries don't go unnoticed
+pub fn decode_wordlist<R: Read>(
+    mut reader: R,
+    encoding: &'static Encoding,
+) -> io::Result<Vec<String>> {
+    log::trace!("enter: decode_wordlist({})", encoding.name());
+
+    // new_decoder sniffs for a bom before falling back to `encoding`
+    let mut decoder = encoding.new_decoder();
+    let mut chunk = vec![0; WORDLIST_CHUNK_SIZE];
+
+    // decoded text that hasn't been split into lines yet, i.e. a line that spans two chunks
+    let mut pending = String::new();
+    let mut lines = Vec::new();
+    let mut had_errors = false;
+    let mut replaced_lines = 0;
+    let mut first_replaced = None;
+
+    loop {
+        let num_read = match reader.read(&mut chunk) {
+            Ok(num_read) => num_read,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+
+        let last = num_read == 0;
+
+        let needed = decoder
+            .max_utf8_buffer_length(num_read)
+            .unwrap_or(num_read * 3 + 16);
+        pending.reserve(needed);
+
+        let (_, _, chunk_had_errors) =
+            decoder.decode_to_string(&chunk[..num_read], &mut pending, last);
+        had_errors |= chunk_had_errors;
+
+        // only complete lines are split off; the rest waits for the next chunk
+        let complete = if last {
+            pending.len()
+        } else {
+            match pending.rfind('\n') {
+                Some(index) => index + 1,
+                None => continue,
+            }
+        };
+
+        for line in pending[..complete].lines() {
+            if line.contains('\u{FFFD}') {
+                replaced_lines += 1;
+                first_replaced.get_or_insert(lines.len() + 1);
+            }
+
+            lines.push(line.to_string());
+        }
+
+        pending.drain(..complete);
+
+        if last {
+            break;
+        }
+    }
+
+    if had_errors {
+        log::warn!(
+            "Replaced invalid {} in {} wordlist line(s), starting with line {}",
+            decoder.encoding().name(),
+            replaced_lines,
+            first_replaced.unwrap_or_default()
+        );
+    }
+
+    log::trace!("exit: decode_wordlist -> [{} lines]", lines.len());
+    Ok(lines)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -833,4 +1232,184 @@ mod tests {
         assert!(!should_deny_url(&tested_https_url, handles.clone()).unwrap());
         assert!(should_deny_url(&tested_http_url, handles).unwrap());
     }
+
+    #[test]
+    /// wordlists should be decoded using the given encoding, or a bom if one is present
+    fn decode_wordlist_uses_given_encoding() {
+        let latin1 = b"caf\xe9\r\nna\xefve\n";
+
+        assert_eq!(
+            decode_wordlist(&latin1[..], encoding_rs::WINDOWS_1252).unwrap(),
+            ["café", "naïve"]
+        );
+
+        let utf8_with_bom = b"\xef\xbb\xbfcaf\xc3\xa9\nadmin";
+
+        assert_eq!(
+            decode_wordlist(&utf8_with_bom[..], encoding_rs::WINDOWS_1252).unwrap(),
+            ["café", "admin"]
+        );
+    }
+
+    #[test]
+    /// invalid bytes should be replaced instead of dropping the whole line
+    fn decode_wordlist_replaces_invalid_utf8() {
+        let latin1 = b"caf\xe9\nadmin\n";
+
+        assert_eq!(
+            decode_wordlist(&latin1[..], encoding_rs::UTF_8).unwrap(),
+            ["caf\u{FFFD}", "admin"]
+        );
+    }
+
+    /// reader that hands out a single byte per read, to split lines and characters across reads
+    struct OneByteReader<'a>(&'a [u8]);
+
+    impl Read for OneByteReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if self.0.is_empty() || buf.is_empty() {
+                return Ok(0);
+            }
+
+            buf[0] = self.0[0];
+            self.0 = &self.0[1..];
+            Ok(1)
+        }
+    }
+
+    #[test]
+    /// lines and multi-byte characters that span reads should be decoded as if read all at once
+    fn decode_wordlist_handles_lines_split_across_reads() {
+        let utf16 = b"\xff\xfec\x00a\x00f\x00\xe9\x00\r\x00\n\x00\n\x00=\xd8\x00\xde";
+
+        assert_eq!(
+            decode_wordlist(OneByteReader(utf16), encoding_rs::UTF_8).unwrap(),
+            ["café", "", "\u{1F600}"]
+        );
+
+        let utf8 = "café\r\nadmin\nlogin".as_bytes();
+
+        assert_eq!(
+            decode_wordlist(OneByteReader(utf8), encoding_rs::UTF_8).unwrap(),
+            ["café", "admin", "login"]
+        );
+    }
+
+    #[test]
+    /// Allow headers should be split into uppercase, de-duplicated methods
+    fn parse_allow_header_normalizes_methods() {
+        assert_eq!(
+            parse_allow_header("GET, head,OPTIONS , put,,GET"),
+            vec!["GET", "HEAD", "OPTIONS", "PUT"]
+        );
+        assert!(parse_allow_header("").is_empty());
+    }
+
+    #[test]
+    /// paths should be split on slashes, skipping empty and repeated components
+    fn path_components_splits_path() {
+        let url = Url::parse("http://localhost/api/v1//users/?id=1").unwrap();
+        assert_eq!(path_components(&url), vec!["api", "v1", "users"]);
+
+        let url = Url::parse("http://localhost/js/app/js/").unwrap();
+        assert_eq!(path_components(&url), vec!["js", "app"]);
+
+        let url = Url::parse("http://localhost/").unwrap();
+        assert!(path_components(&url).is_empty());
+    }
+
+    #[test]
+    /// query strings and fragments should be stripped, whether or not the url parses
+    fn normalize_url_strips_query_and_fragment() {
+        assert_eq!(
+            normalize_url("http://localhost/item?id=1&page=2#top"),
+            "http://localhost/item"
+        );
+        assert_eq!(normalize_url("http://localhost/"), "http://localhost/");
+        assert_eq!(normalize_url("/relative/path?q=1"), "/relative/path");
+    }
+
+    #[test]
+    /// only the path should be lowercased, and only when enabled
+    fn normalize_case_lowercases_path_when_enabled() {
+        assert_eq!(
+            normalize_case("http://localhost/Admin/Login.ASPX?Next=Home", true),
+            "http://localhost/admin/login.aspx?Next=Home"
+        );
+        assert_eq!(
+            normalize_case("http://localhost/Admin", false),
+            "http://localhost/Admin"
+        );
+        assert_eq!(normalize_case("/Relative/Path", true), "/relative/path");
+    }
+
+    #[test]
+    /// content types are chosen by extension, case insensitively, with a binary fallback
+    fn content_type_for_uses_extension() {
+        assert_eq!(content_type_for("shell.php"), "application/x-httpd-php");
+        assert_eq!(content_type_for("image.JPG"), "image/jpeg");
+        assert_eq!(content_type_for("archive.tar.gz"), "application/gzip");
+        assert_eq!(content_type_for("shell.aspx"), "application/octet-stream");
+        assert_eq!(content_type_for("noextension"), "application/octet-stream");
+    }
+
+    #[test]
+    /// the built-in interesting paths should skip comments and blank lines
+    fn interesting_paths_skips_comments_and_blank_lines() {
+        let paths: Vec<_> = interesting_paths().collect();
+
+        assert!(paths.contains(&".env"));
+        assert!(paths.contains(&"WEB-INF/web.xml"));
+        assert!(paths
+            .iter()
+            .all(|path| !path.is_empty() && !path.starts_with('#')));
+    }
+
+    #[test]
+    /// the multipart body should hold a single file part, with quotes and newlines in the
+    /// filename escaped
+    fn build_multipart_body_creates_file_part() {
+        let (boundary, body) = build_multipart_body("upload", "a\"b\r\n.png", b"contents");
+        let body = String::from_utf8(body).unwrap();
+
+        assert_eq!(
+            body,
+            format!(
+                "--{0}\r\nContent-Disposition: form-data; name=\"upload\"; filename=\"a%22b%0D%0A.png\"\r\nContent-Type: image/png\r\n\r\ncontents\r\n--{0}--\r\n",
+                boundary
+            )
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    /// replayed requests should carry the request id of the response they reproduce
+    async fn make_replay_request_sends_request_id_header() {
+        let srv = httpmock::MockServer::start();
+
+        let mock = srv.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/replayed")
+                .header(REQUEST_ID_HEADER, "7c9e6679-7425-40de-944b-e07fc1f90ae7");
+            then.status(200);
+        });
+
+        let config = Configuration::new().unwrap();
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        let url = Url::parse(&srv.url("/replayed")).unwrap();
+
+        let response = make_replay_request(
+            &Client::new(),
+            &url,
+            "GET",
+            None,
+            "7c9e6679-7425-40de-944b-e07fc1f90ae7",
+            &config,
+            tx,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(mock.hits(), 1);
+    }
 }
@@ -16,7 +16,7 @@ fn extractor_finds_absolute_url() -> Result<(), Box<dyn std::error::Error>> {
     let mock = srv.mock(|when, then| {
         when.method(GET).path("/LICENSE");
         then.status(200)
-            .body(&srv.url("'/homepage/assets/img/icons/handshake.svg'"));
+            .body(srv.url("'/homepage/assets/img/icons/handshake.svg'"));
     });
 
     let mock_two = srv.mock(|when, then| {
@@ -136,13 +136,13 @@ fn extractor_finds_same_relative_url_twice() {
     let mock = srv.mock(|when, then| {
         when.method(GET).path("/LICENSE");
         then.status(200)
-            .body(&srv.url("\"/homepage/assets/img/icons/handshake.svg\""));
+            .body(srv.url("\"/homepage/assets/img/icons/handshake.svg\""));
     });
 
     let mock_two = srv.mock(|when, then| {
         when.method(GET).path("/README");
         then.status(200)
-            .body(&srv.url("\"/homepage/assets/img/icons/handshake.svg\""));
+            .body(srv.url("\"/homepage/assets/img/icons/handshake.svg\""));
     });
 
     let mock_three = srv.mock(|when, then| {
@@ -185,7 +185,7 @@ fn extractor_finds_filtered_content() -> Result<(), Box<dyn std::error::Error>>
     let mock = srv.mock(|when, then| {
         when.method(GET).path("/LICENSE");
         then.status(200)
-            .body(&srv.url("\"/homepage/assets/img/icons/handshake.svg\""));
+            .body(srv.url("\"/homepage/assets/img/icons/handshake.svg\""));
     });
 
     let mock_two = srv.mock(|when, then| {
@@ -413,7 +413,7 @@ fn extractor_finds_directory_listing_links_and_displays_files() {
 
     let mock_dir_redir = srv.mock(|when, then| {
         when.method(GET).path("/misc");
-        then.status(301).header("Location", &srv.url("/misc/"));
+        then.status(301).header("Location", srv.url("/misc/"));
     });
     let mock_dir = srv.mock(|when, then| {
         when.method(GET).path("/misc/");
@@ -522,7 +522,7 @@ fn extractor_finds_directory_listing_links_and_displays_files_non_recursive() {
 
     let mock_dir_redir = srv.mock(|when, then| {
         when.method(GET).path("/misc");
-        then.status(301).header("Location", &srv.url("/misc/"));
+        then.status(301).header("Location", srv.url("/misc/"));
     });
     let mock_dir = srv.mock(|when, then| {
         when.method(GET).path("/misc/");
@@ -593,6 +593,7 @@ fn extractor_finds_directory_listing_links_and_displays_files_non_recursive() {
 #[test]
 /// send a request to a page that contains a link that contains a directory that returns a 403
 /// --extract-links should find the link and make recurse into the 403 directory, finding LICENSE
+/// (403 isn't in the default --recurse-on-status)
 fn extractor_recurses_into_403_directories() -> Result<(), Box<dyn std::error::Error>> {
     let srv = MockServer::start();
     let (tmp_dir, file) = setup_tmp_directory(&["LICENSE".to_string()], "wordlist")?;
@@ -600,7 +601,7 @@ fn extractor_recurses_into_403_directories() -> Result<(), Box<dyn std::error::E
     let mock = srv.mock(|when, then| {
         when.method(GET).path("/LICENSE");
         then.status(200)
-            .body(&srv.url("'/homepage/assets/img/icons/handshake.svg'"));
+            .body(srv.url("'/homepage/assets/img/icons/handshake.svg'"));
     });
 
     let mock_two = srv.mock(|when, then| {
@@ -622,6 +623,8 @@ fn extractor_recurses_into_403_directories() -> Result<(), Box<dyn std::error::E
         .arg("--extract-links")
         .arg("--depth") // need to go past default 4 directories
         .arg("0")
+        .arg("--recurse-on-status")
+        .arg("200,301,302,403")
         .unwrap();
 
     cmd.assert().success().stdout(
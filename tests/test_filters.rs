@@ -1,9 +1,10 @@
 mod utils;
 use assert_cmd::prelude::*;
 use httpmock::Method::GET;
-use httpmock::MockServer;
+use httpmock::{MockServer, Regex};
 use predicates::prelude::*;
 use std::process::Command;
+use std::time::Duration;
 use utils::{setup_tmp_directory, teardown_tmp_directory};
 
 #[test]
@@ -52,6 +53,48 @@ fn filters_status_code_should_filter_response() {
     teardown_tmp_directory(tmp_dir);
 }
 
+#[test]
+/// --include-all-status should show responses that -C and the default -s would otherwise hide
+fn filters_include_all_status_overrides_status_filters() {
+    let srv = MockServer::start();
+    let (tmp_dir, file) =
+        setup_tmp_directory(&["LICENSE".to_string(), "file.js".to_string()], "wordlist").unwrap();
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(302).body("this is a test");
+    });
+
+    let mock_two = srv.mock(|when, then| {
+        when.method(GET).path("/file.js");
+        then.status(418).body("this is also a test of some import");
+    });
+
+    let cmd = Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--filter-status")
+        .arg("302")
+        .arg("--include-all-status")
+        .unwrap();
+
+    cmd.assert().success().stdout(
+        predicate::str::contains("/LICENSE")
+            .and(predicate::str::contains("302"))
+            .and(predicate::str::contains("14c"))
+            .and(predicate::str::contains("/file.js"))
+            .and(predicate::str::contains("418"))
+            .and(predicate::str::contains("34c")),
+    );
+
+    assert_eq!(mock.hits(), 1);
+    assert_eq!(mock_two.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+}
+
 #[test]
 /// create a FeroxResponse that should elicit a true from
 /// LinesFilter::should_filter_response
@@ -247,3 +290,562 @@ fn filters_similar_should_filter_response() {
     assert_eq!(not_similar.hits(), 1);
     teardown_tmp_directory(tmp_dir);
 }
+
+#[test]
+/// create a FeroxResponse that should elicit a true from
+/// ExtensionFilter::should_filter_response
+fn filters_extension_should_filter_response() {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(
+        &[
+            "LICENSE".to_string(),
+            "logo.png".to_string(),
+            "photo.JPG".to_string(),
+        ],
+        "wordlist",
+    )
+    .unwrap();
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(200).body("this is a test");
+    });
+
+    let mock_two = srv.mock(|when, then| {
+        when.method(GET).path("/logo.png");
+        then.status(200).body("definitely an image");
+    });
+
+    let mock_three = srv.mock(|when, then| {
+        when.method(GET).path("/photo.JPG");
+        then.status(200).body("also definitely an image");
+    });
+
+    let cmd = Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--filter-extension")
+        .arg("png,jpg")
+        .unwrap();
+
+    cmd.assert().success().stdout(
+        predicate::str::contains("/LICENSE")
+            .and(predicate::str::contains("/logo.png").not())
+            .and(predicate::str::contains("/photo.JPG").not()),
+    );
+
+    assert_eq!(mock.hits(), 1);
+    assert_eq!(mock_two.hits(), 1);
+    assert_eq!(mock_three.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+}
+
+#[test]
+/// --output-filter-status should limit what's written to --output, while the terminal still
+/// shows every response that passes the normal filters
+fn filters_output_filter_status_only_applies_to_output_file() {
+    let srv = MockServer::start();
+    let (tmp_dir, file) =
+        setup_tmp_directory(&["LICENSE".to_string(), "secret".to_string()], "wordlist").unwrap();
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(200).body("this is a test");
+    });
+
+    let mock_two = srv.mock(|when, then| {
+        when.method(GET).path("/secret");
+        then.status(403);
+    });
+
+    let outfile = tmp_dir.path().join("output");
+
+    let cmd = Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--output")
+        .arg(outfile.as_os_str())
+        .arg("--output-filter-status")
+        .arg("200")
+        .unwrap();
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("/LICENSE").and(predicate::str::contains("/secret")));
+
+    let contents = std::fs::read_to_string(outfile).unwrap();
+
+    assert!(contents.contains("/LICENSE"));
+    assert!(!contents.contains("/secret"));
+
+    assert_eq!(mock.hits(), 1);
+    assert_eq!(mock_two.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+}
+
+#[test]
+/// --filter-body-size-range should hide responses within the range, while
+/// --keep-body-size-range should hide responses outside of it
+fn filters_body_size_ranges_filter_and_keep_responses() {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(
+        &[
+            "small".to_string(),
+            "medium".to_string(),
+            "large".to_string(),
+        ],
+        "wordlist",
+    )
+    .unwrap();
+
+    for (path, size) in [("/small", 5), ("/medium", 50), ("/large", 500)] {
+        srv.mock(|when, then| {
+            when.method(GET).path(path);
+            then.status(200).body("a".repeat(size));
+        });
+    }
+
+    let cmd = Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--filter-body-size-range")
+        .arg("40-60")
+        .unwrap();
+
+    cmd.assert().success().stdout(
+        predicate::str::contains("/small")
+            .and(predicate::str::contains("/large"))
+            .and(predicate::str::contains("/medium").not()),
+    );
+
+    let cmd = Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--keep-body-size-range")
+        .arg("40-60,400-unlimited")
+        .unwrap();
+
+    cmd.assert().success().stdout(
+        predicate::str::contains("/medium")
+            .and(predicate::str::contains("/large"))
+            .and(predicate::str::contains("/small").not()),
+    );
+
+    teardown_tmp_directory(tmp_dir);
+}
+
+#[test]
+/// --filter-empty-body should hide responses without a body, and --filter-near-empty-body should
+/// hide responses whose body is at most the given number of bytes
+fn filters_empty_and_near_empty_bodies() {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(
+        &[
+            "empty".to_string(),
+            "small".to_string(),
+            "large".to_string(),
+        ],
+        "wordlist",
+    )
+    .unwrap();
+
+    for (path, size) in [("/empty", 0), ("/small", 5), ("/large", 500)] {
+        srv.mock(|when, then| {
+            when.method(GET).path(path);
+            then.status(200).body("a".repeat(size));
+        });
+    }
+
+    let cmd = Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--filter-empty-body")
+        .unwrap();
+
+    cmd.assert().success().stdout(
+        predicate::str::contains("/small")
+            .and(predicate::str::contains("/large"))
+            .and(predicate::str::contains("/empty").not()),
+    );
+
+    let cmd = Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--filter-near-empty-body")
+        .arg("10")
+        .unwrap();
+
+    cmd.assert().success().stdout(
+        predicate::str::contains("/large")
+            .and(predicate::str::contains("/small").not())
+            .and(predicate::str::contains("/empty").not()),
+    );
+
+    teardown_tmp_directory(tmp_dir);
+}
+
+#[test]
+/// --filter-words-range and --filter-lines-range should hide responses whose word/line count
+/// falls within the (inclusive) range
+fn filters_words_and_lines_ranges_filter_responses() {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(
+        &["one".to_string(), "three".to_string(), "five".to_string()],
+        "wordlist",
+    )
+    .unwrap();
+
+    for (path, count) in [("/one", 1), ("/three", 3), ("/five", 5)] {
+        srv.mock(|when, then| {
+            when.method(GET).path(path);
+            then.status(200).body(vec!["word"; count].join("\n"));
+        });
+    }
+
+    let cmd = Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--filter-words-range")
+        .arg("2-3")
+        .unwrap();
+
+    cmd.assert().success().stdout(
+        predicate::str::contains("/one")
+            .and(predicate::str::contains("/five"))
+            .and(predicate::str::contains("/three").not()),
+    );
+
+    let cmd = Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--filter-lines-range")
+        .arg("3-5")
+        .unwrap();
+
+    cmd.assert().success().stdout(
+        predicate::str::contains("/one")
+            .and(predicate::str::contains("/three").not())
+            .and(predicate::str::contains("/five").not()),
+    );
+
+    teardown_tmp_directory(tmp_dir);
+}
+
+#[test]
+/// --interesting-only should only show responses that score as interesting
+fn filters_interesting_only_hides_uninteresting_responses() {
+    let srv = MockServer::start();
+    let (tmp_dir, file) =
+        setup_tmp_directory(&["admin".to_string(), "about".to_string()], "wordlist").unwrap();
+
+    let interesting = srv.mock(|when, then| {
+        when.method(GET).path("/admin");
+        then.status(200)
+            .header("Content-Type", "text/html")
+            .body("<html>login</html>");
+    });
+
+    let boring = srv.mock(|when, then| {
+        when.method(GET).path("/about");
+        then.status(200)
+            .header("Content-Type", "text/plain")
+            .body("about us");
+    });
+
+    let cmd = Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--interesting-only")
+        .unwrap();
+
+    cmd.assert().success().stdout(
+        predicate::str::contains("/admin")
+            .and(predicate::str::contains("200"))
+            .and(predicate::str::contains("/about").not()),
+    );
+
+    assert_eq!(interesting.hits(), 1);
+    assert_eq!(boring.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+}
+
+#[test]
+/// --filter-response-time-above should hide responses that took longer than the threshold
+fn filters_response_time_above_hides_slow_responses() {
+    let srv = MockServer::start();
+    let (tmp_dir, file) =
+        setup_tmp_directory(&["fast".to_string(), "slow".to_string()], "wordlist").unwrap();
+
+    let fast = srv.mock(|when, then| {
+        when.method(GET).path("/fast");
+        then.status(200).body("quick");
+    });
+
+    let slow = srv.mock(|when, then| {
+        when.method(GET).path("/slow");
+        then.status(200)
+            .body("sluggish")
+            .delay(Duration::from_millis(1500));
+    });
+
+    let cmd = Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--filter-response-time-above")
+        .arg("1000")
+        .unwrap();
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("/fast").and(predicate::str::contains("/slow").not()));
+
+    assert_eq!(fast.hits(), 1);
+    assert_eq!(slow.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+}
+
+#[test]
+/// --filter-max-headers and --filter-min-headers should hide responses with more or fewer headers
+/// than their thresholds
+fn filters_header_count_hides_responses_outside_thresholds() {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(
+        &[
+            "blocked".to_string(),
+            "admin".to_string(),
+            "bare".to_string(),
+        ],
+        "wordlist",
+    )
+    .unwrap();
+
+    let blocked = srv.mock(|when, then| {
+        when.method(GET).path("/blocked");
+        then.status(200)
+            .header("X-Waf-1", "a")
+            .header("X-Waf-2", "b")
+            .header("X-Waf-3", "c")
+            .header("X-Waf-4", "d")
+            .header("X-Waf-5", "e")
+            .body("blocked");
+    });
+
+    let admin = srv.mock(|when, then| {
+        when.method(GET).path("/admin");
+        then.status(200).header("X-Admin", "yes").body("admin");
+    });
+
+    let bare = srv.mock(|when, then| {
+        when.method(GET).path("/bare");
+        then.status(200).body("bare");
+    });
+
+    // httpmock sends content-length and date with every response
+    let cmd = Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--filter-max-headers")
+        .arg("5")
+        .arg("--filter-min-headers")
+        .arg("3")
+        .unwrap();
+
+    cmd.assert().success().stdout(
+        predicate::str::contains("/admin")
+            .and(predicate::str::contains("/blocked").not())
+            .and(predicate::str::contains("/bare").not()),
+    );
+
+    assert_eq!(blocked.hits(), 1);
+    assert_eq!(admin.hits(), 1);
+    assert_eq!(bare.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+}
+
+#[test]
+/// --filter-min-ratio should hide responses whose size is close to the wildcard response's size,
+/// while keeping responses of a noticeably different size
+fn filters_min_ratio_hides_responses_near_wildcard_size() {
+    let srv = MockServer::start();
+    let (tmp_dir, file) =
+        setup_tmp_directory(&["soft404".to_string(), "admin".to_string()], "wordlist").unwrap();
+
+    let soft404 = srv.mock(|when, then| {
+        when.method(GET).path("/soft404");
+        then.status(200).body("a".repeat(980));
+    });
+
+    let admin = srv.mock(|when, then| {
+        when.method(GET).path("/admin");
+        then.status(200).body("a".repeat(200));
+    });
+
+    let wildcard = srv.mock(|when, then| {
+        when.method(GET)
+            .path_matches(Regex::new("/[a-zA-Z0-9]{32,96}").unwrap());
+        then.status(200).body("a".repeat(1000));
+    });
+
+    let cmd = Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--filter-min-ratio")
+        .arg("0.95")
+        .unwrap();
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("/admin").and(predicate::str::contains("/soft404").not()));
+
+    assert_eq!(soft404.hits(), 1);
+    assert_eq!(admin.hits(), 1);
+    assert_eq!(wildcard.hits(), 2);
+    teardown_tmp_directory(tmp_dir);
+}
+
+#[test]
+/// --json-path-filter should hide json responses matching the expression, while
+/// --json-path-keep-if should hide json responses that don't match; non-json responses are kept
+fn filters_json_path_filter_and_keep_if() {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(
+        &["ok".to_string(), "error".to_string(), "html".to_string()],
+        "wordlist",
+    )
+    .unwrap();
+
+    let ok = srv.mock(|when, then| {
+        when.method(GET).path("/ok");
+        then.status(200)
+            .body(r#"{"status":"ok","data":[{"id":1}]}"#);
+    });
+
+    let error = srv.mock(|when, then| {
+        when.method(GET).path("/error");
+        then.status(200).body(r#"{"status":"error","data":[]}"#);
+    });
+
+    let html = srv.mock(|when, then| {
+        when.method(GET).path("/html");
+        then.status(200).body("<html><body>not json</body></html>");
+    });
+
+    for (flag, expression) in [
+        ("--json-path-filter", r#"$.status == "error""#),
+        ("--json-path-keep-if", "$.data[0].id"),
+    ] {
+        let cmd = Command::cargo_bin("feroxbuster")
+            .unwrap()
+            .arg("--url")
+            .arg(srv.url("/"))
+            .arg("--wordlist")
+            .arg(file.as_os_str())
+            .arg(flag)
+            .arg(expression)
+            .unwrap();
+
+        cmd.assert().success().stdout(
+            predicate::str::contains("/ok")
+                .and(predicate::str::contains("/html"))
+                .and(predicate::str::contains("/error").not()),
+        );
+    }
+
+    assert_eq!(ok.hits(), 2);
+    assert_eq!(error.hits(), 2);
+    assert_eq!(html.hits(), 2);
+    teardown_tmp_directory(tmp_dir);
+}
+
+#[test]
+/// --filter-duplicate-content should hide responses whose body was already shown for another url
+fn filters_duplicate_content_hides_repeated_bodies() {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(
+        &[
+            "first".to_string(),
+            "second".to_string(),
+            "third".to_string(),
+        ],
+        "wordlist",
+    )
+    .unwrap();
+
+    let first = srv.mock(|when, then| {
+        when.method(GET).path("/first");
+        then.status(200).body("the same page");
+    });
+
+    let second = srv.mock(|when, then| {
+        when.method(GET).path("/second");
+        then.status(200).body("the same page");
+    });
+
+    let third = srv.mock(|when, then| {
+        when.method(GET).path("/third");
+        then.status(200).body("a different page");
+    });
+
+    let cmd = Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--threads")
+        .arg("1")
+        .arg("--filter-duplicate-content")
+        .unwrap();
+
+    cmd.assert()
+        .success()
+        .stdout(
+            predicate::str::contains("/first")
+                .and(predicate::str::contains("/third"))
+                .and(predicate::str::contains("/second").not()),
+        )
+        .stderr(predicate::str::contains(
+            "Filtered 1 responses with duplicate content",
+        ));
+
+    assert_eq!(first.hits(), 1);
+    assert_eq!(second.hits(), 1);
+    assert_eq!(third.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+}
@@ -1,6 +1,6 @@
 mod utils;
 use assert_cmd::Command;
-use httpmock::Method::GET;
+use httpmock::Method::{GET, POST};
 use httpmock::{MockServer, Regex};
 use predicates::prelude::*;
 use std::fs::{read_dir, read_to_string};
@@ -199,7 +199,7 @@ fn main_parallel_creates_output_directory() -> Result<(), Box<dyn std::error::Er
     let sub_dir = output_dir.as_ref().join(&sub_dir);
 
     // created directory like output-file-1627845741.logs/
-    assert!(dir_regex.is_match(&sub_dir.to_string_lossy().to_string()));
+    assert!(dir_regex.is_match(sub_dir.to_string_lossy().as_ref()));
 
     for entry in sub_dir.read_dir()? {
         let entry = entry?;
@@ -218,3 +218,776 @@ fn main_parallel_creates_output_directory() -> Result<(), Box<dyn std::error::Er
 
     Ok(())
 }
+
+#[test]
+/// --live-output should print each result straight to stdout
+fn main_live_output_prints_results_to_stdout() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(&["LICENSE".to_string()], "wordlist")?;
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(200).body("this is a test");
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--live-output")
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("/LICENSE")
+                .and(predicate::str::contains("200"))
+                .and(predicate::str::contains("14c")),
+        );
+
+    assert_eq!(mock.hits(), 1);
+
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// send a request that returns an html page and one that doesn't, expect only the html page to
+/// end up in the gallery written by --store-responses-as-html
+fn main_store_responses_as_html_writes_gallery() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) =
+        setup_tmp_directory(&["index".to_string(), "LICENSE".to_string()], "wordlist")?;
+    let gallery_dir = tmp_dir.path().join("gallery");
+
+    let html_mock = srv.mock(|when, then| {
+        when.method(GET).path("/index");
+        then.status(200)
+            .header("Content-Type", "text/html; charset=utf-8")
+            .body("<html><script>alert(1)</script></html>");
+    });
+
+    let text_mock = srv.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(200)
+            .header("Content-Type", "text/plain")
+            .body("this is a test");
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--store-responses-as-html")
+        .arg("--html-gallery-dir")
+        .arg(gallery_dir.as_os_str())
+        .assert()
+        .success();
+
+    assert_eq!(html_mock.hits(), 1);
+    assert_eq!(text_mock.hits(), 1);
+
+    let index = read_to_string(gallery_dir.join("index.html"))?;
+    assert!(index.contains("(1 pages)"));
+    assert!(index.contains("/index</a>"));
+    assert!(!index.contains("/LICENSE"));
+
+    let page = read_to_string(gallery_dir.join("page-00001.html"))?;
+    assert!(page.contains("&lt;script&gt;alert(1)&lt;/script&gt;"));
+    assert!(!page.contains("<script>"));
+
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// send a request that returns an html page with inline and external scripts, expect each inline
+/// script to be written to its own numbered file in --inline-js-dir
+fn main_collect_inline_js_writes_scripts() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) =
+        setup_tmp_directory(&["app".to_string(), "LICENSE".to_string()], "wordlist")?;
+    let inline_js_dir = tmp_dir.path().join("inline-js");
+
+    let html_mock = srv.mock(|when, then| {
+        when.method(GET).path("/app");
+        then.status(200).header("Content-Type", "text/html").body(
+            r#"<html><script src="/main.js"></script>
+                <script>const routes = ["/admin"];</script>
+                <script>fetch("/api/v1/users");</script></html>"#,
+        );
+    });
+
+    let text_mock = srv.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(200)
+            .header("Content-Type", "text/plain")
+            .body("<script>not html</script>");
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--collect-inline-js")
+        .arg("--inline-js-dir")
+        .arg(inline_js_dir.as_os_str())
+        .assert()
+        .success();
+
+    assert_eq!(html_mock.hits(), 1);
+    assert_eq!(text_mock.hits(), 1);
+
+    let name = format!("{}_app", srv.address()).replace(':', "_");
+
+    assert_eq!(
+        read_to_string(inline_js_dir.join(format!("00001-{}.js", name)))?,
+        r#"const routes = ["/admin"];"#
+    );
+    assert_eq!(
+        read_to_string(inline_js_dir.join(format!("00002-{}.js", name)))?,
+        r#"fetch("/api/v1/users");"#
+    );
+    assert_eq!(std::fs::read_dir(&inline_js_dir)?.count(), 2);
+
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --tee should write each result printed to the terminal to the given file as well
+fn main_tee_writes_results_to_file() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) =
+        setup_tmp_directory(&["admin".to_string(), "missing".to_string()], "wordlist")?;
+    let tee_file = tmp_dir.path().join("tee.txt");
+
+    let admin_mock = srv.mock(|when, then| {
+        when.method(GET).path("/admin");
+        then.status(200).body("admin panel");
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--tee")
+        .arg(tee_file.as_os_str())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(srv.url("/admin")));
+
+    assert_eq!(admin_mock.hits(), 1);
+
+    let contents = read_to_string(tee_file)?;
+    assert_eq!(contents.lines().count(), 1);
+    assert!(contents.contains(&srv.url("/admin")));
+    assert!(!contents.contains("/missing"));
+
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --output-deduplicate should write a url reported for several methods to --output only once
+fn main_output_deduplicate_writes_each_url_once() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(&["admin".to_string()], "wordlist")?;
+
+    let get_mock = srv.mock(|when, then| {
+        when.method(GET).path("/admin");
+        then.status(200).body("admin panel");
+    });
+
+    let post_mock = srv.mock(|when, then| {
+        when.method(POST).path("/admin");
+        then.status(200).body("admin panel");
+    });
+
+    for (deduplicate, expected_lines) in [(false, 2), (true, 1)] {
+        let outfile = tmp_dir.path().join(format!("output-{}", deduplicate));
+
+        let mut cmd = Command::cargo_bin("feroxbuster").unwrap();
+        cmd.arg("--url")
+            .arg(srv.url("/"))
+            .arg("--wordlist")
+            .arg(file.as_os_str())
+            .arg("--methods")
+            .arg("GET")
+            .arg("--methods")
+            .arg("POST")
+            .arg("--output")
+            .arg(outfile.as_os_str());
+
+        if deduplicate {
+            cmd.arg("--output-deduplicate");
+        }
+
+        cmd.assert().success();
+
+        let contents = read_to_string(outfile)?;
+        assert_eq!(
+            contents
+                .lines()
+                .filter(|line| line.contains(&srv.url("/admin")))
+                .count(),
+            expected_lines
+        );
+    }
+
+    assert_eq!(get_mock.hits(), 2);
+    assert_eq!(post_mock.hits(), 2);
+
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --report-summary-only should keep results off the terminal, print a json summary instead, and
+/// still write results to --output
+fn main_report_summary_only_prints_json_summary() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) =
+        setup_tmp_directory(&["admin".to_string(), "missing".to_string()], "wordlist")?;
+    let outfile = tmp_dir.path().join("output");
+
+    let admin_mock = srv.mock(|when, then| {
+        when.method(GET).path("/admin");
+        then.status(200).body("admin panel");
+    });
+
+    let cmd = Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--silent")
+        .arg("--report-summary-only")
+        .arg("--output")
+        .arg(outfile.as_os_str())
+        .assert()
+        .success();
+
+    let stdout = String::from_utf8(cmd.get_output().stdout.clone())?;
+    assert_eq!(stdout.lines().count(), 1);
+    assert!(!stdout.contains(&srv.url("/admin")));
+
+    let summary: serde_json::Value = serde_json::from_str(&stdout)?;
+    assert_eq!(summary["total_found"], 1);
+    assert_eq!(summary["urls_by_status"]["200"], 1);
+    assert!(summary["total_scanned"].as_u64().unwrap() >= 2);
+    assert!(summary["elapsed_s"].is_f64());
+    assert!(summary["hostname"].is_string());
+
+    assert_eq!(admin_mock.hits(), 1);
+    assert!(read_to_string(outfile)?.contains(&srv.url("/admin")));
+
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// once the scan is done, expect a table of the scan's response time stats on stderr
+fn main_prints_scan_timing_stats() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(&["LICENSE".to_string()], "wordlist")?;
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(200).body("this is a test");
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .assert()
+        .success()
+        .stderr(
+            predicate::str::contains("P95")
+                .and(predicate::str::contains(srv.url("/")))
+                .and(predicate::str::contains("response times in milliseconds")),
+        );
+
+    assert_eq!(mock.hits(), 1);
+
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// a latin-1 wordlist should be decoded with --wordlist-encoding instead of having its non-ascii
+/// entries dropped
+fn main_wordlist_encoding_decodes_latin1_wordlist() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(&[], "wordlist")?;
+    std::fs::write(&file, b"caf\xe9\nLICENSE\n")?;
+
+    let cafe_mock = srv.mock(|when, then| {
+        when.method(GET).path("/caf%C3%A9");
+        then.status(200).body("this is a test");
+    });
+
+    let license_mock = srv.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(200).body("this is also a test");
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--wordlist-encoding")
+        .arg("latin1")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("/caf%C3%A9").and(predicate::str::contains("/LICENSE")));
+
+    assert_eq!(cafe_mock.hits(), 1);
+    assert_eq!(license_mock.hits(), 1);
+
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// an unknown --wordlist-encoding should be reported instead of scanning
+fn main_wordlist_encoding_rejects_unknown_encoding() -> Result<(), Box<dyn std::error::Error>> {
+    let (tmp_dir, file) = setup_tmp_directory(&["LICENSE".to_string()], "wordlist")?;
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg("http://localhost")
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--wordlist-encoding")
+        .arg("definitely-not-an-encoding")
+        .assert()
+        .stderr(predicate::str::contains(
+            "Unknown wordlist encoding: definitely-not-an-encoding",
+        ));
+
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --post-scan-script should run the script with the state file and scan details once the scan
+/// completes, and exit with the script's exit code
+fn main_post_scan_script_runs_after_scan() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(&["LICENSE".to_string()], "wordlist")?;
+    let script = tmp_dir.path().join("hook.sh");
+    let results = tmp_dir.path().join("results");
+
+    std::fs::write(
+        &script,
+        format!(
+            "echo \"$1 $FEROX_STATE_FILE $FEROX_HOST $FEROX_TOTAL_FOUND\" > {}\nexit 3\n",
+            results.display()
+        ),
+    )?;
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(200).body("this is a test");
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .current_dir(tmp_dir.path())
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--post-scan-script")
+        .arg(script.as_os_str())
+        .assert()
+        .code(3);
+
+    assert_eq!(mock.hits(), 1);
+
+    let contents = read_to_string(&results)?;
+    let fields: Vec<_> = contents.split_whitespace().collect();
+
+    assert_eq!(fields.len(), 4);
+    assert_eq!(fields[0], fields[1]);
+    assert!(fields[0].starts_with("ferox-http_127_0_0_1"));
+    assert!(fields[0].ends_with(".state"));
+    assert_eq!(fields[2], "127.0.0.1");
+    assert_eq!(fields[3], "1");
+
+    let state = read_to_string(tmp_dir.path().join(fields[0]))?;
+    assert!(state.contains("/LICENSE"));
+
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --exit-code-on-found should be used when a url is discovered, --exit-code-on-none otherwise
+fn main_exit_code_on_found_and_none() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(&["LICENSE".to_string()], "wordlist")?;
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(200).body("this is a test");
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--exit-code-on-found")
+        .arg("5")
+        .arg("--exit-code-on-none")
+        .arg("7")
+        .assert()
+        .code(5);
+
+    // nothing responds with a 418, so nothing is found
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--status-codes")
+        .arg("418")
+        .arg("--exit-code-on-found")
+        .arg("5")
+        .arg("--exit-code-on-none")
+        .arg("7")
+        .assert()
+        .code(7);
+
+    assert_eq!(mock.hits(), 2);
+
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --save-state-on-empty should write a state file only when nothing was found
+fn main_save_state_on_empty_writes_state_file() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(&["LICENSE".to_string()], "wordlist")?;
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(200).body("this is a test");
+    });
+
+    let state_files = |dir: &std::path::Path| -> Vec<std::path::PathBuf> {
+        read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "state"))
+            .collect()
+    };
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .current_dir(tmp_dir.path())
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--save-state-on-empty")
+        .assert()
+        .success();
+
+    assert!(state_files(tmp_dir.path()).is_empty());
+
+    // nothing responds with a 418, so nothing is found
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .current_dir(tmp_dir.path())
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--status-codes")
+        .arg("418")
+        .arg("--save-state-on-empty")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "No results found; saved scan state to",
+        ));
+
+    let written = state_files(tmp_dir.path());
+    assert_eq!(written.len(), 1);
+
+    let state = read_to_string(&written[0])?;
+    assert!(state.contains(r#""scans":[{"#));
+    assert!(written[0]
+        .file_name()
+        .unwrap()
+        .to_string_lossy()
+        .starts_with("ferox-http_127_0_0_1"));
+
+    assert_eq!(mock.hits(), 2);
+
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --json-status should print the overall scan's progress to stderr as JSON
+fn main_json_status_prints_scan_stats() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(&["LICENSE".to_string()], "wordlist")?;
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(200)
+            .body("this is a test")
+            .delay(std::time::Duration::from_millis(1500));
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--dont-filter")
+        .arg("--json-status")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            r#"{"complete":0,"incomplete":1,"total":1,"total_responses":0,"type":"status"}"#,
+        ));
+
+    assert_eq!(mock.hits(), 1);
+
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --checkpoint-interval should write state files while the scan runs, keeping only the latest
+fn main_checkpoint_interval_keeps_latest_state_file() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let words = ["one", "two", "three"].map(String::from);
+    let (tmp_dir, file) = setup_tmp_directory(&words, "wordlist")?;
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET);
+        then.status(404)
+            .delay(std::time::Duration::from_millis(1500));
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .current_dir(tmp_dir.path())
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--threads")
+        .arg("1")
+        .arg("--checkpoint-interval")
+        .arg("1s")
+        .assert()
+        .success();
+
+    let written: Vec<_> = read_dir(tmp_dir.path())?
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "state"))
+        .collect();
+
+    // the scan takes ~4.5 seconds, so several checkpoints were written, only one should remain
+    assert_eq!(written.len(), 1);
+
+    let state = read_to_string(&written[0])?;
+    assert!(state.contains(r#""scans":[{"#));
+    assert!(state.contains(r#""checkpoint_interval":"1s""#));
+
+    assert!(mock.hits() >= 3);
+
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --collect-interesting-paths should request the built-in paths alongside the wordlist and flag
+/// the ones that are found
+fn main_collect_interesting_paths_requests_builtin_list() -> Result<(), Box<dyn std::error::Error>>
+{
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(&["LICENSE".to_string()], "wordlist")?;
+
+    let env_mock = srv.mock(|when, then| {
+        when.method(GET).path("/.env");
+        then.status(200).body("DB_PASSWORD=hunter2");
+    });
+
+    let license_mock = srv.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(200).body("this is a test");
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--collect-interesting-paths")
+        .arg("--no-recursion")
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::is_match(r"\[!\].*/\.env")
+                .unwrap()
+                .and(predicate::str::contains("/LICENSE")),
+        );
+
+    assert_eq!(env_mock.hits(), 1);
+    assert_eq!(license_mock.hits(), 1);
+
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --simulate should print the estimated number of requests and exit without sending any
+fn main_simulate_prints_estimate_without_requesting() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) =
+        setup_tmp_directory(&["LICENSE".to_string(), "js".to_string()], "wordlist")?;
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET).path_matches(Regex::new(".*").unwrap());
+        then.status(200).body("this is a test");
+    });
+
+    // 3 words (including the base url) * (1 + 1 extension) * 2 methods
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("-x")
+        .arg("php")
+        .arg("-m")
+        .arg("GET,POST")
+        .arg("--dry-run")
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("Estimated requests:     12")
+                .and(predicate::str::contains("underestimate")),
+        );
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--no-recursion")
+        .arg("--simulate-json")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            r#""targets":1,"words":3,"requests_per_word":1,"requests_per_directory":3,"total_requests":3,"recursion":false"#,
+        ));
+
+    assert_eq!(mock.hits(), 0);
+
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --no-redundant-scans should skip targets that fall beneath another target's directory
+fn main_no_redundant_scans_skips_nested_targets() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (word_tmp_dir, wordlist) = setup_tmp_directory(&["LICENSE".to_string()], "wordlist")?;
+    let (tgt_tmp_dir, targets) =
+        setup_tmp_directory(&[srv.url("/api/"), srv.url("/api/v1")], "targets")?;
+
+    let api_mock = srv.mock(|when, then| {
+        when.method(GET).path("/api/LICENSE");
+        then.status(200).body("this is a test");
+    });
+
+    let nested_mock = srv.mock(|when, then| {
+        when.method(GET).path("/api/v1/LICENSE");
+        then.status(200).body("this is also a test");
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--stdin")
+        .arg("--wordlist")
+        .arg(wordlist.as_os_str())
+        .arg("--no-redundant-scans")
+        .arg("--no-recursion")
+        .pipe_stdin(targets)
+        .unwrap()
+        .assert()
+        .success();
+
+    assert_eq!(api_mock.hits(), 1);
+    assert_eq!(nested_mock.hits(), 0);
+
+    teardown_tmp_directory(word_tmp_dir);
+    teardown_tmp_directory(tgt_tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// with --parallel, the exit code chosen by the child processes (i.e. --exit-code-on-found)
+/// should be passed along by the parent
+fn main_parallel_propagates_child_exit_code() -> Result<(), Box<dyn std::error::Error>> {
+    let t1 = MockServer::start();
+    let t2 = MockServer::start();
+
+    let (word_tmp_dir, wordlist) = setup_tmp_directory(&["LICENSE".to_string()], "wordlist")?;
+    let (tgt_tmp_dir, targets) = setup_tmp_directory(&[t1.url("/"), t2.url("/")], "targets")?;
+
+    let mock = t2.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(200).body("this is a test");
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--stdin")
+        .arg("--parallel")
+        .arg("2")
+        .arg("--wordlist")
+        .arg(wordlist.as_os_str())
+        .arg("--exit-code-on-found")
+        .arg("5")
+        .pipe_stdin(targets)
+        .unwrap()
+        .assert()
+        .code(5);
+
+    assert_eq!(mock.hits(), 1);
+
+    teardown_tmp_directory(word_tmp_dir);
+    teardown_tmp_directory(tgt_tmp_dir);
+    Ok(())
+}
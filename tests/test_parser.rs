@@ -42,7 +42,6 @@ fn parser_incorrect_param_with_tack_h() {
         .arg("-h")
         .assert()
         .success()
-        .stdout(predicate::str::contains(
-            "[CAUTION] 4 -v's is probably too much",
-        ));
+        // the sentence may be wrapped, depending on the help layout clap picks
+        .stdout(predicate::str::is_match(r"\[CAUTION\] 4 -v's is\s+probably too much").unwrap());
 }
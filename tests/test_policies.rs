@@ -75,6 +75,8 @@ fn auto_bail_cancels_scan_with_timeouts() {
 
     let debug_log = read_to_string(logfile).unwrap();
 
+    let re = Regex::new("total_expected: ([0-9]+),").unwrap();
+
     // read debug log to get the number of errors enforced
     for line in debug_log.lines() {
         let log: serde_json::Value = serde_json::from_str(line).unwrap_or_default();
@@ -82,7 +84,6 @@ fn auto_bail_cancels_scan_with_timeouts() {
             let str_msg = message.as_str().unwrap_or_default().to_string();
 
             if str_msg.starts_with("Stats") {
-                let re = Regex::new("total_expected: ([0-9]+),").unwrap();
                 assert!(re.is_match(&str_msg));
                 let total_expected = re
                     .captures(&str_msg)
@@ -154,6 +155,8 @@ fn auto_bail_cancels_scan_with_403s() {
     println!("log filesize: {}", logfile.metadata().unwrap().len());
     let debug_log = read_to_string(logfile).unwrap();
 
+    let re = Regex::new("total_expected: ([0-9]+),").unwrap();
+
     // read debug log to get the number of errors enforced
     for line in debug_log.lines() {
         let log: serde_json::Value = serde_json::from_str(line).unwrap_or_default();
@@ -162,7 +165,6 @@ fn auto_bail_cancels_scan_with_403s() {
 
             if str_msg.starts_with("Stats") {
                 println!("{}", str_msg);
-                let re = Regex::new("total_expected: ([0-9]+),").unwrap();
                 assert!(re.is_match(&str_msg));
                 let total_expected = re
                     .captures(&str_msg)
@@ -236,6 +238,8 @@ fn auto_bail_cancels_scan_with_429s() {
     println!("log filesize: {}", logfile.metadata().unwrap().len());
     let debug_log = read_to_string(logfile).unwrap();
 
+    let re = Regex::new("total_expected: ([0-9]+),").unwrap();
+
     // read debug log to get the number of errors enforced
     for line in debug_log.lines() {
         let log: serde_json::Value = serde_json::from_str(line).unwrap_or_default();
@@ -244,7 +248,6 @@ fn auto_bail_cancels_scan_with_429s() {
 
             if str_msg.starts_with("Stats") {
                 println!("{}", str_msg);
-                let re = Regex::new("total_expected: ([0-9]+),").unwrap();
                 assert!(re.is_match(&str_msg));
                 let total_expected = re
                     .captures(&str_msg)
@@ -430,3 +433,42 @@ fn auto_tune_slows_scan_with_general_errors() {
     assert!(error_mock.hits() <= 180); // may or may not see all other error requests
     assert!(start.elapsed().as_millis() >= 7000); // scan should hit time limit due to limiting
 }
+
+#[test]
+/// --rate-limit-detect should halve --rate-limit when a 429 is received
+fn rate_limit_detect_halves_rate_limit_on_429() {
+    let srv = MockServer::start();
+    let words: Vec<String> = (0..20).map(|i| format!("word{}", i)).collect();
+    let (tmp_dir, file) = setup_tmp_directory(&words, "wordlist").unwrap();
+    let (log_dir, logfile) = setup_tmp_directory(&[], "debug-log").unwrap();
+
+    let limited_mock = srv.mock(|when, then| {
+        when.method(GET).path("/word0");
+        then.status(429).body("slow down");
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--rate-limit")
+        .arg("100")
+        .arg("--rate-limit-detect")
+        .arg("--threads")
+        .arg("1")
+        .arg("--debug-log")
+        .arg(logfile.as_os_str())
+        .arg("-vv")
+        .assert()
+        .success();
+
+    let debug_log = read_to_string(logfile).unwrap();
+
+    teardown_tmp_directory(tmp_dir);
+    teardown_tmp_directory(log_dir);
+
+    assert_eq!(limited_mock.hits(), 1);
+    assert!(debug_log.contains("limiting scan to 50 requests/second"));
+}
@@ -134,3 +134,161 @@ fn time_limit_enforced_when_specified() {
     teardown_tmp_directory(tmp_dir);
     teardown_tmp_directory(tgt_tmp_dir);
 }
+
+#[test]
+/// pass a serialized scan that had already made some of its requests; expect the resumed scan to
+/// request every word again, only its progress bar picks up where the previous run left off
+fn resume_scan_requests_every_word() {
+    let srv = MockServer::start();
+    let words = ["a", "b", "c", "d"].map(String::from);
+    let (tmp_dir, file) = setup_tmp_directory(&words, "wordlist").unwrap();
+
+    let incomplete_scan = format!(
+        r#"{{"id":"400b2323a16f43468a04ffcbbeba34c6","url":"{}","scan_type":"Directory","status":"Running","num_requests":5,"requests_made_so_far":4}}"#,
+        srv.url("/js")
+    );
+    let scans = format!(r#""scans":[{}]"#, incomplete_scan);
+
+    let config = format!(
+        r#""config": {{"type":"configuration","wordlist":"{}","config":"","proxy":"","replay_proxy":"","target_url":"{}","status_codes":[200,204,301,302,307,308,401,403,405],"replay_codes":[200,204,301,302,307,308,401,403,405],"filter_status":[],"threads":1,"timeout":7,"verbosity":0,"silent":false,"quiet":false,"json":false,"output":"","debug_log":"","user_agent":"feroxbuster/1.9.0","redirects":false,"insecure":false,"extensions":[],"headers":{{}},"queries":[],"no_recursion":true,"extract_links":false,"add_slash":false,"stdin":false,"depth":2,"scan_limit":0,"filter_size":[],"filter_line_count":[],"filter_word_count":[],"filter_regex":[],"dont_filter":false}}"#,
+        file.to_string_lossy(),
+        srv.url("/")
+    );
+
+    let mocks: Vec<_> = words
+        .iter()
+        .map(|word| {
+            srv.mock(|when, then| {
+                when.method(GET).path(format!("/js/{}", word));
+                then.status(200).body("found");
+            })
+        })
+        .collect();
+
+    let state_file_contents = format!(r#"{{{},{},"responses":[]}}"#, scans, config);
+    let (tmp_dir2, state_file) = setup_tmp_directory(&[state_file_contents], "state-file").unwrap();
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--resume-from")
+        .arg(state_file.as_os_str())
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("/js/a")
+                .and(predicate::str::contains("/js/b"))
+                .and(predicate::str::contains("/js/c"))
+                .and(predicate::str::contains("/js/d")),
+        );
+
+    teardown_tmp_directory(tmp_dir);
+    teardown_tmp_directory(tmp_dir2);
+
+    let hits: Vec<_> = mocks.iter().map(|mock| mock.hits()).collect();
+    assert_eq!(hits, [1, 1, 1, 1]);
+}
+
+#[test]
+/// pass a serialized scan that has already been resumed --max-retries times; expect it to be
+/// marked as failed instead of being scanned again, and to be reported at exit
+fn resume_scan_marks_scans_failed_after_max_retries() {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(&["css".to_string()], "wordlist").unwrap();
+
+    let retried_scan = format!(
+        r#"{{"id":"400b2323a16f43468a04ffcbbeba34c6","url":"{}","scan_type":"Directory","status":"Running","retry_count":2}}"#,
+        srv.url("/js")
+    );
+    let scans = format!(r#""scans":[{}]"#, retried_scan);
+
+    let config = format!(
+        r#""config": {{"type":"configuration","wordlist":"{}","config":"","proxy":"","replay_proxy":"","target_url":"{}","status_codes":[200,204,301,302,307,308,401,403,405],"replay_codes":[200,204,301,302,307,308,401,403,405],"filter_status":[],"threads":50,"timeout":7,"verbosity":0,"silent":false,"quiet":false,"json":false,"output":"","debug_log":"","user_agent":"feroxbuster/1.9.0","redirects":false,"insecure":false,"extensions":[],"headers":{{}},"queries":[],"no_recursion":true,"extract_links":false,"add_slash":false,"stdin":false,"depth":2,"scan_limit":0,"filter_size":[],"filter_line_count":[],"filter_word_count":[],"filter_regex":[],"dont_filter":false}}"#,
+        file.to_string_lossy(),
+        srv.url("/")
+    );
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET).path("/js/css");
+        then.status(200).body("found");
+    });
+
+    let state_file_contents = format!(r#"{{{},{},"responses":[]}}"#, scans, config);
+    let (tmp_dir2, state_file) = setup_tmp_directory(&[state_file_contents], "state-file").unwrap();
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--resume-from")
+        .arg(state_file.as_os_str())
+        .arg("--max-retries")
+        .arg("2")
+        .assert()
+        .success()
+        .stderr(
+            predicate::str::contains("1 scan(s) permanently failed")
+                .and(predicate::str::contains(srv.url("/js"))),
+        );
+
+    teardown_tmp_directory(tmp_dir);
+    teardown_tmp_directory(tmp_dir2);
+
+    assert_eq!(mock.hits(), 0);
+}
+
+#[test]
+/// --diff should print the responses and scans that differ between two state files, without
+/// scanning anything
+fn diff_prints_differences_between_state_files() {
+    let scan = |id: &str, url: &str| {
+        format!(
+            r#"{{"id":"{}","url":"{}","scan_type":"Directory","status":"Complete"}}"#,
+            id, url
+        )
+    };
+    let response = |url: &str, status: u16| {
+        format!(
+            r#"{{"type":"response","url":"{}","status":{},"method":"GET"}}"#,
+            url, status
+        )
+    };
+
+    let before = format!(
+        r#"{{"scans":[{}],"responses":[{},{}]}}"#,
+        scan("057016a14769414aac9a7a62707598cb", "http://localhost/"),
+        response("http://localhost/css", 200),
+        response("http://localhost/old", 301),
+    );
+    let after = format!(
+        r#"{{"scans":[{},{}],"responses":[{},{}]}}"#,
+        scan("057016a14769414aac9a7a62707598cb", "http://localhost/"),
+        scan("400b2323a16f43468a04ffcbbeba34c6", "http://localhost/js/"),
+        response("http://localhost/css", 200),
+        response("http://localhost/js/app.js", 200),
+    );
+
+    let (tmp_dir, before_file) = setup_tmp_directory(&[before], "before-state").unwrap();
+    let (tmp_dir2, after_file) = setup_tmp_directory(&[after], "after-state").unwrap();
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--diff")
+        .arg(before_file.as_os_str())
+        .arg(after_file.as_os_str())
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("New responses (1):")
+                .and(predicate::str::contains(
+                    "+ 200     GET http://localhost/js/app.js",
+                ))
+                .and(predicate::str::contains("Removed responses (1):"))
+                .and(predicate::str::contains(
+                    "- 301     GET http://localhost/old",
+                ))
+                .and(predicate::str::contains("New scans (1):"))
+                .and(predicate::str::contains("+ http://localhost/js/"))
+                .and(predicate::str::contains("http://localhost/css").not()),
+        );
+
+    teardown_tmp_directory(tmp_dir);
+    teardown_tmp_directory(tmp_dir2);
+}
@@ -1,12 +1,12 @@
 mod utils;
 use assert_cmd::prelude::*;
-use httpmock::Method::GET;
+use httpmock::Method::{GET, HEAD, OPTIONS, POST};
 use httpmock::MockServer;
 use predicates::prelude::*;
 use std::thread::sleep;
 use std::time::Duration;
 use std::{process::Command, time};
-use utils::{setup_tmp_directory, teardown_tmp_directory};
+use utils::{find_report_file, setup_tmp_directory, teardown_tmp_directory};
 
 #[test]
 /// send a single valid request, expect a 200 response
@@ -53,17 +53,17 @@ fn scanner_recursive_request_scan() -> Result<(), Box<dyn std::error::Error>> {
 
     let js_mock = srv.mock(|when, then| {
         when.method(GET).path("/js");
-        then.status(301).header("Location", &srv.url("/js/"));
+        then.status(301).header("Location", srv.url("/js/"));
     });
 
     let js_prod_mock = srv.mock(|when, then| {
         when.method(GET).path("/js/prod");
-        then.status(301).header("Location", &srv.url("/js/prod/"));
+        then.status(301).header("Location", srv.url("/js/prod/"));
     });
 
     let js_dev_mock = srv.mock(|when, then| {
         when.method(GET).path("/js/dev");
-        then.status(301).header("Location", &srv.url("/js/dev/"));
+        then.status(301).header("Location", srv.url("/js/dev/"));
     });
 
     let js_dev_file_mock = srv.mock(|when, then| {
@@ -91,9 +91,9 @@ fn scanner_recursive_request_scan() -> Result<(), Box<dyn std::error::Error>> {
             .and(predicate::str::is_match("200.*js/dev/file.js").unwrap()),
     );
 
-    assert_eq!(js_mock.hits(), 2);
-    assert_eq!(js_prod_mock.hits(), 2);
-    assert_eq!(js_dev_mock.hits(), 2);
+    assert_eq!(js_mock.hits(), 1);
+    assert_eq!(js_prod_mock.hits(), 1);
+    assert_eq!(js_dev_mock.hits(), 1);
     assert_eq!(js_dev_file_mock.hits(), 1);
 
     teardown_tmp_directory(tmp_dir);
@@ -116,17 +116,17 @@ fn scanner_recursive_request_scan_using_only_success_responses(
 
     let js_mock = srv.mock(|when, then| {
         when.method(GET).path("/js/");
-        then.status(200).header("Location", &srv.url("/js/"));
+        then.status(200).header("Location", srv.url("/js/"));
     });
 
     let js_prod_mock = srv.mock(|when, then| {
         when.method(GET).path("/js/prod/");
-        then.status(200).header("Location", &srv.url("/js/prod/"));
+        then.status(200).header("Location", srv.url("/js/prod/"));
     });
 
     let js_dev_mock = srv.mock(|when, then| {
         when.method(GET).path("/js/dev/");
-        then.status(200).header("Location", &srv.url("/js/dev/"));
+        then.status(200).header("Location", srv.url("/js/dev/"));
     });
 
     let js_dev_file_mock = srv.mock(|when, then| {
@@ -155,9 +155,9 @@ fn scanner_recursive_request_scan_using_only_success_responses(
             .and(predicate::str::is_match("200.*js/dev/file.js").unwrap()),
     );
 
-    assert_eq!(js_mock.hits(), 3);
-    assert_eq!(js_prod_mock.hits(), 3);
-    assert_eq!(js_dev_mock.hits(), 3);
+    assert_eq!(js_mock.hits(), 2);
+    assert_eq!(js_prod_mock.hits(), 2);
+    assert_eq!(js_dev_mock.hits(), 2);
     assert_eq!(js_dev_file_mock.hits(), 1);
 
     teardown_tmp_directory(tmp_dir);
@@ -385,6 +385,47 @@ fn scanner_single_request_replayed_to_proxy() -> Result<(), Box<dyn std::error::
     Ok(())
 }
 
+#[test]
+/// hosts given to --proxy-exclude-hosts should be requested directly instead of through --proxy
+fn scanner_proxy_exclude_hosts_bypasses_proxy() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let proxy = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(&["LICENSE".to_string()], "wordlist")?;
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(200).body("this is a test");
+    });
+
+    let proxy_mock = proxy.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(502);
+    });
+
+    let cmd = Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--proxy")
+        .arg(format!("http://{}", proxy.address()))
+        .arg("--proxy-exclude-hosts")
+        .arg("127.0.0.1")
+        .unwrap();
+
+    cmd.assert().success().stdout(
+        predicate::str::contains("/LICENSE")
+            .and(predicate::str::contains("200"))
+            .and(predicate::str::contains("14c")),
+    );
+
+    assert_eq!(mock.hits(), 1);
+    assert_eq!(proxy_mock.hits(), 0);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
 #[test]
 /// send a single valid request, filter the size of the response, expect one out of 2 urls
 fn scanner_single_request_scan_with_filtered_result() -> Result<(), Box<dyn std::error::Error>> {
@@ -554,7 +595,8 @@ fn scanner_single_request_scan_with_regex_filtered_result() {
 }
 
 #[test]
-/// send a request to a 403 directory, expect recursion to work into the 403
+/// send a request to a 403 directory, expect recursion to work into the 403 when 403 is given to
+/// --recurse-on-status
 fn scanner_recursion_works_with_403_directories() {
     let srv = MockServer::start();
     let (tmp_dir, file) =
@@ -582,6 +624,8 @@ fn scanner_recursion_works_with_403_directories() {
         .arg(srv.url("/"))
         .arg("--wordlist")
         .arg(file.as_os_str())
+        .arg("--recurse-on-status")
+        .arg("200,301,302,403")
         .unwrap();
 
     cmd.assert().success().stdout(
@@ -598,7 +642,49 @@ fn scanner_recursion_works_with_403_directories() {
 
     assert_eq!(mock.hits(), 1);
     assert_eq!(found_anyway.hits(), 1);
-    assert_eq!(forbidden_dir.hits(), 3);
+    assert_eq!(forbidden_dir.hits(), 2);
+
+    teardown_tmp_directory(tmp_dir);
+}
+
+#[test]
+/// directories found with a status that isn't in --recurse-on-status (403 by default) are
+/// reported, but not recursed into
+fn scanner_recurse_on_status_skips_other_status_codes() {
+    let srv = MockServer::start();
+    let (tmp_dir, file) =
+        setup_tmp_directory(&["LICENSE".to_string(), "ignored/".to_string()], "wordlist").unwrap();
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(200).body("this is a test");
+    });
+
+    let forbidden_dir = srv.mock(|when, then| {
+        when.method(GET).path("/ignored/");
+        then.status(403);
+    });
+
+    let not_requested = srv.mock(|when, then| {
+        when.method(GET).path("/ignored/LICENSE");
+        then.status(200).body("this is a test");
+    });
+
+    let cmd = Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .unwrap();
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("/ignored/").and(predicate::str::contains("403")));
+
+    assert_eq!(mock.hits(), 1);
+    assert_eq!(forbidden_dir.hits(), 1);
+    assert_eq!(not_requested.hits(), 0);
 
     teardown_tmp_directory(tmp_dir);
 }
@@ -689,7 +775,7 @@ fn collect_backups_makes_appropriate_requests() {
     let srv = MockServer::start();
     let (tmp_dir, file) = setup_tmp_directory(&["LICENSE.txt".to_string()], "wordlist").unwrap();
 
-    let valid_paths = vec![
+    let valid_paths = [
         "/LICENSE.txt",
         "/LICENSE.txt~",
         "/LICENSE.txt.bak",
@@ -864,7 +950,7 @@ fn scanner_forced_recursion_ignores_normal_redirect_logic() -> Result<(), Box<dy
         when.method(GET).path("/LICENSE");
         then.status(301)
             .body("this is a test")
-            .header("Location", &srv.url("/LICENSE"));
+            .header("Location", srv.url("/LICENSE"));
     });
 
     let mock2 = srv.mock(|when, then| {
@@ -902,7 +988,7 @@ fn scanner_forced_recursion_ignores_normal_redirect_logic() -> Result<(), Box<dy
     assert!(contents.contains("301"));
     assert!(contents.contains("14"));
 
-    assert_eq!(mock1.hits(), 2);
+    assert_eq!(mock1.hits(), 1);
     assert_eq!(mock2.hits(), 1);
     assert_eq!(mock3.hits(), 0);
     assert_eq!(mock4.hits(), 0);
@@ -911,3 +997,1914 @@ fn scanner_forced_recursion_ignores_normal_redirect_logic() -> Result<(), Box<dy
 
     Ok(())
 }
+
+#[test]
+/// send a request that redirects to an unscanned location while 3xx codes are excluded by -s;
+/// expect the redirect to be reported with the unconfirmed marker
+fn scanner_reports_unconfirmed_redirects() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) =
+        setup_tmp_directory(&["LICENSE".to_string(), "old".to_string()], "wordlist")?;
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(200).body("this is a test");
+    });
+
+    let redirect_mock = srv.mock(|when, then| {
+        when.method(GET).path("/old");
+        then.status(302).header("Location", "/new-home");
+    });
+
+    let cmd = Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--status-codes")
+        .arg("200")
+        .arg("--include-unconfirmed-redirects")
+        .unwrap();
+
+    cmd.assert().success().stdout(
+        predicate::str::contains("/LICENSE")
+            .and(predicate::str::contains("→"))
+            .and(predicate::str::contains("/old"))
+            .and(predicate::str::contains("/new-home")),
+    );
+
+    assert_eq!(mock.hits(), 1);
+    assert_eq!(redirect_mock.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// send a request that redirects while 3xx codes are excluded by -s, without
+/// --include-unconfirmed-redirects; expect the redirect to remain hidden
+fn scanner_hides_unconfirmed_redirects_by_default() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) =
+        setup_tmp_directory(&["LICENSE".to_string(), "old".to_string()], "wordlist")?;
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(200).body("this is a test");
+    });
+
+    let redirect_mock = srv.mock(|when, then| {
+        when.method(GET).path("/old");
+        then.status(302).header("Location", "/new-home");
+    });
+
+    let cmd = Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--status-codes")
+        .arg("200")
+        .unwrap();
+
+    cmd.assert().success().stdout(
+        predicate::str::contains("/LICENSE")
+            .and(predicate::str::contains("→").not())
+            .and(predicate::str::contains("/new-home").not()),
+    );
+
+    assert_eq!(mock.hits(), 1);
+    assert_eq!(redirect_mock.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --notify-url should result in a single POST w/ a json summary once the scan completes
+fn scanner_posts_summary_to_notify_url() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(&["LICENSE".to_string()], "wordlist")?;
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(200).body("this is a test");
+    });
+
+    let notify_mock = srv.mock(|when, then| {
+        when.method(POST)
+            .path("/hook")
+            .header("content-type", "application/json")
+            .body_contains("\"total_urls\":")
+            .body_contains("\"scan_time\":")
+            .body_contains("\"hostname\":");
+        then.status(200);
+    });
+
+    let cmd = Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--notify-url")
+        .arg(srv.url("/hook"))
+        .unwrap();
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("/LICENSE"));
+
+    assert_eq!(mock.hits(), 1);
+    assert_eq!(notify_mock.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --redirect-as-discovery should scan the destination of a same-host redirect, but not
+/// destinations that are already known (i.e. redirects back to the initial target)
+fn scanner_scans_redirect_destinations_as_discovery() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) =
+        setup_tmp_directory(&["LICENSE".to_string(), "old".to_string()], "wordlist")?;
+
+    let redirect_mock = srv.mock(|when, then| {
+        when.method(GET).path("/old");
+        then.status(302).header("Location", "/new-home/");
+    });
+
+    let loop_mock = srv.mock(|when, then| {
+        when.method(GET).path("/new-home/old");
+        then.status(302).header("Location", "/");
+    });
+
+    let discovered_mock = srv.mock(|when, then| {
+        when.method(GET).path("/new-home/LICENSE");
+        then.status(200).body("this is a test");
+    });
+
+    let cmd = Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--redirect-as-discovery")
+        .unwrap();
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("/new-home/LICENSE"));
+
+    assert_eq!(redirect_mock.hits(), 1);
+    assert_eq!(loop_mock.hits(), 1);
+    assert_eq!(discovered_mock.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --follow-meta-refresh should scan the destination of a same-host meta refresh, but not
+/// destinations that are already known (i.e. refreshes back to the initial target)
+fn scanner_scans_meta_refresh_destinations() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) =
+        setup_tmp_directory(&["LICENSE".to_string(), "old".to_string()], "wordlist")?;
+
+    let refresh_mock = srv.mock(|when, then| {
+        when.method(GET).path("/old");
+        then.status(200).header("Content-Type", "text/html").body(
+            r#"<html><head><meta http-equiv="refresh" content="0; url=/new-home/"></head></html>"#,
+        );
+    });
+
+    let loop_mock = srv.mock(|when, then| {
+        when.method(GET).path("/new-home/old");
+        then.status(200)
+            .header("Content-Type", "text/html")
+            .body(r#"<html><head><meta http-equiv="refresh" content="0; url=/"></head></html>"#);
+    });
+
+    let discovered_mock = srv.mock(|when, then| {
+        when.method(GET).path("/new-home/LICENSE");
+        then.status(200).body("this is a test");
+    });
+
+    let cmd = Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--follow-meta-refresh")
+        .unwrap();
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("/new-home/LICENSE"));
+
+    assert_eq!(refresh_mock.hits(), 1);
+    assert_eq!(loop_mock.hits(), 1);
+    assert_eq!(discovered_mock.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// without --redirect-as-discovery, redirect destinations aren't scanned
+fn scanner_ignores_redirect_destinations_by_default() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) =
+        setup_tmp_directory(&["LICENSE".to_string(), "old".to_string()], "wordlist")?;
+
+    let redirect_mock = srv.mock(|when, then| {
+        when.method(GET).path("/old");
+        then.status(302).header("Location", "/new-home/");
+    });
+
+    let discovered_mock = srv.mock(|when, then| {
+        when.method(GET).path("/new-home/LICENSE");
+        then.status(200).body("this is a test");
+    });
+
+    let cmd = Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .unwrap();
+
+    cmd.assert().success();
+
+    assert_eq!(redirect_mock.hits(), 1);
+    assert_eq!(discovered_mock.hits(), 0);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --collect-forms should scan a form's same-origin action url
+fn scanner_scans_collected_form_actions() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(&["LICENSE".to_string()], "wordlist")?;
+
+    let form_mock = srv.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(200)
+            .body(r#"<html><form action="/portal/submit?id=1" method="post"></form></html>"#);
+    });
+
+    let discovered_mock = srv.mock(|when, then| {
+        when.method(GET).path("/portal/submit/LICENSE");
+        then.status(200).body("this is a test");
+    });
+
+    let cmd = Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--collect-forms")
+        .unwrap();
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("/portal/submit/LICENSE"));
+
+    assert_eq!(form_mock.hits(), 1);
+    assert_eq!(discovered_mock.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --replay-proxy should replay positive results through the proxy, limited to the status codes
+/// given via --replay-codes
+fn scanner_replays_positive_results_through_replay_proxy() -> Result<(), Box<dyn std::error::Error>>
+{
+    let srv = MockServer::start();
+    let proxy = MockServer::start();
+    let (tmp_dir, file) =
+        setup_tmp_directory(&["LICENSE".to_string(), "old".to_string()], "wordlist")?;
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(200).body("this is a test");
+    });
+
+    let redirect_mock = srv.mock(|when, then| {
+        when.method(GET).path("/old");
+        then.status(302).header("Location", "/new-home");
+    });
+
+    let replayed_mock = proxy.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(200).body("this is a test");
+    });
+
+    let not_replayed_mock = proxy.mock(|when, then| {
+        when.method(GET).path("/old");
+        then.status(302).header("Location", "/new-home");
+    });
+
+    let cmd = Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--replay-proxy")
+        .arg(format!("http://{}", proxy.address()))
+        .arg("--replay-codes")
+        .arg("200")
+        .unwrap();
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("/LICENSE").and(predicate::str::contains("/old")));
+
+    assert_eq!(mock.hits(), 1);
+    assert_eq!(redirect_mock.hits(), 1);
+    assert_eq!(replayed_mock.hits(), 1);
+    assert_eq!(not_replayed_mock.hits(), 0);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --collect-parameters should write the parameter names found in responses to
+/// ferox-params-<host>-<timestamp>.txt and print a summary to stderr
+fn scanner_collects_parameters_from_responses() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(&["LICENSE".to_string()], "wordlist")?;
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(200)
+            .body(r#"<a href="/search?q=stuff&amp;page=2">search</a><a href="/view?id=1">"#);
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .current_dir(tmp_dir.path())
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--collect-parameters")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Params").and(predicate::str::contains("id, page, q")));
+
+    let params_file =
+        find_report_file(tmp_dir.path(), "params", &srv.address().to_string()).unwrap();
+
+    assert_eq!(std::fs::read_to_string(params_file)?, "id\npage\nq\n");
+
+    assert_eq!(mock.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --check-previous-state should skip urls found with a non-error status in the given state
+/// file, while still requesting those that errored previously
+fn scanner_skips_urls_found_in_previous_state() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) =
+        setup_tmp_directory(&["LICENSE".to_string(), "missing".to_string()], "wordlist")?;
+
+    let state_file = tmp_dir.path().join("previous.state");
+    std::fs::write(
+        &state_file,
+        format!(
+            r#"{{"responses":[{{"type":"response","url":"{}","status":200,"method":"GET"}},{{"type":"response","url":"{}","status":404,"method":"GET"}}]}}"#,
+            srv.url("/LICENSE"),
+            srv.url("/missing")
+        ),
+    )?;
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(200).body("this is a test");
+    });
+
+    let mock_two = srv.mock(|when, then| {
+        when.method(GET).path("/missing");
+        then.status(200).body("this is also a test");
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--check-previous-state")
+        .arg(state_file.as_os_str())
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("/missing").and(predicate::str::contains("/LICENSE").not()),
+        )
+        .stderr(predicate::str::contains("Skipped 1 urls already found in"));
+
+    assert_eq!(mock.hits(), 0);
+    assert_eq!(mock_two.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --connection-errors-to-file should record timeouts as one json object per line
+fn scanner_writes_timeouts_to_connection_error_log() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(&["LICENSE".to_string()], "wordlist")?;
+    let error_log = tmp_dir.path().join("errors.ndjson");
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(200)
+            .body("this is a test")
+            .delay(Duration::from_secs(3));
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--timeout")
+        .arg("1")
+        .arg("--connection-errors-to-file")
+        .arg(error_log.as_os_str())
+        .assert()
+        .success();
+
+    let contents = std::fs::read_to_string(&error_log)?;
+
+    assert!(contents.contains(&format!(r#""url":"{}""#, srv.url("/LICENSE"))));
+    assert!(contents.contains(r#""error_type":"timeout""#));
+    assert!(contents.contains(r#""timestamp":"#));
+
+    assert_eq!(mock.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --export-metasploit should write a resource script with a curl check for each 200 and an
+/// http_login module run for each url that looks like an admin panel
+fn scanner_exports_metasploit_resource_script() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(
+        &["LICENSE".to_string(), "dashboard".to_string()],
+        "wordlist",
+    )?;
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(200).body("this is a test");
+    });
+
+    let mock_two = srv.mock(|when, then| {
+        when.method(GET).path("/dashboard");
+        then.status(401);
+    });
+
+    let rc_file = tmp_dir.path().join("ferox.rc");
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--export-metasploit")
+        .arg(rc_file.as_os_str())
+        .assert()
+        .success();
+
+    let script = std::fs::read_to_string(rc_file)?;
+
+    assert!(script.contains(&format!(
+        r#"curl -s -o /dev/null -w "%{{http_code}}" '{}'"#,
+        srv.url("/LICENSE")
+    )));
+    assert_eq!(script.matches("curl ").count(), 1); // 401 on /dashboard gets no curl check
+    assert!(script.contains(&format!(
+        "use auxiliary/scanner/http/http_login\nset RHOSTS {}\nset RPORT {}\nset SSL false\nset AUTH_URI /dashboard\nrun\nback\n",
+        srv.host(),
+        srv.port()
+    )));
+
+    assert_eq!(mock.hits(), 1);
+    assert_eq!(mock_two.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+/// answer every A query received on the returned socket's port with 127.0.0.1; all other query
+/// types get an empty answer section
+fn start_local_dns_server() -> std::net::SocketAddr {
+    let socket = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+    let addr = socket.local_addr().unwrap();
+
+    std::thread::spawn(move || {
+        let mut buf = [0u8; 512];
+
+        while let Ok((len, peer)) = socket.recv_from(&mut buf) {
+            // question starts after the 12 byte header; name is a series of length-prefixed
+            // labels, terminated by a zero byte, followed by 2 bytes of type and 2 of class
+            let mut end = 12;
+            while end < len && buf[end] != 0 {
+                end += buf[end] as usize + 1;
+            }
+            let qtype = u16::from_be_bytes([buf[end + 1], buf[end + 2]]);
+            let question = &buf[12..end + 5];
+
+            let is_a_record = qtype == 1;
+
+            let mut response = Vec::new();
+            response.extend_from_slice(&buf[..2]); // id
+            response.extend_from_slice(&[0x81, 0x80]); // standard response, no error
+            response.extend_from_slice(&[0, 1, 0, is_a_record as u8, 0, 0, 0, 0]);
+            response.extend_from_slice(question);
+
+            if is_a_record {
+                // pointer to the name in the question, type A, class IN, ttl 60, 127.0.0.1
+                response.extend_from_slice(&[0xc0, 0x0c, 0, 1, 0, 1, 0, 0, 0, 60, 0, 4]);
+                response.extend_from_slice(&[127, 0, 0, 1]);
+            }
+
+            socket.send_to(&response, peer).unwrap();
+        }
+    });
+
+    addr
+}
+
+#[test]
+/// --dns-server should be used to resolve the target's hostname, allowing scans of hosts the OS
+/// resolver doesn't know about
+fn scanner_resolves_hostnames_with_dns_server() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(&["LICENSE".to_string()], "wordlist")?;
+    let dns_server = start_local_dns_server();
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(200).body("this is a test");
+    });
+
+    let url = format!("http://feroxbuster.test:{}/", srv.port());
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(&url)
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--dns-server")
+        .arg(dns_server.to_string())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!("{}LICENSE", url)));
+
+    assert_eq!(mock.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --collect-technology-stack should print the technologies detected from headers, cookies, and
+/// response bodies, grouped by host
+fn scanner_collects_technology_stack() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) =
+        setup_tmp_directory(&["LICENSE".to_string(), "blog".to_string()], "wordlist")?;
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(200)
+            .header("Server", "nginx/1.16.1")
+            .header("Set-Cookie", "PHPSESSID=stuff; path=/")
+            .body("this is a test");
+    });
+
+    let mock_two = srv.mock(|when, then| {
+        when.method(GET).path("/blog");
+        then.status(200)
+            .body(r#"<script src="/wp-includes/js/jquery.js"></script>"#);
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--collect-technology-stack")
+        .assert()
+        .success()
+        .stderr(
+            predicate::str::contains("Technologies")
+                .and(predicate::str::contains(srv.address().to_string()))
+                .and(predicate::str::contains("nginx/1.16.1"))
+                .and(predicate::str::contains("PHP"))
+                .and(predicate::str::contains("WordPress")),
+        );
+
+    assert_eq!(mock.hits(), 1);
+    assert_eq!(mock_two.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// duplicate wordlist entries should only be requested once, unless --no-wordlist-dedup and
+/// --no-request-dedup are used
+fn scanner_dedups_wordlist_unless_told_not_to() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(
+        &[
+            "LICENSE".to_string(),
+            "LICENSE".to_string(),
+            "stuff".to_string(),
+        ],
+        "wordlist",
+    )?;
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(200).body("this is a test");
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .assert()
+        .success();
+
+    assert_eq!(mock.hits(), 1);
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--no-wordlist-dedup")
+        .arg("--no-request-dedup")
+        .assert()
+        .success();
+
+    assert_eq!(mock.hits(), 3);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --max-response-size should skip bodies whose Content-Length is too large, and say so in the
+/// json output
+fn scanner_skips_bodies_larger_than_max_response_size() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) =
+        setup_tmp_directory(&["LICENSE".to_string(), "tiny".to_string()], "wordlist")?;
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(200).body("this is a test");
+    });
+
+    let mock_two = srv.mock(|when, then| {
+        when.method(GET).path("/tiny");
+        then.status(200).body("tiny");
+    });
+
+    let outfile = tmp_dir.path().join("output");
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--max-response-size")
+        .arg("5")
+        .arg("--json")
+        .arg("--output")
+        .arg(outfile.as_os_str())
+        .assert()
+        .success();
+
+    let contents = std::fs::read_to_string(outfile)?;
+    let responses: Vec<serde_json::Value> = contents
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .filter(|value: &serde_json::Value| value["type"] == "response")
+        .collect();
+
+    let license = responses
+        .iter()
+        .find(|value| value["path"] == "/LICENSE")
+        .unwrap();
+    assert_eq!(license["body_truncated"], true);
+    assert_eq!(license["content_length"], 14);
+    assert_eq!(license["word_count"], 0);
+
+    let tiny = responses
+        .iter()
+        .find(|value| value["path"] == "/tiny")
+        .unwrap();
+    assert_eq!(tiny["body_truncated"], false);
+    assert_eq!(tiny["word_count"], 1);
+
+    assert_eq!(mock.hits(), 1);
+    assert_eq!(mock_two.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --max-urls-per-scan should stop a scan from requesting the rest of the wordlist once it has
+/// found enough urls
+fn scanner_stops_after_max_urls_per_scan() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let words: Vec<_> = (0..20).map(|i| format!("word{}", i)).collect();
+    let (tmp_dir, file) = setup_tmp_directory(&words, "wordlist")?;
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET)
+            .path_matches(regex::Regex::new("^/word[0-9]+$").unwrap());
+        then.status(200).body("this is a test");
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--threads")
+        .arg("1")
+        .arg("--max-urls-per-scan")
+        .arg("2")
+        .assert()
+        .success();
+
+    // the single in-flight request may still finish after the limit is hit
+    assert!(mock.hits() >= 2);
+    assert!(mock.hits() <= 3);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// a recursive scan shouldn't re-send a request that another scan already sent, unless
+/// --no-request-dedup is used
+fn scanner_skips_requests_already_sent_by_another_scan() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(&["admin".to_string()], "wordlist")?;
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET).path("/admin/");
+        then.status(200).body("this is a test");
+    });
+
+    let mock_root = srv.mock(|when, then| {
+        when.method(GET).path("/");
+        then.status(404);
+    });
+
+    // with --add-slash, the implicit empty word at the top of the wordlist formats to "/", so
+    // the recursive scan of /admin/ requests the same base url as the root scan
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--add-slash")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "Skipped 1 duplicate requests that were already sent",
+        ));
+
+    let deduped_hits = mock_root.hits();
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--add-slash")
+        .arg("--no-request-dedup")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("duplicate requests").not());
+
+    assert!(mock.hits() > 0);
+    assert_eq!(mock_root.hits() - deduped_hits, deduped_hits + 1);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --adaptive-wordlist should request words that share a prefix with a found word sooner than
+/// their position in the wordlist would otherwise allow
+fn scanner_adaptive_wordlist_promotes_words_with_found_prefix(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+
+    let mut words = vec!["config.php".to_string()];
+    words.extend((0..150).map(|i| format!("filler{}", i)));
+    words.push("configuration".to_string());
+    words[60] = "late".to_string();
+
+    let (tmp_dir, file) = setup_tmp_directory(&words, "wordlist")?;
+
+    for path in ["/config.php", "/configuration", "/late"] {
+        srv.mock(|when, then| {
+            when.method(GET).path(path);
+            then.status(200).body("this is a test");
+        });
+    }
+
+    let run = |adaptive: bool| -> Result<String, Box<dyn std::error::Error>> {
+        let outfile = tmp_dir.path().join(format!("output-{}", adaptive));
+
+        let mut cmd = Command::cargo_bin("feroxbuster")?;
+        cmd.arg("--url")
+            .arg(srv.url("/"))
+            .arg("--wordlist")
+            .arg(file.as_os_str())
+            .arg("--threads")
+            .arg("1")
+            .arg("--no-recursion")
+            .arg("--output")
+            .arg(outfile.as_os_str());
+
+        if adaptive {
+            cmd.arg("--adaptive-wordlist");
+        }
+
+        cmd.assert().success();
+
+        Ok(std::fs::read_to_string(outfile)?)
+    };
+
+    // with a single thread, results are written in the order their words were requested
+    let contents = run(false)?;
+    assert!(contents.find("/late").unwrap() < contents.find("/configuration").unwrap());
+
+    let contents = run(true)?;
+    assert!(contents.find("/config.php").unwrap() < contents.find("/configuration").unwrap());
+    assert!(contents.find("/configuration").unwrap() < contents.find("/late").unwrap());
+
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --collect-source-maps should request the source map referenced by a javascript file, scan the
+/// original sources it lists, and write their paths to ferox-sourcemaps-<host>-<timestamp>.txt
+fn scanner_collects_source_maps_from_javascript() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(&["app.js".to_string()], "wordlist")?;
+
+    let js_mock = srv.mock(|when, then| {
+        when.method(GET).path("/app.js");
+        then.status(200)
+            .body("console.log('hi');\n//# sourceMappingURL=app.js.map");
+    });
+
+    let map_mock = srv.mock(|when, then| {
+        when.method(GET).path("/app.js.map");
+        then.status(200)
+            .body(r#"{"version":3,"sources":["webpack:///./src/secret-admin.js"],"mappings":""}"#);
+    });
+
+    let source_mock = srv.mock(|when, then| {
+        when.method(GET).path("/src/secret-admin.js");
+        then.status(200).body("const password = 'stuff';");
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .current_dir(tmp_dir.path())
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--collect-source-maps")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("/src/secret-admin.js"))
+        .stderr(predicate::str::contains("Saved 1 source paths"));
+
+    let source_maps_file =
+        find_report_file(tmp_dir.path(), "sourcemaps", &srv.address().to_string()).unwrap();
+
+    assert_eq!(
+        std::fs::read_to_string(source_maps_file)?,
+        "src/secret-admin.js\n"
+    );
+
+    assert_eq!(js_mock.hits(), 1);
+    assert_eq!(map_mock.hits(), 1);
+    assert_eq!(source_mock.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --collect-meta-tags should write the meta tags of html pages to ferox-metatags-<host>-<timestamp>.json and
+/// call out the interesting ones on stderr
+fn scanner_collects_meta_tags() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) =
+        setup_tmp_directory(&["blog".to_string(), "LICENSE".to_string()], "wordlist")?;
+
+    let blog_mock = srv.mock(|when, then| {
+        when.method(GET).path("/blog");
+        then.status(200).header("Content-Type", "text/html").body(
+            r#"<html><head><meta name="generator" content="WordPress 6.1">
+                <meta name="viewport" content="width=device-width"></head></html>"#,
+        );
+    });
+
+    let license_mock = srv.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(200)
+            .header("Content-Type", "text/plain")
+            .body(r#"<meta name="author" content="not html">"#);
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .current_dir(tmp_dir.path())
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--collect-meta-tags")
+        .assert()
+        .success()
+        .stderr(
+            predicate::str::contains(format!("{} generator: WordPress 6.1", srv.url("/blog")))
+                .and(predicate::str::contains("viewport").not())
+                .and(predicate::str::contains("not html").not())
+                .and(predicate::str::contains("Saved meta tags of 1 pages")),
+        );
+
+    let meta_tags_file =
+        find_report_file(tmp_dir.path(), "metatags", &srv.address().to_string()).unwrap();
+
+    let meta_tags: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(meta_tags_file)?)?;
+
+    assert_eq!(
+        meta_tags,
+        serde_json::json!({
+            srv.url("/blog"): {
+                "generator": "WordPress 6.1",
+                "viewport": "width=device-width"
+            }
+        })
+    );
+
+    assert_eq!(blog_mock.hits(), 1);
+    assert_eq!(license_mock.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --collect-page-titles should show the title of html pages next to their urls and write them
+/// to ferox-titles-<host>-<timestamp>.txt
+fn scanner_collects_page_titles() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) =
+        setup_tmp_directory(&["admin".to_string(), "LICENSE".to_string()], "wordlist")?;
+
+    let admin_mock = srv.mock(|when, then| {
+        when.method(GET).path("/admin");
+        then.status(200)
+            .header("Content-Type", "text/html")
+            .body("<html><head><title>Admin Panel</title></head></html>");
+    });
+
+    let license_mock = srv.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(200)
+            .header("Content-Type", "text/plain")
+            .body("<title>not html</title>");
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .current_dir(tmp_dir.path())
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--collect-page-titles")
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("/admin")
+                .and(predicate::str::contains("(Admin Panel)"))
+                .and(predicate::str::contains("/LICENSE"))
+                .and(predicate::str::contains("(not html)").not()),
+        )
+        .stderr(predicate::str::contains("Saved 1 page titles"));
+
+    let titles_file =
+        find_report_file(tmp_dir.path(), "titles", &srv.address().to_string()).unwrap();
+
+    assert_eq!(
+        std::fs::read_to_string(titles_file)?,
+        format!("{} (Admin Panel)\n", srv.url("/admin"))
+    );
+
+    assert_eq!(admin_mock.hits(), 1);
+    assert_eq!(license_mock.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --multipart-wordlist should POST one file upload per word to the target url, and show the
+/// uploaded filename alongside each response
+fn scanner_multipart_wordlist_uploads_files() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(
+        &["shell.php".to_string(), "image.png".to_string()],
+        "uploads",
+    )?;
+
+    let php_mock = srv.mock(|when, then| {
+        when.method(POST)
+            .path("/upload")
+            .header_exists("content-type")
+            .body_contains("name=\"upload\"; filename=\"shell.php\"")
+            .body_contains("Content-Type: application/x-httpd-php");
+        then.status(403).body("php files are not allowed");
+    });
+
+    let png_mock = srv.mock(|when, then| {
+        when.method(POST)
+            .path("/upload")
+            .body_contains("name=\"upload\"; filename=\"image.png\"")
+            .body_contains("Content-Type: image/png");
+        then.status(200).body("uploaded");
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/upload"))
+        .arg("--multipart-wordlist")
+        .arg(file.as_os_str())
+        .arg("--multipart-field")
+        .arg("upload")
+        .arg("--status-codes")
+        .arg("200")
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("/upload")
+                .and(predicate::str::contains("[file: image.png]"))
+                .and(predicate::str::contains("[file: shell.php]").not()),
+        );
+
+    assert_eq!(php_mock.hits(), 1);
+    assert_eq!(png_mock.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --collect-comments-only should only GET the 200 html page found with HEAD requests, and
+/// print the comments in its body
+fn scanner_collect_comments_only_gets_html_pages() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) =
+        setup_tmp_directory(&["index".to_string(), "LICENSE".to_string()], "wordlist")?;
+
+    let head_html = srv.mock(|when, then| {
+        when.method(HEAD).path("/index");
+        then.status(200).header("Content-Type", "text/html");
+    });
+
+    let get_html = srv.mock(|when, then| {
+        when.method(GET).path("/index");
+        then.status(200)
+            .header("Content-Type", "text/html")
+            .body("<html><!-- TODO: remove the debug endpoint --></html>");
+    });
+
+    let head_text = srv.mock(|when, then| {
+        when.method(HEAD).path("/LICENSE");
+        then.status(200).header("Content-Type", "text/plain");
+    });
+
+    let get_text = srv.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(200)
+            .header("Content-Type", "text/plain")
+            .body("<!-- not html -->");
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--collect-comments-only")
+        .unwrap()
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("TODO: remove the debug endpoint")
+                .and(predicate::str::contains("/LICENSE"))
+                .and(predicate::str::contains("not html").not()),
+        );
+
+    assert_eq!(head_html.hits(), 1);
+    assert_eq!(get_html.hits(), 1);
+    assert_eq!(head_text.hits(), 1);
+    assert_eq!(get_text.hits(), 0);
+
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --websocket-discovery should mark found urls that agree to a websocket upgrade with [WS]
+fn scanner_marks_websocket_urls() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) =
+        setup_tmp_directory(&["ws".to_string(), "LICENSE".to_string()], "wordlist")?;
+
+    let upgrade_mock = srv.mock(|when, then| {
+        when.method(GET)
+            .path("/ws")
+            .header("upgrade", "websocket")
+            .header("sec-websocket-version", "13");
+        then.status(101)
+            .header("Connection", "Upgrade")
+            .header("Upgrade", "websocket");
+    });
+
+    let ws_mock = srv.mock(|when, then| {
+        when.method(GET).path("/ws");
+        then.status(200).body("websocket endpoint");
+    });
+
+    let license_mock = srv.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(200).body("this is a test");
+    });
+
+    let cmd = Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--websocket-discovery")
+        .unwrap();
+
+    let output = String::from_utf8_lossy(&cmd.stdout);
+    let ws_line = output.lines().find(|line| line.contains("/ws")).unwrap();
+    let license_line = output
+        .lines()
+        .find(|line| line.contains("/LICENSE"))
+        .unwrap();
+
+    assert!(ws_line.contains("[WS]"));
+    assert!(!license_line.contains("[WS]"));
+
+    assert_eq!(upgrade_mock.hits(), 1);
+    assert_eq!(ws_mock.hits(), 1);
+    // the scan's request and the probe, which gets a 200 instead of a 101
+    assert_eq!(license_mock.hits(), 2);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --cors-discovery should warn about urls that trust a foreign origin and include the finding
+/// in the json output
+fn scanner_warns_about_cors_misconfigurations() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) =
+        setup_tmp_directory(&["api".to_string(), "LICENSE".to_string()], "wordlist")?;
+    let outfile = tmp_dir.path().join("output");
+
+    let cors_mock = srv.mock(|when, then| {
+        when.method(GET)
+            .path("/api")
+            .header("origin", "https://evil.com");
+        then.status(200)
+            .header("Access-Control-Allow-Origin", "https://evil.com")
+            .body("{}");
+    });
+
+    let api_mock = srv.mock(|when, then| {
+        when.method(GET).path("/api");
+        then.status(200).body("{}");
+    });
+
+    let license_mock = srv.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(200).body("this is a test");
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--cors-discovery")
+        .arg("--json")
+        .arg("--output")
+        .arg(outfile.as_os_str())
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("CORS")
+                .and(predicate::str::contains("/api reflects arbitrary origins"))
+                .and(predicate::str::contains("/LICENSE reflects").not()),
+        );
+
+    let contents = std::fs::read_to_string(outfile)?;
+    let api_line = contents
+        .lines()
+        .find(|line| line.contains(r#""type":"response""#) && line.contains("/api"))
+        .unwrap();
+
+    assert!(api_line.contains(r#""cors":"reflected_origin""#));
+
+    assert_eq!(cors_mock.hits(), 1);
+    assert_eq!(api_mock.hits(), 1);
+    // the scan's request and the probe, which isn't answered with any cors headers
+    assert_eq!(license_mock.hits(), 2);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --options-discovery should send an OPTIONS request to discovered directories, show the
+/// methods beyond GET/HEAD, and warn about dangerous ones
+fn scanner_reports_methods_allowed_by_options() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) =
+        setup_tmp_directory(&["uploads".to_string(), "LICENSE".to_string()], "wordlist")?;
+
+    let redirect_mock = srv.mock(|when, then| {
+        when.method(GET).path("/uploads");
+        then.status(301).header("Location", srv.url("/uploads/"));
+    });
+
+    let options_mock = srv.mock(|when, then| {
+        when.method(OPTIONS).path("/uploads/");
+        then.status(200)
+            .header("Allow", "GET, HEAD, OPTIONS, PUT, DELETE");
+    });
+
+    let license_mock = srv.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(200).body("this is a test");
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--options-discovery")
+        .arg("--no-recursion")
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("[OPTIONS, PUT, DELETE]")
+                .and(predicate::str::contains("/uploads/ allows PUT, DELETE")),
+        );
+
+    assert_eq!(redirect_mock.hits(), 1);
+    // LICENSE isn't a directory, so only /uploads/ is asked about its methods
+    assert_eq!(options_mock.hits(), 1);
+    assert_eq!(license_mock.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --collect-api-versions should probe the versions following one found in a url, and print
+/// a table of the versions discovered
+fn scanner_collects_api_versions() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(&["api/v1/users".to_string()], "wordlist")?;
+
+    let users_mock = srv.mock(|when, then| {
+        when.method(GET).path("/api/v1/users");
+        then.status(200).body("[]");
+    });
+
+    let v2_mock = srv.mock(|when, then| {
+        when.method(GET).path("/api/v2/");
+        then.status(200).body("v2");
+    });
+
+    let v3_mock = srv.mock(|when, then| {
+        when.method(GET).path("/api/v3/");
+        then.status(404);
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--collect-api-versions")
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("/api/v2/").and(predicate::str::contains("/api/v3/").not()),
+        )
+        .stderr(
+            predicate::str::contains("API Versions")
+                .and(predicate::str::contains("/api/v1/, /api/v2/")),
+        );
+
+    assert_eq!(users_mock.hits(), 1);
+    // the directory is requested again once recursion into /api/v2/ begins
+    assert!(v2_mock.hits() >= 1);
+    assert_eq!(v3_mock.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --verbose-scan-start should print a line for each scan as it begins, including the depth and
+/// parent of recursive scans
+fn scanner_verbose_scan_start_prints_each_scan() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(&["js".to_string()], "wordlist")?;
+
+    let js_mock = srv.mock(|when, then| {
+        when.method(GET).path("/js");
+        then.status(301).header("Location", srv.url("/js/"));
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--verbose-scan-start")
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains(format!(
+                "[+] Starting scan: {} (depth: 0, wordlist: {})",
+                srv.url("/"),
+                file.display()
+            ))
+            .and(predicate::str::contains(format!(
+                "[+] Starting scan: {} (depth: 1, wordlist: {}, parent: {})",
+                srv.url("/js"),
+                file.display(),
+                srv.url("/")
+            ))),
+        );
+
+    assert_eq!(js_mock.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --collect-301-targets should save the source and destination of each 3xx response to
+/// ferox-redirects-<host>-<timestamp>.txt
+fn scanner_collects_301_targets() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(&["js".to_string(), "old".to_string()], "wordlist")?;
+
+    let js_mock = srv.mock(|when, then| {
+        when.method(GET).path("/js");
+        then.status(301).header("Location", srv.url("/js/"));
+    });
+
+    let old_mock = srv.mock(|when, then| {
+        when.method(GET).path("/old");
+        then.status(302).header("Location", "/new");
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .current_dir(tmp_dir.path())
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--no-recursion")
+        .arg("--collect-301-targets")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Saved 2 redirects"));
+
+    let redirects_file =
+        find_report_file(tmp_dir.path(), "redirects", &srv.address().to_string()).unwrap();
+
+    assert_eq!(
+        std::fs::read_to_string(redirects_file)?,
+        format!(
+            "{} → {}\n{} → {}\n",
+            srv.url("/js"),
+            srv.url("/js/"),
+            srv.url("/old"),
+            srv.url("/new")
+        )
+    );
+
+    assert_eq!(js_mock.hits(), 1);
+    assert_eq!(old_mock.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// without --collect-301-targets, no redirects file should be written
+fn scanner_does_not_collect_301_targets_by_default() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(&["js".to_string()], "wordlist")?;
+
+    let js_mock = srv.mock(|when, then| {
+        when.method(GET).path("/js");
+        then.status(301).header("Location", srv.url("/js/"));
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .current_dir(tmp_dir.path())
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--no-recursion")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Saved").not());
+
+    assert!(find_report_file(tmp_dir.path(), "redirects", &srv.address().to_string()).is_none());
+    assert_eq!(js_mock.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --depth should limit recursion based on the number of parent scans; a depth of 2 allows a
+/// scan one level beneath the initial target, but no deeper
+fn scanner_depth_limits_recursive_scans() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(&["js".to_string()], "wordlist")?;
+
+    let js_mock = srv.mock(|when, then| {
+        when.method(GET).path("/js");
+        then.status(301).header("Location", srv.url("/js/"));
+    });
+
+    let js_js_mock = srv.mock(|when, then| {
+        when.method(GET).path("/js/js");
+        then.status(301).header("Location", srv.url("/js/js/"));
+    });
+
+    let js_js_js_mock = srv.mock(|when, then| {
+        when.method(GET).path("/js/js/js");
+        then.status(301).header("Location", srv.url("/js/js/js/"));
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .current_dir(tmp_dir.path())
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--depth")
+        .arg("2")
+        .assert()
+        .success();
+
+    assert_eq!(js_mock.hits(), 1);
+    assert_eq!(js_js_mock.hits(), 1);
+    assert_eq!(js_js_js_mock.hits(), 0);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --collect-security-headers should report how many html pages were missing each security
+/// header, ignoring responses that aren't html pages
+fn scanner_collects_security_headers() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(
+        &[
+            "login".to_string(),
+            "admin".to_string(),
+            "LICENSE".to_string(),
+        ],
+        "wordlist",
+    )?;
+
+    let login_mock = srv.mock(|when, then| {
+        when.method(GET).path("/login");
+        then.status(200)
+            .header("Content-Type", "text/html")
+            .header("Content-Security-Policy", "default-src 'self'")
+            .header("X-Frame-Options", "DENY")
+            .header("X-Content-Type-Options", "nosniff")
+            .header("Strict-Transport-Security", "max-age=31536000")
+            .body("<html></html>");
+    });
+
+    let admin_mock = srv.mock(|when, then| {
+        when.method(GET).path("/admin");
+        then.status(200)
+            .header("Content-Type", "text/html")
+            .header("Content-Security-Policy", "default-src 'self'")
+            .body("<html></html>");
+    });
+
+    let license_mock = srv.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(200)
+            .header("Content-Type", "text/plain")
+            .body("not html");
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--collect-security-headers")
+        .arg("--no-recursion")
+        .assert()
+        .success()
+        .stderr(
+            predicate::str::is_match("X-Frame-Options +1/2")
+                .unwrap()
+                .and(predicate::str::is_match("Permissions-Policy +2/2").unwrap())
+                .and(predicate::str::contains("Content-Security-Policy").count(0)),
+        );
+
+    assert_eq!(login_mock.hits(), 1);
+    assert_eq!(admin_mock.hits(), 1);
+    assert_eq!(license_mock.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --sort-output should write results to --output once the scan completes, ordered by status and
+/// then by path instead of in the order they were found
+fn scanner_sort_output_writes_sorted_results() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(
+        &["zeta".to_string(), "beta".to_string(), "alpha".to_string()],
+        "wordlist",
+    )?;
+    let outfile = tmp_dir.path().join("output");
+
+    let zeta_mock = srv.mock(|when, then| {
+        when.method(GET).path("/zeta");
+        then.status(200).body("zeta");
+    });
+
+    let beta_mock = srv.mock(|when, then| {
+        when.method(GET).path("/beta");
+        then.status(403).body("beta");
+    });
+
+    let alpha_mock = srv.mock(|when, then| {
+        when.method(GET).path("/alpha");
+        then.status(403).body("alpha");
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--output")
+        .arg(outfile.as_os_str())
+        .arg("--sort-output")
+        .arg("--no-recursion")
+        .assert()
+        .success();
+
+    let contents = std::fs::read_to_string(outfile)?;
+    let paths: Vec<_> = ["/zeta", "/alpha", "/beta"]
+        .iter()
+        .map(|path| contents.find(&srv.url(*path)).unwrap())
+        .collect();
+
+    assert!(paths[0] < paths[1] && paths[1] < paths[2]);
+
+    assert_eq!(zeta_mock.hits(), 1);
+    assert_eq!(beta_mock.hits(), 1);
+    assert_eq!(alpha_mock.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --prefix-wordlist should insert each word in front of the target's path instead of appending
+/// it, and shouldn't recurse into what it finds
+fn scanner_prefix_wordlist_prepends_words() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) =
+        setup_tmp_directory(&["internal".to_string(), "public".to_string()], "wordlist")?;
+
+    let internal_mock = srv.mock(|when, then| {
+        when.method(GET).path("/internal/api/v1/");
+        then.status(200).body("internal api");
+    });
+
+    let public_mock = srv.mock(|when, then| {
+        when.method(GET).path("/public/api/v1/");
+        then.status(404);
+    });
+
+    let appended_mock = srv.mock(|when, then| {
+        when.method(GET).path("/api/v1/internal");
+        then.status(200);
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/api/v1/"))
+        .arg("--prefix-wordlist")
+        .arg(file.as_os_str())
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains(srv.url("/internal/api/v1/"))
+                .and(predicate::str::contains("/public/api/v1/").not()),
+        );
+
+    assert_eq!(internal_mock.hits(), 1);
+    assert_eq!(public_mock.hits(), 1);
+    assert_eq!(appended_mock.hits(), 0);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --pause-on-rate-limit should pause the scan for the number of seconds in a 429's Retry-After
+/// header, then finish the scan
+fn scanner_pause_on_rate_limit_waits_for_retry_after() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(&["limited".to_string()], "wordlist")?;
+
+    let limited_mock = srv.mock(|when, then| {
+        when.method(GET).path("/limited");
+        then.status(429).header("Retry-After", "2");
+    });
+
+    let start = time::Instant::now();
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--pause-on-rate-limit")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "received a 429 from {}, pausing all scans for 2s",
+            srv.url("/limited")
+        )));
+
+    assert!(start.elapsed() >= Duration::from_secs(2));
+    assert_eq!(limited_mock.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --collect-subpath-wordlist should save the path components of found urls to
+/// ferox-discovered-words-<host>-<timestamp>.txt, and --adaptive-wordlist should request the new ones
+fn scanner_collects_subpath_wordlist() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) =
+        setup_tmp_directory(&["api/v1".to_string(), "css".to_string()], "wordlist")?;
+
+    let found_mock = srv.mock(|when, then| {
+        when.method(GET).path("/api/v1");
+        then.status(200).body("found");
+    });
+
+    let api_mock = srv.mock(|when, then| {
+        when.method(GET).path("/api");
+        then.status(404);
+    });
+
+    let v1_mock = srv.mock(|when, then| {
+        when.method(GET).path("/v1");
+        then.status(404);
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .current_dir(tmp_dir.path())
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--no-recursion")
+        .arg("--threads")
+        .arg("1")
+        .arg("--collect-subpath-wordlist")
+        .arg("--adaptive-wordlist")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Saved 2 words"));
+
+    let words_file = find_report_file(
+        tmp_dir.path(),
+        "discovered-words",
+        &srv.address().to_string(),
+    )
+    .unwrap();
+
+    assert_eq!(std::fs::read_to_string(words_file)?, "api\nv1\n");
+
+    assert_eq!(found_mock.hits(), 1);
+    assert_eq!(api_mock.hits(), 1);
+    assert_eq!(v1_mock.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --threads-burst raises concurrency for the start of the scan; every word is still requested
+/// once, whether it's sent during the burst or after it
+fn scanner_threads_burst_requests_every_word() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let words: Vec<String> = (0..50).map(|i| format!("word{}", i)).collect();
+    let (tmp_dir, file) = setup_tmp_directory(&words, "wordlist")?;
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET)
+            .path_matches(regex::Regex::new("^/word[0-9]+$").unwrap());
+        then.status(200)
+            .body("this is a test")
+            .delay(Duration::from_millis(50));
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--threads")
+        .arg("2")
+        .arg("--threads-burst")
+        .arg("20")
+        .arg("--threads-burst-duration")
+        .arg("100")
+        .arg("--dont-filter")
+        .unwrap();
+
+    assert_eq!(mock.hits(), 50);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --watch-wordlist should request words that are added to the wordlist while a scan is still
+/// running
+fn scanner_watch_wordlist_requests_added_words() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(&["slow".to_string()], "wordlist")?;
+
+    let slow_mock = srv.mock(|when, then| {
+        when.method(GET).path("/slow");
+        then.status(200)
+            .body("this is a test")
+            .delay(Duration::from_millis(3000));
+    });
+
+    let added_mock = srv.mock(|when, then| {
+        when.method(GET).path("/added");
+        then.status(200).body("this is also a test");
+    });
+
+    let wordlist = file.clone();
+    let appender = std::thread::spawn(move || {
+        sleep(Duration::from_millis(1000));
+        std::fs::write(wordlist, "slow\nadded\n").unwrap();
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--watch-wordlist")
+        .arg("--dont-filter")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("/added"));
+
+    appender.join().unwrap();
+
+    assert_eq!(slow_mock.hits(), 1);
+    assert_eq!(added_mock.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// with --scan-limit-per-host, a scan waiting on its host shouldn't take up one of the
+/// --scan-limit slots, so a scan against another host can start right away
+fn scanner_scan_limit_per_host_doesnt_block_other_hosts() -> Result<(), Box<dyn std::error::Error>>
+{
+    let srv_a = MockServer::start();
+    let srv_b = MockServer::start();
+
+    // the same mock server reached via two different hostnames counts as two hosts
+    let targets = vec![
+        srv_a.url("/"),
+        srv_a.url("/api/"),
+        format!("http://localhost:{}/", srv_b.port()),
+    ];
+    let (tmp_dir, urls) = setup_tmp_directory(&targets, "targets")?;
+    let (words_dir, file) = setup_tmp_directory(&["slow".to_string()], "wordlist")?;
+
+    let slow_mock = srv_a.mock(|when, then| {
+        when.method(GET).path_contains("slow");
+        then.status(200).delay(Duration::from_secs(3));
+    });
+
+    let other_host_mock = srv_b.mock(|when, then| {
+        when.method(GET).path("/slow");
+        then.status(200);
+    });
+
+    let mut child = Command::cargo_bin("feroxbuster")?
+        .arg("--stdin")
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--scan-limit")
+        .arg("2")
+        .arg("--scan-limit-per-host")
+        .arg("1")
+        .arg("--no-recursion")
+        .arg("--dont-filter")
+        .stdin(std::fs::File::open(urls)?)
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()?;
+
+    // the first scan against srv_a takes at least 3 seconds; the scan against the other host
+    // should have started well before it's done
+    sleep(Duration::from_millis(2000));
+    let other_host_hits = other_host_mock.hits();
+
+    child.wait()?;
+
+    assert_eq!(other_host_hits, 1);
+    assert_eq!(slow_mock.hits(), 2);
+
+    teardown_tmp_directory(tmp_dir);
+    teardown_tmp_directory(words_dir);
+    Ok(())
+}
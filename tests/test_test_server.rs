@@ -0,0 +1,85 @@
+mod utils;
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::net::{TcpListener, TcpStream};
+use std::process::Command;
+use std::thread::sleep;
+use std::time::Duration;
+use utils::{setup_tmp_directory, teardown_tmp_directory};
+
+/// find a port that's (probably) free to listen on
+fn unused_port() -> u16 {
+    TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port()
+}
+
+#[test]
+/// --test-server should serve responses based on its rules file, and feroxbuster should be able
+/// to scan it without a wordlist or url being given to the server
+fn test_server_serves_responses_from_rules() -> Result<(), Box<dyn std::error::Error>> {
+    let (tmp_dir, file) = setup_tmp_directory(
+        &[
+            "admin".to_string(),
+            "secret".to_string(),
+            "nothing".to_string(),
+        ],
+        "wordlist",
+    )?;
+
+    let rules = tmp_dir.path().join("rules.toml");
+    std::fs::write(
+        &rules,
+        r#"
+[[rules]]
+path_pattern = "^/admin$"
+status = 200
+body = "welcome to the admin panel"
+
+[[rules]]
+path_pattern = "^/secret"
+status = 403
+"#,
+    )?;
+
+    let port = unused_port();
+
+    let mut server = Command::cargo_bin("feroxbuster")?
+        .arg("--test-server")
+        .arg(port.to_string())
+        .arg("--test-server-rules")
+        .arg(rules.as_os_str())
+        .spawn()?;
+
+    for _ in 0..50 {
+        if TcpStream::connect(("127.0.0.1", port)).is_ok() {
+            break;
+        }
+        sleep(Duration::from_millis(100));
+    }
+
+    let scan = Command::cargo_bin("feroxbuster")?
+        .arg("--url")
+        .arg(format!("http://127.0.0.1:{}", port))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--no-recursion")
+        .assert()
+        .try_success();
+
+    server.kill()?;
+    server.wait()?;
+
+    scan?.stdout(
+        predicate::str::contains("/admin")
+            .and(predicate::str::contains("200"))
+            .and(predicate::str::contains("/secret"))
+            .and(predicate::str::contains("403"))
+            .and(predicate::str::contains("/nothing").not()),
+    );
+
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
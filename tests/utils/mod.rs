@@ -1,5 +1,5 @@
-use std::fs::{remove_dir_all, write};
-use std::path::PathBuf;
+use std::fs::{read_dir, remove_dir_all, write};
+use std::path::{Path, PathBuf};
 use tempfile::TempDir;
 
 /// integration test helper: creates a temp directory, and writes `words` to
@@ -9,7 +9,7 @@ pub fn setup_tmp_directory(
     filename: &str,
 ) -> Result<(TempDir, PathBuf), Box<dyn std::error::Error>> {
     let tmp_dir = TempDir::new()?;
-    let file = tmp_dir.path().join(&filename);
+    let file = tmp_dir.path().join(filename);
     write(&file, words.join("\n"))?;
     Ok((tmp_dir, file))
 }
@@ -19,3 +19,25 @@ pub fn setup_tmp_directory(
 pub fn teardown_tmp_directory(directory: TempDir) {
     remove_dir_all(directory).unwrap();
 }
+
+/// integration test helper: finds the per-host report of the given `kind` (i.e. params) written
+/// for `host` to `directory`; the report's name ends in a timestamp, so it can't be built ahead
+/// of time
+#[allow(dead_code)]
+pub fn find_report_file(directory: &Path, kind: &str, host: &str) -> Option<PathBuf> {
+    let prefix = format!(
+        "ferox-{}-{}-",
+        kind,
+        host.replace([':', '[', ']', '.'], "_")
+    );
+
+    read_dir(directory)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .find(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(&prefix))
+        })
+}